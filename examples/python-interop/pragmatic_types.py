@@ -23,6 +23,7 @@ class Problem:
     plan: Plan
     fleet: Fleet
     objectives: Optional[List[List[Objective]]] = None
+    timezone: Optional[str] = None
 
 
 @dataclass
@@ -41,7 +42,7 @@ class Job:
 @dataclass
 class JobTask:
     places: List[JobPlace]
-    demand: List[int]
+    demand: List[float]
 
 
 @dataclass
@@ -50,6 +51,7 @@ class JobPlace:
     duration: float
     times: Optional[List[List[datetime]]] = None
     tag: Optional[str] = None
+    timezone: Optional[str] = None
 
 
 @dataclass
@@ -84,7 +86,7 @@ class VehicleType:
     profile: VehicleProfile
     costs: VehicleCosts
     shifts: List[VehicleShift]
-    capacity: List[int]
+    capacity: List[float]
 
 
 @dataclass
@@ -107,11 +109,20 @@ class VehicleShift:
     reloads: Optional[List[VehicleReload]] = None
 
 
+@dataclass
+class ShiftLocation:
+    location: Location
+    cost: Optional[float] = None
+    tag: Optional[str] = None
+
+
 @dataclass
 class VehicleShiftStart:
     earliest: datetime
     location: Location
     latest: Optional[datetime] = None
+    timezone: Optional[str] = None
+    alternatives: Optional[List[ShiftLocation]] = None
 
 
 @dataclass
@@ -119,6 +130,8 @@ class VehicleShiftEnd:
     latest: datetime
     location: Location
     earliest: Optional[datetime] = None
+    timezone: Optional[str] = None
+    alternatives: Optional[List[ShiftLocation]] = None
 
 
 @dataclass
@@ -200,7 +213,7 @@ class Stop:
     location: Location
     time: Schedule
     distance: int
-    load: List[int]
+    load: List[float]
     activities: List[Activity]
 
 
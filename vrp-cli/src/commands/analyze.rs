@@ -3,8 +3,12 @@
 mod analyze_test;
 
 use super::*;
-use vrp_cli::extensions::analyze::get_clusters;
+use clap::ArgAction;
+use vrp_cli::extensions::analyze::{get_clusters, get_matrix_report, repair_matrix, MatrixAnalysisParams};
 use vrp_core::utils::GenericError;
+use vrp_pragmatic::format::problem::{
+    deserialize_matrix, is_binary_matrix, is_compressed_binary_matrix, write_binary_matrix,
+};
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
@@ -12,50 +16,111 @@ const MATRIX_ARG_NAME: &str = "matrix";
 const MIN_POINTS_ARG_NAME: &str = "min-points";
 const EPSILON_ARG_NAME: &str = "epsilon";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
+const MATRICES_ARG_NAME: &str = "MATRICES";
+const OUT_MATRIX_ARG_NAME: &str = "out-matrix";
+const TOLERANCE_ARG_NAME: &str = "tolerance";
+const ASYMMETRY_RATIO_ARG_NAME: &str = "asymmetry-ratio";
+const MAX_CLOSURE_SIZE_ARG_NAME: &str = "max-closure-size";
+const FILL_UNREACHABLE_ARG_NAME: &str = "fill-unreachable";
 
 pub fn get_analyze_app() -> Command {
-    Command::new("analyze").about("Provides helper functionality to analyze problem or solution").subcommand(
-        Command::new("clusters")
-            .about("Analyzes job clusters")
-            .arg(
-                Arg::new(FORMAT_ARG_NAME)
-                    .help("Specifies input type")
-                    .required(true)
-                    .value_parser(["pragmatic"])
-                    .index(1),
-            )
-            .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
-            .arg(
-                Arg::new(MIN_POINTS_ARG_NAME)
-                    .help("Minimum cluster size")
-                    .short('c')
-                    .default_value("3")
-                    .long(MIN_POINTS_ARG_NAME)
-                    .required(false),
-            )
-            .arg(
-                Arg::new(EPSILON_ARG_NAME)
-                    .help("Epsilon parameter in DBSCAN")
-                    .short('e')
-                    .long(EPSILON_ARG_NAME)
-                    .required(false),
-            )
-            .arg(
-                Arg::new(MATRIX_ARG_NAME)
-                    .help("Specifies path to file with routing matrix")
-                    .short('m')
-                    .long(MATRIX_ARG_NAME)
-                    .num_args(1..)
-                    .required(false),
-            )
-            .arg(
-                Arg::new(OUT_RESULT_ARG_NAME)
-                    .help("Specifies path to the file for result output")
-                    .short('o')
-                    .long(OUT_RESULT_ARG_NAME)
-                    .required(true),
-            ),
-    )
+    Command::new("analyze")
+        .about("Provides helper functionality to analyze problem or solution")
+        .subcommand(
+            Command::new("clusters")
+                .about("Analyzes job clusters")
+                .arg(
+                    Arg::new(FORMAT_ARG_NAME)
+                        .help("Specifies input type")
+                        .required(true)
+                        .value_parser(["pragmatic"])
+                        .index(1),
+                )
+                .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+                .arg(
+                    Arg::new(MIN_POINTS_ARG_NAME)
+                        .help("Minimum cluster size")
+                        .short('c')
+                        .default_value("3")
+                        .long(MIN_POINTS_ARG_NAME)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(EPSILON_ARG_NAME)
+                        .help("Epsilon parameter in DBSCAN")
+                        .short('e')
+                        .long(EPSILON_ARG_NAME)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(MATRIX_ARG_NAME)
+                        .help("Specifies path to file with routing matrix in json or binary format")
+                        .short('m')
+                        .long(MATRIX_ARG_NAME)
+                        .num_args(1..)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(OUT_RESULT_ARG_NAME)
+                        .help("Specifies path to the file for result output")
+                        .short('o')
+                        .long(OUT_RESULT_ARG_NAME)
+                        .required(true),
+                ),
+        )
+        .subcommand(
+            Command::new("matrix")
+                .about("Analyzes routing matrix quality and optionally repairs it")
+                .arg(
+                    Arg::new(MATRICES_ARG_NAME)
+                        .help("Sets routing matrix files (one per profile) in json or binary format")
+                        .required(true)
+                        .num_args(1..)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new(TOLERANCE_ARG_NAME)
+                        .help("Relative tolerance used to detect triangle inequality violations")
+                        .short('t')
+                        .long(TOLERANCE_ARG_NAME)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(ASYMMETRY_RATIO_ARG_NAME)
+                        .help("Max ratio between values of opposite directions before they are reported as outlier")
+                        .short('a')
+                        .long(ASYMMETRY_RATIO_ARG_NAME)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(MAX_CLOSURE_SIZE_ARG_NAME)
+                        .help("Max amount of locations for which triangle inequality is checked and repaired")
+                        .long(MAX_CLOSURE_SIZE_ARG_NAME)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(FILL_UNREACHABLE_ARG_NAME)
+                        .help("Specifies whether unreachable pairs are replaced by shortest path in repaired matrices")
+                        .long(FILL_UNREACHABLE_ARG_NAME)
+                        .required(false)
+                        .requires(OUT_MATRIX_ARG_NAME)
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new(OUT_MATRIX_ARG_NAME)
+                        .help("Specifies paths to the files for repaired matrices output, one per input matrix, written in input format")
+                        .long(OUT_MATRIX_ARG_NAME)
+                        .num_args(1..)
+                        .required(false),
+                )
+                .arg(
+                    Arg::new(OUT_RESULT_ARG_NAME)
+                        .help("Specifies path to the file for report output")
+                        .short('o')
+                        .long(OUT_RESULT_ARG_NAME)
+                        .required(false),
+                ),
+        )
 }
 
 pub fn run_analyze(
@@ -89,6 +154,70 @@ pub fn run_analyze(
 
             geo_writer.write_all(clusters.as_bytes()).map_err(|err| format!("cannot write result: '{err}'").into())
         }
+        Some(("matrix", matrix_matches)) => {
+            // NOTE keep input format of each matrix to write repaired one in the same format
+            let (matrices, formats): (Vec<_>, Vec<_>) = matrix_matches
+                .get_many::<String>(MATRICES_ARG_NAME)
+                .unwrap()
+                .map(|path| {
+                    let mut reader = BufReader::new(open_file(path, "routing matrix"));
+                    let is_binary = is_binary_matrix(&mut reader).unwrap_or(false);
+                    let is_compressed = is_compressed_binary_matrix(&mut reader).unwrap_or(false);
+
+                    deserialize_matrix(reader)
+                        .map(|matrix| (matrix, (is_binary, is_compressed)))
+                        .map_err(|err| format!("cannot read routing matrix '{path}': '{err}'"))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .unzip();
+
+            let defaults = MatrixAnalysisParams::default();
+            let params = MatrixAnalysisParams {
+                triangle_tolerance: parse_float_value::<f64>(matrix_matches, TOLERANCE_ARG_NAME, "tolerance")?
+                    .unwrap_or(defaults.triangle_tolerance),
+                asymmetry_ratio: parse_float_value::<f64>(matrix_matches, ASYMMETRY_RATIO_ARG_NAME, "asymmetry ratio")?
+                    .unwrap_or(defaults.asymmetry_ratio),
+                max_closure_size: parse_int_value::<usize>(
+                    matrix_matches,
+                    MAX_CLOSURE_SIZE_ARG_NAME,
+                    "max closure size",
+                )?
+                .unwrap_or(defaults.max_closure_size),
+                fill_unreachable: matrix_matches.get_flag(FILL_UNREACHABLE_ARG_NAME),
+            };
+
+            if let Some(paths) = matrix_matches.get_many::<String>(OUT_MATRIX_ARG_NAME) {
+                let paths = paths.collect::<Vec<_>>();
+                if paths.len() != matrices.len() {
+                    return Err("amount of output matrix paths should match amount of input matrices".into());
+                }
+
+                matrices.iter().zip(formats).zip(paths).try_for_each(
+                    |((matrix, (is_binary, is_compressed)), path)| {
+                        let repaired = repair_matrix(matrix, &params)?;
+                        let writer = BufWriter::new(create_file(path, "out matrix"));
+
+                        if is_binary {
+                            write_binary_matrix(&repaired, is_compressed, writer)
+                                .map_err(|err| GenericError::from(format!("cannot write repaired matrix: '{err}'")))
+                        } else {
+                            serde_json::to_writer_pretty(writer, &repaired)
+                                .map_err(|err| GenericError::from(format!("cannot write repaired matrix: '{err}'")))
+                        }
+                    },
+                )?;
+            }
+
+            let report = get_matrix_report(matrices.as_slice(), &params)
+                .map_err(|err| GenericError::from(format!("cannot analyze matrix: '{err}'")))?;
+
+            let out_report =
+                matrix_matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out report"));
+            let mut report_writer = out_writer_func(out_report);
+
+            report_writer.write_all(report.as_bytes()).map_err(|err| format!("cannot write result: '{err}'").into())
+        }
         _ => Err("no argument with analyze subcommand was used. Use -h to print help information".into()),
     }
 }
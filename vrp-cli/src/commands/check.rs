@@ -25,7 +25,7 @@ pub fn get_check_app() -> Command {
         .arg(Arg::new(SOLUTION_ARG_NAME).help("Sets solution file").short('s').long(SOLUTION_ARG_NAME).required(true))
         .arg(
             Arg::new(MATRIX_ARG_NAME)
-                .help("Specifies path to file with routing matrix")
+                .help("Specifies path to file with routing matrix in json or binary format")
                 .short('m')
                 .long(MATRIX_ARG_NAME)
                 .required(false)
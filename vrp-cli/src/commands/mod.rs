@@ -4,6 +4,7 @@ pub mod analyze;
 pub mod check;
 pub mod generate;
 pub mod import;
+pub mod simulate;
 pub mod solve;
 
 use std::fs::File;
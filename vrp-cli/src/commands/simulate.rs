@@ -0,0 +1,123 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/simulate_test.rs"]
+mod simulate_test;
+
+use super::*;
+use std::sync::Arc;
+use vrp_cli::extensions::simulate::{simulate_pragmatic_solution, NoiseDistribution, SeededRandom, SimulationParams};
+use vrp_core::utils::{DefaultRandom, Random};
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const PROBLEM_ARG_NAME: &str = "problem-file";
+const SOLUTION_ARG_NAME: &str = "solution-file";
+const MATRIX_ARG_NAME: &str = "matrix";
+const ITERATIONS_ARG_NAME: &str = "iterations";
+const TRAVEL_DEVIATION_ARG_NAME: &str = "travel-deviation";
+const SERVICE_DEVIATION_ARG_NAME: &str = "service-deviation";
+const DISTRIBUTION_ARG_NAME: &str = "distribution";
+const SEED_ARG_NAME: &str = "seed";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_simulate_app() -> Command {
+    Command::new("simulate")
+        .about("Simulates solution execution with noisy travel and service durations")
+        .arg(Arg::new(FORMAT_ARG_NAME).help("Specifies input type").required(true).value_parser(["pragmatic"]).index(1))
+        .arg(
+            Arg::new(PROBLEM_ARG_NAME)
+                .help("Sets input file which contains a VRP definition")
+                .short('p')
+                .long(PROBLEM_ARG_NAME)
+                .required(true),
+        )
+        .arg(Arg::new(SOLUTION_ARG_NAME).help("Sets solution file").short('s').long(SOLUTION_ARG_NAME).required(true))
+        .arg(
+            Arg::new(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix in json or binary format")
+                .short('m')
+                .long(MATRIX_ARG_NAME)
+                .required(false)
+                .num_args(1..),
+        )
+        .arg(
+            Arg::new(ITERATIONS_ARG_NAME)
+                .help("Specifies amount of simulation runs")
+                .short('n')
+                .long(ITERATIONS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(TRAVEL_DEVIATION_ARG_NAME)
+                .help("Specifies standard deviation of travel durations relative to their value")
+                .long(TRAVEL_DEVIATION_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(SERVICE_DEVIATION_ARG_NAME)
+                .help("Specifies standard deviation of service durations relative to their value")
+                .long(SERVICE_DEVIATION_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(DISTRIBUTION_ARG_NAME)
+                .help("Specifies distribution of noise")
+                .long(DISTRIBUTION_ARG_NAME)
+                .value_parser(["normal", "gamma"])
+                .default_value("normal")
+                .required(false),
+        )
+        .arg(
+            Arg::new(SEED_ARG_NAME)
+                .help("Specifies seed of random generator to get reproducible results")
+                .long(SEED_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for report output")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false),
+        )
+}
+
+pub fn run_simulate(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> Result<(), GenericError> {
+    let input_format = matches.get_one::<String>(FORMAT_ARG_NAME).unwrap();
+    if input_format != "pragmatic" {
+        return Err(format!("unknown format: '{input_format}'").into());
+    }
+
+    let problem_reader = BufReader::new(open_file(matches.get_one::<String>(PROBLEM_ARG_NAME).unwrap(), "problem"));
+    let solution_reader = BufReader::new(open_file(matches.get_one::<String>(SOLUTION_ARG_NAME).unwrap(), "solution"));
+    let matrices_readers = matches
+        .get_many::<String>(MATRIX_ARG_NAME)
+        .map(|paths| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect());
+
+    let mut params = SimulationParams::default();
+    params.iterations =
+        parse_int_value::<usize>(matches, ITERATIONS_ARG_NAME, "iterations")?.unwrap_or(params.iterations);
+    params.noise.travel_deviation = parse_float_value::<f64>(matches, TRAVEL_DEVIATION_ARG_NAME, "travel deviation")?
+        .unwrap_or(params.noise.travel_deviation);
+    params.noise.service_deviation =
+        parse_float_value::<f64>(matches, SERVICE_DEVIATION_ARG_NAME, "service deviation")?
+            .unwrap_or(params.noise.service_deviation);
+    params.noise.distribution = match matches.get_one::<String>(DISTRIBUTION_ARG_NAME).map(|value| value.as_str()) {
+        Some("gamma") => NoiseDistribution::Gamma,
+        _ => NoiseDistribution::Normal,
+    };
+
+    let random: Arc<dyn Random> = match parse_int_value::<u64>(matches, SEED_ARG_NAME, "seed")? {
+        Some(seed) => Arc::new(SeededRandom::new(seed)),
+        None => Arc::new(DefaultRandom::default()),
+    };
+
+    let report = simulate_pragmatic_solution(problem_reader, solution_reader, matrices_readers, &params, random)
+        .map_err(|err| GenericError::from(format!("cannot simulate solution: '{err}'")))?;
+
+    let out_report = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out report"));
+    let mut report_writer = out_writer_func(out_report);
+
+    report_writer.write_all(report.as_bytes()).map_err(|err| format!("cannot write result: '{err}'").into())
+}
@@ -26,6 +26,8 @@ use vrp_pragmatic::format::solution::{write_pragmatic, PragmaticOutputType};
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
 const MATRIX_ARG_NAME: &str = "matrix";
+const OSM_ARG_NAME: &str = "osm";
+const OSM_PROFILE_ARG_NAME: &str = "osm-profile";
 const GENERATIONS_ARG_NAME: &str = "max-generations";
 const TIME_ARG_NAME: &str = "max-time";
 const MIN_CV_ARG_NAME: &str = "min-cv";
@@ -119,15 +121,25 @@ fn add_scientific(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn
     }
 }
 
-fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random>) {
+fn add_pragmatic(formats: &mut FormatMap, matches: &ArgMatches, random: Arc<dyn Random>) {
     use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
     use vrp_pragmatic::format::solution::read_init_solution as read_init_pragmatic;
 
+    let osm_path = matches.get_one::<String>(OSM_ARG_NAME).cloned();
+    let osm_profiles = matches
+        .get_many::<String>(OSM_PROFILE_ARG_NAME)
+        .map(|values| values.cloned().collect::<Vec<_>>())
+        .unwrap_or_default();
+
     formats.insert(
         "pragmatic",
         (
-            ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
-                if let Some(matrices) = matrices {
+            ProblemReader(Box::new(move |problem: File, matrices: Option<Vec<File>>| {
+                if let Some(osm_path) = osm_path.as_ref() {
+                    let problem = deserialize_problem(BufReader::new(problem))?;
+                    let matrices = read_osm_matrices(&problem, osm_path, osm_profiles.as_slice())?;
+                    (problem, matrices).read_pragmatic()
+                } else if let Some(matrices) = matrices {
                     let matrices = matrices.into_iter().map(BufReader::new).collect();
                     (BufReader::new(problem), matrices).read_pragmatic()
                 } else {
@@ -160,7 +172,7 @@ fn get_formats<'a>(matches: &ArgMatches, random: Arc<dyn Random>) -> FormatMap<'
     let mut formats = FormatMap::default();
 
     add_scientific(&mut formats, matches, random.clone());
-    add_pragmatic(&mut formats, random);
+    add_pragmatic(&mut formats, matches, random);
 
     formats
 }
@@ -214,12 +226,28 @@ pub fn get_solve_app() -> Command {
         )
         .arg(
             Arg::new(MATRIX_ARG_NAME)
-                .help("Specifies path to file with routing matrix")
+                .help("Specifies path to file with routing matrix in json or binary format")
                 .short('m')
                 .long(MATRIX_ARG_NAME)
                 .num_args(1..)
                 .required(false)
         )
+        .arg(
+            Arg::new(OSM_ARG_NAME)
+                .help("Specifies path to OpenStreetMap extract in pbf format used to calculate routing matrices offline")
+                .long(OSM_ARG_NAME)
+                .required(false)
+                .conflicts_with_all([MATRIX_ARG_NAME, CHECK_ARG_NAME])
+        )
+        .arg(
+            Arg::new(OSM_PROFILE_ARG_NAME)
+                .help("Specifies routing profile (car, truck or bike) per fleet profile used with OpenStreetMap extract, \
+                e.g. 'normal_car=car'")
+                .long(OSM_PROFILE_ARG_NAME)
+                .num_args(1..)
+                .required(false)
+                .requires(OSM_ARG_NAME)
+        )
         .arg(
             Arg::new(OUT_RESULT_ARG_NAME)
                 .help("Specifies path to file for result output")
@@ -502,6 +530,40 @@ fn get_matrix_files(matches: &ArgMatches) -> Option<Vec<File>> {
         .map(|paths| paths.map(|path| open_file(path, "routing matrix")).collect())
 }
 
+#[cfg(feature = "osm-routing")]
+fn read_osm_matrices(
+    problem: &vrp_pragmatic::format::problem::Problem,
+    osm_path: &str,
+    osm_profiles: &[String],
+) -> GenericResult<Vec<vrp_pragmatic::format::problem::Matrix>> {
+    use vrp_cli::extensions::routing::{create_osm_matrices, RoutingProfile};
+
+    let routing_profiles = osm_profiles
+        .iter()
+        .map(|value| {
+            let (name, routing_profile) = value.split_once('=').ok_or_else(|| {
+                GenericError::from(format!(
+                    "invalid osm profile: '{value}', expected '<fleet profile>=<car|truck|bike>'"
+                ))
+            })?;
+
+            RoutingProfile::from_name(routing_profile).map(|routing_profile| (name.to_string(), routing_profile))
+        })
+        .collect::<GenericResult<HashMap<_, _>>>()?;
+
+    create_osm_matrices(problem, BufReader::new(open_file(osm_path, "osm extract")), &routing_profiles)
+        .map_err(|err| format!("cannot create routing matrices from osm extract: '{err}'").into())
+}
+
+#[cfg(not(feature = "osm-routing"))]
+fn read_osm_matrices(
+    _: &vrp_pragmatic::format::problem::Problem,
+    _: &str,
+    _: &[String],
+) -> GenericResult<Vec<vrp_pragmatic::format::problem::Matrix>> {
+    Err("osm-routing feature is not included".into())
+}
+
 fn get_population(
     mode: Option<&String>,
     objective: Arc<GoalContext>,
@@ -0,0 +1,346 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/matrix_test.rs"]
+mod matrix_test;
+
+use serde::Serialize;
+use std::io::{BufWriter, Write};
+use vrp_core::prelude::{GenericError, GenericResult};
+use vrp_pragmatic::format::problem::Matrix;
+
+/// Specifies parameters of routing matrix analysis.
+pub struct MatrixAnalysisParams {
+    /// A relative tolerance used to detect triangle inequality violations: a value is reported when it is bigger
+    /// than the shortest path via other locations multiplied by `1 + tolerance`.
+    pub triangle_tolerance: f64,
+    /// A max ratio between values of opposite directions before they are reported as asymmetric outlier.
+    pub asymmetry_ratio: f64,
+    /// A max matrix size (amount of locations) for which shortest path closure is calculated. As its complexity is
+    /// cubic, triangle inequality is not checked for bigger matrices.
+    pub max_closure_size: usize,
+    /// Whether unreachable pairs are replaced by shortest path via other locations in repaired matrix. By default,
+    /// they are only reported as routing engine might mark them unreachable on purpose.
+    pub fill_unreachable: bool,
+}
+
+impl Default for MatrixAnalysisParams {
+    fn default() -> Self {
+        Self { triangle_tolerance: 0.01, asymmetry_ratio: 3., max_closure_size: 1000, fill_unreachable: false }
+    }
+}
+
+/// Specifies a kind of routing matrix anomaly.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MatrixAnomalyKind {
+    /// Travelling via another location is faster than the direct route.
+    TriangleInequality,
+    /// The value differs too much from the value of the opposite direction.
+    AsymmetricOutlier,
+    /// Travel duration is zero while distance is not.
+    ZeroDuration,
+    /// Location is marked as unreachable.
+    Unreachable,
+}
+
+/// Describes a single routing matrix anomaly.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatrixAnomaly {
+    /// A kind of anomaly.
+    pub kind: MatrixAnomalyKind,
+    /// An index of start location.
+    pub from: usize,
+    /// An index of end location.
+    pub to: usize,
+    /// An original travel duration.
+    pub duration: i64,
+    /// An original travel distance.
+    pub distance: i64,
+    /// A travel duration used by repaired matrix, if the anomaly can be repaired.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_duration: Option<i64>,
+    /// A travel distance used by repaired matrix, if the anomaly can be repaired.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_distance: Option<i64>,
+}
+
+/// Keeps amount of anomalies by their kind.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatrixAnomalyCounts {
+    /// Amount of triangle inequality violations.
+    pub triangle_inequality: usize,
+    /// Amount of asymmetric outliers.
+    pub asymmetric_outlier: usize,
+    /// Amount of zero durations with non zero distance.
+    pub zero_duration: usize,
+    /// Amount of unreachable pairs.
+    pub unreachable: usize,
+}
+
+impl MatrixAnomalyCounts {
+    /// Returns total amount of anomalies.
+    pub fn total(&self) -> usize {
+        self.triangle_inequality + self.asymmetric_outlier + self.zero_duration + self.unreachable
+    }
+
+    fn add(&mut self, kind: MatrixAnomalyKind) {
+        match kind {
+            MatrixAnomalyKind::TriangleInequality => self.triangle_inequality += 1,
+            MatrixAnomalyKind::AsymmetricOutlier => self.asymmetric_outlier += 1,
+            MatrixAnomalyKind::ZeroDuration => self.zero_duration += 1,
+            MatrixAnomalyKind::Unreachable => self.unreachable += 1,
+        }
+    }
+}
+
+/// Keeps amount of anomalies related to a specific location.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationAnomalies {
+    /// A location index.
+    pub index: usize,
+    /// Amount of anomalies where location is used as start or end.
+    #[serde(flatten)]
+    pub counts: MatrixAnomalyCounts,
+}
+
+/// Contains routing matrix analysis results of a single profile.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatrixReport {
+    /// A name of profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// A date for which routing info is applicable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<String>,
+    /// Amount of locations.
+    pub size: usize,
+    /// Whether triangle inequality is checked: it is skipped for big matrices.
+    pub is_triangle_checked: bool,
+    /// Total amount of anomalies by their kind.
+    pub summary: MatrixAnomalyCounts,
+    /// Amount of anomalies per location, only locations with anomalies are listed.
+    pub locations: Vec<LocationAnomalies>,
+    /// All found anomalies.
+    pub anomalies: Vec<MatrixAnomaly>,
+}
+
+/// Analyzes routing matrix and reports its anomalies which might lead to strange routes.
+pub fn analyze_matrix(matrix: &Matrix, params: &MatrixAnalysisParams) -> GenericResult<MatrixReport> {
+    let size = get_matrix_size(matrix)?;
+    let (anomalies, _, is_triangle_checked) = diagnose_matrix(matrix, size, params);
+
+    let mut summary = MatrixAnomalyCounts::default();
+    let mut locations = vec![MatrixAnomalyCounts::default(); size];
+    anomalies.iter().for_each(|anomaly| {
+        summary.add(anomaly.kind);
+        locations[anomaly.from].add(anomaly.kind);
+        if anomaly.from != anomaly.to {
+            locations[anomaly.to].add(anomaly.kind);
+        }
+    });
+
+    let locations = locations
+        .into_iter()
+        .enumerate()
+        .filter(|(_, counts)| counts.total() > 0)
+        .map(|(index, counts)| LocationAnomalies { index, counts })
+        .collect();
+
+    Ok(MatrixReport {
+        profile: matrix.profile.clone(),
+        timestamp: matrix.timestamp.clone(),
+        size,
+        is_triangle_checked,
+        summary,
+        locations,
+        anomalies,
+    })
+}
+
+/// Returns a repaired copy of routing matrix:
+/// * zero durations with non zero distance are estimated using average speed
+/// * asymmetric outliers are capped using the value of the opposite direction
+/// * values which violate triangle inequality are replaced by shortest path via other locations (small matrices only)
+/// * unreachable pairs are replaced by shortest path when it exists and filling is requested (small matrices only)
+pub fn repair_matrix(matrix: &Matrix, params: &MatrixAnalysisParams) -> GenericResult<Matrix> {
+    let size = get_matrix_size(matrix)?;
+    let (_, repaired, _) = diagnose_matrix(matrix, size, params);
+
+    Ok(repaired)
+}
+
+/// Analyzes routing matrices and returns report serialized as json.
+pub fn get_matrix_report(matrices: &[Matrix], params: &MatrixAnalysisParams) -> GenericResult<String> {
+    let reports = matrices.iter().map(|matrix| analyze_matrix(matrix, params)).collect::<GenericResult<Vec<_>>>()?;
+
+    let mut writer = BufWriter::new(Vec::new());
+    serde_json::to_writer_pretty(&mut writer, &reports).map_err(|err| format!("cannot write report: '{err}'"))?;
+    writer.flush().map_err(GenericError::from)?;
+
+    let bytes = writer.into_inner().map_err(|err| format!("{err}"))?;
+
+    String::from_utf8(bytes).map_err(|err| format!("{err}").into())
+}
+
+fn get_matrix_size(matrix: &Matrix) -> GenericResult<usize> {
+    let length = matrix.travel_times.len();
+    let size = (length as f64).sqrt().round() as usize;
+
+    if size * size != length
+        || matrix.distances.len() != length
+        || matrix.error_codes.as_ref().is_some_and(|codes| codes.len() != length)
+    {
+        return Err("matrix is not square or has arrays of different length".into());
+    }
+
+    Ok(size)
+}
+
+/// Finds anomalies and repairs them. Returns anomalies, repaired matrix and a flag whether triangle inequality
+/// has been checked.
+fn diagnose_matrix(matrix: &Matrix, size: usize, params: &MatrixAnalysisParams) -> (Vec<MatrixAnomaly>, Matrix, bool) {
+    let is_reachable = |idx: usize| {
+        matrix.error_codes.as_ref().is_none_or(|codes| codes[idx] == 0)
+            && matrix.travel_times[idx] >= 0
+            && matrix.distances[idx] >= 0
+    };
+    let create_anomaly = |kind, from, to, expected: Option<(i64, i64)>| {
+        let idx = from * size + to;
+        MatrixAnomaly {
+            kind,
+            from,
+            to,
+            duration: matrix.travel_times[idx],
+            distance: matrix.distances[idx],
+            expected_duration: expected.map(|(duration, _)| duration),
+            expected_distance: expected.map(|(_, distance)| distance),
+        }
+    };
+
+    let pairs = || (0..size).flat_map(|from| (0..size).map(move |to| (from, to))).filter(|(from, to)| from != to);
+
+    // NOTE repaired values are kept only for reachable pairs, None means unreachable
+    let mut values = (0..size * size)
+        .map(|idx| {
+            if idx % (size + 1) == 0 {
+                Some((0, 0))
+            } else {
+                Some((matrix.travel_times[idx], matrix.distances[idx])).filter(|_| is_reachable(idx))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut anomalies = Vec::default();
+
+    // estimate zero durations using average speed
+    let (total_duration, total_distance) = values
+        .iter()
+        .flatten()
+        .filter(|(duration, distance)| *duration > 0 && *distance > 0)
+        .fold((0_i64, 0_i64), |acc, (duration, distance)| (acc.0 + duration, acc.1 + distance));
+    let speed = if total_duration > 0 { Some(total_distance as f64 / total_duration as f64) } else { None };
+
+    pairs().for_each(|(from, to)| {
+        let idx = from * size + to;
+        if let Some((0, distance)) = values[idx].filter(|&(duration, distance)| duration == 0 && distance > 0) {
+            let expected = speed.map(|speed| (((distance as f64 / speed).round() as i64).max(1), distance));
+            if let Some(expected) = expected {
+                values[idx] = Some(expected);
+            }
+            anomalies.push(create_anomaly(MatrixAnomalyKind::ZeroDuration, from, to, expected));
+        }
+    });
+
+    // cap asymmetric outliers
+    let cap = |value: i64, opposite: i64| {
+        if value > 0 && opposite > 0 && value as f64 > opposite as f64 * params.asymmetry_ratio {
+            (opposite as f64 * params.asymmetry_ratio).round() as i64
+        } else {
+            value
+        }
+    };
+    // NOTE use unmodified copy, otherwise the opposite value can be already capped
+    let original = values.clone();
+    pairs().for_each(|(from, to)| {
+        let (idx, opposite_idx) = (from * size + to, to * size + from);
+        if let (Some((duration, distance)), Some((opposite_duration, opposite_distance))) =
+            (original[idx], original[opposite_idx])
+        {
+            let expected = (cap(duration, opposite_duration), cap(distance, opposite_distance));
+            if expected != (duration, distance) {
+                values[idx] = Some(expected);
+                anomalies.push(create_anomaly(MatrixAnomalyKind::AsymmetricOutlier, from, to, Some(expected)));
+            }
+        }
+    });
+
+    // calculate shortest path closure using durations, distances are taken from the same path
+    let is_triangle_checked = size <= params.max_closure_size;
+    let closure = if is_triangle_checked { Some(get_shortest_path_closure(values.as_slice(), size)) } else { None };
+
+    pairs().for_each(|(from, to)| {
+        let idx = from * size + to;
+        let shortest = closure.as_ref().and_then(|closure| closure[idx]);
+
+        match (values[idx], shortest) {
+            (Some((duration, _)), Some(shortest))
+                if duration as f64 > shortest.0 as f64 * (1. + params.triangle_tolerance) =>
+            {
+                values[idx] = Some(shortest);
+                anomalies.push(create_anomaly(MatrixAnomalyKind::TriangleInequality, from, to, Some(shortest)));
+            }
+            (None, shortest) => {
+                let expected = shortest.filter(|_| params.fill_unreachable);
+                values[idx] = expected;
+                anomalies.push(create_anomaly(MatrixAnomalyKind::Unreachable, from, to, expected));
+            }
+            _ => {}
+        }
+    });
+
+    anomalies.sort_by_key(|anomaly| (anomaly.from, anomaly.to));
+
+    let travel_times =
+        (0..size * size).map(|idx| values[idx].map_or(matrix.travel_times[idx], |(duration, _)| duration)).collect();
+    let distances =
+        (0..size * size).map(|idx| values[idx].map_or(matrix.distances[idx], |(_, distance)| distance)).collect();
+    let error_codes = matrix.error_codes.as_ref().map(|codes| {
+        codes.iter().enumerate().map(|(idx, &code)| if values[idx].is_some() { 0 } else { code }).collect()
+    });
+
+    let repaired = Matrix {
+        profile: matrix.profile.clone(),
+        timestamp: matrix.timestamp.clone(),
+        travel_times,
+        distances,
+        error_codes,
+    };
+
+    (anomalies, repaired, is_triangle_checked)
+}
+
+/// Calculates shortest paths between all pairs of locations using Floyd-Warshall algorithm.
+fn get_shortest_path_closure(values: &[Option<(i64, i64)>], size: usize) -> Vec<Option<(i64, i64)>> {
+    let mut closure = values.to_vec();
+
+    for via in 0..size {
+        for from in 0..size {
+            let Some((first_duration, first_distance)) = closure[from * size + via] else { continue };
+
+            for to in 0..size {
+                let Some((second_duration, second_distance)) = closure[via * size + to] else { continue };
+
+                let idx = from * size + to;
+                let duration = first_duration + second_duration;
+                if closure[idx].is_none_or(|(current, _)| duration < current) {
+                    closure[idx] = Some((duration, first_distance + second_distance));
+                }
+            }
+        }
+    }
+
+    closure
+}
@@ -2,3 +2,6 @@
 
 mod clusters;
 pub use self::clusters::get_clusters;
+
+mod matrix;
+pub use self::matrix::*;
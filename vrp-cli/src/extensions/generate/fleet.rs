@@ -33,6 +33,8 @@ pub(crate) fn generate_fleet(problem_proto: &Problem, vehicle_types_size: usize)
                 capacity: get_random_item(capacities.as_slice(), &rnd).expect("cannot find any capacity").clone(),
                 skills: get_random_item(skills.as_slice(), &rnd).expect("cannot find any skills").clone(),
                 limits: get_random_item(limits.as_slice(), &rnd).expect("cannot find any limits").clone(),
+                trailer: None,
+                backhaul: None,
             }
         })
         .collect();
@@ -55,11 +57,11 @@ fn get_vehicle_shifts(problem_proto: &Problem) -> Vec<Vec<VehicleShift>> {
     get_from_vehicle(problem_proto, |vehicle| vehicle.shifts.clone())
 }
 
-fn get_vehicle_capacities(problem_proto: &Problem) -> Vec<Vec<i32>> {
+fn get_vehicle_capacities(problem_proto: &Problem) -> Vec<Vec<f64>> {
     get_from_vehicle(problem_proto, |vehicle| vehicle.capacity.clone())
 }
 
-fn get_vehicle_skills(problem_proto: &Problem) -> Vec<Option<Vec<String>>> {
+fn get_vehicle_skills(problem_proto: &Problem) -> Vec<Option<Vec<VehicleSkill>>> {
     get_from_vehicle(problem_proto, |vehicle| vehicle.skills.clone())
 }
 
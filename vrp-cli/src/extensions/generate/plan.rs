@@ -38,6 +38,7 @@ pub(crate) fn generate_plan(
                             duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
                             tag: place.tag.clone(),
+                            timezone: None,
                         })
                         .collect(),
                     demand: if keep_original_demand {
@@ -67,13 +68,19 @@ pub(crate) fn generate_plan(
                 services: generate_tasks(&job_proto.services, true),
                 skills: job_proto.skills.clone(),
                 value: job_proto.value,
+                value_decay: None,
                 group: job_proto.group.clone(),
                 compatibility: job_proto.compatibility.clone(),
+                conflicts: None,
+                affinity: None,
+                transfers: None,
+                truck_only: None,
+                divisible: None,
             }
         })
         .collect();
 
-    Ok(Plan { jobs, relations: None, clustering: None })
+    Ok(Plan { jobs, relations: None, clustering: None, transfers: None })
 }
 
 type LocationFn = Box<dyn Fn(&DefaultRandom) -> Location>;
@@ -158,7 +165,7 @@ fn get_plan_time_windows(plan: &Plan) -> Vec<Vec<Vec<String>>> {
     get_plan_places(plan).flat_map(|job_place| job_place.times.iter()).cloned().collect()
 }
 
-fn get_plan_demands(plan: &Plan) -> Vec<Vec<i32>> {
+fn get_plan_demands(plan: &Plan) -> Vec<Vec<f64>> {
     plan.jobs.iter().flat_map(get_job_tasks).filter_map(|job_task| job_task.demand.as_ref()).cloned().collect()
 }
 
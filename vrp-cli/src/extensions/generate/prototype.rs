@@ -26,5 +26,7 @@ pub(crate) fn generate_from_prototype(
         plan: generate_plan(problem, locations, jobs_size, area_size)?,
         fleet: generate_fleet(problem, vehicle_types_size),
         objectives: problem.objectives.clone(),
+        timezone: None,
+        matrix_storage: None,
     })
 }
@@ -23,7 +23,7 @@ mod actual {
         id: String,
         lat: f64,
         lng: f64,
-        demand: i32,
+        demand: f64,
         duration: usize,
         tw_start: Option<String>,
         tw_end: Option<String>,
@@ -35,7 +35,7 @@ mod actual {
         id: String,
         lat: f64,
         lng: f64,
-        capacity: i32,
+        capacity: f64,
         tw_start: String,
         tw_end: String,
         amount: usize,
@@ -70,8 +70,9 @@ mod actual {
                 duration: job.duration as f64,
                 times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
                 tag: None,
+                timezone: None,
             }],
-            demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
+            demand: if job.demand != 0. { Some(vec![job.demand.abs()]) } else { None },
             order: None,
         };
 
@@ -93,14 +94,20 @@ mod actual {
             .into_iter()
             .map(|(job_id, tasks)| Job {
                 id: job_id.clone(),
-                pickups: get_tasks(&tasks, Box::new(|j| j.demand > 0)),
-                deliveries: get_tasks(&tasks, Box::new(|j| j.demand < 0)),
+                pickups: get_tasks(&tasks, Box::new(|j| j.demand > 0.)),
+                deliveries: get_tasks(&tasks, Box::new(|j| j.demand < 0.)),
                 replacements: None,
-                services: get_tasks(&tasks, Box::new(|j| j.demand == 0)),
+                services: get_tasks(&tasks, Box::new(|j| j.demand == 0.)),
                 skills: None,
                 value: None,
+                value_decay: None,
                 group: None,
                 compatibility: None,
+                conflicts: None,
+                affinity: None,
+                transfers: None,
+                truck_only: None,
+                divisible: None,
             })
             .collect();
 
@@ -123,15 +130,29 @@ mod actual {
                             earliest: vehicle.tw_start,
                             latest: None,
                             location: depot_location.clone(),
+                            timezone: None,
+                            alternatives: None,
                         },
-                        end: Some(ShiftEnd { earliest: None, latest: vehicle.tw_end, location: depot_location }),
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: vehicle.tw_end,
+                            location: depot_location,
+                            timezone: None,
+                            alternatives: None,
+                        }),
                         breaks: None,
                         reloads: None,
                         recharges: None,
+                        capacity: None,
+                        skills: None,
+                        limits: None,
+                        costs: None,
                     }],
                     capacity: vec![vehicle.capacity],
                     skills: None,
                     limits: None,
+                    trailer: None,
+                    backhaul: None,
                 }
             })
             .collect();
@@ -158,13 +179,25 @@ mod actual {
         let matrix_profile_names = vehicles.iter().map(|v| v.profile.matrix.clone()).collect::<HashSet<_>>();
 
         Ok(Problem {
-            plan: Plan { jobs, relations: None, clustering: None },
+            plan: Plan { jobs, relations: None, clustering: None, transfers: None },
             fleet: Fleet {
                 vehicles,
-                profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
+                profiles: matrix_profile_names
+                    .into_iter()
+                    .map(|name| MatrixProfile {
+                        name,
+                        speed: None,
+                        speed_bands: None,
+                        overlay: None,
+                        distance_model: None,
+                        travel_time_uncertainty: None,
+                    })
+                    .collect(),
                 resources: None,
             },
             objectives: None,
+            timezone: None,
+            matrix_storage: None,
         })
     }
 }
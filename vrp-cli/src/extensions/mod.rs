@@ -8,4 +8,8 @@ pub mod check;
 pub mod generate;
 
 pub mod import;
+#[cfg(all(not(target_arch = "wasm32"), feature = "osm-routing"))]
+pub mod routing;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod simulate;
 pub mod solve;
@@ -0,0 +1,352 @@
+//! A road network graph with shortest path search.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/routing/graph_test.rs"]
+mod graph_test;
+
+use super::OsmData;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use vrp_core::prelude::GenericResult;
+use vrp_core::utils::{get_haversine_distance, parallel_collect};
+
+/// A size of spatial index cell in degrees.
+const CELL_SIZE: f64 = 0.01;
+/// Max amount of spatial index cell rings checked around location.
+const MAX_SNAP_RINGS: i32 = 5;
+
+/// Specifies a routing profile which defines road access and speeds.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RoutingProfile {
+    /// A passenger car.
+    Car,
+    /// A truck: lower speeds and no access to roads restricted for heavy goods vehicles.
+    Truck,
+    /// A bicycle: no access to motorways, access to cycleways and paths.
+    Bike,
+}
+
+impl RoutingProfile {
+    /// Gets routing profile by its exact name: `car`, `truck` or `bike`.
+    pub fn from_name(name: &str) -> GenericResult<Self> {
+        match name {
+            "car" => Ok(Self::Car),
+            "truck" => Ok(Self::Truck),
+            "bike" => Ok(Self::Bike),
+            _ => Err(format!("unknown routing profile: '{name}', expected one of: car, truck, bike").into()),
+        }
+    }
+
+    /// Returns speed in meters per second on the way with given tags or `None` if the way is not accessible.
+    pub fn get_speed(&self, tags: &HashMap<String, String>) -> Option<f64> {
+        let tag = |key: &str| tags.get(key).map(String::as_str);
+        let is_denied = |key: &str| matches!(tag(key), Some("no" | "private"));
+
+        if is_denied("access") && !matches!(tag(self.access_key()), Some("yes" | "designated")) {
+            return None;
+        }
+
+        let speed = match self {
+            Self::Car | Self::Truck if is_denied("motor_vehicle") => None,
+            Self::Truck if is_denied("hgv") => None,
+            Self::Bike if is_denied("bicycle") => None,
+            Self::Car => get_car_speed(tag("highway")?),
+            Self::Truck => get_truck_speed(tag("highway")?),
+            Self::Bike => get_bike_speed(tag("highway")?, tag("bicycle")),
+        }?;
+
+        let max_speed = match self {
+            Self::Car | Self::Truck => tag("maxspeed").and_then(parse_max_speed),
+            Self::Bike => None,
+        };
+
+        Some(max_speed.map_or(speed, |max_speed| speed.min(max_speed)) / 3.6)
+    }
+
+    /// Returns speed in meters per second used to reach the road network from the location.
+    fn access_speed(&self) -> f64 {
+        match self {
+            Self::Car => 15. / 3.6,
+            Self::Truck | Self::Bike => 10. / 3.6,
+        }
+    }
+
+    fn access_key(&self) -> &str {
+        match self {
+            Self::Car => "motorcar",
+            Self::Truck => "hgv",
+            Self::Bike => "bicycle",
+        }
+    }
+}
+
+/// A location snapped to the road network.
+#[derive(Clone, Copy, Debug)]
+pub struct SnappedLocation {
+    /// A graph node index.
+    pub node: usize,
+    /// A distance between location and graph node in meters.
+    pub distance: f64,
+}
+
+/// A road network for specific routing profile.
+pub struct RoadGraph {
+    profile: RoutingProfile,
+    coordinates: Vec<(f64, f64)>,
+    offsets: Vec<usize>,
+    edges: Vec<Edge>,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+}
+
+struct Edge {
+    to: usize,
+    duration: f64,
+    distance: f64,
+}
+
+impl RoadGraph {
+    /// Creates a new instance of `RoadGraph` from ways accessible by given routing profile.
+    pub fn new(data: &OsmData, profile: RoutingProfile) -> Self {
+        let mut node_indices = HashMap::<i64, usize>::default();
+        let mut coordinates = Vec::default();
+        let mut arcs = Vec::default();
+
+        data.ways.iter().filter_map(|way| profile.get_speed(&way.tags).map(|speed| (way, speed))).for_each(
+            |(way, speed)| {
+                let (is_forward, is_backward) = get_directions(&way.tags, profile);
+
+                way.refs
+                    .windows(2)
+                    .filter_map(|pair| {
+                        let from = data.nodes.get(&pair[0]).map(|coordinate| (pair[0], *coordinate))?;
+                        let to = data.nodes.get(&pair[1]).map(|coordinate| (pair[1], *coordinate))?;
+                        Some((from, to))
+                    })
+                    .for_each(|((from_id, from_coord), (to_id, to_coord))| {
+                        let mut get_index = |id: i64, coordinate: (f64, f64)| {
+                            *node_indices.entry(id).or_insert_with(|| {
+                                coordinates.push(coordinate);
+                                coordinates.len() - 1
+                            })
+                        };
+                        let (from, to) = (get_index(from_id, from_coord), get_index(to_id, to_coord));
+
+                        let distance = get_haversine_distance(from_coord, to_coord);
+                        let duration = distance / speed;
+
+                        if is_forward {
+                            arcs.push((from, Edge { to, duration, distance }));
+                        }
+                        if is_backward {
+                            arcs.push((to, Edge { to: from, duration, distance }));
+                        }
+                    });
+            },
+        );
+
+        // NOTE use compressed sparse row representation for adjacency list
+        arcs.sort_by_key(|(from, _)| *from);
+        let mut offsets = vec![0; coordinates.len() + 1];
+        arcs.iter().for_each(|(from, _)| offsets[*from + 1] += 1);
+        (1..offsets.len()).for_each(|idx| offsets[idx] += offsets[idx - 1]);
+        let edges = arcs.into_iter().map(|(_, edge)| edge).collect();
+
+        let cells = coordinates.iter().enumerate().fold(HashMap::<_, Vec<_>>::default(), |mut cells, (idx, coord)| {
+            cells.entry(get_cell(*coord)).or_default().push(idx);
+            cells
+        });
+
+        Self { profile, coordinates, offsets, edges, cells }
+    }
+
+    /// Returns amount of nodes in the graph.
+    pub fn size(&self) -> usize {
+        self.coordinates.len()
+    }
+
+    /// Snaps the location to the nearest graph node. Returns `None` if there is no node nearby.
+    pub fn snap(&self, coordinate: (f64, f64)) -> Option<SnappedLocation> {
+        let (lat_cell, lng_cell) = get_cell(coordinate);
+        let get_nearest = |ring: i32| {
+            (-ring..=ring)
+                .flat_map(|lat| (-ring..=ring).map(move |lng| (lat, lng)))
+                .filter(|(lat, lng)| lat.abs() == ring || lng.abs() == ring)
+                .filter_map(|(lat, lng)| self.cells.get(&(lat_cell + lat, lng_cell + lng)))
+                .flatten()
+                .map(|&node| SnappedLocation {
+                    node,
+                    distance: get_haversine_distance(coordinate, self.coordinates[node]),
+                })
+                .min_by(|a, b| a.distance.total_cmp(&b.distance))
+        };
+
+        let ring = (0..=MAX_SNAP_RINGS).find(|&ring| get_nearest(ring).is_some())?;
+
+        // NOTE a node in the next ring can be closer than the one found in the current ring
+        get_nearest(ring).into_iter().chain(get_nearest(ring + 1)).min_by(|a, b| a.distance.total_cmp(&b.distance))
+    }
+
+    /// Calculates durations (in seconds) and distances (in meters) between all pairs of given snapped locations.
+    /// Returns a flattened matrix where `None` means that the destination is not reachable from the source.
+    /// A route from the location to itself is always empty.
+    pub fn get_routes(&self, locations: &[SnappedLocation]) -> Vec<Option<(f64, f64)>> {
+        let access_speed = self.profile.access_speed();
+        let indexed = locations.iter().enumerate().collect::<Vec<_>>();
+
+        parallel_collect(&indexed, |&(source_idx, source)| {
+            let routes = self.find_shortest_paths(source.node, locations);
+
+            locations
+                .iter()
+                .enumerate()
+                .zip(routes)
+                .map(|((target_idx, target), route)| {
+                    if source_idx == target_idx {
+                        return Some((0., 0.));
+                    }
+
+                    route.map(|(duration, distance)| {
+                        let access_distance = source.distance + target.distance;
+                        (duration + access_distance / access_speed, distance + access_distance)
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    /// Runs Dijkstra search optimizing duration from the source node until all targets are settled.
+    fn find_shortest_paths(&self, source: usize, targets: &[SnappedLocation]) -> Vec<Option<(f64, f64)>> {
+        let mut best = HashMap::<usize, (f64, f64)>::default();
+        let mut heap = BinaryHeap::default();
+        let mut remaining = targets.iter().map(|target| target.node).collect::<HashSet<_>>();
+
+        best.insert(source, (0., 0.));
+        heap.push(SearchState { node: source, duration: 0., distance: 0. });
+
+        while let Some(SearchState { node, duration, distance }) = heap.pop() {
+            if best.get(&node).is_some_and(|&(best_duration, _)| duration > best_duration) {
+                continue;
+            }
+
+            remaining.remove(&node);
+            if remaining.is_empty() {
+                break;
+            }
+
+            self.edges[self.offsets[node]..self.offsets[node + 1]].iter().for_each(|edge| {
+                let (duration, distance) = (duration + edge.duration, distance + edge.distance);
+                if best.get(&edge.to).is_none_or(|&(best_duration, _)| duration < best_duration) {
+                    best.insert(edge.to, (duration, distance));
+                    heap.push(SearchState { node: edge.to, duration, distance });
+                }
+            });
+        }
+
+        targets.iter().map(|target| best.get(&target.node).copied()).collect()
+    }
+}
+
+struct SearchState {
+    node: usize,
+    duration: f64,
+    distance: f64,
+}
+
+impl PartialEq for SearchState {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SearchState {}
+
+impl PartialOrd for SearchState {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SearchState {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // NOTE reversed order to have min heap
+        other.duration.total_cmp(&self.duration).then_with(|| self.node.cmp(&other.node))
+    }
+}
+
+fn get_directions(tags: &HashMap<String, String>, profile: RoutingProfile) -> (bool, bool) {
+    let tag = |key: &str| tags.get(key).map(String::as_str);
+
+    if profile == RoutingProfile::Bike && tag("oneway:bicycle") == Some("no") {
+        return (true, true);
+    }
+
+    match tag("oneway") {
+        Some("yes" | "true" | "1") => (true, false),
+        Some("-1" | "reverse") => (false, true),
+        Some("no" | "false" | "0") => (true, true),
+        _ if tag("highway") == Some("motorway") || tag("junction") == Some("roundabout") => (true, false),
+        _ => (true, true),
+    }
+}
+
+/// Returns default car speed in km/h.
+fn get_car_speed(highway: &str) -> Option<f64> {
+    Some(match highway {
+        "motorway" => 110.,
+        "trunk" => 90.,
+        "primary" => 70.,
+        "secondary" => 60.,
+        "tertiary" => 50.,
+        "motorway_link" | "trunk_link" | "primary_link" | "secondary_link" | "tertiary_link" => 45.,
+        "unclassified" => 40.,
+        "residential" => 30.,
+        "service" => 15.,
+        "living_street" => 10.,
+        _ => return None,
+    })
+}
+
+/// Returns default truck speed in km/h.
+fn get_truck_speed(highway: &str) -> Option<f64> {
+    Some(match highway {
+        "motorway" => 80.,
+        "trunk" => 70.,
+        "primary" => 60.,
+        "secondary" => 50.,
+        "tertiary" => 40.,
+        "motorway_link" | "trunk_link" | "primary_link" | "secondary_link" | "tertiary_link" => 40.,
+        "unclassified" => 35.,
+        "residential" => 25.,
+        "service" => 10.,
+        "living_street" => 5.,
+        _ => return None,
+    })
+}
+
+/// Returns default bike speed in km/h.
+fn get_bike_speed(highway: &str, bicycle: Option<&str>) -> Option<f64> {
+    Some(match (highway, bicycle) {
+        ("cycleway", _) => 18.,
+        ("primary" | "primary_link" | "secondary" | "secondary_link" | "tertiary" | "tertiary_link", _) => 15.,
+        ("unclassified" | "residential", _) => 15.,
+        ("path" | "track" | "service", _) => 12.,
+        ("living_street", _) => 10.,
+        ("footway" | "pedestrian", Some("yes" | "designated")) => 8.,
+        _ => return None,
+    })
+}
+
+/// Parses max speed tag value in km/h, e.g. `50` or `30 mph`.
+fn parse_max_speed(value: &str) -> Option<f64> {
+    let value = value.trim();
+    let (value, factor) = value.strip_suffix("mph").map_or((value, 1.), |value| (value, 1.609344));
+
+    value.trim().parse::<f64>().ok().filter(|speed| *speed > 0.).map(|speed| speed * factor)
+}
+
+fn get_cell((lat, lng): (f64, f64)) -> (i32, i32) {
+    ((lat / CELL_SIZE).floor() as i32, (lng / CELL_SIZE).floor() as i32)
+}
@@ -0,0 +1,114 @@
+//! Provides offline routing on a road network built from OpenStreetMap extract.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/routing/routing_test.rs"]
+mod routing_test;
+
+mod graph;
+pub use self::graph::*;
+
+mod pbf;
+pub use self::pbf::*;
+
+use std::collections::HashMap;
+use std::io::Read;
+use vrp_core::prelude::{GenericError, GenericResult};
+use vrp_core::utils::get_haversine_distance;
+use vrp_pragmatic::format::problem::{Matrix, Problem};
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::get_unique_locations;
+
+/// An error code used in routing matrix to mark locations which are not connected by the road network.
+const UNREACHABLE_ERROR_CODE: i64 = 1;
+
+/// Creates routing matrices for all fleet profiles without overlay using road network from OpenStreetMap extract in PBF format.
+/// Routing profile (car, truck or bike) has to be specified explicitly for each of these fleet profiles.
+pub fn create_osm_matrices<R: Read>(
+    problem: &Problem,
+    reader: R,
+    routing_profiles: &HashMap<String, RoutingProfile>,
+) -> GenericResult<Vec<Matrix>> {
+    read_osm_pbf(reader).and_then(|data| create_road_matrices(problem, &data, routing_profiles))
+}
+
+/// Creates routing matrices for all fleet profiles without overlay using road network from OpenStreetMap data.
+/// Routing profiles are specified per fleet profile name.
+///
+/// A location which is too far from any accessible road is connected to others by a straight line with the
+/// approximation speed of the fleet profile. Locations which are not connected by the road network are marked
+/// as unreachable.
+pub fn create_road_matrices(
+    problem: &Problem,
+    data: &OsmData,
+    routing_profiles: &HashMap<String, RoutingProfile>,
+) -> GenericResult<Vec<Matrix>> {
+    const DEFAULT_SPEED: f64 = 10.;
+
+    let profiles = problem.fleet.profiles.iter().filter(|profile| profile.overlay.is_none()).collect::<Vec<_>>();
+
+    if let Some(name) = routing_profiles.keys().find(|name| profiles.iter().all(|profile| profile.name != **name)) {
+        return Err(format!("routing profile is specified for unknown fleet profile: '{name}'").into());
+    }
+
+    let coordinates = get_unique_locations(problem)
+        .into_iter()
+        .filter(|location| !matches!(location, Location::Custom { .. }))
+        .map(|location| match location {
+            Location::Coordinate { lat, lng } => Ok((lat, lng)),
+            _ => Err("offline routing requires locations to be specified by coordinates".into()),
+        })
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    let mut graphs = HashMap::<RoutingProfile, RoadGraph>::default();
+
+    profiles
+        .into_iter()
+        .map(|profile| {
+            let routing_profile = *routing_profiles.get(&profile.name).ok_or_else(|| {
+                GenericError::from(format!("routing profile is not specified for fleet profile: '{}'", profile.name))
+            })?;
+            let graph = graphs.entry(routing_profile).or_insert_with(|| RoadGraph::new(data, routing_profile));
+            let speed = profile.speed.unwrap_or(DEFAULT_SPEED);
+
+            let snapped = coordinates.iter().map(|coordinate| graph.snap(*coordinate)).collect::<Vec<_>>();
+            let connected = snapped.iter().flatten().copied().collect::<Vec<_>>();
+            let mut routes = graph.get_routes(connected.as_slice()).into_iter();
+
+            let size = coordinates.len();
+            let mut travel_times = Vec::with_capacity(size * size);
+            let mut distances = Vec::with_capacity(size * size);
+            let mut error_codes = Vec::with_capacity(size * size);
+
+            snapped.iter().zip(coordinates.iter()).try_for_each::<_, GenericResult<_>>(|(from_snapped, from)| {
+                snapped.iter().zip(coordinates.iter()).try_for_each::<_, GenericResult<_>>(|(to_snapped, to)| {
+                    let route = match (from_snapped, to_snapped) {
+                        (Some(_), Some(_)) => routes.next().ok_or_else(|| {
+                            GenericError::from(format!("cannot get route for profile '{}'", profile.name))
+                        })?,
+                        _ => {
+                            let distance = get_haversine_distance(*from, *to);
+                            Some((distance / speed, distance))
+                        }
+                    };
+
+                    let (duration, distance) = route.unwrap_or_default();
+                    travel_times.push(duration.round() as i64);
+                    distances.push(distance.round() as i64);
+                    error_codes.push(if route.is_some() { 0 } else { UNREACHABLE_ERROR_CODE });
+
+                    Ok(())
+                })
+            })?;
+
+            let has_unreachable = error_codes.iter().any(|&code| code != 0);
+
+            Ok(Matrix {
+                profile: Some(profile.name.clone()),
+                timestamp: None,
+                travel_times,
+                distances,
+                error_codes: if has_unreachable { Some(error_codes) } else { None },
+            })
+        })
+        .collect()
+}
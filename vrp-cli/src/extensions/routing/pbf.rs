@@ -0,0 +1,389 @@
+//! A minimalistic reader of OpenStreetMap data in PBF format. Only nodes and road ways are read.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/routing/pbf_test.rs"]
+mod pbf_test;
+
+use flate2::read::ZlibDecoder;
+use std::collections::HashMap;
+use std::io::{ErrorKind, Read};
+use vrp_core::prelude::{GenericError, GenericResult};
+
+/// Max blob header size as specified by PBF format.
+const MAX_BLOB_HEADER_SIZE: usize = 64 * 1024;
+/// Max uncompressed blob size as specified by PBF format.
+const MAX_BLOB_SIZE: usize = 32 * 1024 * 1024;
+
+/// Keeps OpenStreetMap data required to build a road network.
+#[derive(Debug, Default)]
+pub struct OsmData {
+    /// Node coordinates as (latitude, longitude) pairs by node id.
+    pub nodes: HashMap<i64, (f64, f64)>,
+    /// Ways which have `highway` tag.
+    pub ways: Vec<OsmWay>,
+}
+
+/// An OpenStreetMap way.
+#[derive(Debug, Default)]
+pub struct OsmWay {
+    /// Ordered node ids.
+    pub refs: Vec<i64>,
+    /// Way tags.
+    pub tags: HashMap<String, String>,
+}
+
+/// Reads OpenStreetMap data in PBF format. Only uncompressed and zlib compressed blobs are supported.
+pub fn read_osm_pbf<R: Read>(mut reader: R) -> GenericResult<OsmData> {
+    let mut data = OsmData::default();
+    let mut size_buffer = [0_u8; 4];
+
+    loop {
+        match reader.read_exact(&mut size_buffer) {
+            Ok(_) => {}
+            Err(err) if err.kind() == ErrorKind::UnexpectedEof => break,
+            Err(err) => return Err(err.into()),
+        }
+
+        let header_size = u32::from_be_bytes(size_buffer) as usize;
+        if header_size > MAX_BLOB_HEADER_SIZE {
+            return Err(format!("blob header size is too big: {header_size}").into());
+        }
+
+        let (blob_type, blob_size) = read_blob_header(&read_bytes(&mut reader, header_size)?)?;
+        if blob_size > MAX_BLOB_SIZE {
+            return Err(format!("blob size is too big: {blob_size}").into());
+        }
+
+        let blob = read_bytes(&mut reader, blob_size)?;
+
+        match blob_type.as_str() {
+            "OSMHeader" => check_header_block(&read_blob(&blob)?)?,
+            "OSMData" => read_primitive_block(&read_blob(&blob)?, &mut data)?,
+            // NOTE unknown blob types should be skipped according to format specification
+            _ => {}
+        }
+    }
+
+    Ok(data)
+}
+
+fn read_bytes<R: Read>(reader: &mut R, size: usize) -> GenericResult<Vec<u8>> {
+    let mut buffer = vec![0; size];
+    reader.read_exact(&mut buffer).map_err(|err| format!("cannot read pbf data: {err}"))?;
+
+    Ok(buffer)
+}
+
+fn read_blob_header(data: &[u8]) -> GenericResult<(String, usize)> {
+    let mut reader = ProtoReader::new(data);
+    let (mut blob_type, mut blob_size) = (None, None);
+
+    while let Some((field, value)) = reader.next_field()? {
+        match (field, value) {
+            (1, FieldValue::Bytes(bytes)) => blob_type = Some(read_string(bytes)?),
+            (3, FieldValue::Varint(size)) => blob_size = Some(size as usize),
+            _ => {}
+        }
+    }
+
+    blob_type.zip(blob_size).ok_or_else(|| "invalid blob header".into())
+}
+
+fn read_blob(data: &[u8]) -> GenericResult<Vec<u8>> {
+    let mut reader = ProtoReader::new(data);
+    let (mut raw, mut raw_size, mut zlib_data) = (None, None, None);
+
+    while let Some((field, value)) = reader.next_field()? {
+        match (field, value) {
+            (1, FieldValue::Bytes(bytes)) => raw = Some(bytes),
+            (2, FieldValue::Varint(size)) => raw_size = Some(size as usize),
+            (3, FieldValue::Bytes(bytes)) => zlib_data = Some(bytes),
+            (4..=7, _) => return Err("unsupported blob compression, only zlib is supported".into()),
+            _ => {}
+        }
+    }
+
+    match (raw, zlib_data) {
+        (Some(raw), _) => Ok(raw.to_vec()),
+        (None, Some(zlib_data)) => {
+            let raw_size = raw_size.unwrap_or_default().min(MAX_BLOB_SIZE);
+            let mut buffer = Vec::with_capacity(raw_size);
+            ZlibDecoder::new(zlib_data)
+                .take(MAX_BLOB_SIZE as u64)
+                .read_to_end(&mut buffer)
+                .map_err(|err| format!("cannot decompress blob: {err}"))?;
+
+            Ok(buffer)
+        }
+        (None, None) => Err("blob has no data".into()),
+    }
+}
+
+fn check_header_block(data: &[u8]) -> GenericResult<()> {
+    const SUPPORTED_FEATURES: [&str; 2] = ["OsmSchema-V0.6", "DenseNodes"];
+
+    let mut reader = ProtoReader::new(data);
+    while let Some((field, value)) = reader.next_field()? {
+        if let (4, FieldValue::Bytes(bytes)) = (field, value) {
+            let feature = read_string(bytes)?;
+            if !SUPPORTED_FEATURES.contains(&feature.as_str()) {
+                return Err(format!("unsupported required feature: '{feature}'").into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Keeps primitive block properties which are used to decode its groups.
+struct BlockContext {
+    strings: Vec<String>,
+    granularity: i64,
+    lat_offset: i64,
+    lon_offset: i64,
+}
+
+impl BlockContext {
+    fn get_coordinate(&self, lat: i64, lon: i64) -> (f64, f64) {
+        let lat = 1E-9 * (self.lat_offset + self.granularity * lat) as f64;
+        let lon = 1E-9 * (self.lon_offset + self.granularity * lon) as f64;
+
+        (lat, lon)
+    }
+
+    fn get_string(&self, idx: u64) -> GenericResult<&str> {
+        self.strings.get(idx as usize).map(String::as_str).ok_or_else(|| format!("unknown string index: {idx}").into())
+    }
+}
+
+fn read_primitive_block(data: &[u8], osm_data: &mut OsmData) -> GenericResult<()> {
+    let mut reader = ProtoReader::new(data);
+    let mut groups = Vec::default();
+    let mut ctx = BlockContext { strings: Vec::default(), granularity: 100, lat_offset: 0, lon_offset: 0 };
+
+    while let Some((field, value)) = reader.next_field()? {
+        match (field, value) {
+            (1, FieldValue::Bytes(bytes)) => ctx.strings = read_string_table(bytes)?,
+            (2, FieldValue::Bytes(bytes)) => groups.push(bytes),
+            (17, FieldValue::Varint(value)) => ctx.granularity = value as i64,
+            (19, FieldValue::Varint(value)) => ctx.lat_offset = value as i64,
+            (20, FieldValue::Varint(value)) => ctx.lon_offset = value as i64,
+            _ => {}
+        }
+    }
+
+    groups.into_iter().try_for_each(|group| read_primitive_group(group, &ctx, osm_data))
+}
+
+fn read_string_table(data: &[u8]) -> GenericResult<Vec<String>> {
+    let mut reader = ProtoReader::new(data);
+    let mut strings = Vec::default();
+
+    while let Some((field, value)) = reader.next_field()? {
+        if let (1, FieldValue::Bytes(bytes)) = (field, value) {
+            strings.push(read_string(bytes)?);
+        }
+    }
+
+    Ok(strings)
+}
+
+fn read_primitive_group(data: &[u8], ctx: &BlockContext, osm_data: &mut OsmData) -> GenericResult<()> {
+    let mut reader = ProtoReader::new(data);
+
+    while let Some((field, value)) = reader.next_field()? {
+        match (field, value) {
+            (1, FieldValue::Bytes(bytes)) => read_node(bytes, ctx, osm_data)?,
+            (2, FieldValue::Bytes(bytes)) => read_dense_nodes(bytes, ctx, osm_data)?,
+            (3, FieldValue::Bytes(bytes)) => read_way(bytes, ctx, osm_data)?,
+            // NOTE relations and changesets are not needed for routing
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn read_node(data: &[u8], ctx: &BlockContext, osm_data: &mut OsmData) -> GenericResult<()> {
+    let mut reader = ProtoReader::new(data);
+    let (mut id, mut lat, mut lon) = (None, None, None);
+
+    while let Some((field, value)) = reader.next_field()? {
+        match (field, value) {
+            (1, FieldValue::Varint(value)) => id = Some(decode_zigzag(value)),
+            (8, FieldValue::Varint(value)) => lat = Some(decode_zigzag(value)),
+            (9, FieldValue::Varint(value)) => lon = Some(decode_zigzag(value)),
+            _ => {}
+        }
+    }
+
+    match (id, lat, lon) {
+        (Some(id), Some(lat), Some(lon)) => {
+            osm_data.nodes.insert(id, ctx.get_coordinate(lat, lon));
+            Ok(())
+        }
+        _ => Err("invalid node".into()),
+    }
+}
+
+fn read_dense_nodes(data: &[u8], ctx: &BlockContext, osm_data: &mut OsmData) -> GenericResult<()> {
+    let mut reader = ProtoReader::new(data);
+    let (mut ids, mut lats, mut lons) = (Vec::default(), Vec::default(), Vec::default());
+
+    while let Some((field, value)) = reader.next_field()? {
+        match field {
+            1 => ids.extend(read_packed(value)?),
+            8 => lats.extend(read_packed(value)?),
+            9 => lons.extend(read_packed(value)?),
+            _ => {}
+        }
+    }
+
+    if ids.len() != lats.len() || ids.len() != lons.len() {
+        return Err("invalid dense nodes".into());
+    }
+
+    // NOTE all values are delta coded
+    ids.into_iter().zip(lats).zip(lons).fold((0, 0, 0), |(id, lat, lon), ((id_delta, lat_delta), lon_delta)| {
+        let (id, lat, lon) =
+            (id + decode_zigzag(id_delta), lat + decode_zigzag(lat_delta), lon + decode_zigzag(lon_delta));
+        osm_data.nodes.insert(id, ctx.get_coordinate(lat, lon));
+
+        (id, lat, lon)
+    });
+
+    Ok(())
+}
+
+fn read_way(data: &[u8], ctx: &BlockContext, osm_data: &mut OsmData) -> GenericResult<()> {
+    let mut reader = ProtoReader::new(data);
+    let (mut keys, mut values, mut refs) = (Vec::default(), Vec::default(), Vec::default());
+
+    while let Some((field, value)) = reader.next_field()? {
+        match field {
+            2 => keys.extend(read_packed(value)?),
+            3 => values.extend(read_packed(value)?),
+            8 => refs.extend(read_packed(value)?),
+            _ => {}
+        }
+    }
+
+    if keys.len() != values.len() {
+        return Err("invalid way tags".into());
+    }
+
+    let tags = keys
+        .into_iter()
+        .zip(values)
+        .map(|(key, value)| Ok((ctx.get_string(key)?.to_string(), ctx.get_string(value)?.to_string())))
+        .collect::<GenericResult<HashMap<_, _>>>()?;
+
+    if tags.contains_key("highway") {
+        let refs = refs
+            .into_iter()
+            .scan(0, |id, delta| {
+                *id += decode_zigzag(delta);
+                Some(*id)
+            })
+            .collect();
+
+        osm_data.ways.push(OsmWay { refs, tags });
+    }
+
+    Ok(())
+}
+
+fn read_string(bytes: &[u8]) -> GenericResult<String> {
+    String::from_utf8(bytes.to_vec()).map_err(|err| GenericError::from(format!("invalid string: {err}")))
+}
+
+fn read_packed(value: FieldValue) -> GenericResult<Vec<u64>> {
+    match value {
+        FieldValue::Varint(value) => Ok(vec![value]),
+        FieldValue::Bytes(bytes) => {
+            let mut reader = ProtoReader::new(bytes);
+            let mut values = Vec::default();
+            while !reader.is_empty() {
+                values.push(reader.read_varint()?);
+            }
+
+            Ok(values)
+        }
+        FieldValue::Fixed => Err("unexpected fixed size value in packed field".into()),
+    }
+}
+
+fn decode_zigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+/// A value of protobuf message field.
+enum FieldValue<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+    /// A fixed size value which is not used by PBF format messages read here.
+    Fixed,
+}
+
+/// A reader of protobuf wire format.
+struct ProtoReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> ProtoReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position >= self.data.len()
+    }
+
+    fn next_field(&mut self) -> GenericResult<Option<(u32, FieldValue<'a>)>> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        let key = self.read_varint()?;
+        let field = (key >> 3) as u32;
+
+        let value = match key & 0x7 {
+            0 => FieldValue::Varint(self.read_varint()?),
+            1 => self.skip(8).map(|_| FieldValue::Fixed)?,
+            2 => {
+                let size = self.read_varint()? as usize;
+                FieldValue::Bytes(self.skip(size)?)
+            }
+            5 => self.skip(4).map(|_| FieldValue::Fixed)?,
+            wire_type => return Err(format!("unsupported wire type: {wire_type}").into()),
+        };
+
+        Ok(Some((field, value)))
+    }
+
+    fn read_varint(&mut self) -> GenericResult<u64> {
+        let mut value = 0_u64;
+
+        for shift in (0..64).step_by(7) {
+            let byte = *self.data.get(self.position).ok_or("unexpected end of varint")?;
+            self.position += 1;
+
+            value |= ((byte & 0x7F) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err("varint is too long".into())
+    }
+
+    fn skip(&mut self, size: usize) -> GenericResult<&'a [u8]> {
+        let end =
+            self.position.checked_add(size).filter(|&end| end <= self.data.len()).ok_or("unexpected end of data")?;
+        let bytes = &self.data[self.position..end];
+        self.position = end;
+
+        Ok(bytes)
+    }
+}
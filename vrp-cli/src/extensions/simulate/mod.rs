@@ -0,0 +1,472 @@
+//! A helper module which contains functionality to simulate solution execution under noisy travel and service times.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/simulate/simulate_test.rs"]
+mod simulate_test;
+
+use rand::prelude::*;
+use rand_distr::{Gamma, Normal};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::io::{BufReader, Read};
+use std::sync::{Arc, Mutex};
+use vrp_core::construction::enablers::{update_route_schedule, TotalDistanceTourState};
+use vrp_core::construction::heuristics::{RouteContext, RouteState};
+use vrp_core::models::common::{Distance, Duration, Location, Profile, Timestamp};
+use vrp_core::models::problem::{ActivityCost, Costs, JobIdDimension, TransportCost, TravelTime, VehicleIdDimension};
+use vrp_core::models::solution::{Activity, Route};
+use vrp_core::models::{Problem, Solution};
+use vrp_core::prelude::{GenericError, GenericResult};
+use vrp_core::utils::{Random, RandomGen};
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem};
+use vrp_pragmatic::format::solution::read_init_solution;
+use vrp_pragmatic::format::ShiftIndexDimension;
+
+/// Specifies a distribution of noise factors applied to durations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NoiseDistribution {
+    /// Normal distribution truncated at zero.
+    Normal,
+    /// Gamma distribution which is skewed to the right: long delays are more likely than big speed ups.
+    Gamma,
+}
+
+/// Specifies noise applied to durations during simulation.
+#[derive(Clone, Debug)]
+pub struct NoiseModel {
+    /// A standard deviation of travel durations relative to their value.
+    pub travel_deviation: f64,
+    /// A standard deviation of service durations relative to their value.
+    pub service_deviation: f64,
+    /// A distribution of noise factors.
+    pub distribution: NoiseDistribution,
+}
+
+/// Specifies simulation parameters.
+#[derive(Clone, Debug)]
+pub struct SimulationParams {
+    /// An amount of simulation runs.
+    pub iterations: usize,
+    /// A noise model.
+    pub noise: NoiseModel,
+}
+
+impl Default for SimulationParams {
+    fn default() -> Self {
+        Self {
+            iterations: 1000,
+            noise: NoiseModel {
+                travel_deviation: 0.2,
+                service_deviation: 0.1,
+                distribution: NoiseDistribution::Normal,
+            },
+        }
+    }
+}
+
+/// Summarizes a distribution of simulated values.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValueDistribution {
+    /// A mean value.
+    pub mean: f64,
+    /// A standard deviation.
+    pub std_dev: f64,
+    /// A median.
+    pub p50: f64,
+    /// 90th percentile.
+    pub p90: f64,
+    /// 95th percentile.
+    pub p95: f64,
+    /// A maximum value.
+    pub max: f64,
+}
+
+/// Keeps simulation results of a single job.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSimulation {
+    /// A job id.
+    pub job_id: String,
+    /// A probability to be served after the end of time window.
+    pub lateness_probability: f64,
+    /// An expected lateness (zero when job is served in time).
+    pub expected_lateness: f64,
+}
+
+/// Keeps simulation results of a single tour.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TourSimulation {
+    /// A vehicle id.
+    pub vehicle_id: String,
+    /// A vehicle shift index.
+    pub shift_index: usize,
+    /// A probability to finish the tour after the end of vehicle shift.
+    pub overtime_probability: f64,
+    /// A distribution of overtime.
+    pub overtime: ValueDistribution,
+}
+
+/// Keeps simulation results of a solution.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationReport {
+    /// An amount of simulation runs.
+    pub iterations: usize,
+    /// A cost of solution without noise.
+    pub planned_cost: f64,
+    /// An expected cost of solution.
+    pub expected_cost: f64,
+    /// A distribution of solution cost.
+    pub cost: ValueDistribution,
+    /// Simulation results of jobs.
+    pub jobs: Vec<JobSimulation>,
+    /// Simulation results of tours.
+    pub tours: Vec<TourSimulation>,
+}
+
+/// Replays solution tours many times with noisy travel and service durations using schedule logic of the solver.
+pub fn simulate_solution(
+    problem: &Problem,
+    solution: &Solution,
+    params: &SimulationParams,
+    random: Arc<dyn Random>,
+) -> GenericResult<SimulationReport> {
+    let noise = &params.noise;
+    if !is_valid_deviation(noise.travel_deviation) || !is_valid_deviation(noise.service_deviation) {
+        return Err("noise deviations should be non-negative".into());
+    }
+
+    if params.iterations == 0 {
+        return Err("amount of iterations should be positive".into());
+    }
+
+    let sampler = NoiseSampler::new(noise.clone(), random.as_ref());
+    let activity = NoisyActivityCost { inner: problem.activity.clone(), sampler: sampler.clone() };
+
+    let planned_cost = solution
+        .routes
+        .iter()
+        .map(|route| replay_route(route, problem.activity.as_ref(), problem.transport.as_ref()).cost)
+        .sum::<f64>();
+
+    let mut costs = vec![0.; params.iterations];
+    let mut tours = Vec::with_capacity(solution.routes.len());
+    let mut lateness = HashMap::<String, Vec<f64>>::default();
+
+    solution.routes.iter().for_each(|route| {
+        let replays = (0..params.iterations)
+            .map(|_| {
+                let transport = NoisyTransportCost::new(problem.transport.clone(), sampler.clone());
+                replay_route(route, &activity, &transport)
+            })
+            .collect::<Vec<_>>();
+
+        replays.iter().zip(costs.iter_mut()).for_each(|(replay, cost)| *cost += replay.cost);
+
+        replays.iter().enumerate().for_each(|(iteration, replay)| {
+            replay.lateness.iter().for_each(|(job_id, value)| {
+                let values = lateness.entry(job_id.clone()).or_insert_with(|| vec![0.; params.iterations]);
+                values[iteration] = values[iteration].max(*value);
+            })
+        });
+
+        let overtime = replays.iter().map(|replay| replay.overtime).collect::<Vec<_>>();
+        let dimens = &route.actor.vehicle.dimens;
+
+        tours.push(TourSimulation {
+            vehicle_id: dimens.get_vehicle_id().cloned().unwrap_or_default(),
+            shift_index: dimens.get_shift_index().copied().unwrap_or_default(),
+            overtime_probability: get_probability(overtime.as_slice()),
+            overtime: get_distribution(overtime),
+        });
+    });
+
+    let mut jobs = lateness
+        .into_iter()
+        .map(|(job_id, values)| JobSimulation {
+            job_id,
+            lateness_probability: get_probability(values.as_slice()),
+            expected_lateness: values.iter().sum::<f64>() / values.len() as f64,
+        })
+        .collect::<Vec<_>>();
+    jobs.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+
+    let cost = get_distribution(costs);
+
+    Ok(SimulationReport { iterations: params.iterations, planned_cost, expected_cost: cost.mean, cost, jobs, tours })
+}
+
+/// Simulates pragmatic solution and returns simulation report serialized as json.
+/// Use seeded random to get reproducible reports.
+pub fn simulate_pragmatic_solution<F: Read>(
+    problem_reader: BufReader<F>,
+    solution_reader: BufReader<F>,
+    matrices_readers: Option<Vec<BufReader<F>>>,
+    params: &SimulationParams,
+    random: Arc<dyn Random>,
+) -> GenericResult<String> {
+    let problem = deserialize_problem(problem_reader).map_err(|errs| format!("cannot read problem: '{errs}'"))?;
+    let matrices = matrices_readers
+        .map(|readers| {
+            readers
+                .into_iter()
+                .map(|reader| deserialize_matrix(reader).map_err(|errs| format!("cannot read matrix: '{errs}'")))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?;
+
+    let job_ids = problem.plan.jobs.iter().map(|job| job.id.clone()).collect::<HashSet<_>>();
+    let problem = Arc::new(
+        (problem, matrices).read_pragmatic().map_err(|errs| format!("cannot read pragmatic problem: '{errs}'"))?,
+    );
+
+    let solution = read_init_solution(solution_reader, problem.clone(), random.clone())?;
+
+    let mut report = simulate_solution(problem.as_ref(), &solution, params, random)?;
+    report.jobs.retain(|job| job_ids.contains(&job.job_id));
+
+    serde_json::to_string_pretty(&report).map_err(|err| GenericError::from(format!("cannot write report: '{err}'")))
+}
+
+/// Keeps results of a single route replay.
+struct RouteReplay {
+    cost: f64,
+    overtime: Duration,
+    lateness: Vec<(String, Duration)>,
+}
+
+fn replay_route(route: &Route, activity: &dyn ActivityCost, transport: &dyn TransportCost) -> RouteReplay {
+    let mut route_ctx = RouteContext::new_with_state(route.deep_copy(), RouteState::default());
+    update_route_schedule(&mut route_ctx, activity, transport);
+
+    let route = route_ctx.route();
+    let distance = route_ctx.state().get_total_distance().copied().unwrap_or_default();
+
+    let (driving, service, waiting) = route
+        .tour
+        .legs()
+        .filter_map(|(activities, _)| match activities {
+            [prev, next] => Some((prev, next)),
+            _ => None,
+        })
+        .fold((0., 0., 0.), |(driving, service, waiting), (prev, next)| {
+            let service_start = next.schedule.arrival.max(next.place.time.start);
+            (
+                driving + (next.schedule.arrival - prev.schedule.departure),
+                service + (next.schedule.departure - service_start),
+                waiting + (service_start - next.schedule.arrival),
+            )
+        });
+
+    let actor = route.actor.as_ref();
+    let get_cost = |costs: &Costs| {
+        costs.fixed
+            + costs.per_distance * distance
+            + costs.per_driving_time * driving
+            + costs.per_service_time * service
+            + costs.per_waiting_time * waiting
+    };
+
+    let finish = route.tour.end().map_or(0., |end| end.schedule.departure);
+    let overtime = (finish - actor.detail.time.end).max(0.);
+
+    let lateness = route
+        .tour
+        .all_activities()
+        .filter_map(|activity| get_job_id(activity).map(|job_id| (job_id, activity)))
+        .map(|(job_id, activity)| (job_id, (activity.schedule.arrival - activity.place.time.end).max(0.)))
+        .collect();
+
+    RouteReplay { cost: get_cost(&actor.vehicle.costs) + get_cost(&actor.driver.costs), overtime, lateness }
+}
+
+fn get_job_id(activity: &Activity) -> Option<String> {
+    activity.retrieve_job().and_then(|job| job.dimens().get_job_id().cloned())
+}
+
+fn get_probability(values: &[f64]) -> f64 {
+    values.iter().filter(|&&value| value > 0.).count() as f64 / values.len() as f64
+}
+
+fn get_distribution(mut values: Vec<f64>) -> ValueDistribution {
+    if values.is_empty() {
+        return ValueDistribution::default();
+    }
+
+    values.sort_by(|a, b| a.total_cmp(b));
+
+    let size = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / size;
+    let std_dev = (values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / size).sqrt();
+    let percentile = |p: f64| values[((p * size).ceil() as usize).clamp(1, values.len()) - 1];
+
+    ValueDistribution {
+        mean,
+        std_dev,
+        p50: percentile(0.5),
+        p90: percentile(0.9),
+        p95: percentile(0.95),
+        max: *values.last().unwrap(),
+    }
+}
+
+fn is_valid_deviation(deviation: f64) -> bool {
+    deviation.is_finite() && deviation >= 0.
+}
+
+/// A random implementation which uses its own generator seeded by given value, so the sequence of generated
+/// values does not depend on other random instances.
+pub struct SeededRandom {
+    rng: Mutex<SmallRng>,
+}
+
+impl SeededRandom {
+    /// Creates a new instance of `SeededRandom`.
+    pub fn new(seed: u64) -> Self {
+        Self { rng: Mutex::new(SmallRng::seed_from_u64(seed)) }
+    }
+
+    fn with_rng<T>(&self, func: impl FnOnce(&mut SmallRng) -> T) -> T {
+        func(&mut self.rng.lock().expect("cannot lock seeded rng"))
+    }
+}
+
+impl Random for SeededRandom {
+    fn uniform_int(&self, min: i32, max: i32) -> i32 {
+        if min == max {
+            return min;
+        }
+
+        assert!(min < max);
+        self.with_rng(|rng| rng.gen_range(min..max + 1))
+    }
+
+    fn uniform_real(&self, min: f64, max: f64) -> f64 {
+        if (min - max).abs() < f64::EPSILON {
+            return min;
+        }
+
+        assert!(min < max);
+        self.with_rng(|rng| rng.gen_range(min..max))
+    }
+
+    fn is_head_not_tails(&self) -> bool {
+        self.with_rng(|rng| rng.gen_bool(0.5))
+    }
+
+    fn is_hit(&self, probability: f64) -> bool {
+        self.with_rng(|rng| rng.gen_bool(probability.clamp(0., 1.)))
+    }
+
+    fn weighted(&self, weights: &[usize]) -> usize {
+        weights
+            .iter()
+            .zip(0_usize..)
+            .map(|(&weight, index)| (-self.uniform_real(0., 1.).ln() / weight as f64, index))
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .unwrap()
+            .1
+    }
+
+    fn get_rng(&self) -> RandomGen {
+        // NOTE RandomGen is backed by thread local generator which cannot be seeded per instance,
+        // so simulation does not use it to sample noise.
+        RandomGen::new_repeatable()
+    }
+}
+
+#[derive(Clone)]
+struct NoiseSampler {
+    noise: NoiseModel,
+    rng: Arc<Mutex<SmallRng>>,
+}
+
+impl NoiseSampler {
+    fn new(noise: NoiseModel, random: &dyn Random) -> Self {
+        let seed = random.uniform_int(0, i32::MAX - 1) as u64;
+
+        Self { noise, rng: Arc::new(Mutex::new(SmallRng::seed_from_u64(seed))) }
+    }
+
+    fn sample(&self, deviation: f64) -> f64 {
+        if deviation == 0. {
+            return 1.;
+        }
+
+        let mut rng = self.rng.lock().expect("cannot lock noise rng");
+
+        match self.noise.distribution {
+            NoiseDistribution::Normal => Normal::new(1., deviation)
+                .unwrap_or_else(|_| panic!("cannot create normal dist: deviation={deviation}"))
+                .sample(&mut *rng)
+                .max(0.),
+            NoiseDistribution::Gamma => {
+                let variance = deviation * deviation;
+                Gamma::new(1. / variance, variance)
+                    .unwrap_or_else(|_| panic!("cannot create gamma dist: deviation={deviation}"))
+                    .sample(&mut *rng)
+            }
+        }
+    }
+}
+
+/// Applies noise to travel durations: the factor is sampled once per leg, so forward and backward passes
+/// of schedule update observe the same leg duration within one route replay.
+struct NoisyTransportCost {
+    inner: Arc<dyn TransportCost>,
+    sampler: NoiseSampler,
+    factors: Mutex<HashMap<(Location, Location), f64>>,
+}
+
+impl NoisyTransportCost {
+    fn new(inner: Arc<dyn TransportCost>, sampler: NoiseSampler) -> Self {
+        Self { inner, sampler, factors: Mutex::default() }
+    }
+
+    fn get_factor(&self, from: Location, to: Location) -> f64 {
+        *self
+            .factors
+            .lock()
+            .expect("cannot lock noise factors")
+            .entry((from, to))
+            .or_insert_with(|| self.sampler.sample(self.sampler.noise.travel_deviation))
+    }
+}
+
+impl TransportCost for NoisyTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.inner.duration_approx(profile, from, to)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.inner.distance_approx(profile, from, to)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        self.inner.duration(route, from, to, travel_time) * self.get_factor(from, to)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        self.inner.distance(route, from, to, travel_time)
+    }
+}
+
+struct NoisyActivityCost {
+    inner: Arc<dyn ActivityCost>,
+    sampler: NoiseSampler,
+}
+
+impl ActivityCost for NoisyActivityCost {
+    fn estimate_departure(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Timestamp {
+        let factor = self.sampler.sample(self.sampler.noise.service_deviation);
+
+        self.inner.estimate_departure(route, activity, arrival) + activity.place.duration * (factor - 1.)
+    }
+
+    fn estimate_arrival(&self, route: &Route, activity: &Activity, departure: Timestamp) -> Timestamp {
+        self.inner.estimate_arrival(route, activity, departure)
+    }
+}
@@ -140,6 +140,54 @@ mod c_interop {
         });
     }
 
+    fn to_bytes(pointers: *const *const u8, sizes: *const usize, len: usize) -> Vec<Vec<u8>> {
+        let pointers = unsafe { slice::from_raw_parts(pointers, len) };
+        let sizes = unsafe { slice::from_raw_parts(sizes, len) };
+
+        pointers
+            .iter()
+            .zip(sizes.iter())
+            .map(|(&pointer, &size)| unsafe { slice::from_raw_parts(pointer, size) }.to_vec())
+            .collect()
+    }
+
+    fn validate_with_matrices(problem: &str, matrices: &[&[u8]]) -> Result<String, GenericError> {
+        let problem = deserialize_problem(BufReader::new(problem.as_bytes()));
+        let matrices =
+            matrices.iter().map(|matrix| deserialize_matrix(BufReader::new(*matrix))).collect::<Result<Vec<_>, _>>();
+
+        match (problem, matrices) {
+            (Ok(problem), Ok(matrices)) => {
+                let matrices = if matrices.is_empty() { None } else { Some(&matrices) };
+                let coord_index = CoordIndex::new(&problem);
+
+                ValidationContext::new(&problem, matrices, &coord_index).validate()
+            }
+            (Err(errors), Ok(_)) | (Ok(_), Err(errors)) => Err(errors),
+            (Err(errors1), Err(errors2)) => {
+                Err(MultiFormatError::from(errors1.into_iter().chain(errors2).collect::<Vec<_>>()))
+            }
+        }
+        .map_err(From::from)
+        .map(|_| "[]".to_string())
+    }
+
+    fn solve_with_matrices(problem: String, matrices: &[&[u8]], config: String) -> Result<String, GenericError> {
+        if matrices.is_empty() {
+            problem.read_pragmatic()
+        } else {
+            let matrices = matrices.iter().map(|matrix| BufReader::new(*matrix)).collect();
+            (BufReader::new(problem.as_bytes()), matrices).read_pragmatic()
+        }
+        .map_err(From::from)
+        .and_then(|problem| {
+            read_config(BufReader::new(config.as_bytes()))
+                .map_err(|err| GenericError::from(serialize_as_config_error(err.to_string().as_str())))
+                .map(|config| (problem, config))
+        })
+        .and_then(|(problem, config)| get_solution_serialized(Arc::new(problem), config))
+    }
+
     /// Validates Vehicle Routing Problem passed in `pragmatic` format.
     #[no_mangle]
     extern "C" fn validate_pragmatic(
@@ -153,27 +201,31 @@ mod c_interop {
             let problem = to_string(problem);
             let matrices = unsafe { slice::from_raw_parts(matrices, matrices_len).to_vec() };
             let matrices = matrices.iter().map(|m| to_string(*m)).collect::<Vec<_>>();
+            let matrices = matrices.iter().map(|m| m.as_bytes()).collect::<Vec<_>>();
 
-            let problem = deserialize_problem(BufReader::new(problem.as_bytes()));
-            let matrices = matrices
-                .iter()
-                .map(|matrix| deserialize_matrix(BufReader::new(matrix.as_bytes())))
-                .collect::<Result<Vec<_>, _>>();
+            let result = validate_with_matrices(problem.as_str(), matrices.as_slice());
 
-            let result = match (problem, matrices) {
-                (Ok(problem), Ok(matrices)) => {
-                    let matrices = if matrices.is_empty() { None } else { Some(&matrices) };
-                    let coord_index = CoordIndex::new(&problem);
+            call_back(result, success, failure);
+        });
+    }
 
-                    ValidationContext::new(&problem, matrices, &coord_index).validate()
-                }
-                (Err(errors), Ok(_)) | (Ok(_), Err(errors)) => Err(errors),
-                (Err(errors1), Err(errors2)) => {
-                    Err(MultiFormatError::from(errors1.into_iter().chain(errors2).collect::<Vec<_>>()))
-                }
-            }
-            .map_err(From::from)
-            .map(|_| "[]".to_string());
+    /// Validates Vehicle Routing Problem passed in `pragmatic` format. Routing matrices are passed as
+    /// byte buffers with their sizes and can be specified either in json or in binary format.
+    #[no_mangle]
+    extern "C" fn validate_pragmatic_with_bytes(
+        problem: *const c_char,
+        matrices: *const *const u8,
+        matrices_sizes: *const usize,
+        matrices_len: usize,
+        success: Callback,
+        failure: Callback,
+    ) {
+        catch_panic(failure, || {
+            let problem = to_string(problem);
+            let matrices = to_bytes(matrices, matrices_sizes, matrices_len);
+            let matrices = matrices.iter().map(|m| m.as_slice()).collect::<Vec<_>>();
+
+            let result = validate_with_matrices(problem.as_str(), matrices.as_slice());
 
             call_back(result, success, failure);
         });
@@ -193,16 +245,32 @@ mod c_interop {
             let problem = to_string(problem);
             let matrices = unsafe { slice::from_raw_parts(matrices, matrices_len).to_vec() };
             let matrices = matrices.iter().map(|m| to_string(*m)).collect::<Vec<_>>();
+            let matrices = matrices.iter().map(|m| m.as_bytes()).collect::<Vec<_>>();
 
-            let result =
-                if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
-                    .map_err(From::from)
-                    .and_then(|problem| {
-                        read_config(BufReader::new(to_string(config).as_bytes()))
-                            .map_err(|err| GenericError::from(serialize_as_config_error(err.to_string().as_str())))
-                            .map(|config| (problem, config))
-                    })
-                    .and_then(|(problem, config)| get_solution_serialized(Arc::new(problem), config));
+            let result = solve_with_matrices(problem, matrices.as_slice(), to_string(config));
+
+            call_back(result, success, failure);
+        });
+    }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. Routing matrices are passed as
+    /// byte buffers with their sizes and can be specified either in json or in binary format.
+    #[no_mangle]
+    extern "C" fn solve_pragmatic_with_bytes(
+        problem: *const c_char,
+        matrices: *const *const u8,
+        matrices_sizes: *const usize,
+        matrices_len: usize,
+        config: *const c_char,
+        success: Callback,
+        failure: Callback,
+    ) {
+        catch_panic(failure, || {
+            let problem = to_string(problem);
+            let matrices = to_bytes(matrices, matrices_sizes, matrices_len);
+            let matrices = matrices.iter().map(|m| m.as_slice()).collect::<Vec<_>>();
+
+            let result = solve_with_matrices(problem, matrices.as_slice(), to_string(config));
 
             call_back(result, success, failure);
         });
@@ -212,6 +280,17 @@ mod c_interop {
     mod tests {
         use super::*;
         use crate::helpers::generate::SIMPLE_PROBLEM;
+        use vrp_pragmatic::format::problem::{create_approx_matrices, write_binary_matrix};
+
+        fn create_binary_matrix(problem: &str) -> Vec<u8> {
+            let problem = deserialize_problem(BufReader::new(problem.as_bytes())).unwrap();
+            let matrix = create_approx_matrices(&problem).swap_remove(0);
+
+            let mut bytes = Vec::new();
+            write_binary_matrix(&matrix, true, &mut bytes).unwrap();
+
+            bytes
+        }
 
         #[test]
         fn can_use_to_string() {
@@ -309,6 +388,58 @@ mod c_interop {
             );
         }
 
+        #[test]
+        fn can_validate_problem_with_binary_matrix() {
+            extern "C" fn success(solution: *const c_char) {
+                assert_eq!(to_string(solution), "[]")
+            }
+            extern "C" fn failure(err: *const c_char) {
+                unreachable!("{}", to_string(err))
+            }
+
+            let problem = CString::new(SIMPLE_PROBLEM).unwrap();
+            let matrix = create_binary_matrix(SIMPLE_PROBLEM);
+            let matrices = [matrix.as_ptr()];
+            let sizes = [matrix.len()];
+
+            validate_pragmatic_with_bytes(
+                problem.as_ptr() as *const c_char,
+                matrices.as_ptr(),
+                sizes.as_ptr(),
+                1,
+                success,
+                failure,
+            );
+        }
+
+        #[test]
+        fn can_solve_problem_with_binary_matrix() {
+            extern "C" fn success(solution: *const c_char) {
+                let solution = to_string(solution);
+                assert!(solution.starts_with('{'));
+                assert!(solution.ends_with('}'));
+            }
+            extern "C" fn failure(err: *const c_char) {
+                unreachable!("{}", to_string(err))
+            }
+
+            let problem = CString::new(SIMPLE_PROBLEM).unwrap();
+            let matrix = create_binary_matrix(SIMPLE_PROBLEM);
+            let matrices = [matrix.as_ptr()];
+            let sizes = [matrix.len()];
+            let config = CString::new("{\"termination\": {\"max-generations\": 1}}").unwrap();
+
+            solve_pragmatic_with_bytes(
+                problem.as_ptr() as *const c_char,
+                matrices.as_ptr(),
+                sizes.as_ptr(),
+                1,
+                config.as_ptr() as *const c_char,
+                success,
+                failure,
+            );
+        }
+
         #[test]
         fn can_solve_problem() {
             extern "C" fn success(solution: *const c_char) {
@@ -350,6 +481,22 @@ mod py_interop {
 
     // TODO avoid duplications between 3 interop approaches
 
+    /// A routing matrix passed either as json string or as bytes in json or binary format.
+    #[derive(FromPyObject)]
+    enum MatrixInput {
+        Text(String),
+        Bytes(Vec<u8>),
+    }
+
+    impl MatrixInput {
+        fn as_bytes(&self) -> &[u8] {
+            match self {
+                MatrixInput::Text(text) => text.as_bytes(),
+                MatrixInput::Bytes(bytes) => bytes.as_slice(),
+            }
+        }
+    }
+
     /// Converts `problem` from format specified by `format` to `pragmatic` format.
     #[pyfunction]
     fn convert_to_pragmatic(format: &str, inputs: Vec<String>) -> PyResult<String> {
@@ -378,7 +525,7 @@ mod py_interop {
 
     /// Validates and solves Vehicle Routing Problem.
     #[pyfunction]
-    fn solve_pragmatic(problem: String, matrices: Vec<String>, config: String) -> PyResult<String> {
+    fn solve_pragmatic(problem: String, matrices: Vec<MatrixInput>, config: String) -> PyResult<String> {
         // validate first
         deserialize_problem(BufReader::new(problem.as_bytes()))
             .and_then(|problem| {
@@ -397,15 +544,20 @@ mod py_interop {
             .map_err(|errs| PyOSError::new_err(errs.to_string()))?;
 
         // try solve problem
-        if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
-            .map_err(From::from)
-            .and_then(|problem| {
-                read_config(BufReader::new(config.as_bytes()))
-                    .map_err(|err| GenericError::from(serialize_as_config_error(err.to_string().as_str())))
-                    .map(|config| (problem, config))
-            })
-            .and_then(|(problem, config)| get_solution_serialized(Arc::new(problem), config))
-            .map_err(|err| PyOSError::new_err(err.to_string()))
+        if matrices.is_empty() {
+            problem.read_pragmatic()
+        } else {
+            let matrices = matrices.iter().map(|m| BufReader::new(m.as_bytes())).collect();
+            (BufReader::new(problem.as_bytes()), matrices).read_pragmatic()
+        }
+        .map_err(From::from)
+        .and_then(|problem| {
+            read_config(BufReader::new(config.as_bytes()))
+                .map_err(|err| GenericError::from(serialize_as_config_error(err.to_string().as_str())))
+                .map(|config| (problem, config))
+        })
+        .and_then(|(problem, config)| get_solution_serialized(Arc::new(problem), config))
+        .map_err(|err| PyOSError::new_err(err.to_string()))
     }
 
     #[pymodule]
@@ -423,9 +575,42 @@ mod wasm {
     extern crate wasm_bindgen;
 
     use super::*;
-    use vrp_pragmatic::format::problem::Matrix;
+    use js_sys::{Array, Uint8Array};
+    use std::io::Read;
+    use vrp_pragmatic::format::problem::{deserialize_matrix, Matrix};
     use vrp_pragmatic::format::CoordIndex;
     use wasm_bindgen::prelude::*;
+    use wasm_bindgen::JsCast;
+
+    /// Reads bytes of `Uint8Array` chunk by chunk without copying the whole array into wasm memory.
+    struct ByteArrayReader {
+        bytes: Uint8Array,
+        offset: u32,
+    }
+
+    impl Read for ByteArrayReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let length = (self.bytes.length() - self.offset).min(buf.len() as u32);
+            self.bytes.subarray(self.offset, self.offset + length).copy_to(&mut buf[..length as usize]);
+            self.offset += length;
+
+            Ok(length as usize)
+        }
+    }
+
+    /// Reads routing matrices passed either as objects or as `Uint8Array` in json or binary format.
+    fn read_matrices(matrices: JsValue) -> Result<Vec<Matrix>, JsValue> {
+        Array::from(&matrices)
+            .iter()
+            .map(|matrix| match matrix.dyn_ref::<Uint8Array>() {
+                Some(bytes) => deserialize_matrix(BufReader::new(ByteArrayReader { bytes: bytes.clone(), offset: 0 }))
+                    .map_err(|errs| JsValue::from_str(errs.to_json().as_str())),
+                None => {
+                    serde_wasm_bindgen::from_value(matrix).map_err(|err| JsValue::from_str(err.to_string().as_str()))
+                }
+            })
+            .collect()
+    }
 
     /// Returns a list of unique locations which can be used to request a routing matrix.
     /// A `problem` should be passed in `pragmatic` format.
@@ -444,8 +629,7 @@ mod wasm {
     pub fn validate_pragmatic(problem: JsValue, matrices: JsValue) -> Result<JsValue, JsValue> {
         let problem: Problem =
             serde_wasm_bindgen::from_value(problem).map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
-        let matrices: Vec<Matrix> =
-            serde_wasm_bindgen::from_value(matrices).map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
+        let matrices = read_matrices(matrices)?;
         let coord_index = CoordIndex::new(&problem);
 
         let matrices = if matrices.is_empty() { None } else { Some(&matrices) };
@@ -483,8 +667,7 @@ mod wasm {
         let problem: Problem =
             serde_wasm_bindgen::from_value(problem).map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
 
-        let matrices: Vec<Matrix> =
-            serde_wasm_bindgen::from_value(matrices).map_err(|err| JsValue::from_str(err.to_string().as_str()))?;
+        let matrices = read_matrices(matrices)?;
 
         let problem = Arc::new(
             if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
@@ -12,6 +12,12 @@ pub fn create_empty_job() -> Job {
         value: None,
         group: None,
         compatibility: None,
+        conflicts: None,
+        affinity: None,
+        transfers: None,
+        truck_only: None,
+        value_decay: None,
+        divisible: None,
     }
 }
 
@@ -20,11 +26,17 @@ pub fn create_empty_job_task() -> JobTask {
 }
 
 pub fn create_empty_job_place() -> JobPlace {
-    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None, tag: None }
+    JobPlace {
+        location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+        duration: 0.0,
+        times: None,
+        tag: None,
+        timezone: None,
+    }
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, clustering: None, transfers: None }
 }
 
 pub fn create_test_vehicle_type() -> VehicleType {
@@ -38,20 +50,35 @@ pub fn create_test_vehicle_type() -> VehicleType {
                 earliest: "2020-05-01T09:00:00.00Z".to_string(),
                 latest: None,
                 location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+                timezone: None,
+                alternatives: None,
             },
             end: None,
             breaks: None,
             reloads: None,
             recharges: None,
+            capacity: None,
+            skills: None,
+            limits: None,
+            costs: None,
         }],
-        capacity: vec![10],
+        capacity: vec![10.],
         skills: None,
         limits: None,
+        trailer: None,
+        backhaul: None,
     }
 }
 
 pub fn create_test_vehicle_profile() -> MatrixProfile {
-    MatrixProfile { name: "car".to_string(), speed: None }
+    MatrixProfile {
+        name: "car".to_string(),
+        speed: None,
+        speed_bands: None,
+        overlay: None,
+        distance_model: None,
+        travel_time_uncertainty: None,
+    }
 }
 
 pub fn create_test_time_window() -> Vec<String> {
@@ -66,7 +93,7 @@ pub fn create_test_job(lat: f64, lng: f64) -> Job {
                 times: Some(vec![create_test_time_window()]),
                 ..create_empty_job_place()
             }],
-            demand: Some(vec![1]),
+            demand: Some(vec![1.]),
             ..create_empty_job_task()
         }]),
         ..create_empty_job()
@@ -4,3 +4,6 @@
 pub mod macros;
 
 pub mod generate;
+
+#[cfg(feature = "osm-routing")]
+pub mod routing;
@@ -0,0 +1,31 @@
+use crate::extensions::routing::{OsmData, OsmWay};
+
+/// Creates a road network with the following nodes and ways:
+/// * nodes from 1 to 4 are placed along the same latitude with 0.01 degree longitude step
+/// * node 5 is placed north from node 1
+/// * two-way residential road: 1 - 2 - 3
+/// * oneway residential road: 3 -> 4
+/// * cycleway: 1 - 5
+pub fn create_test_osm_data() -> OsmData {
+    let create_way = |refs: Vec<i64>, tags: Vec<(&str, &str)>| OsmWay {
+        refs,
+        tags: tags.into_iter().map(|(key, value)| (key.to_string(), value.to_string())).collect(),
+    };
+
+    OsmData {
+        nodes: vec![
+            (1, (52.5, 13.40)),
+            (2, (52.5, 13.41)),
+            (3, (52.5, 13.42)),
+            (4, (52.5, 13.43)),
+            (5, (52.51, 13.40)),
+        ]
+        .into_iter()
+        .collect(),
+        ways: vec![
+            create_way(vec![1, 2, 3], vec![("highway", "residential")]),
+            create_way(vec![3, 4], vec![("highway", "residential"), ("oneway", "yes")]),
+            create_way(vec![1, 5], vec![("highway", "cycleway")]),
+        ],
+    }
+}
@@ -36,3 +36,64 @@ fn can_detect_wrong_argument() {
 
     assert!(get_analyze_app().try_get_matches_from(args).is_err());
 }
+
+#[test]
+fn can_run_analyze_matrix() {
+    let tmpfile = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "analyze",
+        "matrix",
+        "../examples/data/pragmatic/simple.basic.matrix.json",
+        "--out-matrix",
+        tmpfile.path().to_str().unwrap(),
+    ];
+    let matches = get_analyze_app().try_get_matches_from(args).unwrap();
+
+    run_analyze(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
+
+    let repaired = deserialize_matrix(BufReader::new(File::open(tmpfile.path()).unwrap())).unwrap();
+    assert_eq!(repaired.travel_times.len(), repaired.distances.len());
+}
+
+#[test]
+fn can_detect_fill_unreachable_without_out_matrix() {
+    let args = vec!["analyze", "matrix", "../examples/data/pragmatic/simple.basic.matrix.json", "--fill-unreachable"];
+
+    get_analyze_app().try_get_matches_from(args).unwrap_err();
+}
+
+#[test]
+fn can_write_repaired_matrix_in_binary_format() {
+    let matrix_path = "../examples/data/pragmatic/simple.basic.matrix.json";
+    let matrix = deserialize_matrix(BufReader::new(File::open(matrix_path).unwrap())).unwrap();
+    let infile = tempfile::NamedTempFile::new().unwrap();
+    write_binary_matrix(&matrix, true, BufWriter::new(File::create(infile.path()).unwrap())).unwrap();
+    let outfile = tempfile::NamedTempFile::new().unwrap();
+    let args =
+        vec!["analyze", "matrix", infile.path().to_str().unwrap(), "--out-matrix", outfile.path().to_str().unwrap()];
+    let matches = get_analyze_app().try_get_matches_from(args).unwrap();
+
+    run_analyze(&matches, |_| BufWriter::new(Box::new(DummyWrite {}))).unwrap();
+
+    let mut reader = BufReader::new(File::open(outfile.path()).unwrap());
+    assert!(is_compressed_binary_matrix(&mut reader).unwrap());
+    let repaired = deserialize_matrix(reader).unwrap();
+    assert_eq!(repaired.travel_times.len(), matrix.travel_times.len());
+}
+
+#[test]
+fn can_detect_wrong_amount_of_out_matrices() {
+    let args = vec![
+        "analyze",
+        "matrix",
+        "../examples/data/pragmatic/simple.basic.matrix.json",
+        "--out-matrix",
+        "/some/path1",
+        "/some/path2",
+    ];
+    let matches = get_analyze_app().try_get_matches_from(args).unwrap();
+
+    let result = run_analyze(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert!(result.is_err());
+}
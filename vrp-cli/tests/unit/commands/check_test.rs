@@ -2,17 +2,27 @@ use super::*;
 
 const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
 const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+const PRAGMATIC_BINARY_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.bin";
 const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
 
 #[test]
 fn can_run_check_solution() {
+    run_check_solution(PRAGMATIC_MATRIX_PATH);
+}
+
+#[test]
+fn can_run_check_solution_with_binary_matrix() {
+    run_check_solution(PRAGMATIC_BINARY_MATRIX_PATH);
+}
+
+fn run_check_solution(matrix_path: &str) {
     let args = vec![
         "check",
         "pragmatic",
         "--problem-file",
         PRAGMATIC_PROBLEM_PATH,
         "--matrix",
-        PRAGMATIC_MATRIX_PATH,
+        matrix_path,
         "--solution-file",
         PRAGMATIC_SOLUTION_PATH,
     ];
@@ -0,0 +1,82 @@
+use super::*;
+use std::sync::Mutex;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+static REPORT: Mutex<Vec<u8>> = Mutex::new(Vec::new());
+
+struct ReportWrite {}
+
+impl Write for ReportWrite {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        REPORT.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn can_run_simulate() {
+    let args = vec![
+        "simulate",
+        "pragmatic",
+        "--problem-file",
+        PRAGMATIC_PROBLEM_PATH,
+        "--solution-file",
+        PRAGMATIC_SOLUTION_PATH,
+        "--matrix",
+        PRAGMATIC_MATRIX_PATH,
+        "--iterations",
+        "50",
+        "--travel-deviation",
+        "0.3",
+        "--distribution",
+        "gamma",
+        "--seed",
+        "42",
+    ];
+    let matches = get_simulate_app().try_get_matches_from(args).unwrap();
+
+    run_simulate(&matches, |_| BufWriter::new(Box::new(ReportWrite {}))).unwrap();
+
+    let report: serde_json::Value = serde_json::from_slice(REPORT.lock().unwrap().as_slice()).unwrap();
+    assert_eq!(report.pointer("/iterations").and_then(|value| value.as_u64()), Some(50));
+}
+
+#[test]
+fn can_detect_wrong_argument() {
+    let args = vec![
+        "simulate",
+        "pragmatic",
+        "-p",
+        PRAGMATIC_PROBLEM_PATH,
+        "-s",
+        PRAGMATIC_SOLUTION_PATH,
+        "--distribution",
+        "uniform",
+    ];
+
+    assert!(get_simulate_app().try_get_matches_from(args).is_err());
+}
+
+#[test]
+fn can_detect_wrong_iterations() {
+    let args = vec!["simulate", "pragmatic", "-p", PRAGMATIC_PROBLEM_PATH, "-s", PRAGMATIC_SOLUTION_PATH, "-n", "many"];
+    let matches = get_simulate_app().try_get_matches_from(args).unwrap();
+
+    assert!(run_simulate(&matches, |_| BufWriter::new(Box::new(ReportWrite {}))).is_err());
+}
+
+#[test]
+fn can_detect_wrong_seed() {
+    let args =
+        vec!["simulate", "pragmatic", "-p", PRAGMATIC_PROBLEM_PATH, "-s", PRAGMATIC_SOLUTION_PATH, "--seed", "none"];
+    let matches = get_simulate_app().try_get_matches_from(args).unwrap();
+
+    assert!(run_simulate(&matches, |_| BufWriter::new(Box::new(ReportWrite {}))).is_err());
+}
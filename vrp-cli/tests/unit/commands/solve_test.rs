@@ -34,6 +34,74 @@ fn can_solve_pragmatic_problem_with_generation_limit() {
     run_solve_with_out_writer(&matches);
 }
 
+#[test]
+fn can_solve_pragmatic_problem_with_binary_matrix() {
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--matrix",
+        "../examples/data/pragmatic/simple.basic.matrix.bin",
+        "--max-generations",
+        "1",
+    ];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+#[cfg(feature = "osm-routing")]
+fn can_solve_pragmatic_problem_with_osm_extract() {
+    // NOTE empty extract has no roads, so straight line fallback is used for all locations
+    let osm_file = tempfile::NamedTempFile::new().unwrap();
+    let osm_path = osm_file.path().to_str().unwrap();
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--osm",
+        osm_path,
+        "--osm-profile",
+        "normal_car=car",
+        "--max-generations",
+        "1",
+    ];
+    let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+#[cfg(feature = "osm-routing")]
+fn can_detect_invalid_osm_profile() {
+    let osm_file = tempfile::NamedTempFile::new().unwrap();
+    let osm_path = osm_file.path().to_str().unwrap();
+
+    ["normal_car=van", "normal_car", "other_car=car"].into_iter().for_each(|osm_profile| {
+        let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--osm", osm_path, "--osm-profile", osm_profile];
+        let matches = get_solve_app().try_get_matches_from(args).unwrap();
+
+        let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+        assert!(result.is_err(), "expected error for '{osm_profile}'");
+    });
+}
+
+#[test]
+fn can_detect_osm_profile_without_osm_extract() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--osm-profile", "normal_car=car"];
+
+    get_solve_app().try_get_matches_from(args).unwrap_err();
+}
+
+#[test]
+fn can_detect_osm_extract_with_matrix_conflict() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--osm", "map.osm.pbf", "--matrix", "matrix.json"];
+
+    get_solve_app().try_get_matches_from(args).unwrap_err();
+}
+
 #[test]
 fn can_solve_lilim_problem_with_multiple_limits() {
     let args = vec!["solve", "lilim", LILIM_PROBLEM_PATH, "--max-time", "300", "--max-generations", "1"];
@@ -0,0 +1,169 @@
+use super::*;
+
+fn create_matrix(travel_times: Vec<i64>, distances: Vec<i64>, error_codes: Option<Vec<i64>>) -> Matrix {
+    Matrix { profile: Some("car".to_string()), timestamp: None, travel_times, distances, error_codes }
+}
+
+fn create_triangle_matrix() -> Matrix {
+    create_matrix(vec![0, 10, 100, 10, 0, 10, 100, 10, 0], vec![0, 100, 1000, 100, 0, 100, 1000, 100, 0], None)
+}
+
+fn create_unreachable_matrix() -> Matrix {
+    create_matrix(
+        vec![0, 10, 0, 10, 0, 10, 20, 10, 0],
+        vec![0, 100, 0, 100, 0, 100, 200, 100, 0],
+        Some(vec![0, 0, 1, 0, 0, 0, 0, 0, 0]),
+    )
+}
+
+type Anomalies = Vec<(MatrixAnomalyKind, usize, usize, Option<(i64, i64)>)>;
+
+fn get_anomalies(report: &MatrixReport) -> Anomalies {
+    report
+        .anomalies
+        .iter()
+        .map(|anomaly| {
+            let expected = anomaly.expected_duration.zip(anomaly.expected_distance);
+            (anomaly.kind, anomaly.from, anomaly.to, expected)
+        })
+        .collect()
+}
+
+parameterized_test! {can_analyze_and_repair_matrix, (matrix, expected_anomalies, expected_travel_times, expected_distances), {
+    can_analyze_and_repair_matrix_impl(matrix, expected_anomalies, expected_travel_times, expected_distances);
+}}
+
+can_analyze_and_repair_matrix! {
+    case01_triangle_inequality: (
+        create_triangle_matrix(),
+        vec![
+            (MatrixAnomalyKind::TriangleInequality, 0, 2, Some((20, 200))),
+            (MatrixAnomalyKind::TriangleInequality, 2, 0, Some((20, 200))),
+        ],
+        vec![0, 10, 20, 10, 0, 10, 20, 10, 0],
+        vec![0, 100, 200, 100, 0, 100, 200, 100, 0],
+    ),
+    case02_zero_duration: (
+        create_matrix(vec![0, 0, 10, 0], vec![0, 100, 100, 0], None),
+        vec![(MatrixAnomalyKind::ZeroDuration, 0, 1, Some((10, 100)))],
+        vec![0, 10, 10, 0],
+        vec![0, 100, 100, 0],
+    ),
+    case03_asymmetric_outlier: (
+        create_matrix(vec![0, 10, 100, 0], vec![0, 100, 100, 0], None),
+        vec![(MatrixAnomalyKind::AsymmetricOutlier, 1, 0, Some((30, 100)))],
+        vec![0, 10, 30, 0],
+        vec![0, 100, 100, 0],
+    ),
+    case04_unreachable_with_path: (
+        create_unreachable_matrix(),
+        vec![(MatrixAnomalyKind::Unreachable, 0, 2, None)],
+        vec![0, 10, 0, 10, 0, 10, 20, 10, 0],
+        vec![0, 100, 0, 100, 0, 100, 200, 100, 0],
+    ),
+    case05_unreachable_without_path: (
+        create_matrix(vec![0, -1, -1, 0], vec![0, -1, -1, 0], None),
+        vec![(MatrixAnomalyKind::Unreachable, 0, 1, None), (MatrixAnomalyKind::Unreachable, 1, 0, None)],
+        vec![0, -1, -1, 0],
+        vec![0, -1, -1, 0],
+    ),
+    case06_asymmetric_outliers_in_both_directions: (
+        create_matrix(vec![0, 10, 100, 0], vec![0, 1000, 100, 0], None),
+        vec![
+            (MatrixAnomalyKind::AsymmetricOutlier, 0, 1, Some((10, 300))),
+            (MatrixAnomalyKind::AsymmetricOutlier, 1, 0, Some((30, 100))),
+        ],
+        vec![0, 10, 30, 0],
+        vec![0, 300, 100, 0],
+    ),
+    case07_no_anomalies: (
+        create_matrix(vec![0, 10, 12, 0], vec![0, 100, 120, 0], None),
+        vec![],
+        vec![0, 10, 12, 0],
+        vec![0, 100, 120, 0],
+    ),
+}
+
+fn can_analyze_and_repair_matrix_impl(
+    matrix: Matrix,
+    expected_anomalies: Anomalies,
+    expected_travel_times: Vec<i64>,
+    expected_distances: Vec<i64>,
+) {
+    let params = MatrixAnalysisParams::default();
+
+    let report = analyze_matrix(&matrix, &params).expect("cannot analyze matrix");
+    let repaired = repair_matrix(&matrix, &params).expect("cannot repair matrix");
+
+    assert!(report.is_triangle_checked);
+    assert_eq!(report.summary.total(), expected_anomalies.len());
+    assert_eq!(get_anomalies(&report), expected_anomalies);
+    assert_eq!(repaired.travel_times, expected_travel_times);
+    assert_eq!(repaired.distances, expected_distances);
+    assert_eq!(repaired.profile, matrix.profile);
+}
+
+#[test]
+fn can_report_anomalies_per_location() {
+    let report = analyze_matrix(&create_triangle_matrix(), &MatrixAnalysisParams::default()).unwrap();
+
+    assert_eq!(report.size, 3);
+    assert_eq!(report.summary, MatrixAnomalyCounts { triangle_inequality: 2, ..MatrixAnomalyCounts::default() });
+    assert_eq!(
+        report.locations.iter().map(|location| (location.index, location.counts.total())).collect::<Vec<_>>(),
+        vec![(0, 2), (2, 2)]
+    );
+}
+
+parameterized_test! {can_fill_unreachable_pairs_only_when_requested, (fill_unreachable, expected_error_codes), {
+    can_fill_unreachable_pairs_only_when_requested_impl(fill_unreachable, expected_error_codes);
+}}
+
+can_fill_unreachable_pairs_only_when_requested! {
+    case01_fill: (true, vec![0; 9]),
+    case02_keep: (false, vec![0, 0, 1, 0, 0, 0, 0, 0, 0]),
+}
+
+fn can_fill_unreachable_pairs_only_when_requested_impl(fill_unreachable: bool, expected_error_codes: Vec<i64>) {
+    let matrix = create_unreachable_matrix();
+    let params = MatrixAnalysisParams { fill_unreachable, ..MatrixAnalysisParams::default() };
+
+    let report = analyze_matrix(&matrix, &params).unwrap();
+    let repaired = repair_matrix(&matrix, &params).unwrap();
+
+    let expected = Some((20, 200)).filter(|_| fill_unreachable);
+    assert_eq!(get_anomalies(&report), vec![(MatrixAnomalyKind::Unreachable, 0, 2, expected)]);
+    assert_eq!(repaired.travel_times[2], if fill_unreachable { 20 } else { 0 });
+    assert_eq!(repaired.error_codes, Some(expected_error_codes));
+}
+
+#[test]
+fn can_skip_triangle_check_for_big_matrix() {
+    let params = MatrixAnalysisParams { max_closure_size: 2, ..MatrixAnalysisParams::default() };
+
+    let report = analyze_matrix(&create_triangle_matrix(), &params).unwrap();
+
+    assert!(!report.is_triangle_checked);
+    assert!(report.anomalies.is_empty());
+}
+
+#[test]
+fn can_detect_invalid_matrix() {
+    let matrix = create_matrix(vec![0, 1, 2], vec![0, 1, 2], None);
+
+    let result = analyze_matrix(&matrix, &MatrixAnalysisParams::default());
+
+    assert_eq!(
+        result.err().map(|err| err.to_string()),
+        Some("matrix is not square or has arrays of different length".to_string())
+    );
+}
+
+#[test]
+fn can_get_matrix_report_as_json() {
+    let report = get_matrix_report(&[create_triangle_matrix()], &MatrixAnalysisParams::default()).unwrap();
+
+    assert!(report.contains("\"profile\": \"car\""));
+    assert!(report.contains("\"kind\": \"triangle-inequality\""));
+    assert!(report.contains("\"triangleInequality\": 2"));
+}
@@ -8,10 +8,19 @@ fn can_generate_fleet_of_specific_size() {
         plan: create_empty_plan(),
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
-            profiles: vec![MatrixProfile { name: "normal_car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile {
+                name: "normal_car".to_string(),
+                speed: None,
+                speed_bands: None,
+                overlay: None,
+                distance_model: None,
+                travel_time_uncertainty: None,
+            }],
             resources: None,
         },
         objectives: None,
+        timezone: None,
+        matrix_storage: None,
     };
 
     let generated = generate_fleet(&prototype, 2);
@@ -20,6 +20,8 @@ fn can_generate_jobs_with_time_windows() {
             resources: None,
         },
         objectives: None,
+        timezone: None,
+        matrix_storage: None,
     };
 
     let result =
@@ -0,0 +1,94 @@
+use super::*;
+use crate::helpers::routing::create_test_osm_data;
+
+fn create_tags(tags: &[(&str, &str)]) -> HashMap<String, String> {
+    tags.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+}
+
+parameterized_test! {can_get_speed, (profile, tags, expected), {
+    can_get_speed_impl(profile, tags, expected);
+}}
+
+can_get_speed! {
+    case01_car_residential: (RoutingProfile::Car, &[("highway", "residential")], Some(30.)),
+    case02_truck_residential: (RoutingProfile::Truck, &[("highway", "residential")], Some(25.)),
+    case03_bike_residential: (RoutingProfile::Bike, &[("highway", "residential")], Some(15.)),
+    case04_car_max_speed: (RoutingProfile::Car, &[("highway", "motorway"), ("maxspeed", "80")], Some(80.)),
+    case05_car_max_speed_mph: (RoutingProfile::Car, &[("highway", "motorway"), ("maxspeed", "50 mph")], Some(80.4672)),
+    case06_bike_motorway: (RoutingProfile::Bike, &[("highway", "motorway")], None),
+    case07_car_cycleway: (RoutingProfile::Car, &[("highway", "cycleway")], None),
+    case08_truck_no_hgv: (RoutingProfile::Truck, &[("highway", "primary"), ("hgv", "no")], None),
+    case09_car_no_hgv: (RoutingProfile::Car, &[("highway", "primary"), ("hgv", "no")], Some(70.)),
+    case10_car_private: (RoutingProfile::Car, &[("highway", "service"), ("access", "private")], None),
+    case11_bike_designated: (RoutingProfile::Bike, &[("highway", "footway"), ("access", "no"), ("bicycle", "designated")], Some(8.)),
+    case12_no_highway: (RoutingProfile::Car, &[("building", "yes")], None),
+}
+
+fn can_get_speed_impl(profile: RoutingProfile, tags: &[(&str, &str)], expected: Option<f64>) {
+    let result = profile.get_speed(&create_tags(tags)).map(|speed| speed * 3.6);
+
+    match (result, expected) {
+        (Some(result), Some(expected)) => assert!((result - expected).abs() < 1E-6, "{result} != {expected}"),
+        (result, expected) => assert_eq!(result, expected),
+    }
+}
+
+parameterized_test! {can_get_profile_from_name, (name, expected), {
+    assert_eq!(RoutingProfile::from_name(name).ok(), expected);
+}}
+
+can_get_profile_from_name! {
+    case01_car: ("car", Some(RoutingProfile::Car)),
+    case02_truck: ("truck", Some(RoutingProfile::Truck)),
+    case03_bike: ("bike", Some(RoutingProfile::Bike)),
+    case04_unknown: ("van", None),
+    case05_not_exact: ("normal_car", None),
+}
+
+parameterized_test! {can_build_graph_for_profile, (profile, expected_size), {
+    assert_eq!(RoadGraph::new(&create_test_osm_data(), profile).size(), expected_size);
+}}
+
+can_build_graph_for_profile! {
+    case01_car: (RoutingProfile::Car, 4),
+    case02_bike: (RoutingProfile::Bike, 5),
+}
+
+#[test]
+fn can_snap_location() {
+    let graph = RoadGraph::new(&create_test_osm_data(), RoutingProfile::Car);
+
+    let snapped = graph.snap((52.5001, 13.4101)).expect("cannot snap");
+    assert!(snapped.distance > 0. && snapped.distance < 20.);
+    assert_eq!(graph.coordinates[snapped.node], (52.5, 13.41));
+
+    assert!(graph.snap((53.5, 13.41)).is_none());
+}
+
+#[test]
+fn can_get_routes_with_oneway_road() {
+    let graph = RoadGraph::new(&create_test_osm_data(), RoutingProfile::Car);
+    let locations =
+        [(52.5, 13.40), (52.5, 13.42), (52.5, 13.43)].map(|coordinate| graph.snap(coordinate).expect("cannot snap"));
+    let expected_distance =
+        get_haversine_distance((52.5, 13.40), (52.5, 13.41)) + get_haversine_distance((52.5, 13.41), (52.5, 13.42));
+
+    let routes = graph.get_routes(&locations);
+
+    assert_eq!(routes.len(), 9);
+    assert_eq!(routes[0], Some((0., 0.)));
+    let (duration, distance) = routes[1].expect("no route");
+    assert!((distance - expected_distance).abs() < 1E-6);
+    assert!((duration - expected_distance / (30. / 3.6)).abs() < 1E-6);
+    assert_eq!(routes[1], routes[3]);
+    assert!(routes[5].is_some());
+    assert!(routes[6].is_none());
+    assert!(routes[7].is_none());
+}
+
+#[test]
+fn can_calculate_haversine_distance() {
+    let distance = get_haversine_distance((52.5, 13.40), (52.5, 13.41));
+
+    assert!((distance - 677.).abs() < 1., "unexpected distance: {distance}");
+}
@@ -0,0 +1,159 @@
+use super::*;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use std::io::Write;
+
+#[derive(Default)]
+struct ProtoWriter(Vec<u8>);
+
+impl ProtoWriter {
+    fn varint(mut self, field: u64, value: u64) -> Self {
+        self.write_varint(field << 3);
+        self.write_varint(value);
+        self
+    }
+
+    fn bytes(mut self, field: u64, bytes: &[u8]) -> Self {
+        self.write_varint((field << 3) | 2);
+        self.write_varint(bytes.len() as u64);
+        self.0.extend_from_slice(bytes);
+        self
+    }
+
+    fn packed(self, field: u64, values: &[u64]) -> Self {
+        let packed = values.iter().fold(ProtoWriter::default(), |mut writer, value| {
+            writer.write_varint(*value);
+            writer
+        });
+
+        self.bytes(field, &packed.0)
+    }
+
+    fn write_varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.0.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.0.push(value as u8);
+    }
+}
+
+fn encode_zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn encode_deltas(values: &[i64]) -> Vec<u64> {
+    values
+        .iter()
+        .scan(0, |prev, value| {
+            let delta = value - *prev;
+            *prev = *value;
+            Some(encode_zigzag(delta))
+        })
+        .collect()
+}
+
+fn create_header_block(features: &[&str]) -> Vec<u8> {
+    features.iter().fold(ProtoWriter::default(), |writer, feature| writer.bytes(4, feature.as_bytes())).0
+}
+
+fn create_primitive_block() -> Vec<u8> {
+    let strings = ["", "highway", "residential", "building", "yes"];
+    let string_table = strings.iter().fold(ProtoWriter::default(), |writer, value| writer.bytes(1, value.as_bytes()));
+
+    // NOTE coordinates are in units of granularity (100 nanodegrees by default)
+    let dense_nodes = ProtoWriter::default()
+        .packed(1, &encode_deltas(&[1, 2, 3]))
+        .packed(8, &encode_deltas(&[525_000_000, 525_000_000, 525_100_000]))
+        .packed(9, &encode_deltas(&[134_000_000, 134_100_000, 134_100_000]));
+    let node = ProtoWriter::default()
+        .varint(1, encode_zigzag(10))
+        .varint(8, encode_zigzag(-10_000_000))
+        .varint(9, encode_zigzag(20_000_000));
+    let road =
+        ProtoWriter::default().varint(1, 1).packed(2, &[1]).packed(3, &[2]).packed(8, &encode_deltas(&[1, 2, 3]));
+    let building =
+        ProtoWriter::default().varint(1, 2).packed(2, &[3]).packed(3, &[4]).packed(8, &encode_deltas(&[3, 1]));
+
+    ProtoWriter::default()
+        .bytes(1, &string_table.0)
+        .bytes(2, &ProtoWriter::default().bytes(2, &dense_nodes.0).0)
+        .bytes(2, &ProtoWriter::default().bytes(1, &node.0).bytes(3, &road.0).bytes(3, &building.0).0)
+        .0
+}
+
+fn create_pbf(blobs: Vec<(&str, Vec<u8>)>, is_compressed: bool) -> Vec<u8> {
+    blobs.into_iter().fold(Vec::default(), |mut pbf, (blob_type, data)| {
+        let blob = if is_compressed {
+            let mut encoder = ZlibEncoder::new(Vec::default(), Compression::default());
+            encoder.write_all(&data).unwrap();
+            ProtoWriter::default().varint(2, data.len() as u64).bytes(3, &encoder.finish().unwrap())
+        } else {
+            ProtoWriter::default().bytes(1, &data)
+        };
+        let header = ProtoWriter::default().bytes(1, blob_type.as_bytes()).varint(3, blob.0.len() as u64);
+
+        pbf.extend_from_slice(&(header.0.len() as u32).to_be_bytes());
+        pbf.extend_from_slice(&header.0);
+        pbf.extend_from_slice(&blob.0);
+
+        pbf
+    })
+}
+
+parameterized_test! {can_read_nodes_and_roads, is_compressed, {
+    can_read_nodes_and_roads_impl(is_compressed);
+}}
+
+can_read_nodes_and_roads! {
+    case01_raw: false,
+    case02_zlib: true,
+}
+
+fn can_read_nodes_and_roads_impl(is_compressed: bool) {
+    let pbf = create_pbf(
+        vec![
+            ("OSMHeader", create_header_block(&["OsmSchema-V0.6", "DenseNodes"])),
+            ("OSMData", create_primitive_block()),
+        ],
+        is_compressed,
+    );
+
+    let data = read_osm_pbf(pbf.as_slice()).expect("cannot read pbf");
+
+    assert_eq!(data.nodes.len(), 4);
+    let (lat, lng) = data.nodes[&3];
+    assert!((lat - 52.51).abs() < 1E-9 && (lng - 13.41).abs() < 1E-9);
+    let (lat, lng) = data.nodes[&10];
+    assert!((lat + 1.).abs() < 1E-9 && (lng - 2.).abs() < 1E-9);
+    assert_eq!(data.ways.len(), 1);
+    assert_eq!(data.ways[0].refs, vec![1, 2, 3]);
+    assert_eq!(data.ways[0].tags.get("highway").map(String::as_str), Some("residential"));
+}
+
+#[test]
+fn can_skip_unknown_blobs() {
+    let pbf = create_pbf(vec![("Unknown", vec![1, 2, 3]), ("OSMData", create_primitive_block())], false);
+
+    let data = read_osm_pbf(pbf.as_slice()).expect("cannot read pbf");
+
+    assert_eq!(data.ways.len(), 1);
+}
+
+#[test]
+fn can_detect_unsupported_feature() {
+    let pbf = create_pbf(vec![("OSMHeader", create_header_block(&["OsmSchema-V0.6", "HistoricalInformation"]))], false);
+
+    let result = read_osm_pbf(pbf.as_slice()).err();
+
+    assert_eq!(result, Some("unsupported required feature: 'HistoricalInformation'".into()));
+}
+
+#[test]
+fn can_detect_truncated_data() {
+    let pbf = create_pbf(vec![("OSMData", create_primitive_block())], false);
+
+    let result = read_osm_pbf(&pbf[..pbf.len() - 10]);
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,121 @@
+use super::*;
+use crate::helpers::generate::*;
+use crate::helpers::routing::create_test_osm_data;
+use vrp_pragmatic::format::problem::*;
+
+fn create_test_problem(profiles: Vec<MatrixProfile>) -> Problem {
+    let mut vehicle = create_test_vehicle_type();
+    vehicle.shifts[0].start.location = Location::Coordinate { lat: 52.5, lng: 13.40 };
+
+    Problem {
+        plan: Plan {
+            jobs: vec![create_test_job(52.5, 13.42), create_test_job(52.5, 13.43), create_test_job(52.6, 13.40)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![vehicle], profiles, resources: None },
+        objectives: None,
+        timezone: None,
+        matrix_storage: None,
+    }
+}
+
+fn get_index(problem: &Problem, lat: f64, lng: f64) -> usize {
+    get_unique_locations(problem)
+        .iter()
+        .position(|location| *location == Location::Coordinate { lat, lng })
+        .expect("cannot find location")
+}
+
+#[test]
+fn can_create_road_matrices() {
+    let problem = create_test_problem(vec![
+        MatrixProfile {
+            name: "car".to_string(),
+            speed: Some(5.),
+            speed_bands: None,
+            overlay: None,
+            distance_model: None,
+            travel_time_uncertainty: None,
+        },
+        MatrixProfile {
+            name: "bike".to_string(),
+            speed: None,
+            speed_bands: None,
+            overlay: None,
+            distance_model: None,
+            travel_time_uncertainty: None,
+        },
+    ]);
+    let size = get_unique_locations(&problem).len();
+    let get_value = |values: &[i64], from: (f64, f64), to: (f64, f64)| {
+        values[get_index(&problem, from.0, from.1) * size + get_index(&problem, to.0, to.1)]
+    };
+    let road_distance = (get_haversine_distance((52.5, 13.40), (52.5, 13.41))
+        + get_haversine_distance((52.5, 13.41), (52.5, 13.42)))
+    .round() as i64;
+    let line_distance = get_haversine_distance((52.5, 13.40), (52.6, 13.40)).round() as i64;
+
+    let routing_profiles =
+        HashMap::from([("car".to_string(), RoutingProfile::Car), ("bike".to_string(), RoutingProfile::Bike)]);
+
+    let matrices =
+        create_road_matrices(&problem, &create_test_osm_data(), &routing_profiles).expect("cannot create matrices");
+
+    assert_eq!(matrices.len(), 2);
+    let car = &matrices[0];
+    assert_eq!(car.profile.as_deref(), Some("car"));
+    assert_eq!(car.distances.len(), size * size);
+    assert_eq!(get_value(&car.distances, (52.5, 13.40), (52.5, 13.42)), road_distance);
+    assert_eq!(get_value(&car.distances, (52.5, 13.42), (52.5, 13.40)), road_distance);
+    assert_eq!(
+        get_value(&car.travel_times, (52.5, 13.40), (52.5, 13.42)),
+        (road_distance as f64 / (30. / 3.6)).round() as i64
+    );
+    assert_eq!(get_value(&car.distances, (52.5, 13.40), (52.6, 13.40)), line_distance);
+    assert_eq!(get_value(&car.travel_times, (52.5, 13.40), (52.6, 13.40)), (line_distance as f64 / 5.).round() as i64);
+
+    assert!((0..size).all(|idx| car.distances[idx * size + idx] == 0 && car.travel_times[idx * size + idx] == 0));
+
+    let error_codes = car.error_codes.as_ref().expect("no error codes");
+    assert_eq!(get_value(error_codes, (52.5, 13.42), (52.5, 13.43)), 0);
+    assert_eq!(get_value(error_codes, (52.5, 13.43), (52.5, 13.42)), 1);
+
+    let bike = &matrices[1];
+    assert_eq!(bike.profile.as_deref(), Some("bike"));
+    assert_eq!(
+        get_value(&bike.travel_times, (52.5, 13.40), (52.5, 13.42)),
+        (road_distance as f64 / (15. / 3.6)).round() as i64
+    );
+}
+
+#[test]
+fn can_detect_non_coordinate_locations() {
+    let mut problem = create_test_problem(vec![create_test_vehicle_profile()]);
+    problem.fleet.vehicles[0].shifts[0].start.location = Location::Reference { index: 0 };
+
+    let routing_profiles = HashMap::from([(create_test_vehicle_profile().name, RoutingProfile::Car)]);
+
+    let result = create_road_matrices(&problem, &create_test_osm_data(), &routing_profiles).err();
+
+    assert_eq!(result, Some("offline routing requires locations to be specified by coordinates".into()));
+}
+
+parameterized_test! {can_detect_invalid_routing_profiles, (routing_profiles, expected), {
+    can_detect_invalid_routing_profiles_impl(routing_profiles, expected);
+}}
+
+can_detect_invalid_routing_profiles! {
+    case01_missing: (vec![], "routing profile is not specified for fleet profile: 'car'"),
+    case02_unknown: (vec![("car", RoutingProfile::Car), ("truck", RoutingProfile::Truck)],
+                     "routing profile is specified for unknown fleet profile: 'truck'"),
+}
+
+fn can_detect_invalid_routing_profiles_impl(routing_profiles: Vec<(&str, RoutingProfile)>, expected: &str) {
+    let problem = create_test_problem(vec![create_test_vehicle_profile()]);
+    let routing_profiles =
+        routing_profiles.into_iter().map(|(name, profile)| (name.to_string(), profile)).collect::<HashMap<_, _>>();
+
+    let result = create_road_matrices(&problem, &create_test_osm_data(), &routing_profiles).err();
+
+    assert_eq!(result, Some(expected.into()));
+}
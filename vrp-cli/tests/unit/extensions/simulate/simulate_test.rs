@@ -0,0 +1,149 @@
+use super::*;
+use std::fs::File;
+use vrp_core::utils::DefaultRandom;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+fn reader(path: &str) -> BufReader<File> {
+    BufReader::new(File::open(path).expect("cannot open test file"))
+}
+
+fn create_params(iterations: usize, deviation: f64, distribution: NoiseDistribution) -> SimulationParams {
+    SimulationParams {
+        iterations,
+        noise: NoiseModel { travel_deviation: deviation, service_deviation: deviation, distribution },
+    }
+}
+
+fn simulate(params: &SimulationParams) -> GenericResult<serde_json::Value> {
+    simulate_with_random(params, Arc::new(DefaultRandom::default()))
+}
+
+fn simulate_with_random(params: &SimulationParams, random: Arc<dyn Random>) -> GenericResult<serde_json::Value> {
+    let report = simulate_pragmatic_solution(
+        reader(PRAGMATIC_PROBLEM_PATH),
+        reader(PRAGMATIC_SOLUTION_PATH),
+        Some(vec![reader(PRAGMATIC_MATRIX_PATH)]),
+        params,
+        random,
+    )?;
+
+    Ok(serde_json::from_str(report.as_str()).unwrap())
+}
+
+fn get_f64(value: &serde_json::Value, pointer: &str) -> f64 {
+    value.pointer(pointer).and_then(|value| value.as_f64()).expect("cannot get value")
+}
+
+#[test]
+fn can_simulate_solution_without_noise() {
+    let report = simulate(&create_params(10, 0., NoiseDistribution::Normal)).unwrap();
+
+    let planned_cost = get_f64(&report, "/plannedCost");
+    assert!((planned_cost - 41.504842).abs() < 1E-6, "planned cost: {planned_cost}");
+    assert_eq!(get_f64(&report, "/expectedCost"), planned_cost);
+    assert_eq!(get_f64(&report, "/cost/stdDev"), 0.);
+    assert_eq!(get_f64(&report, "/cost/max"), planned_cost);
+    let jobs = report.pointer("/jobs").and_then(|jobs| jobs.as_array()).unwrap();
+    assert_eq!(jobs.len(), 3);
+    assert!(jobs.iter().all(|job| get_f64(job, "/latenessProbability") == 0.));
+    let tours = report.pointer("/tours").and_then(|tours| tours.as_array()).unwrap();
+    assert_eq!(tours.len(), 1);
+    assert_eq!(tours[0].pointer("/vehicleId").and_then(|id| id.as_str()), Some("vehicle_1"));
+    assert_eq!(get_f64(&tours[0], "/overtimeProbability"), 0.);
+}
+
+parameterized_test! {can_simulate_solution_with_noise, distribution, {
+    can_simulate_solution_with_noise_impl(distribution);
+}}
+
+can_simulate_solution_with_noise! {
+    case01_normal: NoiseDistribution::Normal,
+    case02_gamma: NoiseDistribution::Gamma,
+}
+
+fn can_simulate_solution_with_noise_impl(distribution: NoiseDistribution) {
+    let report = simulate(&create_params(200, 0.3, distribution)).unwrap();
+
+    assert_eq!(get_f64(&report, "/iterations"), 200.);
+    assert!(get_f64(&report, "/cost/stdDev") > 0.);
+    assert!(get_f64(&report, "/cost/p50") <= get_f64(&report, "/cost/p95"));
+    assert!(get_f64(&report, "/cost/p95") <= get_f64(&report, "/cost/max"));
+    let jobs = report.pointer("/jobs").and_then(|jobs| jobs.as_array()).unwrap();
+    assert_eq!(jobs.len(), 3);
+    assert!(jobs.iter().all(|job| (0. ..=1.).contains(&get_f64(job, "/latenessProbability"))));
+}
+
+parameterized_test! {can_validate_params, (iterations, deviation, expected), {
+    can_validate_params_impl(iterations, deviation, expected);
+}}
+
+can_validate_params! {
+    case01_negative_deviation: (10, -0.1, "noise deviations should be non-negative"),
+    case02_nan_deviation: (10, f64::NAN, "noise deviations should be non-negative"),
+    case03_no_iterations: (0, 0.1, "amount of iterations should be positive"),
+}
+
+fn can_validate_params_impl(iterations: usize, deviation: f64, expected: &str) {
+    let result = simulate(&create_params(iterations, deviation, NoiseDistribution::Normal));
+
+    assert_eq!(result.expect_err("no error returned").to_string(), expected);
+}
+
+parameterized_test! {can_get_distribution, (values, expected), {
+    can_get_distribution_impl(values, expected);
+}}
+
+can_get_distribution! {
+    case01_empty: (vec![], (0., 0., 0., 0., 0., 0.)),
+    case02_single: (vec![3.], (3., 0., 3., 3., 3., 3.)),
+    case03_unsorted: (vec![4., 1., 3., 2.], (2.5, 1.25_f64.sqrt(), 2., 4., 4., 4.)),
+    case04_many: ((1..=100).rev().map(|value| value as f64).collect(), (50.5, (9999_f64 / 12.).sqrt(), 50., 90., 95., 100.)),
+}
+
+fn can_get_distribution_impl(values: Vec<f64>, expected: (f64, f64, f64, f64, f64, f64)) {
+    let (mean, std_dev, p50, p90, p95, max) = expected;
+
+    let result = get_distribution(values);
+
+    assert_eq!(result.mean, mean);
+    assert!((result.std_dev - std_dev).abs() < 1E-9);
+    assert_eq!((result.p50, result.p90, result.p95, result.max), (p50, p90, p95, max));
+}
+
+#[test]
+fn can_reproduce_simulation_with_same_seed() {
+    let params = create_params(20, 0.3, NoiseDistribution::Normal);
+    let simulate_seeded = |seed| simulate_with_random(&params, Arc::new(SeededRandom::new(seed))).unwrap();
+
+    assert_eq!(simulate_seeded(42), simulate_seeded(42));
+}
+
+#[test]
+fn can_generate_same_sequence_with_same_seed() {
+    let sample = |random: &SeededRandom| (0..10).map(|_| random.uniform_int(0, 1000)).collect::<Vec<_>>();
+
+    let expected = sample(&SeededRandom::new(42));
+
+    assert_eq!(sample(&SeededRandom::new(42)), expected);
+    assert_ne!(sample(&SeededRandom::new(7)), expected);
+}
+
+#[test]
+fn can_sample_travel_noise_once_per_leg() {
+    let problem = (
+        deserialize_problem(reader(PRAGMATIC_PROBLEM_PATH)).unwrap(),
+        Some(vec![deserialize_matrix(reader(PRAGMATIC_MATRIX_PATH)).unwrap()]),
+    )
+        .read_pragmatic()
+        .unwrap();
+    let noise = create_params(1, 0.3, NoiseDistribution::Normal).noise;
+    let transport = NoisyTransportCost::new(problem.transport.clone(), NoiseSampler::new(noise, &SeededRandom::new(0)));
+
+    let factors = (0..3).map(|_| (transport.get_factor(0, 1), transport.get_factor(1, 0))).collect::<Vec<_>>();
+
+    assert!(factors.iter().all(|&factor| factor == factors[0]));
+    assert_ne!(factors[0].0, factors[0].1);
+}
@@ -9,6 +9,8 @@ fn can_get_locations_serialized() {
         plan: Plan { jobs: vec![create_test_job(1., 1.), create_test_job(1., 0.)], ..create_empty_plan() },
         fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![], resources: None },
         objectives: None,
+        timezone: None,
+        matrix_storage: None,
     };
 
     let locations = get_locations_serialized(&problem).unwrap().replace([' ', '\n'], "");
@@ -22,10 +24,19 @@ fn can_get_solution_serialized() {
         plan: Plan { jobs: vec![create_test_job(1., 0.)], ..create_empty_plan() },
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                speed_bands: None,
+                overlay: None,
+                distance_model: None,
+                travel_time_uncertainty: None,
+            }],
             resources: None,
         },
         objectives: None,
+        timezone: None,
+        matrix_storage: None,
     };
     let problem = Arc::new(problem.read_pragmatic().unwrap());
 
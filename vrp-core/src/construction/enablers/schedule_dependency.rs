@@ -0,0 +1,179 @@
+//! Provides functionality to synchronize schedules of activities from different routes when one activity
+//! cannot be started before another one is finished. Dependencies are modeled by restricting activities'
+//! time windows, so the rest of the time constraints is respected automatically.
+
+use crate::construction::enablers::LatestArrivalActivityState;
+use crate::construction::heuristics::{ActivityContext, RouteContext};
+use crate::models::common::{TimeWindow, Timestamp};
+use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use rosomaxa::prelude::compare_floats;
+use std::cmp::Ordering;
+
+/// Checks whether a new activity can be started not earlier than given time without violating
+/// time constraints of the next activity in the route.
+pub fn can_start_activity_at(
+    route_ctx: &RouteContext,
+    activity_ctx: &ActivityContext,
+    earliest: Timestamp,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+) -> bool {
+    let route = route_ctx.route();
+    let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+    let arrival = prev.schedule.departure
+        + transport.duration(
+            route,
+            prev.place.location,
+            target.place.location,
+            TravelTime::Departure(prev.schedule.departure),
+        );
+    let service_start = arrival.max(target.place.time.start).max(earliest);
+
+    if compare_floats(service_start, target.place.time.end) == Ordering::Greater {
+        return false;
+    }
+
+    let Some(next) = activity_ctx.next else { return true };
+
+    let target_departure = activity.estimate_departure(route, target, service_start);
+    let next_arrival = target_departure
+        + transport.duration(
+            route,
+            target.place.location,
+            next.place.location,
+            TravelTime::Departure(target_departure),
+        );
+    let latest_arrival = if next.job.is_some() {
+        route_ctx.state().get_latest_arrival_at(activity_ctx.index + 1).copied().unwrap_or(next.place.time.end)
+    } else {
+        next.place.time.end.min(route.actor.detail.time.end)
+    };
+
+    compare_floats(next_arrival, latest_arrival) != Ordering::Greater
+}
+
+/// Sets the earliest start time of the activity: no restriction is applied when time is not specified.
+/// Returns true if the activity is changed. Route schedule has to be updated separately.
+pub fn restrict_activity_start(route_ctx: &mut RouteContext, activity_idx: usize, earliest: Option<Timestamp>) -> bool {
+    let route = route_ctx.route();
+    let activity = get_activity(route, activity_idx);
+
+    let original = if activity_idx == 0 {
+        route
+            .actor
+            .detail
+            .start
+            .as_ref()
+            .map(|start| start.time.to_time_window())
+            .unwrap_or_else(|| activity.place.time.clone())
+    } else {
+        get_original_time_window(route, activity)
+    };
+
+    let old_start = activity.place.time.start;
+    let new_start = earliest.map_or(original.start, |earliest| original.start.max(earliest));
+
+    if compare_floats(old_start, new_start) == Ordering::Equal {
+        return false;
+    }
+
+    let activity = route_ctx.route_mut().tour.get_mut(activity_idx).unwrap();
+    activity.place.time.start = new_start;
+
+    // NOTE: route departure is moved together with its start time
+    if activity_idx == 0
+        && (activity.schedule.departure < new_start
+            || compare_floats(activity.schedule.departure, old_start) == Ordering::Equal)
+    {
+        activity.schedule.departure = new_start;
+    }
+
+    true
+}
+
+/// Sets the latest departure time of the activity: no restriction is applied when time is not specified.
+/// Returns true if the activity is changed. Route schedule has to be updated separately.
+pub fn restrict_activity_end(
+    route_ctx: &mut RouteContext,
+    activity_idx: usize,
+    latest_departure: Option<Timestamp>,
+    activity: &dyn ActivityCost,
+) -> bool {
+    let route = route_ctx.route();
+    let target = get_activity(route, activity_idx);
+    let original = get_original_time_window(route, target);
+
+    let new_end = latest_departure
+        .map_or(original.end, |latest| original.end.min(activity.estimate_arrival(route, target, latest)));
+
+    if compare_floats(target.place.time.end, new_end) == Ordering::Equal {
+        return false;
+    }
+
+    route_ctx.route_mut().tour.get_mut(activity_idx).unwrap().place.time.end = new_end;
+
+    true
+}
+
+/// Returns the latest time when the activity can be started. For the route start, it is the latest departure.
+pub fn get_latest_activity_start(
+    route_ctx: &RouteContext,
+    activity_idx: usize,
+    transport: &dyn TransportCost,
+) -> Option<Timestamp> {
+    if activity_idx > 0 {
+        return route_ctx.state().get_latest_arrival_at(activity_idx).copied();
+    }
+
+    let route = route_ctx.route();
+    let start = route.tour.start()?;
+
+    match route.tour.get(1).filter(|activity| activity.job.is_some()) {
+        Some(first) => {
+            let latest_arrival = *route_ctx.state().get_latest_arrival_at(1)?;
+            let duration = transport.duration(
+                route,
+                start.place.location,
+                first.place.location,
+                TravelTime::Arrival(latest_arrival),
+            );
+
+            Some(start.place.time.end.min(latest_arrival - duration))
+        }
+        None => Some(start.place.time.end),
+    }
+}
+
+/// Returns original time window of the job activity which is possibly restricted.
+pub fn get_original_time_window(route: &Route, activity: &Activity) -> TimeWindow {
+    let start_time = route.tour.start().map_or(Timestamp::default(), |start| start.schedule.departure);
+    let current = &activity.place.time;
+
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.places.get(activity.place.idx))
+        .and_then(|place| {
+            place.times.iter().map(|time| time.to_time_window(start_time)).find(|time| {
+                compare_floats(time.start, current.start) != Ordering::Greater
+                    && compare_floats(time.end, current.end) != Ordering::Less
+            })
+        })
+        .unwrap_or_else(|| current.clone())
+}
+
+/// Checks whether route schedule is feasible: each activity starts within its (possibly restricted) time window.
+pub fn is_feasible_schedule(route_ctx: &RouteContext) -> bool {
+    route_ctx.route().tour.all_activities().enumerate().all(|(idx, activity)| {
+        let start = if idx == 0 { activity.schedule.departure } else { activity.schedule.arrival };
+
+        compare_floats(activity.place.time.start, activity.place.time.end) != Ordering::Greater
+            && compare_floats(start, activity.place.time.end) != Ordering::Greater
+    })
+}
+
+fn get_activity(route: &Route, idx: usize) -> &Activity {
+    route.tour.get(idx).expect("cannot get activity by idx")
+}
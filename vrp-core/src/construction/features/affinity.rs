@@ -0,0 +1,105 @@
+//! A job affinity feature provides the way to prefer serving related jobs in the same tour.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/affinity_test.rs"]
+mod affinity_test;
+
+use super::*;
+use std::collections::HashMap;
+
+/// Specifies a soft affinity of the job to other jobs with the same group.
+#[derive(Clone, Debug)]
+pub struct JobAffinity {
+    /// An affinity group.
+    pub group: String,
+    /// A penalty weight applied when the job is served apart from the main tour of its group.
+    pub weight: Cost,
+}
+
+custom_dimension!(JobAffinity typeof JobAffinity);
+custom_tour_state!(AffinityWeights typeof HashMap<String, Cost>);
+custom_solution_state!(AffinityPenalty typeof Cost);
+
+/// Creates a job affinity feature as an objective which prefers jobs of the same affinity group
+/// to be served in the same tour.
+///
+/// A penalty is a total weight of the jobs which are served apart from the tour with the biggest
+/// weight of their group.
+pub fn create_job_affinity_feature(name: &str) -> Result<Feature, GenericError> {
+    FeatureBuilder::default().with_name(name).with_objective(AffinityObjective {}).with_state(AffinityState {}).build()
+}
+
+struct AffinityObjective {}
+
+impl FeatureObjective for AffinityObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.state.get_affinity_penalty().copied().unwrap_or_default()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, job } => {
+                job.dimens().get_job_affinity().map_or(Cost::default(), |affinity| {
+                    let route_weight = get_group_weight(route_ctx, &affinity.group);
+                    let max_weight = solution_ctx
+                        .routes
+                        .iter()
+                        .map(|route_ctx| get_group_weight(route_ctx, &affinity.group))
+                        .max_by(|a, b| a.total_cmp(b))
+                        .unwrap_or_default();
+
+                    // NOTE penalty grows by job's weight unless the tour becomes (or stays) the main one
+                    affinity.weight - ((route_weight + affinity.weight).max(max_weight) - max_weight)
+                })
+            }
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
+struct AffinityState {}
+
+impl FeatureState for AffinityState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if job.dimens().get_job_affinity().is_some() {
+            self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        }
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let weights = route_ctx.route().tour.jobs().filter_map(|job| job.dimens().get_job_affinity()).fold(
+            HashMap::<String, Cost>::default(),
+            |mut acc, affinity| {
+                *acc.entry(affinity.group.clone()).or_default() += affinity.weight;
+                acc
+            },
+        );
+
+        route_ctx.state_mut().set_affinity_weights(weights);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| self.accept_route_state(route_ctx));
+
+        // group -> (total weight, max tour weight)
+        let groups = solution_ctx.routes.iter().filter_map(|route_ctx| route_ctx.state().get_affinity_weights()).fold(
+            HashMap::<&String, (Cost, Cost)>::default(),
+            |mut acc, weights| {
+                weights.iter().for_each(|(group, &weight)| {
+                    let (total, max) = acc.entry(group).or_default();
+                    *total += weight;
+                    *max = max.max(weight);
+                });
+                acc
+            },
+        );
+
+        let penalty = groups.values().map(|(total, max)| total - max).sum::<Cost>();
+
+        solution_ctx.state.set_affinity_penalty(penalty);
+    }
+}
+
+fn get_group_weight(route_ctx: &RouteContext, group: &str) -> Cost {
+    route_ctx.state().get_affinity_weights().and_then(|weights| weights.get(group)).copied().unwrap_or_default()
+}
@@ -0,0 +1,162 @@
+//! A backhaul feature provides the way to serve all deliveries (linehauls) of the tour before any pickup (backhaul).
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/backhaul_test.rs"]
+mod backhaul_test;
+
+use super::*;
+use crate::models::problem::Actor;
+use crate::models::solution::Activity;
+use std::marker::PhantomData;
+
+/// Specifies a backhaul policy of the vehicle.
+#[derive(Clone, Copy, Debug)]
+pub enum BackhaulPolicy {
+    /// Pickups are never served before deliveries in the tour.
+    Strict,
+    /// Deliveries can be served after pickups with a given penalty per each such delivery.
+    Mixed {
+        /// A penalty applied for each delivery served after the first pickup in the tour.
+        penalty: Cost,
+    },
+}
+
+custom_dimension!(VehicleBackhaulPolicy typeof BackhaulPolicy);
+custom_solution_state!(BackhaulPenalty typeof Cost);
+
+/// Creates a backhaul feature. Strict policy is modeled as a hard tour order constraint, while mixed policy
+/// is modeled as an objective which penalizes deliveries served after pickups. A vehicle without a backhaul
+/// policy (see `VehicleBackhaulPolicyDimension`) is not affected.
+///
+/// A linehaul is a job with a static delivery demand and a backhaul is a job with a static pickup demand
+/// (see `JobDemandDimension`). All other jobs (e.g. pickup and delivery) are not affected.
+pub fn create_backhaul_feature<T: LoadOps>(name: &str, code: ViolationCode) -> Result<Feature, GenericError> {
+    let order_fn = TourOrderFn::Right(Arc::new(|actor, single| match get_policy(actor) {
+        Some(BackhaulPolicy::Strict) => get_haul_type::<T>(single).map_or(OrderResult::Ignored, |haul_type| {
+            OrderResult::Value(match haul_type {
+                HaulType::Linehaul => 1.,
+                HaulType::Backhaul => 2.,
+            })
+        }),
+        _ => OrderResult::Ignored,
+    }));
+
+    FeatureBuilder::from_feature(create_tour_order_hard_feature(name, code, order_fn)?)
+        .with_objective(BackhaulObjective::<T> { phantom: Default::default() })
+        .with_state(BackhaulState::<T> { phantom: Default::default() })
+        .build()
+}
+
+enum HaulType {
+    Linehaul,
+    Backhaul,
+}
+
+struct BackhaulObjective<T: LoadOps> {
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> FeatureObjective for BackhaulObjective<T> {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        let solution = &solution.solution;
+
+        solution.state.get_backhaul_penalty().copied().unwrap_or_else(|| get_total_penalty::<T>(&solution.routes))
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let Some(penalty) = get_mixed_penalty(route_ctx.route().actor.as_ref()) else {
+                    return Cost::default();
+                };
+                let Some(haul_type) = activity_ctx.target.job.as_ref().and_then(|single| get_haul_type::<T>(single))
+                else {
+                    return Cost::default();
+                };
+
+                let tour = &route_ctx.route().tour;
+                let has_backhaul_before = tour
+                    .all_activities()
+                    .take(activity_ctx.index + 1)
+                    .any(|activity| matches!(get_activity_haul_type::<T>(activity), Some(HaulType::Backhaul)));
+
+                match haul_type {
+                    HaulType::Linehaul if has_backhaul_before => penalty,
+                    HaulType::Backhaul if !has_backhaul_before => {
+                        // NOTE linehauls after the next backhaul are already penalized
+                        let linehauls_after = tour
+                            .all_activities()
+                            .skip(activity_ctx.index + 1)
+                            .map(get_activity_haul_type::<T>)
+                            .take_while(|haul_type| !matches!(haul_type, Some(HaulType::Backhaul)))
+                            .filter(|haul_type| matches!(haul_type, Some(HaulType::Linehaul)))
+                            .count();
+
+                        penalty * linehauls_after as Cost
+                    }
+                    _ => Cost::default(),
+                }
+            }
+            MoveContext::Route { .. } => Cost::default(),
+        }
+    }
+}
+
+struct BackhaulState<T: LoadOps> {
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> FeatureState for BackhaulState<T> {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let penalty = get_total_penalty::<T>(solution_ctx.routes.as_slice());
+        solution_ctx.state.set_backhaul_penalty(penalty);
+    }
+}
+
+fn get_total_penalty<T: LoadOps>(routes: &[RouteContext]) -> Cost {
+    routes
+        .iter()
+        .filter_map(|route_ctx| {
+            get_mixed_penalty(route_ctx.route().actor.as_ref()).map(|penalty| (route_ctx.route(), penalty))
+        })
+        .map(|(route, penalty)| {
+            let (_, linehauls_after) =
+                route.tour.all_activities().fold((false, 0_usize), |(has_backhaul, count), activity| {
+                    match get_activity_haul_type::<T>(activity) {
+                        Some(HaulType::Backhaul) => (true, count),
+                        Some(HaulType::Linehaul) if has_backhaul => (has_backhaul, count + 1),
+                        _ => (has_backhaul, count),
+                    }
+                });
+
+            penalty * linehauls_after as Cost
+        })
+        .sum()
+}
+
+fn get_policy(actor: &Actor) -> Option<BackhaulPolicy> {
+    actor.vehicle.dimens.get_vehicle_backhaul_policy().copied()
+}
+
+fn get_mixed_penalty(actor: &Actor) -> Option<Cost> {
+    match get_policy(actor) {
+        Some(BackhaulPolicy::Mixed { penalty }) => Some(penalty),
+        _ => None,
+    }
+}
+
+fn get_activity_haul_type<T: LoadOps>(activity: &Activity) -> Option<HaulType> {
+    activity.job.as_ref().and_then(|single| get_haul_type::<T>(single))
+}
+
+fn get_haul_type<T: LoadOps>(single: &Single) -> Option<HaulType> {
+    single.dimens.get_job_demand::<T>().and_then(|demand| match demand.get_type() {
+        DemandType::Delivery => Some(HaulType::Linehaul),
+        DemandType::Pickup => Some(HaulType::Backhaul),
+        _ => None,
+    })
+}
@@ -7,6 +7,7 @@ mod capacity_test;
 use super::*;
 use crate::construction::enablers::*;
 use crate::models::solution::Activity;
+use std::cmp::Ordering;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
@@ -27,6 +28,24 @@ pub trait JobDemandDimension {
 
     /// Gets job demand.
     fn get_job_demand<T: LoadOps>(&self) -> Option<&Demand<T>>;
+
+    /// Sets job demand per each job place. It is used when the job demand depends on the place where
+    /// the job is served, e.g. when the job can be served partially.
+    fn set_job_place_demands<T: LoadOps>(&mut self, demands: Vec<Demand<T>>) -> &mut Self;
+
+    /// Gets job demand per each job place.
+    fn get_job_place_demands<T: LoadOps>(&self) -> Option<&Vec<Demand<T>>>;
+}
+
+/// Returns a demand of the job served by the activity: the demand of the served place is preferred over job demand.
+pub fn get_activity_demand<T: LoadOps>(activity: &Activity) -> Option<&Demand<T>> {
+    let single = activity.job.as_ref()?;
+
+    single
+        .dimens
+        .get_job_place_demands()
+        .and_then(|demands| demands.get(activity.place.idx))
+        .or_else(|| single.dimens.get_job_demand())
 }
 
 /// Provides a way to build capacity limit feature.
@@ -95,6 +114,13 @@ where
 
     fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
         match (&source, &candidate) {
+            // NOTE demand of a job with place specific demands cannot be merged
+            (Job::Single(s_source), Job::Single(s_candidate))
+                if s_source.dimens.get_job_place_demands::<T>().is_some()
+                    || s_candidate.dimens.get_job_place_demands::<T>().is_some() =>
+            {
+                Err(self.violation_code)
+            }
             (Job::Single(s_source), Job::Single(s_candidate)) => {
                 let source_demand: Option<&Demand<T>> = s_source.dimens.get_job_demand();
                 let candidate_demand: Option<&Demand<T>> = s_candidate.dimens.get_job_demand();
@@ -159,10 +185,9 @@ where
                 // determine static deliveries loaded at the begin and static pickups brought to the end
                 let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
                     (acc, T::default()),
-                    |acc, activity| {
-                        self.get_demand(activity)
-                            .map(|demand| (acc.0 + demand.delivery.0, acc.1 + demand.pickup.0))
-                            .unwrap_or_else(|| acc)
+                    |(delivery, pickup), activity| match self.get_demand(activity) {
+                        Some(demand) => (delivery + demand.delivery.0.clone(), pickup + demand.pickup.0.clone()),
+                        None => (delivery, pickup),
                     },
                 );
 
@@ -174,18 +199,18 @@ where
                         let change = self.get_demand(activity).map(|demand| demand.change()).unwrap_or_default();
 
                         let current = current + change;
-                        let max = max.max_load(current);
+                        let max = max.max_load(current.clone());
 
-                        current_capacities[activity_idx] = current;
-                        max_past_capacities[activity_idx] = max;
+                        current_capacities[activity_idx] = current.clone();
+                        max_past_capacities[activity_idx] = max.clone();
 
                         (current, max)
                     },
                 );
 
-                let current_max = (start_idx..=end_idx).rev().fold(current, |max, activity_idx| {
-                    let max = max.max_load(current_capacities[activity_idx]);
-                    max_future_capacities[activity_idx] = max;
+                let current_max = (start_idx..=end_idx).rev().fold(current.clone(), |max, activity_idx| {
+                    let max = max.max_load(current_capacities[activity_idx].clone());
+                    max_future_capacities[activity_idx] = max.clone();
 
                     max
                 });
@@ -214,7 +239,7 @@ where
 {
     fn evaluate_job(&self, route_ctx: &RouteContext, job: &Job) -> Option<ConstraintViolation> {
         let can_handle = match job {
-            Job::Single(job) => self.can_handle_demand_on_intervals(route_ctx, job.dimens.get_job_demand(), None),
+            Job::Single(job) => self.can_handle_demand_on_intervals(route_ctx, get_min_demand(job), None),
             Job::Multi(job) => job
                 .jobs
                 .iter()
@@ -243,7 +268,19 @@ where
                 Some(false)
             }
         } else {
-            has_demand_violation(route_ctx, activity_ctx.index, demand, !self.has_markers(route_ctx))
+            // NOTE other places of the job can have smaller demand, so their evaluation should not be stopped
+            let has_place_demands = activity_ctx
+                .target
+                .job
+                .as_ref()
+                .is_some_and(|single| single.dimens.get_job_place_demands::<T>().is_some());
+
+            has_demand_violation(
+                route_ctx,
+                activity_ctx.index,
+                demand,
+                !self.has_markers(route_ctx) && !has_place_demands,
+            )
         };
 
         violation.map(|stopped| ConstraintViolation { code: self.violation_code, stopped })
@@ -288,10 +325,25 @@ where
     }
 
     fn get_demand<'a>(&self, activity: &'a Activity) -> Option<&'a Demand<T>> {
-        activity.job.as_ref().and_then(|single| single.dimens.get_job_demand())
+        get_activity_demand(activity)
     }
 }
 
+/// Returns the smallest demand of the job as it can be served at any of its places.
+fn get_min_demand<T: LoadOps>(single: &Single) -> Option<&Demand<T>> {
+    let get_total = |demand: &Demand<T>| {
+        demand.pickup.0.clone() + demand.pickup.1.clone() + demand.delivery.0.clone() + demand.delivery.1.clone()
+    };
+
+    single
+        .dimens
+        .get_job_place_demands()
+        .and_then(|demands| {
+            demands.iter().min_by(|a, b| get_total(a).partial_cmp(&get_total(b)).unwrap_or(Ordering::Equal))
+        })
+        .or_else(|| single.dimens.get_job_demand())
+}
+
 fn has_demand_violation<T: LoadOps>(
     route_ctx: &RouteContext,
     pivot_idx: usize,
@@ -311,16 +363,16 @@ fn has_demand_violation<T: LoadOps>(
 
     // check how static delivery affects a past max load
     if demand.delivery.0.is_not_empty() {
-        let past: T = state.get_max_past_capacity_at(pivot_idx).copied().unwrap_or_default();
-        if !capacity.can_fit(&(past + demand.delivery.0)) {
+        let past: T = state.get_max_past_capacity_at(pivot_idx).cloned().unwrap_or_default();
+        if !capacity.can_fit(&(past + demand.delivery.0.clone())) {
             return Some(stopped);
         }
     }
 
     // check how static pickup affect future max load
     if demand.pickup.0.is_not_empty() {
-        let future: T = state.get_max_future_capacity_at(pivot_idx).copied().unwrap_or_default();
-        if !capacity.can_fit(&(future + demand.pickup.0)) {
+        let future: T = state.get_max_future_capacity_at(pivot_idx).cloned().unwrap_or_default();
+        if !capacity.can_fit(&(future + demand.pickup.0.clone())) {
             return Some(false);
         }
     }
@@ -328,12 +380,12 @@ fn has_demand_violation<T: LoadOps>(
     // check dynamic load change
     let change = demand.change();
     if change.is_not_empty() {
-        let future: T = state.get_max_future_capacity_at(pivot_idx).copied().unwrap_or_default();
-        if !capacity.can_fit(&(future + change)) {
+        let future: T = state.get_max_future_capacity_at(pivot_idx).cloned().unwrap_or_default();
+        if !capacity.can_fit(&(future + change.clone())) {
             return Some(false);
         }
 
-        let current: T = state.get_current_capacity_at(pivot_idx).copied().unwrap_or_default();
+        let current: T = state.get_current_capacity_at(pivot_idx).cloned().unwrap_or_default();
         if !capacity.can_fit(&(current + change)) {
             return Some(false);
         }
@@ -344,6 +396,7 @@ fn has_demand_violation<T: LoadOps>(
 
 // TODO extend macro to support this.
 struct JobDemandDimenKey;
+struct JobPlaceDemandsDimenKey;
 impl JobDemandDimension for Dimensions {
     fn set_job_demand<T: LoadOps>(&mut self, demand: Demand<T>) -> &mut Self {
         self.set_value::<JobDemandDimenKey, _>(demand);
@@ -353,4 +406,13 @@ impl JobDemandDimension for Dimensions {
     fn get_job_demand<T: LoadOps>(&self) -> Option<&Demand<T>> {
         self.get_value::<JobDemandDimenKey, _>()
     }
+
+    fn set_job_place_demands<T: LoadOps>(&mut self, demands: Vec<Demand<T>>) -> &mut Self {
+        self.set_value::<JobPlaceDemandsDimenKey, _>(demands);
+        self
+    }
+
+    fn get_job_place_demands<T: LoadOps>(&self) -> Option<&Vec<Demand<T>>> {
+        self.get_value::<JobPlaceDemandsDimenKey, _>()
+    }
 }
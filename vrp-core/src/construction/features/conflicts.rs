@@ -0,0 +1,88 @@
+//! A conflicts feature provides the way to avoid assigning specific pairs of jobs in the same tour.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/conflicts_test.rs"]
+mod conflicts_test;
+
+use super::*;
+use std::collections::HashSet;
+
+custom_dimension!(JobConflicts typeof HashSet<String>);
+custom_tour_state!(ConflictIndex typeof ConflictIndex);
+
+/// Keeps track of ids of the jobs served in the tour and ids of the jobs they are in conflict with.
+#[derive(Clone, Default)]
+pub(crate) struct ConflictIndex {
+    job_ids: HashSet<String>,
+    conflicts: HashSet<String>,
+}
+
+/// Creates a job conflicts feature as a hard constraint: a job cannot be assigned to the tour
+/// which has any job it is in conflict with. Conflicts are checked in both directions.
+pub fn create_job_conflicts_feature(name: &str, code: ViolationCode) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(ConflictsConstraint { code })
+        .with_state(ConflictsState {})
+        .build()
+}
+
+struct ConflictsConstraint {
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for ConflictsConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let index = route_ctx.state().get_conflict_index()?;
+
+                let has_conflict = job.dimens().get_job_id().is_some_and(|id| index.conflicts.contains(id))
+                    || job.dimens().get_job_conflicts().is_some_and(|conflicts| !conflicts.is_disjoint(&index.job_ids));
+
+                if has_conflict {
+                    ConstraintViolation::fail(self.code)
+                } else {
+                    None
+                }
+            }
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        // NOTE merged job keeps only source's identity, so conflicts cannot be tracked anymore
+        match (source.dimens().get_job_conflicts(), candidate.dimens().get_job_conflicts()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
+
+struct ConflictsState {}
+
+impl FeatureState for ConflictsState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        let mut index = route_ctx.state().get_conflict_index().cloned().unwrap_or_default();
+        add_job(&mut index, job);
+
+        route_ctx.state_mut().set_conflict_index(index);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let index = route_ctx.route().tour.jobs().fold(ConflictIndex::default(), |mut acc, job| {
+            add_job(&mut acc, job);
+            acc
+        });
+
+        route_ctx.state_mut().set_conflict_index(index);
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+}
+
+fn add_job(index: &mut ConflictIndex, job: &Job) {
+    index.job_ids.extend(job.dimens().get_job_id().cloned());
+    index.conflicts.extend(job.dimens().get_job_conflicts().into_iter().flatten().cloned());
+}
@@ -5,10 +5,28 @@
 mod minimize_unassigned_test;
 
 use super::*;
+use crate::models::solution::Activity;
 use crate::utils::Either;
+use std::collections::HashMap;
 use std::iter::empty;
 
-/// Provides a way to build a feature to minimize amount of unassigned jobs.
+custom_dimension!(JobPartialRatios typeof Vec<f64>);
+
+// NOTE keeps estimates of the partially servable jobs which are not assigned yet
+custom_tour_state!(PartialJobEstimates typeof Arc<HashMap<Job, f64>>);
+
+/// Returns a ratio of the job served by the activity if the job can be served partially.
+/// A job can be served partially when it has partial ratios specified per each place (see `JobPartialRatiosDimension`).
+pub fn get_partial_ratio(activity: &Activity) -> Option<f64> {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.dimens.get_job_partial_ratios())
+        .and_then(|ratios| ratios.get(activity.place.idx).copied())
+}
+
+/// Provides a way to build a feature to minimize amount of unassigned jobs. A remaining part of partially served
+/// job is counted as unassigned proportionally to its size.
 pub struct MinimizeUnassignedBuilder {
     name: String,
     job_estimator: Option<UnassignedJobEstimator>,
@@ -36,7 +54,8 @@ impl MinimizeUnassignedBuilder {
 
         FeatureBuilder::default()
             .with_name(self.name.as_str())
-            .with_objective(MinimizeUnassignedObjective { unassigned_job_estimator })
+            .with_objective(MinimizeUnassignedObjective { unassigned_job_estimator: unassigned_job_estimator.clone() })
+            .with_state(MinimizeUnassignedState { unassigned_job_estimator })
             .build()
     }
 }
@@ -60,12 +79,75 @@ impl FeatureObjective for MinimizeUnassignedObjective {
         .chain(solution.solution.unassigned.keys())
         .map(|job| (self.unassigned_job_estimator)(&solution.solution, job))
         .sum::<f64>()
+            + solution
+                .solution
+                .routes
+                .iter()
+                .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+                .filter_map(|activity| activity.job.as_ref().zip(get_partial_ratio(activity)))
+                .map(|(single, ratio)| {
+                    (1. - ratio) * (self.unassigned_job_estimator)(&solution.solution, &Job::Single(single.clone()))
+                })
+                .sum::<f64>()
     }
 
     fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
         match move_ctx {
             MoveContext::Route { solution_ctx, job, .. } => -1. * (self.unassigned_job_estimator)(solution_ctx, job),
-            MoveContext::Activity { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let target = activity_ctx.target;
+
+                target.job.as_ref().zip(get_partial_ratio(target)).map_or(Cost::default(), |(single, ratio)| {
+                    // NOTE a new route has no state yet, so the default estimate is used for it
+                    let estimate = route_ctx
+                        .state()
+                        .get_partial_job_estimates()
+                        .and_then(|estimates| estimates.get(&Job::Single(single.clone())))
+                        .copied()
+                        .unwrap_or(1.);
+
+                    (1. - ratio) * estimate
+                })
+            }
         }
     }
 }
+
+/// Keeps estimates of partially servable jobs in route states as solution context is not available when
+/// a single activity insertion is estimated.
+struct MinimizeUnassignedState {
+    unassigned_job_estimator: UnassignedJobEstimator,
+}
+
+impl MinimizeUnassignedState {
+    fn update_estimates(&self, solution_ctx: &mut SolutionContext) {
+        let estimates = solution_ctx
+            .required
+            .iter()
+            .chain(solution_ctx.unassigned.keys())
+            .filter(|job| job.as_single().is_some_and(|single| single.dimens.get_job_partial_ratios().is_some()))
+            .map(|job| (job.clone(), (self.unassigned_job_estimator)(solution_ctx, job)))
+            .collect::<HashMap<_, _>>();
+
+        if estimates.is_empty() {
+            return;
+        }
+
+        let estimates = Arc::new(estimates);
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            route_ctx.state_mut().set_partial_job_estimates(estimates.clone());
+        });
+    }
+}
+
+impl FeatureState for MinimizeUnassignedState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, _: &Job) {
+        self.update_estimates(solution_ctx);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.update_estimates(solution_ctx);
+    }
+}
@@ -7,17 +7,26 @@ use crate::models::*;
 use rosomaxa::prelude::*;
 use std::sync::Arc;
 
+mod affinity;
+pub use self::affinity::{create_job_affinity_feature, JobAffinity, JobAffinityDimension};
+
+mod backhaul;
+pub use self::backhaul::{create_backhaul_feature, BackhaulPolicy, VehicleBackhaulPolicyDimension};
+
 mod breaks;
 pub use self::breaks::*;
 
 mod capacity;
 pub use self::capacity::{
-    CapacityFeatureBuilder, JobDemandDimension, MaxVehicleLoadTourState, VehicleCapacityDimension,
+    get_activity_demand, CapacityFeatureBuilder, JobDemandDimension, MaxVehicleLoadTourState, VehicleCapacityDimension,
 };
 
 mod compatibility;
 pub use self::compatibility::{create_compatibility_feature, JobCompatibilityDimension};
 
+mod conflicts;
+pub use self::conflicts::{create_job_conflicts_feature, JobConflictsDimension};
+
 mod fast_service;
 pub use self::fast_service::FastServiceFeatureBuilder;
 
@@ -42,8 +51,17 @@ pub use self::recharge::RechargeFeatureBuilder;
 mod reloads;
 pub use self::reloads::{ReloadFeatureFactory, ReloadIntervalsTourState, SharedResource, SharedResourceId};
 
+mod robust_schedule;
+pub use self::robust_schedule::{
+    create_robust_schedule_feature, RobustLatestArrivalActivityState, TravelUncertainty, TravelUncertaintyFn,
+    TravelVarianceActivityState,
+};
+
 mod skills;
-pub use self::skills::{create_skills_feature, JobSkills, JobSkillsDimension, VehicleSkillsDimension};
+pub use self::skills::{
+    create_skills_feature, create_skills_overqualification_feature, JobSkills, JobSkillsDimension,
+    VehicleSkillLevelsDimension, VehicleSkillsDimension,
+};
 
 mod total_value;
 pub use self::total_value::*;
@@ -57,9 +75,20 @@ pub use self::tour_limits::*;
 mod tour_order;
 pub use self::tour_order::*;
 
+mod trailer;
+pub use self::trailer::{JobTruckOnlyDimension, TrailerFeatureBuilder, VehicleTruckCapacityDimension};
+
+mod transfers;
+pub use self::transfers::{
+    create_transfer_feature, get_handover_transfer, JobTransfer, JobTransferDimension, TransferLeg,
+};
+
 mod transport;
 pub use self::transport::*;
 
+mod vehicle_alternatives;
+pub use self::vehicle_alternatives::{create_vehicle_alternatives_feature, VehicleAlternativeGroupDimension};
+
 mod work_balance;
 pub use self::work_balance::{
     create_activity_balanced_feature, create_distance_balanced_feature, create_duration_balanced_feature,
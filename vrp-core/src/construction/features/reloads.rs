@@ -2,18 +2,24 @@
 //! the tour. This is used to overcome a vehicle capacity limit. The feature has two flavors:
 //!  - simple: a basic reload place with unlimited number of jobs which can be loaded/unloaded from there
 //!  - shared: a resource constrained reload place
+//!
+//! Shared resources can be supplied by a dedicated job: such resource is available only when the supply
+//! job is served, and a route consuming it does not depart before the supply job departure.
+//! This is used to model two-echelon routing when first-level vehicles deliver cargo to transfer locations
+//! (satellites) and second-level vehicles pick it up there.
 
 #[cfg(test)]
 #[path = "../../../tests/unit/construction/features/reloads_test.rs"]
 mod reloads_test;
 
-use crate::construction::enablers::{FeatureCombinator, RouteIntervals, RouteIntervalsState};
+use crate::construction::enablers::*;
 use crate::construction::features::capacity::*;
 use crate::construction::heuristics::*;
-use crate::models::common::{Demand, LoadOps, MultiDimLoad, SingleDimLoad};
-use crate::models::problem::{Job, Single};
+use crate::models::common::{Demand, LoadOps, Location, MultiDimLoad, SingleDimLoad, Timestamp};
+use crate::models::problem::{ActivityCost, Job, Single, TransportCost, TravelTime};
 use crate::models::solution::{Activity, Route};
 use crate::models::*;
+use rosomaxa::prelude::compare_floats;
 use rosomaxa::utils::{GenericError, GenericResult};
 use std::cmp::Ordering;
 use std::collections::HashMap;
@@ -21,7 +27,7 @@ use std::ops::{Add, Range, RangeInclusive, Sub};
 use std::sync::Arc;
 
 /// Represents a shared unique resource which is used to model reload with capacity constraint.
-pub trait SharedResource: LoadOps + Add + Sub + PartialOrd + Copy + Sized + Send + Sync + Default + 'static {}
+pub trait SharedResource: LoadOps + Add + Sub + PartialOrd + Clone + Sized + Send + Sync + Default + 'static {}
 
 /// Represents a shared resource id.
 pub type SharedResourceId = usize;
@@ -40,6 +46,7 @@ pub struct ReloadFeatureFactory<T: LoadOps> {
     // these fields are needed to be set for shared reload flavor
     shared_resource_capacity_fn: Option<SharedResourceCapacityFn<T>>,
     shared_resource_demand_fn: Option<SharedResourceDemandFn<T>>,
+    shared_resource_supply: Option<SharedResourceSupply>,
     is_partial_solution_fn: Option<PartialSolutionFn>,
 }
 
@@ -68,6 +75,22 @@ impl<T: SharedResource> ReloadFeatureFactory<T> {
         self
     }
 
+    /// Sets a function which returns a shared resource id supplied by a given single job. Supplied
+    /// resource can be consumed only when the supply job is assigned and not before it is served.
+    /// Transport and activity costs are used to schedule supply and consumption in time.
+    pub fn set_shared_resource_supply<F>(
+        mut self,
+        func: F,
+        transport: Arc<dyn TransportCost>,
+        activity: Arc<dyn ActivityCost>,
+    ) -> Self
+    where
+        F: Fn(&Single) -> Option<SharedResourceId> + Send + Sync + 'static,
+    {
+        self.shared_resource_supply = Some(SharedResourceSupply { supply_fn: Arc::new(func), transport, activity });
+        self
+    }
+
     /// Sets a function which tells whether a given solution is partial.
     pub fn set_is_partial_solution<F>(mut self, func: F) -> Self
     where
@@ -99,16 +122,24 @@ impl<T: SharedResource> ReloadFeatureFactory<T> {
                     .map_or(true, |resource_available| resource_available.can_fit(demand))
             });
 
+        let supply = self.shared_resource_supply.take();
         let simple_reload = self.build(Some(shared_resource_threshold_fn))?;
 
         let shared_resource = FeatureBuilder::default()
             .with_name(self.name.as_str())
             .with_constraint(SharedResourceConstraint {
                 violation_code,
+                resource_capacity_fn: resource_capacity_fn.clone(),
                 resource_demand_fn: resource_demand_fn.clone(),
                 is_partial_solution_fn: is_partial_solution_fn.clone(),
+                supply: supply.clone(),
+            })
+            .with_state(SharedResourceState {
+                resource_capacity_fn,
+                resource_demand_fn,
+                is_partial_solution_fn,
+                supply,
             })
-            .with_state(SharedResourceState { resource_capacity_fn, resource_demand_fn, is_partial_solution_fn })
             .build()?;
 
         FeatureCombinator::default().use_name(self.name).add_features(&[simple_reload, shared_resource]).combine()
@@ -127,6 +158,7 @@ impl<T: LoadOps> ReloadFeatureFactory<T> {
             load_schedule_threshold_fn: None,
             shared_resource_capacity_fn: None,
             shared_resource_demand_fn: None,
+            shared_resource_supply: None,
             is_partial_solution_fn: None,
         }
     }
@@ -211,19 +243,15 @@ impl<T: LoadOps> ReloadFeatureFactory<T> {
                 let fold_demand = |range: Range<usize>, demand_fn: fn(&Demand<T>) -> T| {
                     route_ctx.route().tour.activities_slice(range.start, range.end).iter().fold(
                         T::default(),
-                        |acc, activity| {
-                            activity
-                                .job
-                                .as_ref()
-                                .and_then(|job| job.dimens.get_job_demand())
-                                .map(|demand| acc + demand_fn(demand))
-                                .unwrap_or_else(|| acc)
+                        |acc, activity| match get_activity_demand(activity) {
+                            Some(demand) => acc + demand_fn(demand),
+                            None => acc,
                         },
                     )
                 };
 
-                let left_pickup = fold_demand(left.clone(), |demand| demand.pickup.0);
-                let right_delivery = fold_demand(right.clone(), |demand| demand.delivery.0);
+                let left_pickup = fold_demand(left.clone(), |demand| demand.pickup.0.clone());
+                let right_delivery = fold_demand(right.clone(), |demand| demand.delivery.0.clone());
 
                 // static delivery moved to left
                 let new_max_load_left =
@@ -241,7 +269,7 @@ impl<T: LoadOps> ReloadFeatureFactory<T> {
                 has_enough_vehicle_capacity
                     && shared_resource_threshold_fn.as_ref().map_or(true, |shared_resource_threshold_fn| {
                         // total static delivery at left
-                        let left_delivery = fold_demand(left.start..right.end, |demand| demand.delivery.0);
+                        let left_delivery = fold_demand(left.start..right.end, |demand| demand.delivery.0.clone());
 
                         (shared_resource_threshold_fn)(route_ctx, left.start, &left_delivery)
                     })
@@ -267,18 +295,39 @@ custom_route_intervals_state!(pub ReloadIntervals);
 // TODO: dedicated macro doesn't support Option<T> to be stored as a type
 struct SharedResourceStateKey;
 
+/// Keeps total resource demand per shared resource on solution level.
+struct SharedResourceDemandKey;
+
+/// Keeps supply schedule per supplied shared resource on solution level.
+struct SharedResourceSupplyKey;
+
+custom_tour_state!(SharedResourceSupplies typeof HashMap<SharedResourceId, SupplySchedule>);
+custom_activity_state!(SupplyLatestArrival typeof Timestamp);
+
+/// Specifies when supplied resource is available.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SupplySchedule {
+    /// Departure of the supply job: it is None when supply job is not assigned.
+    departure: Option<Timestamp>,
+    /// The latest departure of the supply job which is allowed by resource consumers.
+    deadline: Option<Timestamp>,
+}
+
 type SharedResourceCapacityFn<T> = Arc<dyn Fn(&Activity) -> Option<(T, SharedResourceId)> + Send + Sync>;
 type SharedResourceDemandFn<T> = Arc<dyn Fn(&Single) -> Option<T> + Send + Sync>;
+type SharedResourceSupplyFn = Arc<dyn Fn(&Single) -> Option<SharedResourceId> + Send + Sync>;
 type SharedResourceThresholdFn<T> = Box<dyn Fn(&RouteContext, usize, &T) -> bool + Send + Sync>;
 type PartialSolutionFn = Arc<dyn Fn(&SolutionContext) -> bool + Send + Sync>;
 
 struct SharedResourceConstraint<T: SharedResource> {
     violation_code: ViolationCode,
+    resource_capacity_fn: SharedResourceCapacityFn<T>,
     resource_demand_fn: SharedResourceDemandFn<T>,
     is_partial_solution_fn: PartialSolutionFn,
+    supply: Option<SharedResourceSupply>,
 }
 
-impl<T: SharedResource> SharedResourceConstraint<T> {
+impl<T: SharedResource + Sub<Output = T>> SharedResourceConstraint<T> {
     fn evaluate_route(
         &self,
         solution_ctx: &SolutionContext,
@@ -289,17 +338,60 @@ impl<T: SharedResource> SharedResourceConstraint<T> {
             .and_then(|job| {
                 (self.resource_demand_fn)(job)
                     .zip(route_ctx.state().get_reload_intervals().and_then(|intervals| intervals.first()))
+                    .map(|(demand, _)| (job, demand))
             })
-            .and_then(|(_, _)| {
+            .and_then(|(job, demand)| {
                 // NOTE cannot do resource assignment for partial solution
                 if (self.is_partial_solution_fn)(solution_ctx) {
                     ConstraintViolation::fail(self.violation_code)
                 } else {
-                    ConstraintViolation::success()
+                    self.evaluate_new_route(solution_ctx, route_ctx, job, &demand)
                 }
             })
     }
 
+    /// Checks resource consumption when a new route starts with shared resource.
+    fn evaluate_new_route(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Single,
+        demand: &T,
+    ) -> Option<ConstraintViolation> {
+        if route_ctx.route().tour.has_jobs() {
+            return ConstraintViolation::success();
+        }
+
+        let (capacity, resource_id) =
+            route_ctx.route().tour.start().and_then(|start| (self.resource_capacity_fn)(start))?;
+
+        let total_demand = solution_ctx
+            .state
+            .get_value::<SharedResourceDemandKey, HashMap<SharedResourceId, T>>()
+            .and_then(|total_demand| total_demand.get(&resource_id))
+            .cloned()
+            .unwrap_or_default();
+
+        if !(capacity - total_demand).can_fit(demand) {
+            return ConstraintViolation::fail(self.violation_code);
+        }
+
+        let departure = solution_ctx
+            .state
+            .get_value::<SharedResourceSupplyKey, HashMap<SharedResourceId, SupplySchedule>>()
+            .and_then(|supplies| supplies.get(&resource_id))
+            .map(|schedule| schedule.departure)
+            .zip(self.supply.as_ref());
+
+        match departure {
+            Some((Some(departure), supply)) if supply.can_serve_from_start(route_ctx, job, departure) => {
+                ConstraintViolation::success()
+            }
+            Some(_) => ConstraintViolation::fail(self.violation_code),
+            None => ConstraintViolation::success(),
+        }
+    }
+
     fn evaluate_activity(
         &self,
         route_ctx: &RouteContext,
@@ -315,7 +407,7 @@ impl<T: SharedResource> SharedResourceConstraint<T> {
                 route_ctx
                     .state()
                     .get_activity_state::<SharedResourceStateKey, Option<T>>(start_idx)
-                    .and_then(|resource_available| *resource_available)
+                    .and_then(|resource_available| resource_available.clone())
                     .and_then(|resource_available| {
                         let resource_demand = activity_ctx
                             .target
@@ -334,10 +426,21 @@ impl<T: SharedResource> SharedResourceConstraint<T> {
                         }
                     })
             })
+            .or_else(|| {
+                self.supply.as_ref().and_then(|supply| {
+                    supply.evaluate_activity(
+                        route_ctx,
+                        activity_ctx,
+                        &self.resource_capacity_fn,
+                        &self.resource_demand_fn,
+                        self.violation_code,
+                    )
+                })
+            })
     }
 }
 
-impl<T: SharedResource> FeatureConstraint for SharedResourceConstraint<T> {
+impl<T: SharedResource + Sub<Output = T>> FeatureConstraint for SharedResourceConstraint<T> {
     fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
         match move_ctx {
             MoveContext::Route { solution_ctx, route_ctx, job } => self.evaluate_route(solution_ctx, route_ctx, job),
@@ -357,6 +460,7 @@ where
     resource_capacity_fn: SharedResourceCapacityFn<T>,
     resource_demand_fn: SharedResourceDemandFn<T>,
     is_partial_solution_fn: PartialSolutionFn,
+    supply: Option<SharedResourceSupply>,
 }
 
 impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> SharedResourceState<T> {
@@ -367,6 +471,11 @@ impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> SharedResourceState<
             return;
         }
 
+        let supplies = self
+            .supply
+            .as_ref()
+            .map(|supply| supply.update_schedules(solution_ctx, &self.resource_capacity_fn, &self.resource_demand_fn));
+
         // first pass: get total demand for each shared resource
         let total_demand = solution_ctx.routes.iter().fold(HashMap::<usize, T>::default(), |acc, route_ctx| {
             route_ctx.state().get_reload_intervals().iter().flat_map(|intervals| intervals.iter()).fold(
@@ -379,7 +488,7 @@ impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> SharedResourceState<
 
                     if let Some((resource_demand, id)) = resource_demand_with_id {
                         let entry = acc.entry(id).or_default();
-                        *entry = *entry + resource_demand;
+                        *entry = entry.clone() + resource_demand;
                     }
 
                     acc
@@ -396,7 +505,17 @@ impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> SharedResourceState<
                 let activity_idx = get_activity_by_idx(route_ctx.route(), start_idx);
                 let resource_available =
                     (self.resource_capacity_fn)(activity_idx).and_then(|(total_capacity, resource_id)| {
-                        total_demand.get(&resource_id).map(|total_demand| total_capacity - *total_demand)
+                        // NOTE: resource cannot be consumed until its supply is assigned
+                        let is_not_supplied = supplies
+                            .as_ref()
+                            .and_then(|supplies| supplies.get(&resource_id))
+                            .is_some_and(|schedule| schedule.departure.is_none());
+
+                        if is_not_supplied {
+                            Some(T::default())
+                        } else {
+                            total_demand.get(&resource_id).map(|total_demand| total_capacity - total_demand.clone())
+                        }
                     });
 
                 if let Some(resource_available) = resource_available {
@@ -404,8 +523,17 @@ impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> SharedResourceState<
                 }
             }
 
-            route_ctx.state_mut().set_activity_states::<SharedResourceStateKey, Option<T>>(available_resources)
+            route_ctx.state_mut().set_activity_states::<SharedResourceStateKey, Option<T>>(available_resources);
+
+            if let Some(supplies) = supplies.as_ref() {
+                route_ctx.state_mut().set_shared_resource_supplies(supplies.clone());
+            }
         });
+
+        if let Some(supplies) = supplies {
+            solution_ctx.state.set_value::<SharedResourceSupplyKey, _>(supplies);
+        }
+        solution_ctx.state.set_value::<SharedResourceDemandKey, _>(total_demand);
     }
 
     /// Prevents resource consumption in given route by setting available to zero (default).
@@ -416,10 +544,7 @@ impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> SharedResourceState<
             |(start_idx, end_idx)| {
                 let activity = get_activity_by_idx(route_ctx.route(), start_idx);
                 let has_resource_demand = (self.resource_capacity_fn)(activity).map_or(false, |(_, _)| {
-                    (start_idx..=end_idx)
-                        .filter_map(|idx| route_ctx.route().tour.get(idx))
-                        .filter_map(|activity| activity.job.as_ref())
-                        .any(|job| (self.resource_demand_fn)(job).is_some())
+                    has_resource_demand(route_ctx.route(), start_idx..=end_idx, &self.resource_demand_fn)
                 });
 
                 if has_resource_demand {
@@ -442,7 +567,12 @@ impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> SharedResourceState<
 
 impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> FeatureState for SharedResourceState<T> {
     fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
-        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+        let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        self.accept_route_state(route_ctx);
+        // NOTE: supply schedule depends on the actual schedule of the modified route
+        if let Some(supply) = self.supply.as_ref() {
+            update_route_schedule(route_ctx, supply.activity.as_ref(), supply.transport.as_ref());
+        }
         self.update_resource_consumption(solution_ctx);
     }
 
@@ -459,6 +589,387 @@ impl<T: SharedResource + Add<Output = T> + Sub<Output = T>> FeatureState for Sha
     }
 }
 
+/// Specifies a supply of shared resources: a job which delivers resource to its reload place.
+#[derive(Clone)]
+struct SharedResourceSupply {
+    supply_fn: SharedResourceSupplyFn,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl SharedResourceSupply {
+    /// Returns a location of supply job (route and activity index) for each supplied resource.
+    fn get_supplies(&self, solution_ctx: &SolutionContext) -> HashMap<SharedResourceId, Option<(usize, usize)>> {
+        let not_assigned = solution_ctx
+            .required
+            .iter()
+            .chain(solution_ctx.ignored.iter())
+            .chain(solution_ctx.unassigned.keys())
+            .filter_map(|job| job.as_single())
+            .filter_map(|single| (self.supply_fn)(single))
+            .map(|resource_id| (resource_id, None));
+
+        let assigned = solution_ctx.routes.iter().enumerate().flat_map(|(route_idx, route_ctx)| {
+            route_ctx.route().tour.all_activities().enumerate().filter_map(move |(activity_idx, activity)| {
+                self.get_supplied_resource(activity).map(|resource_id| (resource_id, Some((route_idx, activity_idx))))
+            })
+        });
+
+        // NOTE: assigned supplies override not assigned
+        not_assigned.chain(assigned).collect()
+    }
+
+    fn get_supplied_resource(&self, activity: &Activity) -> Option<SharedResourceId> {
+        activity.job.as_ref().and_then(|single| (self.supply_fn)(single))
+    }
+
+    /// Synchronizes schedules of supply and its consumers: a route which consumes supplied resource does not
+    /// depart before the supply is served. Returns supply schedules and stores the latest arrival states
+    /// which keep supplies not later than their consumers can wait.
+    fn update_schedules<T: SharedResource>(
+        &self,
+        solution_ctx: &mut SolutionContext,
+        resource_capacity_fn: &SharedResourceCapacityFn<T>,
+        resource_demand_fn: &SharedResourceDemandFn<T>,
+    ) -> HashMap<SharedResourceId, SupplySchedule> {
+        let supplies = self.get_supplies(solution_ctx);
+        if supplies.is_empty() {
+            return HashMap::default();
+        }
+
+        // NOTE: delayed route departure can delay supply served by the same route
+        let mut departures = get_supply_departures(solution_ctx, &supplies);
+        for _ in 0..solution_ctx.routes.len() {
+            if !self.delay_consumers(solution_ctx, &departures, resource_capacity_fn, resource_demand_fn) {
+                break;
+            }
+            departures = get_supply_departures(solution_ctx, &supplies);
+        }
+
+        let deadlines =
+            solution_ctx.routes.iter().fold(HashMap::<SharedResourceId, Timestamp>::default(), |acc, route_ctx| {
+                get_consumed_resources(route_ctx, resource_capacity_fn, resource_demand_fn)
+                    .into_iter()
+                    .filter(|resource_id| supplies.contains_key(resource_id))
+                    .fold(acc, |mut acc, resource_id| {
+                        if let Some(latest) = get_latest_activity_start(route_ctx, 0, self.transport.as_ref()) {
+                            let deadline = acc.entry(resource_id).or_insert(latest);
+                            *deadline = deadline.min(latest);
+                        }
+
+                        acc
+                    })
+            });
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let latest_arrivals = self.get_supply_latest_arrivals(route_ctx.route(), &deadlines);
+            route_ctx.state_mut().set_supply_latest_arrival_states(latest_arrivals);
+        });
+
+        departures
+            .into_iter()
+            .map(|(resource_id, departure)| {
+                (resource_id, SupplySchedule { departure, deadline: deadlines.get(&resource_id).copied() })
+            })
+            .collect()
+    }
+
+    /// Moves departure of the routes which consume supplied resource not earlier than supply is served.
+    /// Returns true if any route is changed.
+    fn delay_consumers<T: SharedResource>(
+        &self,
+        solution_ctx: &mut SolutionContext,
+        departures: &HashMap<SharedResourceId, Option<Timestamp>>,
+        resource_capacity_fn: &SharedResourceCapacityFn<T>,
+        resource_demand_fn: &SharedResourceDemandFn<T>,
+    ) -> bool {
+        solution_ctx.routes.iter_mut().fold(false, |is_changed, route_ctx| {
+            let required_departure = get_consumed_resources(route_ctx, resource_capacity_fn, resource_demand_fn)
+                .into_iter()
+                .filter_map(|resource_id| departures.get(&resource_id).copied().flatten())
+                .max_by(|a, b| compare_floats(*a, *b));
+
+            let departure = route_ctx.route().tour.start().map(|start| start.schedule.departure);
+
+            match (required_departure, departure) {
+                (Some(required), Some(departure)) if compare_floats(required, departure) == Ordering::Greater => {
+                    update_route_departure(route_ctx, self.activity.as_ref(), self.transport.as_ref(), required);
+                    true
+                }
+                _ => is_changed,
+            }
+        })
+    }
+
+    /// Returns the latest arrival at each activity which keeps supply jobs of the route not later than their deadline.
+    fn get_supply_latest_arrivals(
+        &self,
+        route: &Route,
+        deadlines: &HashMap<SharedResourceId, Timestamp>,
+    ) -> Vec<Timestamp> {
+        let mut latest_arrivals = vec![Timestamp::MAX; route.tour.total()];
+
+        (1..route.tour.total()).rev().fold(None, |next: Option<(Timestamp, Location)>, activity_idx| {
+            let activity = get_activity_by_idx(route, activity_idx);
+
+            let by_next = next.map(|(next_latest_arrival, next_location)| {
+                let latest_departure = next_latest_arrival
+                    - self.transport.duration(
+                        route,
+                        activity.place.location,
+                        next_location,
+                        TravelTime::Arrival(next_latest_arrival),
+                    );
+                self.activity.estimate_arrival(route, activity, latest_departure)
+            });
+            let by_supply = self
+                .get_supplied_resource(activity)
+                .and_then(|resource_id| deadlines.get(&resource_id))
+                .map(|deadline| self.activity.estimate_arrival(route, activity, *deadline));
+
+            let latest_arrival = match (by_next, by_supply) {
+                (Some(by_next), Some(by_supply)) => Some(by_next.min(by_supply)),
+                (by_next, by_supply) => by_next.or(by_supply),
+            };
+
+            if let Some(latest_arrival) = latest_arrival {
+                latest_arrivals[activity_idx] = latest_arrival;
+            }
+
+            latest_arrival.map(|latest_arrival| (latest_arrival, activity.place.location))
+        });
+
+        latest_arrivals
+    }
+
+    /// Checks insertion of the activity into the route with supplied resources.
+    fn evaluate_activity<T: SharedResource>(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        resource_capacity_fn: &SharedResourceCapacityFn<T>,
+        resource_demand_fn: &SharedResourceDemandFn<T>,
+        violation_code: ViolationCode,
+    ) -> Option<ConstraintViolation> {
+        // NOTE: new routes are checked on route level
+        let supplies = route_ctx.state().get_shared_resource_supplies()?;
+        let route = route_ctx.route();
+        let target_departure = self.get_departure(route, activity_ctx.prev, activity_ctx.target);
+
+        // supply job is inserted: it cannot be consumed in the same route and served after its deadline
+        if let Some(resource_id) = self.get_supplied_resource(activity_ctx.target) {
+            let is_consumed =
+                get_consumed_resources(route_ctx, resource_capacity_fn, resource_demand_fn).contains(&resource_id);
+            let is_late = supplies
+                .get(&resource_id)
+                .and_then(|schedule| schedule.deadline)
+                .is_some_and(|deadline| compare_floats(target_departure, deadline) == Ordering::Greater);
+
+            if is_consumed || is_late {
+                return ConstraintViolation::skip(violation_code);
+            }
+        }
+
+        // activity is inserted before supply job: supply cannot be delayed after its deadline
+        let latest_arrival = route_ctx.state().get_supply_latest_arrival_at(activity_ctx.index + 1);
+        if let Some((next, latest_arrival)) = activity_ctx.next.zip(latest_arrival) {
+            let next_arrival = target_departure
+                + self.transport.duration(
+                    route,
+                    activity_ctx.target.place.location,
+                    next.place.location,
+                    TravelTime::Departure(target_departure),
+                );
+
+            if compare_floats(next_arrival, *latest_arrival) == Ordering::Greater {
+                return ConstraintViolation::skip(violation_code);
+            }
+        }
+
+        // resource is consumed: the route cannot depart before supply is served
+        let resource_id = get_consumed_resource(route_ctx, activity_ctx, resource_capacity_fn, resource_demand_fn)?;
+        let departure = match supplies.get(&resource_id)?.departure {
+            Some(departure) => departure,
+            None => return ConstraintViolation::skip(violation_code),
+        };
+
+        let is_supplied_by_route =
+            route.tour.all_activities().any(|activity| self.get_supplied_resource(activity) == Some(resource_id));
+
+        if !is_supplied_by_route && self.can_depart_at(route_ctx, activity_ctx, departure) {
+            ConstraintViolation::success()
+        } else {
+            ConstraintViolation::skip(violation_code)
+        }
+    }
+
+    /// Checks whether the route with inserted activity can depart at given time.
+    fn can_depart_at(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext, departure: Timestamp) -> bool {
+        let route = route_ctx.route();
+        let Some(start) = route.tour.start() else { return false };
+
+        let delay = departure - start.schedule.departure;
+        if compare_floats(delay, 0.) != Ordering::Greater {
+            return true;
+        }
+
+        if compare_floats(departure, start.place.time.end) == Ordering::Greater {
+            return false;
+        }
+
+        // existing activities have to be served when departure is delayed
+        let is_route_feasible = !route.tour.has_jobs()
+            || get_latest_activity_start(route_ctx, 0, self.transport.as_ref())
+                .is_some_and(|latest| compare_floats(departure, latest) != Ordering::Greater);
+        if !is_route_feasible {
+            return false;
+        }
+
+        // NOTE: previous activity is delayed not more than the route departure
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let arrival = self.get_arrival(route, prev, target, delay);
+        if compare_floats(arrival.max(target.place.time.start), target.place.time.end) == Ordering::Greater {
+            return false;
+        }
+
+        let Some(next) = activity_ctx.next else { return true };
+
+        let target_departure = self.activity.estimate_departure(route, target, arrival);
+        let next_arrival = target_departure
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(target_departure),
+            );
+        let latest_arrival = if next.job.is_some() {
+            route_ctx.state().get_latest_arrival_at(activity_ctx.index + 1).copied().unwrap_or(next.place.time.end)
+        } else {
+            next.place.time.end.min(route.actor.detail.time.end)
+        };
+
+        compare_floats(next_arrival, latest_arrival) != Ordering::Greater
+    }
+
+    fn get_arrival(&self, route: &Route, prev: &Activity, target: &Activity, delay: Timestamp) -> Timestamp {
+        let departure = prev.schedule.departure + delay;
+
+        departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            )
+    }
+
+    fn get_departure(&self, route: &Route, prev: &Activity, target: &Activity) -> Timestamp {
+        self.activity.estimate_departure(route, target, self.get_arrival(route, prev, target, 0.))
+    }
+
+    /// Checks whether a job can be served in the new route which departs at given time.
+    fn can_serve_from_start(&self, route_ctx: &RouteContext, job: &Single, departure: Timestamp) -> bool {
+        let route = route_ctx.route();
+        let Some(start) = route.tour.start() else { return false };
+
+        if compare_floats(departure, start.place.time.end) == Ordering::Greater {
+            return false;
+        }
+
+        job.places.iter().any(|place| {
+            let location = place.location.unwrap_or(start.place.location);
+            let arrival = departure
+                + self.transport.duration(route, start.place.location, location, TravelTime::Departure(departure));
+
+            place.times.iter().map(|time| time.to_time_window(departure)).any(|time| {
+                let service_start = arrival.max(time.start);
+                let service_end = service_start + place.duration;
+
+                compare_floats(service_start, time.end) != Ordering::Greater
+                    && route.tour.end().filter(|end| end.job.is_none()).is_none_or(|end| {
+                        let arrival = service_end
+                            + self.transport.duration(
+                                route,
+                                location,
+                                end.place.location,
+                                TravelTime::Departure(service_end),
+                            );
+
+                        compare_floats(arrival, end.place.time.end) != Ordering::Greater
+                    })
+            })
+        })
+    }
+}
+
+/// Returns departure of supply job for each supplied resource.
+fn get_supply_departures(
+    solution_ctx: &SolutionContext,
+    supplies: &HashMap<SharedResourceId, Option<(usize, usize)>>,
+) -> HashMap<SharedResourceId, Option<Timestamp>> {
+    supplies
+        .iter()
+        .map(|(&resource_id, supply)| {
+            let departure = supply.map(|(route_idx, activity_idx)| {
+                get_activity_by_idx(solution_ctx.routes[route_idx].route(), activity_idx).schedule.departure
+            });
+
+            (resource_id, departure)
+        })
+        .collect()
+}
+
+/// Returns shared resources consumed in the route.
+fn get_consumed_resources<T>(
+    route_ctx: &RouteContext,
+    resource_capacity_fn: &SharedResourceCapacityFn<T>,
+    resource_demand_fn: &SharedResourceDemandFn<T>,
+) -> Vec<SharedResourceId> {
+    let route = route_ctx.route();
+
+    route_ctx
+        .state()
+        .get_reload_intervals()
+        .iter()
+        .flat_map(|intervals| intervals.iter())
+        .filter(|&&(start_idx, end_idx)| has_resource_demand(route, start_idx..=end_idx, resource_demand_fn))
+        .filter_map(|&(start_idx, _)| (resource_capacity_fn)(get_activity_by_idx(route, start_idx)))
+        .map(|(_, resource_id)| resource_id)
+        .collect()
+}
+
+/// Returns shared resource which is consumed when the activity is inserted into the route.
+fn get_consumed_resource<T>(
+    route_ctx: &RouteContext,
+    activity_ctx: &ActivityContext,
+    resource_capacity_fn: &SharedResourceCapacityFn<T>,
+    resource_demand_fn: &SharedResourceDemandFn<T>,
+) -> Option<SharedResourceId> {
+    let route = route_ctx.route();
+    let &(start_idx, end_idx) =
+        route_ctx.state().get_reload_intervals()?.iter().find(|(_, end_idx)| activity_ctx.index <= *end_idx)?;
+
+    // reload is inserted: the rest of the interval consumes its resource
+    if let Some((_, resource_id)) = activity_ctx.target.job.as_ref().and((resource_capacity_fn)(activity_ctx.target)) {
+        return has_resource_demand(route, activity_ctx.index + 1..=end_idx, resource_demand_fn).then_some(resource_id);
+    }
+
+    activity_ctx.target.job.as_ref().and_then(|job| (resource_demand_fn)(job))?;
+
+    (resource_capacity_fn)(get_activity_by_idx(route, start_idx)).map(|(_, resource_id)| resource_id)
+}
+
+fn has_resource_demand<T>(
+    route: &Route,
+    range: RangeInclusive<usize>,
+    resource_demand_fn: &SharedResourceDemandFn<T>,
+) -> bool {
+    range
+        .filter_map(|idx| route.tour.get(idx))
+        .filter_map(|activity| activity.job.as_ref())
+        .any(|job| (resource_demand_fn)(job).is_some())
+}
+
 fn get_activity_by_idx(route: &Route, idx: usize) -> &Activity {
     route.tour.get(idx).expect("cannot get activity by idx")
 }
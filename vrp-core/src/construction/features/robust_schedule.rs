@@ -0,0 +1,269 @@
+//! A feature to produce schedules which are robust against travel time uncertainty.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/robust_schedule_test.rs"]
+mod robust_schedule_test;
+
+use super::*;
+use crate::models::common::{Duration, Timestamp};
+use crate::models::problem::{ActivityCost, Actor, TransportCost, TravelTime};
+use crate::models::solution::Route;
+
+custom_activity_state!(TravelVariance typeof Duration);
+custom_activity_state!(RobustLatestArrival typeof Timestamp);
+
+/// Specifies travel time uncertainty of a vehicle.
+#[derive(Clone, Copy, Debug)]
+pub struct TravelUncertainty {
+    deviation: f64,
+    safety_factor: f64,
+}
+
+impl TravelUncertainty {
+    /// Creates a new instance of `TravelUncertainty`. Travel durations are modeled as independent normally distributed
+    /// values with standard deviation defined relatively to duration by `deviation` parameter (e.g. 0.2 means ±20%).
+    /// The `probability` parameter specifies the minimum probability of arriving within time window at each activity.
+    pub fn new(deviation: f64, probability: f64) -> GenericResult<Self> {
+        if !deviation.is_finite() || deviation < 0. {
+            return Err(format!("travel time deviation should be non-negative, got: {deviation}").into());
+        }
+
+        if !(0.5..1.).contains(&probability) {
+            return Err(format!("probability should be in [0.5, 1) range, got: {probability}").into());
+        }
+
+        Ok(Self { deviation, safety_factor: get_normal_quantile(probability) })
+    }
+
+    /// Returns variance of travel with given duration.
+    pub fn variance(&self, duration: Duration) -> f64 {
+        (self.deviation * duration).powi(2)
+    }
+
+    /// Returns a time buffer required to keep given variance within desired probability.
+    pub fn buffer(&self, variance: f64) -> Duration {
+        self.safety_factor * variance.max(0.).sqrt()
+    }
+}
+
+/// A function to resolve travel uncertainty of the actor.
+pub type TravelUncertaintyFn = Arc<dyn Fn(&Actor) -> Option<TravelUncertainty> + Send + Sync>;
+
+/// Creates a feature which requires arrival within time window at each activity with desired probability when travel
+/// durations are uncertain. Arrival variance is accumulated over travel legs, waiting is not considered as something
+/// what reduces it, so the estimation is conservative.
+/// This is a hard constraint.
+pub fn create_robust_schedule_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+    code: ViolationCode,
+    uncertainty_fn: TravelUncertaintyFn,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(RobustScheduleConstraint {
+            transport: transport.clone(),
+            activity: activity.clone(),
+            code,
+            uncertainty_fn: uncertainty_fn.clone(),
+        })
+        .with_state(RobustScheduleState { transport, activity, uncertainty_fn })
+        .build()
+}
+
+struct RobustScheduleConstraint {
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+    code: ViolationCode,
+    uncertainty_fn: TravelUncertaintyFn,
+}
+
+impl RobustScheduleConstraint {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let uncertainty = (self.uncertainty_fn)(route.actor.as_ref())?;
+
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let departure = prev.schedule.departure;
+        let duration_fn = |from: Location, to: Location, departure: Timestamp| {
+            self.transport.duration(route, from, to, TravelTime::Departure(departure))
+        };
+
+        let prev_variance = route_ctx.state().get_travel_variance_at(activity_ctx.index).copied().unwrap_or_default();
+        let prev_target = duration_fn(prev.place.location, target.place.location, departure);
+        let arr_time_at_target = departure + prev_target;
+        let target_variance = prev_variance + uncertainty.variance(prev_target);
+
+        if arr_time_at_target + uncertainty.buffer(target_variance) > target.place.time.end {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        let Some(next) = activity_ctx.next else {
+            return ConstraintViolation::success();
+        };
+
+        let end_time_at_target = self.activity.estimate_departure(route, target, arr_time_at_target);
+        let target_next = duration_fn(target.place.location, next.place.location, end_time_at_target);
+        let prev_next = duration_fn(prev.place.location, next.place.location, departure);
+        let arr_time_at_next = end_time_at_target + target_next;
+
+        // NOTE sqrt(a + b) <= sqrt(a) + sqrt(b), so extra buffer can be checked against the current state
+        let extra_variance =
+            uncertainty.variance(prev_target) + uncertainty.variance(target_next) - uncertainty.variance(prev_next);
+        let latest_arrival_at_next = route_ctx
+            .state()
+            .get_robust_latest_arrival_at(activity_ctx.index + 1)
+            .copied()
+            .unwrap_or(next.place.time.end);
+
+        if arr_time_at_next + uncertainty.buffer(extra_variance) > latest_arrival_at_next {
+            ConstraintViolation::skip(self.code)
+        } else {
+            ConstraintViolation::success()
+        }
+    }
+}
+
+impl FeatureConstraint for RobustScheduleConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct RobustScheduleState {
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+    uncertainty_fn: TravelUncertaintyFn,
+}
+
+impl RobustScheduleState {
+    fn get_variances(&self, route: &Route, uncertainty: &TravelUncertainty) -> Vec<f64> {
+        let Some(start) = route.tour.start() else { return Vec::default() };
+        let init = (start.place.location, start.schedule.departure, 0.);
+
+        route
+            .tour
+            .all_activities()
+            .scan(init, |(location, departure, variance), activity| {
+                let duration = self.transport.duration(
+                    route,
+                    *location,
+                    activity.place.location,
+                    TravelTime::Departure(*departure),
+                );
+
+                *variance += uncertainty.variance(duration);
+                *location = activity.place.location;
+                *departure = activity.schedule.departure;
+
+                Some(*variance)
+            })
+            .collect()
+    }
+
+    fn get_latest_arrivals(&self, route: &Route, uncertainty: &TravelUncertainty, variances: &[f64]) -> Vec<Timestamp> {
+        let mut latest_arrivals = vec![Timestamp::default(); route.tour.total()];
+
+        route.tour.all_activities().enumerate().rev().fold(None, |next, (idx, activity)| {
+            let robust_end = activity.place.time.end - uncertainty.buffer(variances[idx]);
+
+            let latest_arrival = match next {
+                Some((end_time, next_location)) if end_time != f64::MAX => {
+                    let latest_departure = end_time
+                        - self.transport.duration(
+                            route,
+                            activity.place.location,
+                            next_location,
+                            TravelTime::Arrival(end_time),
+                        );
+                    robust_end.min(self.activity.estimate_arrival(route, activity, latest_departure))
+                }
+                _ => robust_end,
+            };
+
+            latest_arrivals[idx] = latest_arrival;
+
+            Some((latest_arrival, activity.place.location))
+        });
+
+        latest_arrivals
+    }
+}
+
+impl FeatureState for RobustScheduleState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let Some(uncertainty) = (self.uncertainty_fn)(route_ctx.route().actor.as_ref()) else { return };
+
+        let variances = self.get_variances(route_ctx.route(), &uncertainty);
+        let latest_arrivals = self.get_latest_arrivals(route_ctx.route(), &uncertainty, variances.as_slice());
+
+        route_ctx.state_mut().set_travel_variance_states(variances);
+        route_ctx.state_mut().set_robust_latest_arrival_states(latest_arrivals);
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+}
+
+/// Returns quantile of standard normal distribution using rational approximation (Acklam's algorithm) which has
+/// relative error less than 1.15e-9.
+fn get_normal_quantile(probability: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] =
+        [7.784_695_709_041_462e-3, 3.224_671_290_700_398e-1, 2.445_134_137_142_996, 3.754_408_661_907_416];
+    const P_LOW: f64 = 0.02425;
+
+    let tail = |p: f64| {
+        let q = (-2. * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.)
+    };
+
+    if probability < P_LOW {
+        tail(probability)
+    } else if probability > 1. - P_LOW {
+        -tail(1. - probability)
+    } else {
+        let q = probability - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.)
+    }
+}
@@ -5,10 +5,11 @@
 mod skills_test;
 
 use super::*;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 custom_dimension!(JobSkills typeof JobSkills);
 custom_dimension!(VehicleSkills typeof HashSet<String>);
+custom_dimension!(VehicleSkillLevels typeof HashMap<String, u32>);
 
 /// A job skills limitation for a vehicle.
 pub struct JobSkills {
@@ -18,6 +19,8 @@ pub struct JobSkills {
     pub one_of: Option<HashSet<String>>,
     /// Vehicle should have none of these skills defined.
     pub none_of: Option<HashSet<String>>,
+    /// Vehicle should have all of these skills with at least specified proficiency level.
+    pub min_levels: Option<HashMap<String, u32>>,
 }
 
 impl JobSkills {
@@ -26,15 +29,29 @@ impl JobSkills {
         let map: fn(Option<Vec<_>>) -> Option<HashSet<_>> =
             |skills| skills.and_then(|v| if v.is_empty() { None } else { Some(v.into_iter().collect()) });
 
-        Self { all_of: map(all_of), one_of: map(one_of), none_of: map(none_of) }
+        Self { all_of: map(all_of), one_of: map(one_of), none_of: map(none_of), min_levels: None }
+    }
+
+    /// Sets minimum proficiency levels of skills required from a vehicle.
+    pub fn with_min_levels(mut self, min_levels: Vec<(String, u32)>) -> Self {
+        self.min_levels = if min_levels.is_empty() { None } else { Some(min_levels.into_iter().collect()) };
+        self
     }
 }
 
 /// Creates a skills feature as hard constraint.
+///
+/// A vehicle skill without explicitly specified level is considered as a skill of the first level.
 pub fn create_skills_feature(name: &str, code: ViolationCode) -> Result<Feature, GenericError> {
     FeatureBuilder::default().with_name(name).with_constraint(SkillsConstraint { code }).build()
 }
 
+/// Creates a feature which prefers not to over-qualify: it minimizes the total difference between
+/// vehicle skill levels and minimum levels required by the jobs assigned to the vehicle.
+pub fn create_skills_overqualification_feature(name: &str) -> Result<Feature, GenericError> {
+    FeatureBuilder::default().with_name(name).with_objective(SkillsOverqualificationObjective {}).build()
+}
+
 struct SkillsConstraint {
     code: ViolationCode,
 }
@@ -45,9 +62,11 @@ impl FeatureConstraint for SkillsConstraint {
             MoveContext::Route { route_ctx, job, .. } => {
                 if let Some(job_skills) = job.dimens().get_job_skills() {
                     let vehicle_skills = route_ctx.route().actor.vehicle.dimens.get_vehicle_skills();
+                    let vehicle = route_ctx.route().actor.vehicle.as_ref();
                     let is_ok = check_all_of(job_skills, &vehicle_skills)
                         && check_one_of(job_skills, &vehicle_skills)
-                        && check_none_of(job_skills, &vehicle_skills);
+                        && check_none_of(job_skills, &vehicle_skills)
+                        && check_min_levels(job_skills, vehicle);
                     if !is_ok {
                         return ConstraintViolation::fail(self.code);
                     }
@@ -72,6 +91,17 @@ impl FeatureConstraint for SkillsConstraint {
             (Some(source_skills), Some(candidate_skills)) => candidate_skills.is_subset(source_skills),
         };
 
+        let check_skill_levels = |source_levels: Option<&HashMap<String, u32>>,
+                                  candidate_levels: Option<&HashMap<String, u32>>| {
+            match (source_levels, candidate_levels) {
+                (Some(_), None) | (None, None) => true,
+                (None, Some(_)) => false,
+                (Some(source_levels), Some(candidate_levels)) => candidate_levels
+                    .iter()
+                    .all(|(skill, level)| source_levels.get(skill).is_some_and(|source_level| source_level >= level)),
+            }
+        };
+
         let has_comparable_skills = match (source_skills, candidate_skills) {
             (Some(_), None) | (None, None) => true,
             (None, Some(_)) => false,
@@ -79,6 +109,7 @@ impl FeatureConstraint for SkillsConstraint {
                 check_skill_sets(source_skills.all_of.as_ref(), candidate_skills.all_of.as_ref())
                     && check_skill_sets(source_skills.one_of.as_ref(), candidate_skills.one_of.as_ref())
                     && check_skill_sets(source_skills.none_of.as_ref(), candidate_skills.none_of.as_ref())
+                    && check_skill_levels(source_skills.min_levels.as_ref(), candidate_skills.min_levels.as_ref())
             }
         };
 
@@ -90,6 +121,47 @@ impl FeatureConstraint for SkillsConstraint {
     }
 }
 
+struct SkillsOverqualificationObjective {}
+
+impl FeatureObjective for SkillsOverqualificationObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.routes.iter().fold(Cost::default(), |acc, route_ctx| {
+            let vehicle = route_ctx.route().actor.vehicle.as_ref();
+            route_ctx.route().tour.jobs().fold(acc, |acc, job| acc + get_overqualification(job, vehicle))
+        })
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                get_overqualification(job, route_ctx.route().actor.vehicle.as_ref())
+            }
+            MoveContext::Activity { .. } => Cost::default(),
+        }
+    }
+}
+
+fn get_vehicle_skill_level(vehicle: &Vehicle, skill: &str) -> Option<u32> {
+    vehicle
+        .dimens
+        .get_vehicle_skill_levels()
+        .and_then(|levels| levels.get(skill))
+        .copied()
+        .or_else(|| vehicle.dimens.get_vehicle_skills().filter(|skills| skills.contains(skill)).map(|_| 1))
+}
+
+fn get_overqualification(job: &Job, vehicle: &Vehicle) -> Cost {
+    job.dimens()
+        .get_job_skills()
+        .and_then(|job_skills| job_skills.min_levels.as_ref())
+        .iter()
+        .flat_map(|min_levels| min_levels.iter())
+        .filter_map(|(skill, min_level)| {
+            get_vehicle_skill_level(vehicle, skill).map(|level| level.saturating_sub(*min_level) as Cost)
+        })
+        .sum()
+}
+
 fn check_all_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String>>) -> bool {
     match (job_skills.all_of.as_ref(), vehicle_skills) {
         (Some(job_skills), Some(vehicle_skills)) => job_skills.is_subset(vehicle_skills),
@@ -114,3 +186,11 @@ fn check_none_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String
         _ => true,
     }
 }
+
+fn check_min_levels(job_skills: &JobSkills, vehicle: &Vehicle) -> bool {
+    job_skills.min_levels.as_ref().is_none_or(|min_levels| {
+        min_levels
+            .iter()
+            .all(|(skill, min_level)| get_vehicle_skill_level(vehicle, skill).is_some_and(|level| level >= *min_level))
+    })
+}
@@ -5,7 +5,8 @@
 mod total_value_test;
 
 use super::*;
-use crate::models::problem::Actor;
+use crate::models::problem::{Actor, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
 use crate::utils::Either;
 use std::cmp::Ordering;
 
@@ -20,6 +21,49 @@ pub type JobWriteValueFn = Arc<dyn Fn(Job, f64) -> Job + Send + Sync>;
 /// A job value estimation function.
 type EstimateValueFn = Arc<dyn Fn(&RouteContext, &Job) -> f64 + Send + Sync>;
 
+/// Specifies a piecewise linear function of time which defines a multiplier of the job value.
+/// The function is constant before the first and after the last point.
+#[derive(Clone, Debug)]
+pub struct ValueDecay {
+    points: Vec<(Timestamp, f64)>,
+}
+
+impl ValueDecay {
+    /// Creates a new instance of `ValueDecay` from points defined as time and value multiplier pairs.
+    /// Points should be sorted by time and have non-negative finite multipliers.
+    pub fn new(points: Vec<(Timestamp, f64)>) -> Result<Self, GenericError> {
+        if points.is_empty() {
+            return Err("value decay should have at least one point".into());
+        }
+
+        if points.iter().any(|&(time, factor)| !time.is_finite() || !factor.is_finite() || factor < 0.) {
+            return Err("value decay should have finite time and non-negative multiplier".into());
+        }
+
+        if points.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+            return Err("value decay points should be sorted by time".into());
+        }
+
+        Ok(Self { points })
+    }
+
+    /// Returns value multiplier at given time.
+    pub fn factor(&self, time: Timestamp) -> f64 {
+        let idx = self.points.partition_point(|&(point_time, _)| point_time <= time);
+
+        match (idx.checked_sub(1).and_then(|idx| self.points.get(idx)), self.points.get(idx)) {
+            (Some(&(left_time, left)), Some(&(right_time, right))) => {
+                left + (right - left) * (time - left_time) / (right_time - left_time)
+            }
+            (Some(&(_, factor)), None) | (None, Some(&(_, factor))) => factor,
+            (None, None) => unreachable!("value decay has at least one point"),
+        }
+    }
+}
+
+custom_dimension!(JobValueDecay typeof ValueDecay);
+custom_tour_state!(LastValueDecayIndex typeof usize);
+
 /// Maximizes a total value of served jobs.
 pub fn create_maximize_total_job_value_feature(
     name: &str,
@@ -45,6 +89,24 @@ pub fn create_maximize_total_job_value_feature(
         .build()
 }
 
+/// Maximizes a total value of served jobs where value of some jobs decays with time (see `JobValueDecayDimension`).
+/// A job value is multiplied by value decay at arrival time to the job's activity. For multi job, the arrival
+/// time to its last sub job is used.
+pub fn create_maximize_total_job_value_with_decay_feature(
+    name: &str,
+    job_read_value_fn: JobReadValueFn,
+    job_write_value_fn: JobWriteValueFn,
+    transport: Arc<dyn TransportCost>,
+    merge_code: ViolationCode,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(MaximizeDecayedValueObjective { job_read_value_fn: job_read_value_fn.clone(), transport })
+        .with_constraint(MaximizeTotalValueConstraint { merge_code, job_read_value_fn, job_write_value_fn })
+        .with_state(ValueDecayState {})
+        .build()
+}
+
 struct MaximizeTotalValueObjective {
     estimate_value_fn: EstimateValueFn,
 }
@@ -52,30 +114,219 @@ struct MaximizeTotalValueObjective {
 impl FeatureObjective for MaximizeTotalValueObjective {
     fn fitness(&self, solution: &InsertionContext) -> Cost {
         solution.solution.routes.iter().fold(0., |acc, route_ctx| {
-            route_ctx.route().tour.jobs().fold(acc, |acc, job| acc + (self.estimate_value_fn)(route_ctx, job))
+            let tour = &route_ctx.route().tour;
+            let acc = tour.jobs().fold(acc, |acc, job| acc + (self.estimate_value_fn)(route_ctx, job));
+
+            // NOTE partially served job earns proportional value
+            tour.all_activities().fold(acc, |acc, activity| {
+                acc - get_partial_value_loss(activity, |job| (self.estimate_value_fn)(route_ctx, job))
+            })
         })
     }
 
     fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
         match move_ctx {
             MoveContext::Route { route_ctx, job, .. } => (self.estimate_value_fn)(route_ctx, job),
-            MoveContext::Activity { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                -get_partial_value_loss(activity_ctx.target, |job| (self.estimate_value_fn)(route_ctx, job))
+            }
         }
     }
 }
 
+/// Returns a part of the job value which is not earned when the job is served partially by the activity.
+fn get_partial_value_loss<F>(activity: &Activity, value_fn: F) -> f64
+where
+    F: Fn(&Job) -> f64,
+{
+    activity
+        .job
+        .as_ref()
+        .zip(get_partial_ratio(activity))
+        .map_or(0., |(single, ratio)| (1. - ratio) * value_fn(&Job::Single(single.clone())))
+}
+
 struct MaximizeTotalValueConstraint {
     merge_code: ViolationCode,
     job_read_value_fn: JobReadValueFn,
     job_write_value_fn: JobWriteValueFn,
 }
 
+struct MaximizeDecayedValueObjective {
+    job_read_value_fn: JobReadValueFn,
+    transport: Arc<dyn TransportCost>,
+}
+
+impl MaximizeDecayedValueObjective {
+    fn read_value(&self, route_ctx: &RouteContext, job: &Job) -> f64 {
+        match &self.job_read_value_fn {
+            JobReadValueFn::Left(left_fn) => (left_fn)(job),
+            JobReadValueFn::Right(right_fn) => (right_fn)(route_ctx.route().actor.as_ref(), job),
+        }
+    }
+
+    /// Returns a value of the activity's job multiplied by value decay at given arrival time.
+    fn get_decayed_value(&self, route_ctx: &RouteContext, activity: &Activity, arrival: Timestamp) -> Option<f64> {
+        let ratio = get_partial_ratio(activity).unwrap_or(1.);
+
+        get_decayed_job(activity).and_then(|job| {
+            job.dimens()
+                .get_job_value_decay()
+                .map(|decay| self.read_value(route_ctx, &job) * decay.factor(arrival) * ratio)
+        })
+    }
+
+    /// Returns a value loss of the partially served job without value decay.
+    fn get_partial_value_loss(&self, route_ctx: &RouteContext, activity: &Activity) -> f64 {
+        get_partial_value_loss(activity, |job| {
+            if job.dimens().get_job_value_decay().is_none() {
+                self.read_value(route_ctx, job)
+            } else {
+                0.
+            }
+        })
+    }
+
+    /// Estimates value loss of the activities which are served later because of the target activity insertion.
+    fn estimate_delay_loss(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> f64 {
+        let (route, state) = (route_ctx.route(), route_ctx.state());
+        let (Some(next), Some(&last_idx)) = (activity_ctx.next, state.get_last_value_decay_index()) else {
+            return 0.;
+        };
+
+        if last_idx <= activity_ctx.index {
+            return 0.;
+        }
+
+        let target = activity_ctx.target;
+        let target_arrival = self.get_arrival(route, activity_ctx.prev, target);
+        let target_departure = target_arrival.max(target.place.time.start) + target.place.duration;
+        let next_arrival = target_departure
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(target_departure),
+            );
+
+        let (loss, _) = route
+            .tour
+            .activities_slice(activity_ctx.index + 1, last_idx)
+            .iter()
+            .try_fold((0., next_arrival - next.schedule.arrival), |(loss, delay), activity| {
+                if delay <= 0. {
+                    return Err((loss, delay));
+                }
+
+                let arrival = activity.schedule.arrival;
+                let loss = loss + self.get_decayed_value(route_ctx, activity, arrival).unwrap_or_default()
+                    - self.get_decayed_value(route_ctx, activity, arrival + delay).unwrap_or_default();
+                let start = arrival.max(activity.place.time.start);
+                let delay = (arrival + delay).max(activity.place.time.start) - start;
+
+                Ok((loss, delay))
+            })
+            .unwrap_or_else(|result| result);
+
+        loss
+    }
+
+    fn get_arrival(&self, route: &Route, prev: &Activity, target: &Activity) -> Timestamp {
+        let departure = prev.schedule.departure;
+
+        departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            )
+    }
+}
+
+impl FeatureObjective for MaximizeDecayedValueObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.routes.iter().fold(0., |acc, route_ctx| {
+            let tour = &route_ctx.route().tour;
+
+            let acc = tour
+                .jobs()
+                .filter(|job| job.dimens().get_job_value_decay().is_none())
+                .fold(acc, |acc, job| acc - self.read_value(route_ctx, job));
+
+            tour.all_activities().fold(acc, |acc, activity| {
+                acc - self.get_decayed_value(route_ctx, activity, activity.schedule.arrival).unwrap_or_default()
+                    + self.get_partial_value_loss(route_ctx, activity)
+            })
+        })
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } if job.dimens().get_job_value_decay().is_none() => {
+                -self.read_value(route_ctx, job)
+            }
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let arrival = self.get_arrival(route_ctx.route(), activity_ctx.prev, activity_ctx.target);
+                let value = self.get_decayed_value(route_ctx, activity_ctx.target, arrival).unwrap_or_default();
+
+                self.estimate_delay_loss(route_ctx, activity_ctx) - value
+                    + self.get_partial_value_loss(route_ctx, activity_ctx.target)
+            }
+        }
+    }
+}
+
+struct ValueDecayState {}
+
+impl FeatureState for ValueDecayState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let last_idx =
+            route_ctx.route().tour.all_activities().rposition(|activity| get_decayed_job(activity).is_some());
+
+        if let Some(last_idx) = last_idx {
+            route_ctx.state_mut().set_last_value_decay_index(last_idx);
+        } else {
+            route_ctx.state_mut().remove_last_value_decay_index();
+        }
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        });
+    }
+}
+
+/// Returns a job with value decay if the activity defines the time when the job is served.
+fn get_decayed_job(activity: &Activity) -> Option<Job> {
+    let single = activity.job.as_ref()?;
+
+    match Multi::roots(single) {
+        Some(multi) => multi
+            .jobs
+            .last()
+            .is_some_and(|last| Arc::ptr_eq(last, single) && multi.dimens.get_job_value_decay().is_some())
+            .then_some(Job::Multi(multi)),
+        None => single.dimens.get_job_value_decay().map(|_| Job::Single(single.clone())),
+    }
+}
+
 impl FeatureConstraint for MaximizeTotalValueConstraint {
     fn evaluate(&self, _: &MoveContext<'_>) -> Option<ConstraintViolation> {
         None
     }
 
     fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if source.dimens().get_job_value_decay().is_some() || candidate.dimens().get_job_value_decay().is_some() {
+            return Err(self.merge_code);
+        }
+
         match &self.job_read_value_fn {
             JobReadValueFn::Left(left_fn) => {
                 let source_value = (left_fn)(&source);
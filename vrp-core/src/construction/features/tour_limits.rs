@@ -8,8 +8,10 @@ use super::*;
 use crate::construction::enablers::{
     calculate_travel_delta, LimitDurationTourState, TotalDistanceTourState, TotalDurationTourState,
 };
-use crate::models::common::{Distance, Duration};
-use crate::models::problem::{Actor, TransportCost};
+use crate::models::common::{Distance, Duration, Timestamp};
+use crate::models::problem::{Actor, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+use std::iter::once;
 
 /// A function which returns activity size limit for a given actor.
 pub type ActivitySizeResolver = Arc<dyn Fn(&Actor) -> Option<usize> + Sync + Send>;
@@ -52,6 +54,29 @@ pub fn create_travel_limit_feature(
         .build()
 }
 
+/// Creates a travel limits for a single leg between two consecutive activities such as distance and/or duration.
+/// Activities without own location (e.g. breaks without place) do not split a leg.
+/// This is a hard constraint.
+pub fn create_leg_limit_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    distance_code: ViolationCode,
+    duration_code: ViolationCode,
+    leg_distance_limit_fn: TravelLimitFn<Distance>,
+    leg_duration_limit_fn: TravelLimitFn<Duration>,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(LegLimitConstraint {
+            transport,
+            leg_distance_limit_fn,
+            leg_duration_limit_fn,
+            distance_code,
+            duration_code,
+        })
+        .build()
+}
+
 struct ActivityLimitConstraint {
     code: ViolationCode,
     limit_fn: ActivitySizeResolver,
@@ -137,6 +162,94 @@ impl FeatureConstraint for TravelLimitConstraint {
     }
 }
 
+struct LegLimitConstraint {
+    transport: Arc<dyn TransportCost>,
+    leg_distance_limit_fn: TravelLimitFn<Distance>,
+    leg_duration_limit_fn: TravelLimitFn<Duration>,
+    distance_code: ViolationCode,
+    duration_code: ViolationCode,
+}
+
+impl LegLimitConstraint {
+    fn calculate_leg(
+        &self,
+        route: &Route,
+        from: &Activity,
+        to: &Activity,
+        departure: Timestamp,
+    ) -> (Distance, Duration) {
+        let travel_time = TravelTime::Departure(departure);
+
+        (
+            self.transport.distance(route, from.place.location, to.place.location, travel_time),
+            self.transport.duration(route, from.place.location, to.place.location, travel_time),
+        )
+    }
+}
+
+/// Checks whether activity has location defined by its job (or is not a job activity at all).
+fn has_own_location(activity: &Activity) -> bool {
+    activity
+        .job
+        .as_ref()
+        .is_none_or(|single| single.places.get(activity.place.idx).is_none_or(|place| place.location.is_some()))
+}
+
+impl FeatureConstraint for LegLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { route_ctx, activity_ctx } => {
+                let route = route_ctx.route();
+                let leg_distance_limit = (self.leg_distance_limit_fn)(route.actor.as_ref());
+                let leg_duration_limit = (self.leg_duration_limit_fn)(route.actor.as_ref());
+
+                if leg_distance_limit.is_none() && leg_duration_limit.is_none() {
+                    return None;
+                }
+
+                // NOTE activity without own location (e.g. break without place) does not split a leg
+                if !has_own_location(activity_ctx.target) {
+                    return None;
+                }
+
+                let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+                let tour = &route.tour;
+                let prev_located = once(prev)
+                    .chain(tour.all_activities().take(activity_ctx.index).rev())
+                    .find(|activity| has_own_location(activity))
+                    .unwrap_or(prev);
+                let next_located = activity_ctx.next.map(|next| {
+                    once(next)
+                        .chain(tour.all_activities().skip(activity_ctx.index + 2))
+                        .find(|activity| has_own_location(activity))
+                        .unwrap_or(next)
+                });
+
+                let prev_leg = self.calculate_leg(route, prev_located, target, prev.schedule.departure);
+                let next_leg = next_located
+                    .map(|next| self.calculate_leg(route, target, next, prev.schedule.departure + prev_leg.1));
+
+                once(prev_leg).chain(next_leg).find_map(|(distance, duration)| {
+                    if leg_distance_limit.is_some_and(|limit| limit < distance) {
+                        return ConstraintViolation::skip(self.distance_code);
+                    }
+
+                    if leg_duration_limit.is_some_and(|limit| limit < duration) {
+                        return ConstraintViolation::skip(self.duration_code);
+                    }
+
+                    None
+                })
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
 struct TravelLimitState {
     tour_duration_limit_fn: TravelLimitFn<Duration>,
 }
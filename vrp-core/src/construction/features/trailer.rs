@@ -0,0 +1,387 @@
+//! A truck and trailer feature: a vehicle can decouple its trailer at a parking place, serve jobs which
+//! are accessible only for the truck alone, and recouple the trailer later at the same parking place.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/trailer_test.rs"]
+mod trailer_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use crate::models::problem::Actor;
+use crate::models::solution::Activity;
+use std::collections::HashSet;
+use std::marker::PhantomData;
+
+custom_dimension!(JobTruckOnly typeof bool);
+custom_dimension!(VehicleTruckCapacity typeof T: LoadOps);
+custom_activity_state!(TrailerDecouple typeof Option<usize>);
+
+type TrailerSingleFn = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
+type BelongsToActorFn = Arc<dyn Fn(&Actor, &Job) -> bool + Send + Sync>;
+
+/// Provides a way to build truck and trailer feature.
+///
+/// A parking job is expected to be a multi job which consists of two singles: decouple and recouple.
+/// The vehicle capacity (see `VehicleCapacityDimension`) is considered as capacity of the truck-plus-trailer
+/// combination, while a truck alone capacity is specified by `VehicleTruckCapacityDimension`. A vehicle
+/// without truck capacity is considered as a vehicle without a trailer.
+pub struct TrailerFeatureBuilder<T: LoadOps> {
+    name: String,
+    violation_code: Option<ViolationCode>,
+    is_decouple_single_fn: Option<TrailerSingleFn>,
+    is_recouple_single_fn: Option<TrailerSingleFn>,
+    belongs_to_actor_fn: Option<BelongsToActorFn>,
+    phantom_data: PhantomData<T>,
+}
+
+impl<T: LoadOps> TrailerFeatureBuilder<T> {
+    /// Creates a new instance of `TrailerFeatureBuilder`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            violation_code: None,
+            is_decouple_single_fn: None,
+            is_recouple_single_fn: None,
+            belongs_to_actor_fn: None,
+            phantom_data: Default::default(),
+        }
+    }
+
+    /// Sets constraint violation code which is used to report back the reason of job's unassignment.
+    pub fn set_violation_code(mut self, violation_code: ViolationCode) -> Self {
+        self.violation_code = Some(violation_code);
+        self
+    }
+
+    /// Sets a function which specifies whether a given single job is a trailer decouple activity.
+    pub fn set_is_decouple_single<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Single) -> bool + Send + Sync + 'static,
+    {
+        self.is_decouple_single_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets a function which specifies whether a given single job is a trailer recouple activity.
+    pub fn set_is_recouple_single<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Single) -> bool + Send + Sync + 'static,
+    {
+        self.is_recouple_single_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets a function which specifies whether a given actor can serve a given parking job. This function
+    /// should return false, if the job is not a parking job.
+    pub fn set_belongs_to_actor<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Actor, &Job) -> bool + Send + Sync + 'static,
+    {
+        self.belongs_to_actor_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Builds the truck and trailer feature if all dependencies are set.
+    pub fn build(self) -> GenericResult<Feature> {
+        let is_decouple_single_fn =
+            self.is_decouple_single_fn.ok_or_else(|| GenericError::from("is_decouple_single must be set"))?;
+        let is_recouple_single_fn =
+            self.is_recouple_single_fn.ok_or_else(|| GenericError::from("is_recouple_single must be set"))?;
+        let belongs_to_actor_fn =
+            self.belongs_to_actor_fn.ok_or_else(|| GenericError::from("belongs_to_actor must be set"))?;
+
+        let code = self.violation_code.unwrap_or_default();
+        let parking = ParkingFns { is_decouple_single_fn, is_recouple_single_fn, belongs_to_actor_fn };
+
+        let context_transition = Box::new(ConcreteJobContextTransition {
+            remove_required: {
+                let parking = parking.clone();
+                move |_, _, job| parking.is_parking_job(job)
+            },
+            promote_required: |_, _, _| false,
+            remove_locked: |_, _, _| false,
+            promote_locked: {
+                let parking = parking.clone();
+                move |_, _, job| parking.is_parking_job(job)
+            },
+        });
+
+        FeatureBuilder::default()
+            .with_name(self.name.as_str())
+            .with_constraint(TrailerConstraint::<T> { code, parking: parking.clone(), phantom: Default::default() })
+            .with_state(TrailerState { code, parking, context_transition })
+            .build()
+    }
+}
+
+#[derive(Clone)]
+struct ParkingFns {
+    is_decouple_single_fn: TrailerSingleFn,
+    is_recouple_single_fn: TrailerSingleFn,
+    belongs_to_actor_fn: BelongsToActorFn,
+}
+
+impl ParkingFns {
+    fn is_parking_job(&self, job: &Job) -> bool {
+        job.as_multi().is_some_and(|multi| multi.jobs.iter().any(|single| (self.is_decouple_single_fn)(single)))
+    }
+
+    fn is_decouple(&self, activity: &Activity) -> bool {
+        activity.job.as_ref().is_some_and(|single| (self.is_decouple_single_fn)(single))
+    }
+
+    fn is_recouple(&self, activity: &Activity) -> bool {
+        activity.job.as_ref().is_some_and(|single| (self.is_recouple_single_fn)(single))
+    }
+}
+
+struct TrailerConstraint<T: LoadOps> {
+    code: ViolationCode,
+    parking: ParkingFns,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LoadOps> FeatureConstraint for TrailerConstraint<T> {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                if self.parking.is_parking_job(job)
+                    && !(self.parking.belongs_to_actor_fn)(&route_ctx.route().actor, job)
+                {
+                    ConstraintViolation::fail(self.code)
+                } else {
+                    None
+                }
+            }
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if is_truck_only(&source) == is_truck_only(&candidate) {
+            Ok(source)
+        } else {
+            Err(self.code)
+        }
+    }
+}
+
+impl<T: LoadOps> TrailerConstraint<T> {
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let truck_capacity: &T = route.actor.vehicle.dimens.get_vehicle_truck_capacity()?;
+        let target = activity_ctx.target;
+        let decouple_idx = route_ctx.state().get_trailer_decouple_at(activity_ctx.index).copied().flatten();
+
+        let is_violation = if self.parking.is_decouple(target) {
+            // NOTE parking places cannot be nested
+            decouple_idx.is_some()
+        } else if self.parking.is_recouple(target) {
+            match decouple_idx {
+                Some(decouple_idx) if is_same_job(route.tour.get(decouple_idx), target) => {
+                    let activities =
+                        route.tour.all_activities().skip(decouple_idx + 1).take(activity_ctx.index - decouple_idx);
+                    !truck_capacity.can_fit(&get_max_truck_load(activities))
+                }
+                _ => true,
+            }
+        } else if let Some(decouple_idx) = decouple_idx {
+            let recouple_idx = (activity_ctx.index + 1..route.tour.total())
+                .find(|&idx| route.tour.get(idx).is_some_and(|activity| self.parking.is_recouple(activity)))
+                .unwrap_or(route.tour.total());
+
+            let activities = route
+                .tour
+                .all_activities()
+                .skip(decouple_idx + 1)
+                .take(activity_ctx.index - decouple_idx)
+                .chain(std::iter::once(target))
+                .chain(
+                    route
+                        .tour
+                        .all_activities()
+                        .skip(activity_ctx.index + 1)
+                        .take(recouple_idx - activity_ctx.index - 1),
+                );
+
+            !truck_capacity.can_fit(&get_max_truck_load(activities))
+        } else {
+            target.retrieve_job().is_some_and(|job| is_truck_only(&job))
+        };
+
+        if is_violation {
+            ConstraintViolation::skip(self.code)
+        } else {
+            None
+        }
+    }
+}
+
+struct TrailerState {
+    code: ViolationCode,
+    parking: ParkingFns,
+    context_transition: Box<dyn JobContextTransition>,
+}
+
+impl FeatureState for TrailerState {
+    fn notify_failure(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], jobs: &[Job]) -> bool {
+        if !jobs.iter().any(is_truck_only) {
+            return false;
+        }
+
+        let parking_jobs = solution_ctx
+            .ignored
+            .iter()
+            .filter(|job| self.parking.is_parking_job(job))
+            .filter(|job| {
+                // NOTE unused actors are also considered as a new route can be created for truck only job
+                solution_ctx
+                    .routes
+                    .iter()
+                    .enumerate()
+                    .filter(|(idx, _)| route_indices.is_empty() || route_indices.contains(idx))
+                    .map(|(_, route_ctx)| route_ctx.route().actor.clone())
+                    .chain(solution_ctx.registry.resources().available())
+                    .any(|actor| (self.parking.belongs_to_actor_fn)(actor.as_ref(), job))
+            })
+            .cloned()
+            .collect::<HashSet<_>>();
+
+        if parking_jobs.is_empty() {
+            false
+        } else {
+            solution_ctx.ignored.retain(|job| !parking_jobs.contains(job));
+            solution_ctx.locked.extend(parking_jobs.iter().cloned());
+            solution_ctx.required.extend(parking_jobs);
+
+            true
+        }
+    }
+
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap());
+
+        if !self.parking.is_parking_job(job) {
+            return;
+        }
+
+        // move the rest parking jobs of the route back to ignored
+        let actor = &solution_ctx.routes[route_index].route().actor;
+        let jobs = solution_ctx
+            .required
+            .iter()
+            .filter(|job| self.parking.is_parking_job(job) && (self.parking.belongs_to_actor_fn)(actor, job))
+            .cloned()
+            .collect::<HashSet<_>>();
+        solution_ctx.required.retain(|job| !jobs.contains(job));
+        solution_ctx.ignored.extend(jobs);
+
+        // NOTE reevaluate insertion of jobs unassigned due to the trailer constraint
+        solution_ctx.unassigned.iter_mut().for_each(|pair| match pair.1 {
+            UnassignmentInfo::Simple(code) if *code == self.code => {
+                *pair.1 = UnassignmentInfo::Unknown;
+            }
+            _ => {}
+        });
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let (decouples, _) = route_ctx.route().tour.all_activities().enumerate().fold(
+            (Vec::with_capacity(route_ctx.route().tour.total()), None),
+            |(mut decouples, current), (idx, activity)| {
+                let current = if self.parking.is_decouple(activity) {
+                    Some(idx)
+                } else if self.parking.is_recouple(activity) {
+                    None
+                } else {
+                    current
+                };
+
+                decouples.push(current);
+
+                (decouples, current)
+            },
+        );
+
+        route_ctx.state_mut().set_trailer_decouple_states(decouples);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        process_conditional_jobs(solution_ctx, None, self.context_transition.as_ref());
+
+        // NOTE remove parking jobs which have nothing served between decouple and recouple
+        let mut extra_ignored = Vec::new();
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let empty_parking_jobs = route_ctx
+                .route()
+                .tour
+                .jobs()
+                .filter(|job| self.parking.is_parking_job(job))
+                .filter(|job| {
+                    let tour = &route_ctx.route().tour;
+                    tour.index(job).zip(tour.index_last(job)).is_some_and(|(start, end)| start + 1 == end)
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+
+            let removed_parking_jobs =
+                empty_parking_jobs.into_iter().filter(|job| route_ctx.route_mut().tour.remove(job)).collect::<Vec<_>>();
+
+            if !removed_parking_jobs.is_empty() {
+                self.accept_route_state(route_ctx);
+                extra_ignored.extend(removed_parking_jobs);
+            }
+        });
+
+        let unassigned =
+            solution_ctx.unassigned.keys().filter(|job| self.parking.is_parking_job(job)).cloned().collect::<Vec<_>>();
+        solution_ctx.unassigned.retain(|job, _| !self.parking.is_parking_job(job));
+
+        solution_ctx.ignored.extend(extra_ignored.into_iter().chain(unassigned));
+    }
+}
+
+fn is_truck_only(job: &Job) -> bool {
+    job.dimens().get_job_truck_only().copied().unwrap_or(false)
+}
+
+fn is_same_job(activity: Option<&Activity>, other: &Activity) -> bool {
+    activity.and_then(|activity| activity.retrieve_job()).zip(other.retrieve_job()).is_some_and(|(a, b)| a == b)
+}
+
+/// Returns max load carried by the truck alone while serving given activities after trailer is decoupled.
+/// Goods which are not needed for these activities are assumed to be left in the trailer.
+fn get_max_truck_load<'a, T: LoadOps>(activities: impl Iterator<Item = &'a Activity>) -> T {
+    let activities = activities.collect::<Vec<_>>();
+    let get_demand = |activity: &Activity| -> Option<Demand<T>> { get_activity_demand::<T>(activity).cloned() };
+
+    // NOTE dynamic delivery has to be taken at decouple place when its pickup happens before
+    let start = activities.iter().enumerate().fold(T::default(), |acc, (idx, activity)| match get_demand(activity) {
+        Some(demand) => {
+            let is_picked_before = demand.delivery.1.is_not_empty()
+                && activities[..idx].iter().all(|other| !is_same_job(Some(other), activity));
+            let acc = acc + demand.delivery.0;
+
+            if is_picked_before {
+                acc + demand.delivery.1
+            } else {
+                acc
+            }
+        }
+        None => acc,
+    });
+
+    activities
+        .iter()
+        .fold((start.clone(), start), |(current, max), activity| {
+            let current = current + get_demand(activity).map(|demand| demand.change()).unwrap_or_default();
+            let max = max.max_load(current.clone());
+
+            (current, max)
+        })
+        .1
+}
@@ -0,0 +1,535 @@
+//! A feature to model transfer (cross-docking) of jobs between two vehicles at transfer points.
+//!
+//! A transferable job is represented by three alternative jobs which share the same transfer id:
+//! * a direct job which is served by a single vehicle as usual
+//! * a first leg: a multi job which takes the job at its origin and ends with handover at the transfer point
+//! * a second leg: a multi job which starts with handover at the transfer point and brings the job to its destination
+//!
+//! Either the direct job or both legs have to be assigned. Legs are served by different vehicles at the same
+//! transfer point (the same place index of handover activities) and the second leg's handover cannot be started
+//! before the first leg's handover is finished. Partially assigned transfers are removed from the solution when
+//! none of their alternatives is left in required jobs.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/transfers_test.rs"]
+mod transfers_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use crate::models::solution::Activity;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+/// Specifies a role of the job within transfer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum TransferLeg {
+    /// The job is served by a single vehicle.
+    Direct,
+    /// The job is taken from its origin and left at the transfer point: handover is the last job activity.
+    First,
+    /// The job is taken at the transfer point to its destination: handover is the first job activity.
+    Second,
+}
+
+/// Specifies transfer alternative of the job.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct JobTransfer {
+    /// A transfer id which is the same for all alternatives of the job.
+    pub id: usize,
+    /// A transfer leg.
+    pub leg: TransferLeg,
+}
+
+custom_dimension!(JobTransfer typeof JobTransfer);
+
+/// Creates a feature to transfer jobs between vehicles at transfer points.
+pub fn create_transfer_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+    code: ViolationCode,
+) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(TransferConstraint { code, transport: transport.clone(), activity: activity.clone() })
+        .with_state(TransferState { code, transport, activity })
+        .build()
+}
+
+/// Returns transfer of the single job if it represents handover activity.
+pub fn get_handover_transfer(single: &Single) -> Option<JobTransfer> {
+    let multi = Multi::roots(single)?;
+    let transfer = multi.dimens.get_job_transfer().copied()?;
+
+    let handover = match transfer.leg {
+        TransferLeg::Direct => None,
+        TransferLeg::First => multi.jobs.last(),
+        TransferLeg::Second => multi.jobs.first(),
+    }?;
+
+    if std::ptr::eq(handover.as_ref(), single) {
+        Some(transfer)
+    } else {
+        None
+    }
+}
+
+struct TransferAssignmentsKey;
+
+type TransferAssignments = Arc<HashMap<usize, TransferAssignment>>;
+
+/// Keeps information about assigned alternatives of the job.
+#[derive(Clone, Default)]
+struct TransferAssignment {
+    direct: bool,
+    first: Option<Handover>,
+    second: Option<Handover>,
+}
+
+/// Keeps information about assigned handover activity.
+#[derive(Clone)]
+struct Handover {
+    actor: Arc<Actor>,
+    place_idx: usize,
+    departure: Timestamp,
+    latest_start: Option<Timestamp>,
+}
+
+struct TransferConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl TransferConstraint {
+    fn evaluate_route(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<ConstraintViolation> {
+        let transfer = job.dimens().get_job_transfer()?;
+        let assignment = solution_ctx
+            .state
+            .get_value::<TransferAssignmentsKey, TransferAssignments>()
+            .and_then(|assignments| assignments.get(&transfer.id))?;
+
+        let actor = &route_ctx.route().actor;
+        let is_same_actor = |handover: &Option<Handover>| handover.as_ref().is_some_and(|h| h.actor == *actor);
+
+        let is_violated = match transfer.leg {
+            TransferLeg::Direct => assignment.first.is_some() || assignment.second.is_some(),
+            TransferLeg::First => assignment.direct || is_same_actor(&assignment.second),
+            TransferLeg::Second => assignment.direct || is_same_actor(&assignment.first),
+        };
+
+        if is_violated {
+            return ConstraintViolation::fail(self.code);
+        }
+
+        // NOTE: new route has no tour state, so handover time is roughly estimated here
+        if route_ctx.route().tour.job_count() == 0 && !self.can_handover_in_new_route(route_ctx, job, assignment) {
+            return ConstraintViolation::fail(self.code);
+        }
+
+        None
+    }
+
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let transfer = activity_ctx.target.job.as_ref().and_then(|single| get_handover_transfer(single))?;
+        let assignment = route_ctx
+            .state()
+            .get_tour_state::<TransferAssignmentsKey, TransferAssignments>()
+            .and_then(|assignments| assignments.get(&transfer.id))?;
+
+        let target = activity_ctx.target;
+        let is_feasible = match transfer.leg {
+            TransferLeg::Direct => true,
+            TransferLeg::First => assignment.second.as_ref().is_none_or(|second| {
+                second.place_idx == target.place.idx
+                    && second.latest_start.is_none_or(|latest_start| {
+                        let departure = self.estimate_departure(route_ctx, activity_ctx);
+                        compare_floats(departure, latest_start) != Ordering::Greater
+                    })
+            }),
+            TransferLeg::Second => assignment.first.as_ref().is_none_or(|first| {
+                first.place_idx == target.place.idx
+                    && can_start_activity_at(
+                        route_ctx,
+                        activity_ctx,
+                        first.departure,
+                        self.activity.as_ref(),
+                        self.transport.as_ref(),
+                    )
+            }),
+        };
+
+        if is_feasible {
+            None
+        } else {
+            ConstraintViolation::skip(self.code)
+        }
+    }
+
+    fn estimate_departure(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Timestamp {
+        let route = route_ctx.route();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let arrival = prev.schedule.departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+
+        self.activity.estimate_departure(route, target, arrival.max(target.place.time.start))
+    }
+
+    /// Checks whether handover can be done in time when vehicle goes directly to the transfer point.
+    fn can_handover_in_new_route(&self, route_ctx: &RouteContext, job: &Job, assignment: &TransferAssignment) -> bool {
+        let (Some(multi), Some(transfer)) = (job.as_multi(), job.dimens().get_job_transfer()) else { return true };
+
+        let (handover, other) = match transfer.leg {
+            TransferLeg::Direct => return true,
+            TransferLeg::First => (multi.jobs.last(), assignment.second.as_ref()),
+            TransferLeg::Second => (multi.jobs.first(), assignment.first.as_ref()),
+        };
+
+        let Some((handover, other)) = handover.zip(other) else { return true };
+        let route = route_ctx.route();
+        let Some((start, place)) = route.tour.start().zip(handover.places.get(other.place_idx)) else { return false };
+
+        let departure = start.schedule.departure;
+        let location = place.location.unwrap_or(start.place.location);
+        let arrival = departure
+            + self.transport.duration(route, start.place.location, location, TravelTime::Departure(departure));
+
+        place.times.iter().map(|time| time.to_time_window(departure)).any(|time| {
+            let service_start = match transfer.leg {
+                TransferLeg::Second => arrival.max(time.start).max(other.departure),
+                _ => arrival.max(time.start),
+            };
+
+            let is_in_time = compare_floats(service_start, time.end) != Ordering::Greater
+                && compare_floats(service_start, route.actor.detail.time.end) != Ordering::Greater;
+
+            is_in_time
+                && match transfer.leg {
+                    TransferLeg::First => other.latest_start.is_none_or(|latest_start| {
+                        compare_floats(service_start + place.duration, latest_start) != Ordering::Greater
+                    }),
+                    _ => true,
+                }
+        })
+    }
+}
+
+impl FeatureConstraint for TransferConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, job } => self.evaluate_route(solution_ctx, route_ctx, job),
+            MoveContext::Activity { route_ctx, activity_ctx } => self.evaluate_activity(route_ctx, activity_ctx),
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        match (source.dimens().get_job_transfer(), candidate.dimens().get_job_transfer()) {
+            (None, None) => Ok(source),
+            _ => Err(self.code),
+        }
+    }
+}
+
+struct TransferState {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl TransferState {
+    /// Synchronizes handover schedules: the second leg's handover is not started before the first leg's
+    /// one is finished, and the first leg's handover is not finished later than the second leg can wait.
+    fn update_schedules(&self, solution_ctx: &mut SolutionContext) {
+        // NOTE: delayed handover can delay other handovers in the same route, so repeat it a few times
+        let max_iterations = solution_ctx.routes.len().max(1);
+        let _ = (0..max_iterations).try_for_each(|_| {
+            let handovers = get_handovers(solution_ctx);
+
+            let changed_routes = handovers.values().fold(HashSet::new(), |mut changed_routes, (first, second)| {
+                if let Some(&(route_idx, activity_idx)) = second.as_ref() {
+                    let departure = first.map(|(route_idx, activity_idx)| {
+                        get_activity(&solution_ctx.routes[route_idx], activity_idx).schedule.departure
+                    });
+
+                    if restrict_activity_start(&mut solution_ctx.routes[route_idx], activity_idx, departure) {
+                        changed_routes.insert(route_idx);
+                    }
+                }
+
+                changed_routes
+            });
+
+            changed_routes.iter().for_each(|&route_idx| {
+                update_route_schedule(
+                    &mut solution_ctx.routes[route_idx],
+                    self.activity.as_ref(),
+                    self.transport.as_ref(),
+                )
+            });
+
+            if changed_routes.is_empty() {
+                Err(())
+            } else {
+                Ok(())
+            }
+        });
+
+        let handovers = get_handovers(solution_ctx);
+        handovers.values().for_each(|(first, second)| {
+            let Some(&(route_idx, activity_idx)) = first.as_ref() else { return };
+
+            let latest = second.and_then(|(route_idx, activity_idx)| {
+                get_latest_activity_start(&solution_ctx.routes[route_idx], activity_idx, self.transport.as_ref())
+            });
+
+            let route_ctx = &mut solution_ctx.routes[route_idx];
+            if restrict_activity_end(route_ctx, activity_idx, latest, self.activity.as_ref()) {
+                update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+            }
+        });
+
+        self.update_assignments(solution_ctx);
+    }
+
+    fn update_assignments(&self, solution_ctx: &mut SolutionContext) {
+        let mut assignments = get_handovers(solution_ctx).into_iter().fold(
+            HashMap::<usize, TransferAssignment>::default(),
+            |mut assignments, (id, (first, second))| {
+                let create_handover = |(route_idx, activity_idx): (usize, usize), is_first: bool| {
+                    let route_ctx = &solution_ctx.routes[route_idx];
+                    let activity = get_activity(route_ctx, activity_idx);
+
+                    Handover {
+                        actor: route_ctx.route().actor.clone(),
+                        place_idx: activity.place.idx,
+                        departure: activity.schedule.departure,
+                        latest_start: if is_first {
+                            None
+                        } else {
+                            get_latest_activity_start(route_ctx, activity_idx, self.transport.as_ref())
+                        },
+                    }
+                };
+
+                let assignment = assignments.entry(id).or_default();
+                assignment.first = first.map(|first| create_handover(first, true));
+                assignment.second = second.map(|second| create_handover(second, false));
+
+                assignments
+            },
+        );
+
+        solution_ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.jobs())
+            .filter_map(|job| job.dimens().get_job_transfer().copied())
+            .filter(|transfer| transfer.leg == TransferLeg::Direct)
+            .for_each(|transfer| assignments.entry(transfer.id).or_default().direct = true);
+
+        let assignments = Arc::new(assignments);
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            route_ctx.state_mut().set_tour_state::<TransferAssignmentsKey, _>(assignments.clone());
+        });
+        solution_ctx.state.set_value::<TransferAssignmentsKey, _>(assignments);
+    }
+
+    /// Moves job alternatives between required and ignored jobs depending on what is assigned.
+    /// Only transfers which still have required alternatives are processed.
+    fn process_alternatives(&self, solution_ctx: &mut SolutionContext) {
+        let pending = get_pending_transfers(solution_ctx);
+        if pending.is_empty() {
+            return;
+        }
+
+        let get_assignment = |solution_ctx: &SolutionContext, job: &Job| {
+            job.dimens().get_job_transfer().copied().filter(|transfer| pending.contains(&transfer.id)).map(|transfer| {
+                let assignment = solution_ctx
+                    .state
+                    .get_value::<TransferAssignmentsKey, TransferAssignments>()
+                    .and_then(|assignments| assignments.get(&transfer.id))
+                    .cloned()
+                    .unwrap_or_default();
+
+                (transfer.leg, assignment)
+            })
+        };
+
+        process_conditional_jobs(
+            solution_ctx,
+            None,
+            &ConcreteJobContextTransition {
+                remove_required: |solution_ctx, _, job| match get_assignment(solution_ctx, job) {
+                    Some((TransferLeg::Direct, assignment)) => {
+                        assignment.first.is_some() || assignment.second.is_some()
+                    }
+                    Some((_, assignment)) => assignment.direct,
+                    None => false,
+                },
+                promote_required: |solution_ctx, _, job| match get_assignment(solution_ctx, job) {
+                    Some((TransferLeg::Direct, assignment)) => {
+                        assignment.first.is_none() && assignment.second.is_none()
+                    }
+                    Some((_, assignment)) => !assignment.direct,
+                    None => false,
+                },
+                remove_locked: |_, _, _| false,
+                promote_locked: |_, _, _| false,
+            },
+        );
+    }
+
+    /// Removes partially assigned transfers and keeps only one alternative of each job as assigned or unassigned.
+    /// Transfers with required alternatives are skipped as they can be still completed.
+    fn finalize_transfers(&self, solution_ctx: &mut SolutionContext) {
+        let pending = get_pending_transfers(solution_ctx);
+        let handovers = get_handovers(solution_ctx);
+        let assignments =
+            solution_ctx.state.get_value::<TransferAssignmentsKey, TransferAssignments>().cloned().unwrap_or_default();
+
+        let alternatives = solution_ctx
+            .ignored
+            .iter()
+            .chain(solution_ctx.unassigned.keys())
+            .chain(solution_ctx.routes.iter().flat_map(|route_ctx| route_ctx.route().tour.jobs()))
+            .cloned()
+            .filter_map(|job| job.dimens().get_job_transfer().copied().map(|transfer| (transfer, job)))
+            .filter(|(transfer, _)| !pending.contains(&transfer.id))
+            .fold(HashMap::<usize, Vec<(TransferLeg, Job)>>::default(), |mut alternatives, (transfer, job)| {
+                alternatives.entry(transfer.id).or_default().push((transfer.leg, job));
+                alternatives
+            });
+
+        let is_valid_transfer = |id: &usize| {
+            handovers.get(id).is_some_and(|(first, second)| match (first, second) {
+                (Some(first), Some(second)) => {
+                    let first_route = &solution_ctx.routes[first.0];
+                    let second_route = &solution_ctx.routes[second.0];
+                    let departure = get_activity(first_route, first.1).schedule.departure;
+                    let handover = get_activity(second_route, second.1);
+                    let service_start = handover.schedule.arrival.max(handover.place.time.start);
+
+                    compare_floats(service_start, departure) != Ordering::Less
+                        && is_feasible_schedule(first_route)
+                        && is_feasible_schedule(second_route)
+                }
+                _ => false,
+            })
+        };
+
+        let (to_remove, to_ignore, to_unassign) = alternatives.into_iter().fold(
+            (HashSet::new(), HashSet::new(), HashSet::new()),
+            |(mut to_remove, mut to_ignore, mut to_unassign), (id, jobs)| {
+                let is_direct = assignments.get(&id).is_some_and(|assignment| assignment.direct);
+                let is_transferred = !is_direct && is_valid_transfer(&id);
+
+                jobs.into_iter().for_each(|(leg, job)| match (leg, is_direct, is_transferred) {
+                    (TransferLeg::Direct, true, _) => {}
+                    (TransferLeg::Direct, false, true) => {
+                        to_ignore.insert(job);
+                    }
+                    (TransferLeg::Direct, false, false) => {
+                        to_unassign.insert(job);
+                    }
+                    (_, _, true) => {}
+                    (_, _, false) => {
+                        to_remove.insert(job.clone());
+                        to_ignore.insert(job);
+                    }
+                });
+
+                (to_remove, to_ignore, to_unassign)
+            },
+        );
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            to_remove.iter().filter(|job| !solution_ctx.locked.contains(*job)).for_each(|job| {
+                if route_ctx.route().tour.contains(job) {
+                    route_ctx.route_mut().tour.remove(job);
+                }
+            })
+        });
+
+        solution_ctx.unassigned.retain(|job, _| !to_ignore.contains(job));
+        solution_ctx.ignored.retain(|job| !to_ignore.contains(job) && !to_unassign.contains(job));
+        solution_ctx.ignored.extend(to_ignore);
+
+        to_unassign.into_iter().for_each(|job| {
+            solution_ctx.unassigned.entry(job).or_insert(UnassignmentInfo::Simple(self.code));
+        });
+
+        if !to_remove.is_empty() {
+            self.update_schedules(solution_ctx);
+        }
+    }
+}
+
+impl FeatureState for TransferState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _: usize, job: &Job) {
+        self.update_schedules(solution_ctx);
+
+        if job.dimens().get_job_transfer().is_some() {
+            self.process_alternatives(solution_ctx);
+        }
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.update_schedules(solution_ctx);
+
+        // NOTE: both steps depend only on the current state, so calling them repeatedly gives the same result
+        self.process_alternatives(solution_ctx);
+        self.finalize_transfers(solution_ctx);
+    }
+}
+
+/// Returns ids of transfers which have at least one alternative in required jobs.
+fn get_pending_transfers(solution_ctx: &SolutionContext) -> HashSet<usize> {
+    solution_ctx.required.iter().filter_map(|job| job.dimens().get_job_transfer().map(|transfer| transfer.id)).collect()
+}
+
+/// Returns handover activities (route and activity indices) of the first and the second legs per transfer id.
+#[allow(clippy::type_complexity)]
+fn get_handovers(solution_ctx: &SolutionContext) -> HashMap<usize, (Option<(usize, usize)>, Option<(usize, usize)>)> {
+    solution_ctx.routes.iter().enumerate().fold(HashMap::default(), |mut handovers, (route_idx, route_ctx)| {
+        route_ctx
+            .route()
+            .tour
+            .all_activities()
+            .enumerate()
+            .filter_map(|(activity_idx, activity)| {
+                activity.job.as_ref().and_then(|single| get_handover_transfer(single)).map(|t| (activity_idx, t))
+            })
+            .for_each(|(activity_idx, transfer)| {
+                let entry: &mut (Option<(usize, usize)>, Option<(usize, usize)>) =
+                    handovers.entry(transfer.id).or_default();
+                match transfer.leg {
+                    TransferLeg::First => entry.0 = Some((route_idx, activity_idx)),
+                    TransferLeg::Second => entry.1 = Some((route_idx, activity_idx)),
+                    TransferLeg::Direct => {}
+                }
+            });
+
+        handovers
+    })
+}
+
+fn get_activity(route_ctx: &RouteContext, activity_idx: usize) -> &Activity {
+    route_ctx.route().tour.get(activity_idx).expect("cannot get activity by idx")
+}
@@ -0,0 +1,53 @@
+//! A feature to model alternative vehicles: only one vehicle from the same group of alternatives can be used.
+//! It is useful when a vehicle can be used with different settings (e.g. start or end depots), but only once.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/vehicle_alternatives_test.rs"]
+mod vehicle_alternatives_test;
+
+use super::*;
+
+custom_dimension!(VehicleAlternativeGroup typeof usize);
+
+/// Creates a vehicle alternatives feature as a hard constraint. Vehicles with the same
+/// [VehicleAlternativeGroupDimension] value are considered as alternatives of each other.
+pub fn create_vehicle_alternatives_feature(name: &str, code: ViolationCode) -> Result<Feature, GenericError> {
+    FeatureBuilder::default().with_name(name).with_constraint(VehicleAlternativesConstraint { code }).build()
+}
+
+struct VehicleAlternativesConstraint {
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for VehicleAlternativesConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, route_ctx, .. } => {
+                // NOTE only a new route can conflict with already used alternatives
+                if route_ctx.route().tour.has_jobs() {
+                    return None;
+                }
+
+                let actor = &route_ctx.route().actor;
+                let group = actor.vehicle.dimens.get_vehicle_alternative_group()?;
+
+                let is_used = solution_ctx
+                    .routes
+                    .iter()
+                    .filter(|rc| rc.route().actor != *actor && rc.route().tour.has_jobs())
+                    .any(|rc| rc.route().actor.vehicle.dimens.get_vehicle_alternative_group() == Some(group));
+
+                if is_used {
+                    ConstraintViolation::fail(self.code)
+                } else {
+                    None
+                }
+            }
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
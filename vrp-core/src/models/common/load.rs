@@ -7,11 +7,16 @@ use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter};
 use std::iter::Sum;
 use std::ops::{Add, ControlFlow, Mul, Sub};
+use tinyvec::TinyVec;
 
+// NOTE amount of dimensions which are stored inline without heap allocation.
 const LOAD_DIMENSION_SIZE: usize = 8;
 
+// NOTE a scale of fixed point representation used by multi dimensional load: six decimal places.
+const LOAD_SCALE: f64 = 1_000_000.;
+
 /// Represents a load type used to represent customer's demand or vehicle's load.
-pub trait Load: Add + Sub + PartialOrd + Copy + Default + Debug + Send + Sync {
+pub trait Load: Add + Sub + PartialOrd + Clone + Default + Debug + Send + Sync {
     /// Returns true if it represents an empty load.
     fn is_not_empty(&self) -> bool;
 
@@ -68,7 +73,7 @@ pub enum DemandType {
 impl<T: LoadOps> Demand<T> {
     /// Returns capacity change as difference between pickup and delivery.
     pub fn change(&self) -> T {
-        self.pickup.0 + self.pickup.1 - self.delivery.0 - self.delivery.1
+        self.pickup.0.clone() + self.pickup.1.clone() - self.delivery.0.clone() - self.delivery.1.clone()
     }
 }
 
@@ -80,7 +85,7 @@ impl<T: LoadOps> Default for Demand<T> {
 
 impl<T: LoadOps> Clone for Demand<T> {
     fn clone(&self) -> Self {
-        Self { pickup: self.pickup, delivery: self.delivery }
+        Self { pickup: self.pickup.clone(), delivery: self.delivery.clone() }
     }
 }
 
@@ -216,91 +221,88 @@ impl Display for SingleDimLoad {
     }
 }
 
-/// Specifies multi dimensional load type.
-#[derive(Clone, Copy, Debug)]
+/// Specifies multi dimensional load type. The amount of dimensions is not limited: loads with
+/// up to `LOAD_DIMENSION_SIZE` dimensions are kept inline, bigger ones spill to the heap.
+///
+/// Values can be fractional: internally, they are stored as fixed point numbers with six decimal
+/// places, so arithmetic operations do not accumulate rounding errors.
+#[derive(Clone, Debug, Default)]
 pub struct MultiDimLoad {
-    /// Load data.
-    pub load: [i32; LOAD_DIMENSION_SIZE],
-    /// Actual used size.
-    pub size: usize,
+    load: TinyVec<[i64; LOAD_DIMENSION_SIZE]>,
 }
 
 impl MultiDimLoad {
     /// Creates a new instance of `MultiDimLoad`.
-    pub fn new(data: Vec<i32>) -> Self {
-        assert!(data.len() <= LOAD_DIMENSION_SIZE);
+    pub fn new(data: Vec<f64>) -> Self {
+        Self { load: data.into_iter().map(to_fixed_point).collect() }
+    }
 
-        let mut load = [0; LOAD_DIMENSION_SIZE];
-        for (idx, value) in data.iter().enumerate() {
-            load[idx] = *value;
-        }
+    /// Returns actual used size (amount of dimensions).
+    pub fn size(&self) -> usize {
+        self.load.len()
+    }
+
+    /// Returns load value of the given dimension or zero if the dimension is not used.
+    pub fn get(&self, idx: usize) -> f64 {
+        from_fixed_point(self.get_raw(idx))
+    }
+
+    fn get_raw(&self, idx: usize) -> i64 {
+        self.load.get(idx).copied().unwrap_or(0)
+    }
 
-        Self { load, size: data.len() }
+    fn zip_all<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = (i64, i64)> + 'a {
+        (0..self.size().max(other.size())).map(move |idx| (self.get_raw(idx), other.get_raw(idx)))
     }
 
-    fn get(&self, idx: usize) -> i32 {
-        self.load[idx]
+    fn apply<F: Fn(i64, i64) -> i64>(mut self, rhs: &Self, op: F) -> Self {
+        if self.load.len() < rhs.load.len() {
+            self.load.resize(rhs.load.len(), 0);
+        }
+
+        self.load.iter_mut().enumerate().for_each(|(idx, value)| *value = op(*value, rhs.get_raw(idx)));
+
+        self
     }
 
     /// Converts to vector representation.
-    pub fn as_vec(&self) -> Vec<i32> {
-        if self.size == 0 {
-            vec![0]
+    pub fn as_vec(&self) -> Vec<f64> {
+        if self.load.is_empty() {
+            vec![0.]
         } else {
-            self.load[..self.size].to_vec()
+            self.load.iter().copied().map(from_fixed_point).collect()
         }
     }
 }
 
 impl Load for MultiDimLoad {
     fn is_not_empty(&self) -> bool {
-        self.size == 0 || self.load.iter().any(|v| *v != 0)
+        self.load.is_empty() || self.load.iter().any(|v| *v != 0)
     }
 
     fn max_load(self, other: Self) -> Self {
-        let mut result = self;
-        result.load.iter_mut().zip(other.load.iter()).for_each(|(a, b)| *a = (*a).max(*b));
-
-        result
+        self.apply(&other, |a, b| a.max(b))
     }
 
     fn can_fit(&self, other: &Self) -> bool {
-        self.load.iter().zip(other.load.iter()).all(|(a, b)| a >= b)
+        self.zip_all(other).all(|(a, b)| a >= b)
     }
 
     fn ratio(&self, other: &Self) -> f64 {
-        self.load.iter().zip(other.load.iter()).fold(0., |acc, (a, b)| (*a as f64 / *b as f64).max(acc))
+        self.zip_all(other).fold(0., |acc, (a, b)| (a as f64 / b as f64).max(acc))
     }
 }
 
 impl LoadOps for MultiDimLoad {}
 
-impl Default for MultiDimLoad {
-    fn default() -> Self {
-        Self { load: [0; LOAD_DIMENSION_SIZE], size: 0 }
-    }
-}
-
 impl Add for MultiDimLoad {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        fn sum(acc: MultiDimLoad, rhs: &MultiDimLoad) -> MultiDimLoad {
-            let mut dimens = acc;
-
-            for (idx, value) in rhs.load.iter().enumerate() {
-                dimens.load[idx] += *value;
-            }
-
-            dimens.size = dimens.size.max(rhs.size);
-
-            dimens
-        }
-
         if self.load.len() >= rhs.load.len() {
-            sum(self, &rhs)
+            self.apply(&rhs, |a, b| a + b)
         } else {
-            sum(rhs, &self)
+            rhs.apply(&self, |a, b| a + b)
         }
     }
 }
@@ -309,24 +311,15 @@ impl Sub for MultiDimLoad {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let mut dimens = self;
-
-        for (idx, value) in rhs.load.iter().enumerate() {
-            dimens.load[idx] -= *value;
-        }
-
-        dimens.size = dimens.size.max(rhs.size);
-
-        dimens
+        self.apply(&rhs, |a, b| a - b)
     }
 }
 
 impl PartialOrd for MultiDimLoad {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let size = self.size.max(other.size);
-        (0..size)
-            .try_fold(None, |acc, idx| {
-                let result = self.get(idx).cmp(&other.get(idx));
+        self.zip_all(other)
+            .try_fold(None, |acc, (left, right)| {
+                let result = left.cmp(&right);
                 acc.map_or(ControlFlow::Continue(Some(result)), |acc| {
                     if acc != result {
                         ControlFlow::Break(None)
@@ -354,7 +347,7 @@ impl Mul<f64> for MultiDimLoad {
         let mut dimens = self;
 
         dimens.load.iter_mut().for_each(|item| {
-            *item = (*item as f64 * value).round() as i32;
+            *item = (*item as f64 * value).round() as i64;
         });
 
         dimens
@@ -369,6 +362,14 @@ impl Sum for MultiDimLoad {
 
 impl Display for MultiDimLoad {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self.load)
+        write!(f, "{:?}", self.load.iter().copied().map(from_fixed_point).collect::<Vec<_>>())
     }
 }
+
+fn to_fixed_point(value: f64) -> i64 {
+    (value * LOAD_SCALE).round() as i64
+}
+
+fn from_fixed_point(value: i64) -> f64 {
+    value as f64 / LOAD_SCALE
+}
@@ -7,7 +7,10 @@ use crate::models::solution::{Activity, Route};
 use rosomaxa::prelude::{GenericError, GenericResult};
 use rosomaxa::utils::CollectGroupBy;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
 
 /// Specifies travel time type.
 #[derive(Copy, Clone)]
@@ -76,6 +79,12 @@ pub trait TransportCost: Send + Sync {
 
     /// Returns time-dependent travel distance between locations specific for given actor.
     fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance;
+
+    /// Returns locations which are known to be near to given one for given profile. `None` means that
+    /// any location should be considered as a potential neighbour.
+    fn neighbours(&self, _profile: &Profile, _location: Location) -> Option<&[Location]> {
+        None
+    }
 }
 
 /// A simple implementation of transport costs around a single matrix.
@@ -136,6 +145,205 @@ impl MatrixData {
     }
 }
 
+/// Contains matrix routing data for specific profile and, optionally, time which is read from given values. Unlike
+/// `MatrixData`, it allows to put routing data into compact storage without creating its dense copy, e.g. directly
+/// from integer values of deserialized routing matrix.
+pub struct MatrixSource<'a> {
+    /// A routing profile index.
+    pub index: usize,
+    /// A timestamp for which routing info is applicable.
+    pub timestamp: Option<Timestamp>,
+    /// Travel durations.
+    pub durations: &'a dyn MatrixValues,
+    /// Travel distances.
+    pub distances: &'a dyn MatrixValues,
+}
+
+impl<'a> MatrixSource<'a> {
+    /// Creates `MatrixSource` instance.
+    pub fn new(
+        index: usize,
+        timestamp: Option<Timestamp>,
+        durations: &'a dyn MatrixValues,
+        distances: &'a dyn MatrixValues,
+    ) -> Self {
+        Self { index, timestamp, durations, distances }
+    }
+}
+
+/// Specifies how routing matrix values are stored in memory.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MatrixStorage {
+    /// Values are stored as 64 bit floats without any precision loss.
+    #[default]
+    Full,
+    /// Values are stored as 32 bit floats which halves memory usage, but keeps only ~7 significant digits.
+    Float32,
+    /// Values are rounded and stored as 32 bit unsigned integers which halves memory usage. Negative values are
+    /// read back as `-1` which is used to mark unreachable locations.
+    Integer,
+}
+
+/// Provides access to routing matrix values kept outside of the solver, e.g. in a file, see `FileMatrixValues`.
+pub trait MatrixValues: Send + Sync {
+    /// Returns total amount of values (squared amount of locations).
+    fn size(&self) -> usize;
+
+    /// Returns value at given index (`from * locations + to`).
+    fn get(&self, index: usize) -> Option<f64>;
+}
+
+/// Provides access to routing matrix values kept in a file as little-endian signed integers of 4 or 8 bytes, e.g.
+/// in the payload of uncompressed binary routing matrix, without loading all of them into memory. Values are read
+/// by pages: loaded pages are shared between threads without global locking and only limited amount of least
+/// recently used pages is kept in memory. Negative values are read as `-1` which is used to mark unreachable
+/// locations.
+pub struct FileMatrixValues {
+    loader: Mutex<PageLoader>,
+    pages: Vec<RwLock<Option<Arc<Vec<f64>>>>>,
+    last_used: Vec<AtomicU64>,
+    clock: AtomicU64,
+    max_pages: usize,
+    offset: u64,
+    size: usize,
+    width: usize,
+}
+
+/// Keeps state required to load pages of file matrix values.
+struct PageLoader {
+    file: File,
+    loaded: Vec<usize>,
+}
+
+impl FileMatrixValues {
+    /// An amount of values in one page.
+    const PAGE_SIZE: usize = 4096;
+    /// A default max amount of pages kept in memory.
+    const MAX_PAGES: usize = 1024;
+
+    /// Creates a new instance of `FileMatrixValues` for `size` values of given `width` in bytes which start at
+    /// given `offset` in the file. All values are read once to ensure that the file can be read: if it is changed
+    /// later, values which cannot be read anymore are reported as missing.
+    pub fn new(file: File, offset: u64, size: usize, width: usize) -> GenericResult<Self> {
+        Self::new_with_limit(file, offset, size, width, Self::MAX_PAGES)
+    }
+
+    fn new_with_limit(file: File, offset: u64, size: usize, width: usize, max_pages: usize) -> GenericResult<Self> {
+        if width != 4 && width != 8 {
+            return Err(format!("unsupported width of matrix values: {width}").into());
+        }
+
+        let required = size.checked_mul(width).and_then(|length| offset.checked_add(length as u64));
+        let length = file.metadata().map_err(|err| format!("cannot read matrix file: '{err}'"))?.len();
+        if required.is_none_or(|required| required > length) {
+            return Err("matrix file is too small for given amount of values".into());
+        }
+
+        let total = size.div_ceil(Self::PAGE_SIZE);
+        let values = Self {
+            loader: Mutex::new(PageLoader { file, loaded: Vec::default() }),
+            pages: (0..total).map(|_| RwLock::new(None)).collect(),
+            last_used: (0..total).map(|_| AtomicU64::new(0)).collect(),
+            clock: AtomicU64::new(0),
+            max_pages: max_pages.max(1),
+            offset,
+            size,
+            width,
+        };
+
+        {
+            let mut loader = values.loader.lock().map_err(|_| "cannot lock matrix file")?;
+            (0..total).try_for_each(|page| {
+                values
+                    .read_page(&mut loader.file, page)
+                    .map(|_| ())
+                    .map_err(|err| GenericError::from(format!("cannot read matrix values from file: '{err}'")))
+            })?;
+        }
+
+        Ok(values)
+    }
+
+    fn read_page(&self, file: &mut File, page: usize) -> std::io::Result<Vec<f64>> {
+        let start = page * Self::PAGE_SIZE;
+        let mut buffer = vec![0_u8; (self.size - start).min(Self::PAGE_SIZE) * self.width];
+
+        file.seek(SeekFrom::Start(self.offset + (start * self.width) as u64))?;
+        file.read_exact(buffer.as_mut_slice())?;
+
+        Ok(buffer
+            .chunks_exact(self.width)
+            .map(|bytes| match self.width {
+                4 => i32::from_le_bytes(bytes.try_into().unwrap()) as i64,
+                _ => i64::from_le_bytes(bytes.try_into().unwrap()),
+            })
+            .map(|value| if value < 0 { -1. } else { value as f64 })
+            .collect())
+    }
+
+    /// Loads page into memory evicting the least recently used one if the limit of loaded pages is reached.
+    fn load_page(&self, page: usize) -> Option<Arc<Vec<f64>>> {
+        let mut loader = self.loader.lock().ok()?;
+
+        // NOTE: the page can be loaded by another thread while this one was waiting for the lock
+        if let Some(values) = self.pages[page].read().ok()?.clone() {
+            return Some(values);
+        }
+
+        let values = Arc::new(self.read_page(&mut loader.file, page).ok()?);
+
+        if loader.loaded.len() >= self.max_pages {
+            let evicted = loader
+                .loaded
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, &page)| self.last_used[page].load(Ordering::Relaxed))
+                .map(|(idx, _)| idx);
+
+            if let Some(evicted) = evicted {
+                let evicted = loader.loaded.swap_remove(evicted);
+                *self.pages[evicted].write().ok()? = None;
+            }
+        }
+
+        loader.loaded.push(page);
+        self.last_used[page].store(self.clock.fetch_add(1, Ordering::Relaxed) + 1, Ordering::Relaxed);
+        *self.pages[page].write().ok()? = Some(values.clone());
+
+        Some(values)
+    }
+}
+
+impl MatrixValues for FileMatrixValues {
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    fn get(&self, index: usize) -> Option<f64> {
+        if index >= self.size {
+            return None;
+        }
+
+        let (page, page_index) = (index / Self::PAGE_SIZE, index % Self::PAGE_SIZE);
+        let values = self.pages[page].read().ok()?.clone();
+
+        let values = match values {
+            Some(values) => {
+                // NOTE: clock is advanced only on page load, so usage is tracked without writing shared state
+                // on each call
+                let clock = self.clock.load(Ordering::Relaxed);
+                if self.last_used[page].load(Ordering::Relaxed) != clock {
+                    self.last_used[page].store(clock, Ordering::Relaxed);
+                }
+                values
+            }
+            None => self.load_page(page)?,
+        };
+
+        values.get(page_index).copied()
+    }
+}
+
 /// A fallback for transport costs if from->to entry is not defined.
 pub trait TransportFallback: Send + Sync {
     /// Returns fallback duration.
@@ -146,7 +354,7 @@ pub trait TransportFallback: Send + Sync {
 }
 
 /// A trivial implementation of no fallback for transport cost.
-struct NoFallback;
+pub struct NoFallback;
 
 impl TransportFallback for NoFallback {
     fn duration(&self, profile: &Profile, from: Location, to: Location) -> Duration {
@@ -170,42 +378,244 @@ pub fn create_matrix_transport_cost_with_fallback<T: TransportFallback + 'static
     costs: Vec<MatrixData>,
     fallback: T,
 ) -> GenericResult<Arc<dyn TransportCost>> {
-    if costs.is_empty() {
+    create_matrix_transport_cost_with_storage(costs, fallback, MatrixStorage::default())
+}
+
+/// Creates time agnostic or time aware routing costs based on matrix data passed using a fallback function for
+/// unknown route. Time agnostic matrices are kept using given storage type: additionally, equal distances and
+/// durations which differ only by a constant factor (e.g. profiles with different speed) are shared between profiles.
+/// NOTE: matrix data is passed as 64 bit floats, so peak memory usage while costs are created is not reduced. Use
+/// `create_matrix_transport_cost_with_sources` to avoid creating dense copies of matrices.
+pub fn create_matrix_transport_cost_with_storage<T: TransportFallback + 'static>(
+    costs: Vec<MatrixData>,
+    fallback: T,
+    storage: MatrixStorage,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    let size = get_matrix_size(costs.iter().map(|matrix| (matrix.durations.len(), matrix.distances.len())))?;
+
+    Ok(if costs.iter().any(|costs| costs.timestamp.is_some()) {
+        Arc::new(TimeAwareMatrixTransportCost::new(costs, size, fallback)?)
+    } else {
+        Arc::new(TimeAgnosticMatrixTransportCost::new(costs, size, fallback, storage)?)
+    })
+}
+
+/// Creates time agnostic or time aware routing costs based on matrix sources using a fallback function for unknown
+/// route. Time agnostic values are put into given storage one by one, so no dense copy of them is created, and
+/// shared between profiles as in `create_matrix_transport_cost_with_storage`. Time aware values are kept as 64 bit
+/// floats: the same source passed for different profiles is kept only once.
+pub fn create_matrix_transport_cost_with_sources<T: TransportFallback + 'static>(
+    sources: Vec<MatrixSource<'_>>,
+    fallback: T,
+    storage: MatrixStorage,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    let size = get_matrix_size(sources.iter().map(|source| (source.durations.size(), source.distances.size())))?;
+
+    Ok(if sources.iter().any(|source| source.timestamp.is_some()) {
+        Arc::new(TimeAwareMatrixTransportCost::new_with_sources(sources, size, fallback)?)
+    } else {
+        Arc::new(TimeAgnosticMatrixTransportCost::new_with_sources(sources, size, fallback, storage)?)
+    })
+}
+
+/// Creates time agnostic routing costs based on externally stored matrix values: a pair of durations and distances
+/// per profile index. This is the way to use file backed, see `FileMatrixValues`, or other custom matrix storages.
+pub fn create_matrix_transport_cost_with_values<T: TransportFallback + 'static>(
+    values: Vec<(Arc<dyn MatrixValues>, Arc<dyn MatrixValues>)>,
+    fallback: T,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    let size = values.first().map(|(durations, _)| (durations.size() as f64).sqrt().round() as usize).unwrap_or(0);
+
+    if values.is_empty() {
         return Err("no matrix data found".into());
     }
 
-    let size = (costs.first().unwrap().durations.len() as f64).sqrt().round() as usize;
+    if values.iter().any(|(durations, distances)| durations.size() != size * size || distances.size() != size * size) {
+        return Err("matrix values have different length".into());
+    }
+
+    let (durations, distances) = values
+        .into_iter()
+        .map(|(durations, distances)| {
+            (Arc::new(MatrixStore::Custom(durations)), Arc::new(MatrixStore::Custom(distances)))
+        })
+        .unzip();
+
+    Ok(Arc::new(TimeAgnosticMatrixTransportCost { durations, distances, size, fallback }))
+}
+
+/// Checks lengths of durations and distances of each matrix and returns amount of locations.
+fn get_matrix_size(lengths: impl Iterator<Item = (usize, usize)>) -> GenericResult<usize> {
+    let lengths = lengths.collect::<Vec<_>>();
+    let get_size = |length: usize| (length as f64).sqrt().round() as usize;
 
-    if costs.iter().any(|matrix| matrix.distances.len() != matrix.durations.len()) {
+    let size = lengths.first().map(|&(durations, _)| get_size(durations)).ok_or("no matrix data found")?;
+
+    if lengths.iter().any(|(durations, distances)| durations != distances) {
         return Err("distance and duration collections have different length".into());
     }
 
-    if costs.iter().any(|matrix| (matrix.distances.len() as f64).sqrt().round() as usize != size) {
+    if lengths.iter().any(|&(_, distances)| get_size(distances) != size) {
         return Err("distance lengths don't match".into());
     }
 
-    if costs.iter().any(|matrix| (matrix.durations.len() as f64).sqrt().round() as usize != size) {
+    if lengths.iter().any(|&(durations, _)| get_size(durations) != size) {
         return Err("duration lengths don't match".into());
     }
 
-    Ok(if costs.iter().any(|costs| costs.timestamp.is_some()) {
-        Arc::new(TimeAwareMatrixTransportCost::new(costs, size, fallback)?)
-    } else {
-        Arc::new(TimeAgnosticMatrixTransportCost::new(costs, size, fallback)?)
-    })
+    Ok(size)
+}
+
+/// Provides access to matrix values kept in memory.
+struct SliceValues<'a>(&'a [f64]);
+
+impl MatrixValues for SliceValues<'_> {
+    fn size(&self) -> usize {
+        self.0.len()
+    }
+
+    fn get(&self, index: usize) -> Option<f64> {
+        self.0.get(index).copied()
+    }
+}
+
+/// Reads all matrix values.
+fn collect_values(values: &dyn MatrixValues) -> GenericResult<Vec<f64>> {
+    (0..values.size())
+        .map(|index| values.get(index).ok_or_else(|| GenericError::from(format!("no matrix value at {index}"))))
+        .collect()
+}
+
+/// Keeps routing matrix values.
+enum MatrixStore {
+    Full(Vec<f64>),
+    Float32(Vec<f32>),
+    Integer(Vec<u32>),
+    /// Values of another store multiplied by a constant factor.
+    Scaled {
+        store: Arc<MatrixStore>,
+        factor: f64,
+        is_rounded: bool,
+    },
+    Custom(Arc<dyn MatrixValues>),
+}
+
+impl MatrixStore {
+    /// A value which is used to keep negative values in integer storage.
+    const NEGATIVE_INTEGER: u32 = u32::MAX;
+
+    fn new(values: Vec<f64>, storage: MatrixStorage) -> GenericResult<Self> {
+        match storage {
+            MatrixStorage::Full => Ok(Self::Full(values)),
+            _ => Self::new_with_values(&SliceValues(values.as_slice()), storage),
+        }
+    }
+
+    /// Creates a store reading values one by one.
+    fn new_with_values(values: &dyn MatrixValues, storage: MatrixStorage) -> GenericResult<Self> {
+        let values = (0..values.size())
+            .map(|index| values.get(index).ok_or_else(|| GenericError::from(format!("no matrix value at {index}"))));
+
+        Ok(match storage {
+            MatrixStorage::Full => Self::Full(values.collect::<GenericResult<_>>()?),
+            MatrixStorage::Float32 => {
+                Self::Float32(values.map(|value| value.map(|value| value as f32)).collect::<GenericResult<_>>()?)
+            }
+            MatrixStorage::Integer => Self::Integer(
+                values
+                    .map(|value| match value?.round() {
+                        value if value < 0. => Ok(Self::NEGATIVE_INTEGER),
+                        value if value < Self::NEGATIVE_INTEGER as f64 => Ok(value as u32),
+                        value => {
+                            Err(GenericError::from(format!("matrix value is too big for integer storage: {value}")))
+                        }
+                    })
+                    .collect::<GenericResult<_>>()?,
+            ),
+        })
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            Self::Full(values) => values.len(),
+            Self::Float32(values) => values.len(),
+            Self::Integer(values) => values.len(),
+            Self::Scaled { store, .. } => store.size(),
+            Self::Custom(values) => values.size(),
+        }
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<f64> {
+        match self {
+            Self::Full(values) => values.get(index).copied(),
+            Self::Float32(values) => values.get(index).map(|&value| value as f64),
+            Self::Integer(values) => {
+                values.get(index).map(|&value| if value == Self::NEGATIVE_INTEGER { -1. } else { value as f64 })
+            }
+            Self::Scaled { store, factor, is_rounded } => store.get(index).map(|value| match value {
+                value if value < 0. => value,
+                value if *is_rounded => (value * factor).round(),
+                value => value * factor,
+            }),
+            Self::Custom(values) => values.get(index),
+        }
+    }
+
+    /// Tries to represent given values as this store values multiplied by a constant factor within the precision
+    /// of given storage type.
+    fn try_share(
+        store: &Arc<MatrixStore>,
+        values: &dyn MatrixValues,
+        storage: MatrixStorage,
+    ) -> Option<Arc<MatrixStore>> {
+        if matches!(store.as_ref(), Self::Scaled { .. } | Self::Custom(_)) || store.size() != values.size() {
+            return None;
+        }
+
+        let factor = (0..values.size())
+            .filter_map(|idx| store.get(idx).zip(values.get(idx)))
+            .find(|(original, value)| *original > 0. && *value > 0.)
+            .map_or(1., |(original, value)| value / original);
+
+        let is_rounded = storage == MatrixStorage::Integer;
+        let scaled = Self::Scaled { store: store.clone(), factor, is_rounded };
+
+        let is_same = |expected: f64, actual: f64| match storage {
+            MatrixStorage::Full => expected == actual,
+            MatrixStorage::Float32 => (expected - actual).abs() <= f32::EPSILON as f64 * expected.abs().max(1.),
+            MatrixStorage::Integer => expected.round().max(-1.) == actual,
+        };
+
+        let is_shareable = (0..values.size()).all(|idx| {
+            values.get(idx).zip(scaled.get(idx)).is_some_and(|(value, actual)| {
+                if value < 0. {
+                    actual < 0.
+                } else {
+                    is_same(value, actual)
+                }
+            })
+        });
+
+        match (is_shareable, factor == 1.) {
+            (true, true) => Some(store.clone()),
+            (true, false) => Some(Arc::new(scaled)),
+            _ => None,
+        }
+    }
 }
 
 /// A time agnostic matrix routing costs.
 struct TimeAgnosticMatrixTransportCost<T: TransportFallback> {
-    durations: Vec<Vec<Duration>>,
-    distances: Vec<Vec<Distance>>,
+    durations: Vec<Arc<MatrixStore>>,
+    distances: Vec<Arc<MatrixStore>>,
     size: usize,
     fallback: T,
 }
 
 impl<T: TransportFallback> TimeAgnosticMatrixTransportCost<T> {
     /// Creates an instance of `TimeAgnosticMatrixTransportCost`.
-    pub fn new(costs: Vec<MatrixData>, size: usize, fallback: T) -> Result<Self, GenericError> {
+    pub fn new(costs: Vec<MatrixData>, size: usize, fallback: T, storage: MatrixStorage) -> Result<Self, GenericError> {
         let mut costs = costs;
         costs.sort_by(|a, b| a.index.cmp(&b.index));
 
@@ -217,12 +627,56 @@ impl<T: TransportFallback> TimeAgnosticMatrixTransportCost<T> {
             return Err("duplicate profiles can be passed only for time aware routing".into());
         }
 
-        let (durations, distances) = costs.into_iter().fold((vec![], vec![]), |mut acc, data| {
-            acc.0.push(data.durations);
-            acc.1.push(data.distances);
+        let get_store = |stores: &[Arc<MatrixStore>], values: Vec<f64>| {
+            stores
+                .iter()
+                .find_map(|store| MatrixStore::try_share(store, &SliceValues(values.as_slice()), storage))
+                .map_or_else(|| MatrixStore::new(values, storage).map(Arc::new), Ok)
+        };
 
-            acc
-        });
+        let (durations, distances) = costs.into_iter().try_fold((vec![], vec![]), |mut acc, data| {
+            let durations = get_store(acc.0.as_slice(), data.durations)?;
+            let distances = get_store(acc.1.as_slice(), data.distances)?;
+
+            acc.0.push(durations);
+            acc.1.push(distances);
+
+            Ok::<_, GenericError>(acc)
+        })?;
+
+        Ok(Self { durations, distances, size, fallback })
+    }
+
+    /// Creates an instance of `TimeAgnosticMatrixTransportCost` putting values of sources into given storage.
+    fn new_with_sources(
+        sources: Vec<MatrixSource<'_>>,
+        size: usize,
+        fallback: T,
+        storage: MatrixStorage,
+    ) -> Result<Self, GenericError> {
+        let mut sources = sources;
+        sources.sort_by_key(|source| source.index);
+
+        if (0..).zip(sources.iter().map(|source| &source.index)).any(|(a, &b)| a != b) {
+            return Err("duplicate profiles can be passed only for time aware routing".into());
+        }
+
+        let get_store = |stores: &[Arc<MatrixStore>], values: &dyn MatrixValues| {
+            stores
+                .iter()
+                .find_map(|store| MatrixStore::try_share(store, values, storage))
+                .map_or_else(|| MatrixStore::new_with_values(values, storage).map(Arc::new), Ok)
+        };
+
+        let (durations, distances) = sources.into_iter().try_fold((vec![], vec![]), |mut acc, source| {
+            let durations = get_store(acc.0.as_slice(), source.durations)?;
+            let distances = get_store(acc.1.as_slice(), source.distances)?;
+
+            acc.0.push(durations);
+            acc.1.push(distances);
+
+            Ok::<_, GenericError>(acc)
+        })?;
 
         Ok(Self { durations, distances, size, fallback })
     }
@@ -234,7 +688,6 @@ impl<T: TransportFallback> TransportCost for TimeAgnosticMatrixTransportCost<T>
             .get(profile.index)
             .unwrap()
             .get(from * self.size + to)
-            .copied()
             .unwrap_or_else(|| self.fallback.duration(profile, from, to))
             * profile.scale
     }
@@ -244,7 +697,6 @@ impl<T: TransportFallback> TransportCost for TimeAgnosticMatrixTransportCost<T>
             .get(profile.index)
             .unwrap()
             .get(from * self.size + to)
-            .copied()
             .unwrap_or_else(|| self.fallback.distance(profile, from, to))
     }
 
@@ -257,21 +709,77 @@ impl<T: TransportFallback> TransportCost for TimeAgnosticMatrixTransportCost<T>
     }
 }
 
+const TIME_AWARE_TIMESTAMP_MSG: &str = "time-aware routing requires all matrices to have timestamp";
+
 /// A time aware matrix costs.
 struct TimeAwareMatrixTransportCost<T: TransportFallback> {
-    costs: HashMap<usize, (Vec<u64>, Vec<MatrixData>)>,
+    costs: HashMap<usize, (Vec<u64>, Vec<TimedMatrix>)>,
     size: usize,
     fallback: T,
 }
 
+/// Keeps routing matrix values applicable for specific time. Values can be shared between profiles.
+struct TimedMatrix {
+    timestamp: Timestamp,
+    durations: Arc<Vec<Duration>>,
+    distances: Arc<Vec<Distance>>,
+}
+
 impl<T: TransportFallback> TimeAwareMatrixTransportCost<T> {
     /// Creates an instance of `TimeAwareMatrixTransportCost`.
     fn new(costs: Vec<MatrixData>, size: usize, fallback: T) -> Result<Self, GenericError> {
-        if costs.iter().any(|matrix| matrix.timestamp.is_none()) {
-            return Err("time-aware routing requires all matrices to have timestamp".into());
-        }
+        let costs = costs
+            .into_iter()
+            .map(|matrix| {
+                let timestamp = matrix.timestamp.ok_or(TIME_AWARE_TIMESTAMP_MSG)?;
+                let index = matrix.index;
+                let matrix = TimedMatrix {
+                    timestamp,
+                    durations: Arc::new(matrix.durations),
+                    distances: Arc::new(matrix.distances),
+                };
+
+                Ok::<_, GenericError>((index, matrix))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new_with_matrices(costs, size, fallback)
+    }
+
+    /// Creates an instance of `TimeAwareMatrixTransportCost` reading values of sources.
+    fn new_with_sources(sources: Vec<MatrixSource<'_>>, size: usize, fallback: T) -> Result<Self, GenericError> {
+        let mut values: HashMap<*const (), Arc<Vec<f64>>> = HashMap::default();
+        let mut get_values = |source: &dyn MatrixValues| {
+            let key = source as *const dyn MatrixValues as *const ();
+            if let Some(values) = values.get(&key) {
+                return Ok::<_, GenericError>(values.clone());
+            }
+
+            let shared = Arc::new(collect_values(source)?);
+            values.insert(key, shared.clone());
+
+            Ok(shared)
+        };
+
+        let costs = sources
+            .into_iter()
+            .map(|source| {
+                let timestamp = source.timestamp.ok_or(TIME_AWARE_TIMESTAMP_MSG)?;
+                let matrix = TimedMatrix {
+                    timestamp,
+                    durations: get_values(source.durations)?,
+                    distances: get_values(source.distances)?,
+                };
+
+                Ok::<_, GenericError>((source.index, matrix))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::new_with_matrices(costs, size, fallback)
+    }
 
-        let costs = costs.into_iter().collect_group_by_key(|matrix| matrix.index);
+    fn new_with_matrices(costs: Vec<(usize, TimedMatrix)>, size: usize, fallback: T) -> Result<Self, GenericError> {
+        let costs = costs.into_iter().collect_group_by_key(|(index, _)| *index);
 
         if costs.iter().any(|(_, matrices)| matrices.len() == 1) {
             return Err("should not use time aware matrix routing with single matrix".into());
@@ -279,9 +787,10 @@ impl<T: TransportFallback> TimeAwareMatrixTransportCost<T> {
 
         let costs = costs
             .into_iter()
-            .map(|(profile, mut matrices)| {
-                matrices.sort_by(|a, b| (a.timestamp.unwrap() as u64).cmp(&(b.timestamp.unwrap() as u64)));
-                let timestamps = matrices.iter().map(|matrix| matrix.timestamp.unwrap() as u64).collect();
+            .map(|(profile, matrices)| {
+                let mut matrices = matrices.into_iter().map(|(_, matrix)| matrix).collect::<Vec<_>>();
+                matrices.sort_by_key(|matrix| matrix.timestamp as u64);
+                let timestamps = matrices.iter().map(|matrix| matrix.timestamp as u64).collect();
 
                 (profile, (timestamps, matrices))
             })
@@ -323,8 +832,8 @@ impl<T: TransportFallback> TimeAwareMatrixTransportCost<T> {
                     .zip(matrices.get(matrix_idx).unwrap().durations.get(data_idx))
                     .map(|(&left_value, &right_value)| {
                         // perform linear interpolation
-                        let ratio = (timestamp - left_matrix.timestamp.unwrap())
-                            / (right_matrix.timestamp.unwrap() - left_matrix.timestamp.unwrap());
+                        let ratio =
+                            (timestamp - left_matrix.timestamp) / (right_matrix.timestamp - left_matrix.timestamp);
 
                         left_value + ratio * (right_value - left_value)
                     })
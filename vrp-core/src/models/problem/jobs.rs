@@ -7,9 +7,10 @@ use crate::models::problem::{Costs, Fleet, TransportCost};
 use crate::utils::{short_type_name, Either};
 use rosomaxa::utils::compare_floats_f32;
 use std::cmp::Ordering::Less;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
+use std::iter::once;
 use std::sync::{Arc, Weak};
 
 custom_dimension!(JobId typeof String);
@@ -304,6 +305,12 @@ pub fn get_job_locations(job: &Job) -> impl Iterator<Item = Option<Location>> +
 /// Creates job index.
 fn create_index(fleet: &Fleet, jobs: Vec<Job>, transport: &(dyn TransportCost)) -> HashMap<usize, JobIndex> {
     let avg_profile_costs = get_avg_profile_costs(fleet);
+    let location_jobs = jobs.iter().fold(HashMap::<Option<Location>, Vec<Job>>::new(), |mut acc, job| {
+        get_job_locations(job).collect::<HashSet<_>>().into_iter().for_each(|location| {
+            acc.entry(location).or_default().push(job.clone());
+        });
+        acc
+    });
 
     fleet.profiles.iter().fold(HashMap::new(), |mut acc, profile| {
         let avg_costs = avg_profile_costs.get(&profile.index).unwrap();
@@ -318,11 +325,12 @@ fn create_index(fleet: &Fleet, jobs: Vec<Job>, transport: &(dyn TransportCost))
 
         // create job index
         let item = jobs.iter().cloned().fold(HashMap::new(), |mut acc, job| {
-            let mut sorted_job_costs: Vec<(Job, LowPrecisionCost)> = jobs
-                .iter()
-                .filter(|j| **j != job)
-                .map(|j| (j.clone(), get_cost_between_jobs(profile, avg_costs, transport, &job, j)))
-                .collect();
+            let mut sorted_job_costs: Vec<(Job, LowPrecisionCost)> =
+                get_neighbour_candidates(profile, transport, &job, jobs.as_slice(), &location_jobs)
+                    .into_iter()
+                    .filter(|j| **j != job)
+                    .map(|j| (j.clone(), get_cost_between_jobs(profile, avg_costs, transport, &job, j)))
+                    .collect();
             sorted_job_costs.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Less));
 
             sorted_job_costs.truncate(MAX_NEIGHBOURS);
@@ -344,6 +352,36 @@ fn create_index(fleet: &Fleet, jobs: Vec<Job>, transport: &(dyn TransportCost))
     })
 }
 
+/// Returns jobs which can be neighbours of given job. If transport knows neighbours of all job locations
+/// (sparse routing data), only jobs at these locations or without location are considered.
+fn get_neighbour_candidates<'a>(
+    profile: &Profile,
+    transport: &dyn TransportCost,
+    job: &Job,
+    jobs: &'a [Job],
+    location_jobs: &'a HashMap<Option<Location>, Vec<Job>>,
+) -> Vec<&'a Job> {
+    let neighbours = get_job_locations(job)
+        .map(|location| location.and_then(|location| transport.neighbours(profile, location).map(|n| (location, n))))
+        .collect::<Option<Vec<_>>>();
+
+    match neighbours {
+        Some(neighbours) => {
+            let mut visited = HashSet::new();
+            neighbours
+                .into_iter()
+                .flat_map(|(location, neighbours)| once(location).chain(neighbours.iter().copied()))
+                .map(Some)
+                .chain(once(None))
+                .filter_map(|location| location_jobs.get(&location))
+                .flatten()
+                .filter(|candidate| visited.insert(*candidate))
+                .collect()
+        }
+        None => jobs.iter().collect(),
+    }
+}
+
 fn get_cost_between_locations(
     profile: &Profile,
     costs: &Costs,
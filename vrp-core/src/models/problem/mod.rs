@@ -11,3 +11,12 @@ pub use self::fleet::*;
 
 mod jobs;
 pub use self::jobs::*;
+
+mod sparse_costs;
+pub use self::sparse_costs::*;
+
+mod speed_costs;
+pub use self::speed_costs::*;
+
+mod overlay_costs;
+pub use self::overlay_costs::*;
@@ -0,0 +1,165 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/problem/overlay_costs_test.rs"]
+mod overlay_costs_test;
+
+use crate::models::common::*;
+use crate::models::problem::{TransportCost, TravelTime};
+use crate::models::solution::Route;
+use rosomaxa::prelude::GenericResult;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Specifies routing restrictions of a single profile which are applied on top of its routing data. This allows
+/// to model vehicle classes (e.g. heavy trucks) which share a routing matrix, but cannot use some roads or enter
+/// some locations.
+pub struct TransportOverlay {
+    /// A profile index.
+    pub index: usize,
+    /// Locations which cannot be entered or left.
+    pub unreachable: Vec<Location>,
+    /// Overridden routing entries specified as `(from, to, duration, distance)`. A missing value is taken from
+    /// routing data, when both values are missing, the entry is unreachable.
+    pub edges: Vec<(Location, Location, Option<Duration>, Option<Distance>)>,
+}
+
+impl TransportOverlay {
+    /// Creates a new instance of `TransportOverlay`.
+    pub fn new(
+        index: usize,
+        unreachable: Vec<Location>,
+        edges: Vec<(Location, Location, Option<Duration>, Option<Distance>)>,
+    ) -> Self {
+        Self { index, unreachable, edges }
+    }
+}
+
+/// Creates routing costs which apply overlays on top of base routing costs. Unreachable locations and edges are marked
+/// by negative duration and distance, so they are handled by reachable feature. Overridden durations are scaled using
+/// profile's scale.
+pub fn create_overlay_transport_cost(
+    base: Arc<dyn TransportCost>,
+    overlays: Vec<TransportOverlay>,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    Ok(Arc::new(OverlayTransportCost::new(base, overlays)?))
+}
+
+/// Keeps overlay data of a single profile.
+struct ProfileOverlay {
+    unreachable: HashSet<Location>,
+    edges: HashMap<(Location, Location), (Option<Duration>, Option<Distance>)>,
+}
+
+struct OverlayTransportCost {
+    base: Arc<dyn TransportCost>,
+    overlays: Vec<Option<ProfileOverlay>>,
+}
+
+impl OverlayTransportCost {
+    fn new(base: Arc<dyn TransportCost>, overlays: Vec<TransportOverlay>) -> GenericResult<Self> {
+        let mut profiles: Vec<Option<ProfileOverlay>> = Vec::default();
+
+        for TransportOverlay { index, unreachable, edges } in overlays {
+            if edges.iter().any(|(_, _, duration, distance)| {
+                duration.is_some_and(|value| !value.is_finite() || value < 0.)
+                    || distance.is_some_and(|value| !value.is_finite() || value < 0.)
+            }) {
+                return Err(format!("overlay values should be non-negative for profile {index}").into());
+            }
+
+            if profiles.len() <= index {
+                profiles.resize_with(index + 1, || None);
+            }
+
+            if profiles[index].is_some() {
+                return Err(format!("duplicate transport overlay for profile {index}").into());
+            }
+
+            profiles[index] = Some(ProfileOverlay {
+                unreachable: unreachable.into_iter().collect(),
+                edges: edges
+                    .into_iter()
+                    .map(|(from, to, duration, distance)| ((from, to), (duration, distance)))
+                    .collect(),
+            });
+        }
+
+        Ok(Self { base, overlays: profiles })
+    }
+
+    /// Returns overlay values for given pair of locations: `None` when overlay is not applied, `Some(None)` when
+    /// locations are unreachable.
+    fn get_overlay(
+        &self,
+        profile: &Profile,
+        from: Location,
+        to: Location,
+    ) -> Option<Option<(Option<Duration>, Option<Distance>)>> {
+        let overlay = self.overlays.get(profile.index).and_then(|overlay| overlay.as_ref())?;
+
+        if from == to {
+            return None;
+        }
+
+        if overlay.unreachable.contains(&from) || overlay.unreachable.contains(&to) {
+            return Some(None);
+        }
+
+        match overlay.edges.get(&(from, to)) {
+            Some((None, None)) => Some(None),
+            Some(&values) => Some(Some(values)),
+            None => None,
+        }
+    }
+
+    fn get_duration(
+        &self,
+        profile: &Profile,
+        from: Location,
+        to: Location,
+        base_fn: impl Fn() -> Duration,
+    ) -> Duration {
+        match self.get_overlay(profile, from, to) {
+            Some(None) => -1.,
+            Some(Some((Some(duration), _))) => duration * profile.scale,
+            _ => base_fn(),
+        }
+    }
+
+    fn get_distance(
+        &self,
+        profile: &Profile,
+        from: Location,
+        to: Location,
+        base_fn: impl Fn() -> Distance,
+    ) -> Distance {
+        match self.get_overlay(profile, from, to) {
+            Some(None) => -1.,
+            Some(Some((_, Some(distance)))) => distance,
+            _ => base_fn(),
+        }
+    }
+}
+
+impl TransportCost for OverlayTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.get_duration(profile, from, to, || self.base.duration_approx(profile, from, to))
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.get_distance(profile, from, to, || self.base.distance_approx(profile, from, to))
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        let profile = &route.actor.vehicle.profile;
+        self.get_duration(profile, from, to, || self.base.duration(route, from, to, travel_time))
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        let profile = &route.actor.vehicle.profile;
+        self.get_distance(profile, from, to, || self.base.distance(route, from, to, travel_time))
+    }
+
+    fn neighbours(&self, profile: &Profile, location: Location) -> Option<&[Location]> {
+        self.base.neighbours(profile, location)
+    }
+}
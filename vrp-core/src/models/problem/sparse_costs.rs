@@ -0,0 +1,169 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/problem/sparse_costs_test.rs"]
+mod sparse_costs_test;
+
+use crate::models::common::*;
+use crate::models::problem::{TransportCost, TravelTime};
+use crate::models::solution::Route;
+use crate::utils::get_haversine_distance;
+use rosomaxa::prelude::GenericResult;
+use std::sync::Arc;
+
+/// Contains sparse routing data for a single profile: travel durations and distances are known only between
+/// a location and its nearest neighbours (e.g. k-nearest neighbours).
+pub struct SparseMatrixData {
+    /// A profile index.
+    pub index: usize,
+    /// Known routing entries specified as `(from, to, duration, distance)`. Negative values are used to mark
+    /// unreachable location.
+    pub entries: Vec<(Location, Location, Duration, Distance)>,
+}
+
+impl SparseMatrixData {
+    /// Creates a new instance of `SparseMatrixData`.
+    pub fn new(index: usize, entries: Vec<(Location, Location, Duration, Distance)>) -> Self {
+        Self { index, entries }
+    }
+}
+
+/// Creates routing costs based on sparse routing data. Each location has to have a geo coordinate specified as
+/// `(latitude, longitude)` pair in `coordinates` at location's index: it is used to approximate routing for missing
+/// pairs. The approximation uses haversine distance multiplied by detour factor and average speed, both are learned
+/// from known entries of the profile.
+///
+/// Only known neighbours of locations are considered when job neighbourhood is built, see [`TransportCost::neighbours`].
+pub fn create_sparse_matrix_transport_cost(
+    coordinates: Vec<(f64, f64)>,
+    costs: Vec<SparseMatrixData>,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    Ok(Arc::new(SparseMatrixTransportCost::new(coordinates, costs)?))
+}
+
+/// Keeps known routing entries of a single location sorted by destination.
+#[derive(Default)]
+struct SparseRow {
+    locations: Vec<Location>,
+    values: Vec<(Duration, Distance)>,
+}
+
+/// Approximates routing for location pairs which are not present in sparse data.
+struct Approximation {
+    detour_factor: f64,
+    speed: f64,
+}
+
+struct SparseMatrixTransportCost {
+    coordinates: Vec<(f64, f64)>,
+    profiles: Vec<(Vec<SparseRow>, Approximation)>,
+}
+
+impl SparseMatrixTransportCost {
+    fn new(coordinates: Vec<(f64, f64)>, costs: Vec<SparseMatrixData>) -> GenericResult<Self> {
+        let mut costs = costs;
+        costs.sort_by_key(|data| data.index);
+
+        if costs.is_empty() {
+            return Err("no sparse matrix data found".into());
+        }
+
+        if costs.iter().enumerate().any(|(idx, data)| data.index != idx) {
+            return Err("sparse matrix data should have exactly one entry per profile index".into());
+        }
+
+        let size = coordinates.len();
+        let profiles = costs
+            .into_iter()
+            .map(|data| {
+                if data.entries.iter().any(|&(from, to, _, _)| from >= size || to >= size) {
+                    return Err(format!("sparse matrix data of profile {} has unknown location", data.index).into());
+                }
+
+                let approximation = create_approximation(coordinates.as_slice(), data.entries.as_slice())
+                    .ok_or_else(|| format!("cannot calibrate approximation for profile {}", data.index))?;
+
+                let mut rows = (0..size).map(|_| SparseRow::default()).collect::<Vec<_>>();
+                let mut entries = data.entries;
+                entries.sort_by(|(a_from, a_to, ..), (b_from, b_to, ..)| (a_from, a_to).cmp(&(b_from, b_to)));
+                entries.dedup_by(|(a_from, a_to, ..), (b_from, b_to, ..)| a_from == b_from && a_to == b_to);
+
+                entries.into_iter().filter(|(from, to, ..)| from != to).for_each(|(from, to, duration, distance)| {
+                    let row = &mut rows[from];
+                    row.locations.push(to);
+                    row.values.push((duration, distance));
+                });
+
+                rows.iter_mut().for_each(|row| {
+                    row.locations.shrink_to_fit();
+                    row.values.shrink_to_fit();
+                });
+
+                Ok((rows, approximation))
+            })
+            .collect::<GenericResult<Vec<_>>>()?;
+
+        Ok(Self { coordinates, profiles })
+    }
+
+    fn get_values(&self, profile: &Profile, from: Location, to: Location) -> (Duration, Distance) {
+        if from == to {
+            return (0., 0.);
+        }
+
+        let (rows, approximation) = self.profiles.get(profile.index).expect("unknown profile index");
+        let row = &rows[from];
+
+        match row.locations.binary_search(&to) {
+            Ok(idx) => row.values[idx],
+            Err(_) => {
+                let distance =
+                    get_haversine_distance(self.coordinates[from], self.coordinates[to]) * approximation.detour_factor;
+
+                (distance / approximation.speed, distance)
+            }
+        }
+    }
+}
+
+impl TransportCost for SparseMatrixTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.get_values(profile, from, to).0 * profile.scale
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.get_values(profile, from, to).1
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Duration {
+        self.duration_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
+        self.distance_approx(&route.actor.vehicle.profile, from, to)
+    }
+
+    fn neighbours(&self, profile: &Profile, location: Location) -> Option<&[Location]> {
+        self.profiles.get(profile.index).and_then(|(rows, _)| rows.get(location)).map(|row| row.locations.as_slice())
+    }
+}
+
+/// Learns detour factor and average speed from known routing entries.
+fn create_approximation(
+    coordinates: &[(f64, f64)],
+    entries: &[(Location, Location, Duration, Distance)],
+) -> Option<Approximation> {
+    let (haversine, distance, duration) = entries
+        .iter()
+        .filter(|(from, to, duration, distance)| from != to && *duration >= 0. && *distance >= 0.)
+        .map(|&(from, to, duration, distance)| {
+            (get_haversine_distance(coordinates[from], coordinates[to]), distance, duration)
+        })
+        .fold((0., 0., 0.), |acc, (haversine, distance, duration)| {
+            (acc.0 + haversine, acc.1 + distance, acc.2 + duration)
+        });
+
+    if haversine > 0. && distance > 0. && duration > 0. {
+        Some(Approximation { detour_factor: distance / haversine, speed: distance / duration })
+    } else {
+        None
+    }
+}
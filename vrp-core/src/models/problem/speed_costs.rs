@@ -0,0 +1,199 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/models/problem/speed_costs_test.rs"]
+mod speed_costs_test;
+
+use crate::models::common::*;
+use crate::models::problem::{TransportCost, TravelTime};
+use crate::models::solution::Route;
+use rosomaxa::prelude::GenericResult;
+use std::sync::Arc;
+
+/// Specifies how travel speed changes over time for a given profile and edge class: each band is defined by its
+/// start time and a speed factor relative to the base (free flow) speed. A band lasts till the start of the next one,
+/// the last one lasts forever. Before the start of the first band, the base speed is used.
+pub struct SpeedProfile {
+    /// A profile index.
+    pub index: usize,
+    /// An edge class index. Use `0` if edge classes are not used.
+    pub class: usize,
+    /// Speed bands specified as `(start, factor)` pairs sorted by start time.
+    pub bands: Vec<(Timestamp, f64)>,
+}
+
+impl SpeedProfile {
+    /// Creates a new instance of `SpeedProfile`.
+    pub fn new(index: usize, class: usize, bands: Vec<(Timestamp, f64)>) -> Self {
+        Self { index, class, bands }
+    }
+}
+
+/// Creates time dependent routing costs which use base routing durations and speed profiles. Travel time is calculated
+/// using Ichoua-Gendreau-Potvin model: speed changes when a vehicle crosses the boundary of a speed band, so
+/// leaving later never means arriving earlier (FIFO property).
+///
+/// Optional `edge_classes` assign an edge class to each pair of locations (`from * size + to`). If not specified,
+/// all edges have class `0`. Edges without matching speed profile use base durations.
+pub fn create_speed_profile_transport_cost(
+    base: Arc<dyn TransportCost>,
+    speed_profiles: Vec<SpeedProfile>,
+    edge_classes: Option<Vec<usize>>,
+) -> GenericResult<Arc<dyn TransportCost>> {
+    Ok(Arc::new(SpeedProfileTransportCost::new(base, speed_profiles, edge_classes)?))
+}
+
+type SpeedBands = Vec<(Timestamp, f64)>;
+
+struct SpeedProfileTransportCost {
+    base: Arc<dyn TransportCost>,
+    /// Speed bands indexed by profile index and edge class.
+    profiles: Vec<Vec<SpeedBands>>,
+    edge_classes: Option<(Vec<usize>, usize)>,
+}
+
+impl SpeedProfileTransportCost {
+    fn new(
+        base: Arc<dyn TransportCost>,
+        speed_profiles: Vec<SpeedProfile>,
+        edge_classes: Option<Vec<usize>>,
+    ) -> GenericResult<Self> {
+        let edge_classes = edge_classes
+            .map(|classes| {
+                let size = (classes.len() as f64).sqrt().round() as usize;
+                if size * size != classes.len() {
+                    Err("edge classes should be specified for each pair of locations")
+                } else {
+                    Ok((classes, size))
+                }
+            })
+            .transpose()?;
+
+        let mut profiles: Vec<Vec<SpeedBands>> = Vec::default();
+        for SpeedProfile { index, class, bands } in speed_profiles {
+            if bands.iter().any(|(_, factor)| !factor.is_finite() || *factor <= 0.) {
+                return Err(format!("speed factor should be positive for profile {index} and class {class}").into());
+            }
+
+            if bands.windows(2).any(|pair| pair[0].0 >= pair[1].0) {
+                return Err(
+                    format!("speed bands should be sorted by start for profile {index} and class {class}").into()
+                );
+            }
+
+            if profiles.len() <= index {
+                profiles.resize_with(index + 1, Vec::default);
+            }
+
+            let classes = &mut profiles[index];
+            if classes.len() <= class {
+                classes.resize_with(class + 1, Vec::default);
+            }
+
+            if !classes[class].is_empty() {
+                return Err(format!("duplicate speed profile for profile {index} and class {class}").into());
+            }
+
+            classes[class] = bands;
+        }
+
+        Ok(Self { base, profiles, edge_classes })
+    }
+
+    fn get_bands(&self, profile: &Profile, from: Location, to: Location) -> &[(Timestamp, f64)] {
+        let class = self
+            .edge_classes
+            .as_ref()
+            .and_then(|(classes, size)| classes.get(from * size + to).copied())
+            .unwrap_or_default();
+
+        self.profiles
+            .get(profile.index)
+            .and_then(|classes| classes.get(class))
+            .map(|bands| bands.as_slice())
+            .unwrap_or_default()
+    }
+
+    fn get_duration(&self, profile: &Profile, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        let duration = self.base.duration_approx(profile, from, to);
+        let bands = self.get_bands(profile, from, to);
+
+        // NOTE negative duration is used to mark unreachable location
+        if bands.is_empty() || duration <= 0. {
+            return duration;
+        }
+
+        match travel_time {
+            TravelTime::Departure(departure) => integrate_forward(bands, departure, duration) - departure,
+            TravelTime::Arrival(arrival) => arrival - integrate_backward(bands, arrival, duration),
+        }
+    }
+}
+
+impl TransportCost for SpeedProfileTransportCost {
+    fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+        self.base.duration_approx(profile, from, to)
+    }
+
+    fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+        self.base.distance_approx(profile, from, to)
+    }
+
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        self.get_duration(&route.actor.vehicle.profile, from, to, travel_time)
+    }
+
+    fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+        self.base.distance(route, from, to, travel_time)
+    }
+
+    fn neighbours(&self, profile: &Profile, location: Location) -> Option<&[Location]> {
+        self.base.neighbours(profile, location)
+    }
+}
+
+/// Returns speed factor and time interval of the band which contains given time. Time before the first band has
+/// base speed.
+fn get_band(bands: &[(Timestamp, f64)], idx: usize) -> (Timestamp, Timestamp, f64) {
+    let start = if idx == 0 { Timestamp::MIN } else { bands[idx - 1].0 };
+    let end = bands.get(idx).map_or(Timestamp::MAX, |(start, _)| *start);
+    let factor = if idx == 0 { 1. } else { bands[idx - 1].1 };
+
+    (start, end, factor)
+}
+
+/// Returns arrival time for given departure time and base duration.
+fn integrate_forward(bands: &[(Timestamp, f64)], departure: Timestamp, duration: Duration) -> Timestamp {
+    let mut idx = bands.partition_point(|(start, _)| *start <= departure);
+    let (mut time, mut remaining) = (departure, duration);
+
+    loop {
+        let (_, end, factor) = get_band(bands, idx);
+        let arrival = time + remaining / factor;
+
+        if arrival <= end {
+            return arrival;
+        }
+
+        remaining -= (end - time) * factor;
+        time = end;
+        idx += 1;
+    }
+}
+
+/// Returns departure time for given arrival time and base duration.
+fn integrate_backward(bands: &[(Timestamp, f64)], arrival: Timestamp, duration: Duration) -> Timestamp {
+    let mut idx = bands.partition_point(|(start, _)| *start < arrival);
+    let (mut time, mut remaining) = (arrival, duration);
+
+    loop {
+        let (start, _, factor) = get_band(bands, idx);
+        let departure = time - remaining / factor;
+
+        if departure >= start {
+            return departure;
+        }
+
+        remaining -= (time - start) * factor;
+        time = start;
+        idx -= 1;
+    }
+}
@@ -0,0 +1,36 @@
+use crate::models::common::Distance;
+
+/// Gets distance in meters between two coordinates specified as `(latitude, longitude)` pairs using haversine
+/// formula with earth radius taken from WGS-84 reference ellipsoid.
+pub fn get_haversine_distance((lat1, lng1): (f64, f64), (lat2, lng2): (f64, f64)) -> Distance {
+    let d_lat = degree_rad(lat1 - lat2);
+    let d_lng = degree_rad(lng1 - lng2);
+
+    let (lat1, lat2) = (degree_rad(lat1), degree_rad(lat2));
+
+    let a = (d_lat / 2.).sin() * (d_lat / 2.).sin() + (d_lng / 2.).sin() * (d_lng / 2.).sin() * lat1.cos() * lat2.cos();
+    let c = 2. * a.sqrt().atan2((1. - a).sqrt());
+
+    wgs84_earth_radius(d_lat) * c
+}
+
+/// Converts degrees to radians.
+#[inline(always)]
+fn degree_rad(degrees: f64) -> f64 {
+    std::f64::consts::PI * degrees / 180.
+}
+
+#[inline(always)]
+fn wgs84_earth_radius(lat: f64) -> f64 {
+    // semi-axes of WGS-84 geoidal reference
+    const WGS84_A: f64 = 6_378_137.0; // major semiaxis [m]
+    const WGS84_B: f64 = 6_356_752.3; // minor semiaxis [m]
+
+    // http://en.wikipedia.org/wiki/Earth_radius
+    let an = WGS84_A * WGS84_A * lat.cos();
+    let bn = WGS84_B * WGS84_B * lat.sin();
+    let ad = WGS84_A * lat.cos();
+    let bd = WGS84_B * lat.sin();
+
+    ((an * an + bn * bn) / (ad * ad + bd * bd)).sqrt()
+}
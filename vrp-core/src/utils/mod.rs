@@ -5,3 +5,6 @@ pub use rosomaxa::utils::*;
 
 mod types;
 pub use self::types::Either;
+
+mod geo;
+pub use self::geo::get_haversine_distance;
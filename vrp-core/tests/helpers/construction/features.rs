@@ -33,7 +33,7 @@ pub fn single_demand_as_multi(pickup: (i32, i32), delivery: (i32, i32)) -> Deman
         if value == 0 {
             MultiDimLoad::default()
         } else {
-            MultiDimLoad::new(vec![value])
+            MultiDimLoad::new(vec![value as f64])
         }
     };
 
@@ -99,7 +99,7 @@ impl TestVehicleBuilder {
     }
 
     pub fn capacity_mult(&mut self, capacity: Vec<i32>) -> &mut Self {
-        self.0.dimens.set_vehicle_capacity(MultiDimLoad::new(capacity));
+        self.0.dimens.set_vehicle_capacity(MultiDimLoad::new(capacity.into_iter().map(f64::from).collect()));
         self
     }
 
@@ -0,0 +1,99 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+fn create_job(affinity: Option<(&str, Cost)>) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    if let Some((group, weight)) = affinity {
+        builder.dimens_mut().set_job_affinity(JobAffinity { group: group.to_string(), weight });
+    }
+
+    builder.build_as_job_ref()
+}
+
+fn create_solution_ctx(routes: Vec<Vec<Option<(&str, Cost)>>>) -> SolutionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles((0..routes.len()).map(|idx| test_vehicle_with_id(format!("v{idx}").as_str())).collect())
+        .build();
+
+    let routes = routes
+        .into_iter()
+        .enumerate()
+        .map(|(idx, jobs)| {
+            RouteContextBuilder::default()
+                .with_route(
+                    RouteBuilder::default()
+                        .with_vehicle(&fleet, format!("v{idx}").as_str())
+                        .add_activities(jobs.into_iter().map(|affinity| {
+                            ActivityBuilder::with_location(1).job(create_job(affinity).as_single().cloned()).build()
+                        }))
+                        .build(),
+                )
+                .build()
+        })
+        .collect();
+
+    TestInsertionContextBuilder::default().with_routes(routes).build().solution
+}
+
+parameterized_test! {can_estimate_and_calculate_penalty, (routes, candidate, expected), {
+    can_estimate_and_calculate_penalty_impl(routes, candidate, expected);
+}}
+
+can_estimate_and_calculate_penalty! {
+    case_01_no_affinity: (vec![vec![None], vec![None]], (0, None), (0., 0.)),
+    case_02_new_group: (vec![vec![Some(("a", 1.))], vec![None]], (1, Some(("b", 2.))), (0., 0.)),
+    case_03_same_tour: (vec![vec![Some(("a", 1.))], vec![None]], (0, Some(("a", 2.))), (0., 0.)),
+    case_04_other_tour: (vec![vec![Some(("a", 3.))], vec![None]], (1, Some(("a", 2.))), (2., 0.)),
+    case_05_other_tour_becomes_main: (vec![vec![Some(("a", 1.))], vec![None]], (1, Some(("a", 2.))), (1., 0.)),
+    case_06_split_groups: (
+        vec![vec![Some(("a", 1.)), Some(("a", 2.)), Some(("b", 1.))], vec![Some(("a", 1.)), Some(("b", 3.))]],
+        (1, Some(("a", 1.))),
+        (1., 2.)
+    ),
+    case_07_join_main_of_split_group: (
+        vec![vec![Some(("a", 1.)), Some(("a", 2.))], vec![Some(("a", 1.))]],
+        (0, Some(("a", 1.))),
+        (0., 1.)
+    ),
+}
+
+fn can_estimate_and_calculate_penalty_impl(
+    routes: Vec<Vec<Option<(&str, Cost)>>>,
+    candidate: (usize, Option<(&str, Cost)>),
+    expected: (Cost, Cost),
+) {
+    let (route_idx, affinity) = candidate;
+    let (expected_estimate, expected_fitness) = expected;
+    let mut insertion_ctx = TestInsertionContextBuilder::default().build();
+    insertion_ctx.solution = create_solution_ctx(routes);
+    let feature = create_job_affinity_feature("affinity").expect("cannot create feature");
+    let (state, objective) = (feature.state.as_ref().unwrap(), feature.objective.as_ref().unwrap());
+
+    state.accept_solution_state(&mut insertion_ctx.solution);
+    let estimate = objective.estimate(&MoveContext::Route {
+        solution_ctx: &insertion_ctx.solution,
+        route_ctx: &insertion_ctx.solution.routes[route_idx],
+        job: &create_job(affinity),
+    });
+    let fitness = objective.fitness(&insertion_ctx);
+
+    assert_eq!(estimate, expected_estimate);
+    assert_eq!(fitness, expected_fitness);
+}
+
+#[test]
+fn can_update_route_weights_on_insertion() {
+    let mut solution_ctx = create_solution_ctx(vec![vec![Some(("a", 1.)), Some(("a", 2.)), Some(("b", 1.)), None]]);
+    let feature = create_job_affinity_feature("affinity").expect("cannot create feature");
+    let job = solution_ctx.routes[0].route().tour.jobs().find(|job| job.dimens().get_job_affinity().is_some()).cloned();
+
+    feature.state.as_ref().unwrap().accept_insertion(&mut solution_ctx, 0, &job.unwrap());
+
+    let weights = solution_ctx.routes[0].state().get_affinity_weights().cloned().unwrap();
+    assert_eq!(weights.len(), 2);
+    assert_eq!(weights.get("a"), Some(&3.));
+    assert_eq!(weights.get("b"), Some(&1.));
+}
@@ -0,0 +1,147 @@
+use super::*;
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+/// Creates an activity of linehaul (`L`), backhaul (`B`) or pickup and delivery (`D`) job.
+fn create_activity(haul: char) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    match haul {
+        'L' => builder.demand(create_simple_demand(-1)),
+        'B' => builder.demand(create_simple_demand(1)),
+        'D' => builder.demand(Demand::<SingleDimLoad> {
+            pickup: (SingleDimLoad::default(), SingleDimLoad::new(1)),
+            delivery: (SingleDimLoad::default(), SingleDimLoad::new(1)),
+        }),
+        _ => unreachable!(),
+    };
+
+    ActivityBuilder::with_location(1).job(Some(builder.build_shared())).build()
+}
+
+fn create_route_ctx(tour: &str, policy: Option<BackhaulPolicy>) -> RouteContext {
+    let mut vehicle_builder = TestVehicleBuilder::default();
+    vehicle_builder.id("v1");
+    if let Some(policy) = policy {
+        vehicle_builder.dimens_mut().set_vehicle_backhaul_policy(policy);
+    }
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle_builder.build()).build();
+
+    RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activities(tour.chars().map(create_activity))
+                .build(),
+        )
+        .build()
+}
+
+fn create_feature() -> Feature {
+    create_backhaul_feature::<SingleDimLoad>("backhaul", VIOLATION_CODE).unwrap()
+}
+
+parameterized_test! {can_evaluate_strict_policy, (tour, index, haul, policy, expected), {
+    can_evaluate_strict_policy_impl(tour, index, haul, policy, expected);
+}}
+
+can_evaluate_strict_policy! {
+    case_01_linehaul_before_backhaul: ("LB", 1, 'L', Some(BackhaulPolicy::Strict), None),
+    case_02_linehaul_after_backhaul: ("LB", 2, 'L', Some(BackhaulPolicy::Strict), Some(VIOLATION_CODE)),
+    case_03_backhaul_before_linehaul: ("LB", 0, 'B', Some(BackhaulPolicy::Strict), Some(VIOLATION_CODE)),
+    case_04_backhaul_after_linehaul: ("LB", 1, 'B', Some(BackhaulPolicy::Strict), None),
+    case_05_pickup_delivery: ("LB", 2, 'D', Some(BackhaulPolicy::Strict), None),
+    case_06_mixed_policy: ("LB", 2, 'L', Some(BackhaulPolicy::Mixed { penalty: 10. }), None),
+    case_07_no_policy: ("LB", 2, 'L', None, None),
+}
+
+fn can_evaluate_strict_policy_impl(
+    tour: &str,
+    index: usize,
+    haul: char,
+    policy: Option<BackhaulPolicy>,
+    expected: Option<ViolationCode>,
+) {
+    let route_ctx = create_route_ctx(tour, policy);
+    let route = route_ctx.route();
+    let target = create_activity(haul);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route.tour.get(index).unwrap(),
+        target: &target,
+        next: route.tour.get(index + 1),
+    };
+
+    let result = create_feature()
+        .constraint
+        .unwrap()
+        .evaluate(&MoveContext::activity(&route_ctx, &activity_ctx))
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_mixed_policy, (tour, index, haul, policy, expected), {
+    can_estimate_mixed_policy_impl(tour, index, haul, policy, expected);
+}}
+
+can_estimate_mixed_policy! {
+    case_01_linehaul_before_backhaul: ("LB", 1, 'L', Some(BackhaulPolicy::Mixed { penalty: 10. }), 0.),
+    case_02_linehaul_after_backhaul: ("LB", 2, 'L', Some(BackhaulPolicy::Mixed { penalty: 10. }), 10.),
+    case_03_backhaul_first: ("LLBL", 0, 'B', Some(BackhaulPolicy::Mixed { penalty: 10. }), 20.),
+    case_04_backhaul_after_backhaul: ("LBL", 2, 'B', Some(BackhaulPolicy::Mixed { penalty: 10. }), 0.),
+    case_05_pickup_delivery: ("LB", 2, 'D', Some(BackhaulPolicy::Mixed { penalty: 10. }), 0.),
+    case_06_strict_policy: ("LB", 2, 'L', Some(BackhaulPolicy::Strict), 0.),
+    case_07_no_policy: ("LB", 2, 'L', None, 0.),
+}
+
+fn can_estimate_mixed_policy_impl(
+    tour: &str,
+    index: usize,
+    haul: char,
+    policy: Option<BackhaulPolicy>,
+    expected: Cost,
+) {
+    let route_ctx = create_route_ctx(tour, policy);
+    let route = route_ctx.route();
+    let target = create_activity(haul);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route.tour.get(index).unwrap(),
+        target: &target,
+        next: route.tour.get(index + 1),
+    };
+
+    let result = create_feature().objective.unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_calculate_fitness, (tours, expected), {
+    can_calculate_fitness_impl(tours, expected);
+}}
+
+can_calculate_fitness! {
+    case_01_no_violations: (vec![("LLBB", Some(BackhaulPolicy::Mixed { penalty: 10. }))], 0.),
+    case_02_mixed_order: (vec![("BLBLD", Some(BackhaulPolicy::Mixed { penalty: 10. }))], 20.),
+    case_03_several_tours: (vec![
+        ("LBL", Some(BackhaulPolicy::Mixed { penalty: 10. })),
+        ("BL", Some(BackhaulPolicy::Mixed { penalty: 5. })),
+        ("BL", Some(BackhaulPolicy::Strict)),
+        ("BL", None),
+    ], 15.),
+}
+
+fn can_calculate_fitness_impl(tours: Vec<(&str, Option<BackhaulPolicy>)>, expected: Cost) {
+    let feature = create_feature();
+    let routes = tours.into_iter().map(|(tour, policy)| create_route_ctx(tour, policy)).collect();
+    let mut insertion_ctx = TestInsertionContextBuilder::default().with_routes(routes).build();
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+    let fitness = feature.objective.as_ref().unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
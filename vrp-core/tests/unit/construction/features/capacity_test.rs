@@ -5,7 +5,7 @@ use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
 use crate::models::common::{Demand, SingleDimLoad};
-use crate::models::problem::{Job, Vehicle};
+use crate::models::problem::{Job, Single, Vehicle};
 use crate::models::solution::Activity;
 
 const VIOLATION_CODE: ViolationCode = ViolationCode(2);
@@ -144,6 +144,63 @@ fn can_evaluate_demand_on_activity_impl(
     assert_eq!(result, expected);
 }
 
+fn create_job_with_place_demands(size: i32, place_sizes: Vec<i32>) -> Arc<Single> {
+    let mut builder = TestSingleBuilder::default();
+    builder.demand(create_simple_demand(size));
+    builder.dimens_mut().set_job_place_demands(place_sizes.into_iter().map(create_simple_demand).collect());
+
+    builder.build_shared()
+}
+
+parameterized_test! {can_evaluate_place_demand_on_activity, (place_idx, expected), {
+    can_evaluate_place_demand_on_activity_impl(place_idx, expected);
+}}
+
+can_evaluate_place_demand_on_activity! {
+    case01_full: (0, create_constraint_violation(false)),
+    case02_half: (1, None),
+}
+
+fn can_evaluate_place_demand_on_activity_impl(place_idx: usize, expected: Option<ConstraintViolation>) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_test_vehicle(10)).build();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(create_activity_with_simple_demand(-5))
+                .build(),
+        )
+        .build();
+    let feature = create_feature();
+    feature.state.unwrap().accept_route_state(&mut route_ctx);
+    let mut target = ActivityBuilder::default().job(Some(create_job_with_place_demands(-8, vec![-8, -4]))).build();
+    target.place.idx = place_idx;
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_evaluate_min_place_demand_on_route() {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(create_test_vehicle(10)).build();
+    let insertion_ctx = TestInsertionContextBuilder::default().build();
+    let route_ctx =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build();
+    let job = Job::Single(create_job_with_place_demands(12, vec![12, 6]));
+
+    let result =
+        create_feature().constraint.unwrap().evaluate(&MoveContext::route(&insertion_ctx.solution, &route_ctx, &job));
+
+    assert_eq!(result, None);
+}
+
 parameterized_test! {can_merge_jobs_with_demand, (cluster, candidate, expected), {
     can_merge_jobs_with_demand_impl(cluster, candidate, expected);
 }}
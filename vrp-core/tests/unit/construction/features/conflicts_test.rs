@@ -0,0 +1,106 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_job(id: &str, conflicts: Option<Vec<&str>>) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    builder.id(id);
+    if let Some(conflicts) = conflicts {
+        builder.dimens_mut().set_job_conflicts(conflicts.into_iter().map(|id| id.to_string()).collect());
+    }
+
+    builder.build_as_job_ref()
+}
+
+fn create_route_ctx(jobs: Vec<Job>, feature: &Feature) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activities(
+                    jobs.iter().map(|job| ActivityBuilder::with_location(1).job(job.as_single().cloned()).build()),
+                )
+                .build(),
+        )
+        .build();
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_evaluate_job_conflicts, (route_jobs, candidate, expected), {
+    can_evaluate_job_conflicts_impl(route_jobs, candidate, expected);
+}}
+
+can_evaluate_job_conflicts! {
+    case_01_no_conflicts: (vec![("job1", None)], ("job2", None), None),
+    case_02_candidate_conflicts_with_route: (vec![("job1", None)], ("job2", Some(vec!["job1"])), Some(VIOLATION_CODE)),
+    case_03_route_conflicts_with_candidate: (vec![("job1", Some(vec!["job2"]))], ("job2", None), Some(VIOLATION_CODE)),
+    case_04_conflicts_with_other_job: (vec![("job1", Some(vec!["job3"]))], ("job2", Some(vec!["job4"])), None),
+    case_05_empty_route: (vec![], ("job2", Some(vec!["job1"])), None),
+}
+
+fn can_evaluate_job_conflicts_impl(
+    route_jobs: Vec<(&str, Option<Vec<&str>>)>,
+    candidate: (&str, Option<Vec<&str>>),
+    expected: Option<ViolationCode>,
+) {
+    let feature = create_job_conflicts_feature("conflicts", VIOLATION_CODE).unwrap();
+    let route_ctx =
+        create_route_ctx(route_jobs.into_iter().map(|(id, conflicts)| create_job(id, conflicts)).collect(), &feature);
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let (id, conflicts) = candidate;
+
+    let result = feature
+        .constraint
+        .unwrap()
+        .evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &create_job(id, conflicts)))
+        .map(|violation| violation.code);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_update_conflict_index_on_insertion() {
+    let feature = create_job_conflicts_feature("conflicts", VIOLATION_CODE).unwrap();
+    let job1 = create_job("job1", None);
+    let job2 = create_job("job2", Some(vec!["job3"]));
+    let mut solution_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![create_route_ctx(vec![job1], &feature)])
+        .build()
+        .solution;
+    let state = feature.state.as_ref().unwrap();
+    let constraint = feature.constraint.as_ref().unwrap();
+
+    state.accept_insertion(&mut solution_ctx, 0, &job2);
+
+    let job3 = create_job("job3", None);
+    let result = constraint.evaluate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[0], &job3));
+    assert_eq!(result.map(|violation| violation.code), Some(VIOLATION_CODE));
+    let job4 = create_job("job4", Some(vec!["job1"]));
+    let result = constraint.evaluate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[0], &job4));
+    assert_eq!(result.map(|violation| violation.code), Some(VIOLATION_CODE));
+}
+
+parameterized_test! {can_merge_jobs, (source, candidate, expected), {
+    can_merge_jobs_impl(source, candidate, expected);
+}}
+
+can_merge_jobs! {
+    case_01_no_conflicts: (None, None, Ok(())),
+    case_02_source_conflicts: (Some(vec!["job3"]), None, Err(VIOLATION_CODE)),
+    case_03_candidate_conflicts: (None, Some(vec!["job3"]), Err(VIOLATION_CODE)),
+}
+
+fn can_merge_jobs_impl(source: Option<Vec<&str>>, candidate: Option<Vec<&str>>, expected: Result<(), ViolationCode>) {
+    let feature = create_job_conflicts_feature("conflicts", VIOLATION_CODE).unwrap();
+
+    let result =
+        feature.constraint.unwrap().merge(create_job("job1", source), create_job("job2", candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}
@@ -1,6 +1,7 @@
 use super::*;
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
-use crate::helpers::models::solution::RouteContextBuilder;
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
 
 #[test]
 fn can_properly_estimate_empty_solution() {
@@ -12,3 +13,94 @@ fn can_properly_estimate_empty_solution() {
     assert_eq!(objective.fitness(&empty), 0.);
     assert_eq!(objective.fitness(&non_empty), 0.);
 }
+
+fn create_partial_activity(place_idx: Option<usize>) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    if place_idx.is_some() {
+        builder.dimens_mut().set_job_partial_ratios(vec![1., 0.5, 0.25]);
+    }
+    let mut activity = ActivityBuilder::with_location(1).job(Some(builder.build_shared())).build();
+    activity.place.idx = place_idx.unwrap_or_default();
+
+    activity
+}
+
+parameterized_test! {can_estimate_partial_activity, (place_idx, expected), {
+    can_estimate_partial_activity_impl(place_idx, expected);
+}}
+
+can_estimate_partial_activity! {
+    case_01_full: (Some(0), 0.),
+    case_02_half: (Some(1), 0.5),
+    case_03_quarter: (Some(2), 0.75),
+    case_04_not_divisible: (None, 0.),
+}
+
+fn can_estimate_partial_activity_impl(place_idx: Option<usize>, expected: Cost) {
+    let objective = MinimizeUnassignedBuilder::new("minimize_unassigned").build().unwrap().objective.unwrap();
+    let route_ctx = RouteContextBuilder::default().with_route(RouteBuilder::with_default_vehicle().build()).build();
+    let target = create_partial_activity(place_idx);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result = objective.estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_calculate_fitness_with_partial_jobs() {
+    let objective = MinimizeUnassignedBuilder::new("minimize_unassigned").build().unwrap().objective.unwrap();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activities(vec![
+                    create_partial_activity(Some(0)),
+                    create_partial_activity(Some(1)),
+                    create_partial_activity(Some(2)),
+                    create_partial_activity(None),
+                ])
+                .build(),
+        )
+        .build();
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+
+    assert_eq!(objective.fitness(&insertion_ctx), 1.25);
+}
+
+parameterized_test! {can_use_same_weight_of_partial_jobs_in_fitness_and_estimate, (has_state, expected), {
+    can_use_same_weight_of_partial_jobs_in_fitness_and_estimate_impl(has_state, expected);
+}}
+
+can_use_same_weight_of_partial_jobs_in_fitness_and_estimate! {
+    case_01_with_state: (true, 7.5),
+    case_02_without_state: (false, 0.75),
+}
+
+fn can_use_same_weight_of_partial_jobs_in_fitness_and_estimate_impl(has_state: bool, expected: Cost) {
+    let feature = MinimizeUnassignedBuilder::new("minimize_unassigned").set_job_estimator(|_, _| 10.).build().unwrap();
+    let (objective, state) = (feature.objective.unwrap(), feature.state.unwrap());
+    let target = create_partial_activity(Some(2));
+    let job = Job::Single(target.job.clone().unwrap());
+    let mut insertion_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![RouteContextBuilder::default()
+            .with_route(RouteBuilder::with_default_vehicle().add_activity(target.deep_copy()).build())
+            .build()])
+        .with_required(vec![job])
+        .build();
+    if has_state {
+        state.accept_solution_state(&mut insertion_ctx.solution);
+    }
+    let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+    let activity_ctx = ActivityContext { index: 0, prev: &target, target: &target, next: None };
+
+    let estimate = objective.estimate(&MoveContext::activity(route_ctx, &activity_ctx));
+    let fitness = objective.fitness(&insertion_ctx);
+
+    assert_eq!(estimate, expected);
+    assert_eq!(fitness, 7.5);
+}
@@ -6,6 +6,7 @@ use crate::helpers::construction::features::{create_simple_demand, single_demand
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::models::common::{Schedule, TimeWindow};
 use crate::models::problem::{JobIdDimension, VehicleIdDimension};
 use crate::models::solution::Activity;
 use crate::prelude::Fleet;
@@ -227,7 +228,7 @@ fn can_remove_trivial_reloads_when_used_from_capacity_constraint_impl(
         .with_routes(vec![create_route_context(vec![capacity], activities)])
         .build()
         .solution;
-    let reload_feature = create_simple_reload_feature::<MultiDimLoad, _>(move |capacity| *capacity * threshold);
+    let reload_feature = create_simple_reload_feature::<MultiDimLoad, _>(move |capacity| capacity.clone() * threshold);
 
     let min_jobs_feature = MinimizeUnassignedBuilder::new("min_jobs").build().unwrap();
     let features = vec![reload_feature, min_jobs_feature];
@@ -311,6 +312,7 @@ fn create_route_ctx(
         }
         NormalResource(capacity) => create_resource_activity(vehicle_id, *capacity, None),
         Usage(demand) => create_usage_activity(*demand),
+        Supply(resource_id, departure) => create_supply_activity(*resource_id, *departure),
     });
 
     let mut route_ctx = RouteContextBuilder::default()
@@ -355,6 +357,7 @@ enum ActivityType {
     Usage(i32),
     NormalResource(i32),
     SharedResource(usize),
+    Supply(usize, f64),
 }
 
 parameterized_test! {can_update_resource_consumption, (resources, activities, total_jobs, expected_resources), {
@@ -455,8 +458,10 @@ fn can_constraint_route_impl(
     let solution_ctx = create_solution_ctx(resources, vec![activities], 1, false);
     let constraint = SharedResourceConstraint {
         violation_code: VIOLATION_CODE,
+        resource_capacity_fn: builder.shared_resource_capacity_fn.unwrap(),
         resource_demand_fn: builder.shared_resource_demand_fn.unwrap(),
         is_partial_solution_fn: builder.is_partial_solution_fn.unwrap(),
+        supply: None,
     };
 
     let result = constraint.evaluate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[0], &job));
@@ -526,13 +531,16 @@ fn can_constraint_activity_impl(
     let builder = create_shared_reload_builder(total_jobs);
     let constraint = SharedResourceConstraint {
         violation_code: VIOLATION_CODE,
+        resource_capacity_fn: builder.shared_resource_capacity_fn.as_ref().cloned().unwrap(),
         resource_demand_fn: builder.shared_resource_demand_fn.as_ref().cloned().unwrap(),
         is_partial_solution_fn: builder.is_partial_solution_fn.as_ref().cloned().unwrap(),
+        supply: None,
     };
     let state = SharedResourceState {
         resource_capacity_fn: builder.shared_resource_capacity_fn.unwrap(),
         resource_demand_fn: builder.shared_resource_demand_fn.unwrap(),
         is_partial_solution_fn: builder.is_partial_solution_fn.unwrap(),
+        supply: None,
     };
     state.accept_solution_state(&mut solution_ctx);
 
@@ -543,3 +551,120 @@ fn can_constraint_activity_impl(
 
     assert_eq!(result.map(|result| result.code), expected)
 }
+
+// shared reload with supply
+
+struct SupplyIdDimenKey;
+
+fn create_supply_activity(resource_id: SharedResourceId, departure: f64) -> Activity {
+    let single = TestSingleBuilder::default().id("supply").property::<SupplyIdDimenKey, _>(resource_id).build_shared();
+
+    ActivityBuilder::default().job(Some(single)).schedule(Schedule::new(departure, departure)).build()
+}
+
+fn create_supplied_reload_parts(
+    solution_ctx: &SolutionContext,
+) -> (SharedResourceConstraint<SingleDimLoad>, SharedResourceState<SingleDimLoad>) {
+    let total_jobs = solution_ctx.get_jobs_amount();
+    let builder = create_shared_reload_builder(total_jobs).set_shared_resource_supply(
+        |single| single.dimens.get_value::<SupplyIdDimenKey, SharedResourceId>().cloned(),
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+    );
+
+    let constraint = SharedResourceConstraint {
+        violation_code: VIOLATION_CODE,
+        resource_capacity_fn: builder.shared_resource_capacity_fn.as_ref().cloned().unwrap(),
+        resource_demand_fn: builder.shared_resource_demand_fn.as_ref().cloned().unwrap(),
+        is_partial_solution_fn: builder.is_partial_solution_fn.as_ref().cloned().unwrap(),
+        supply: builder.shared_resource_supply.clone(),
+    };
+    let state = SharedResourceState {
+        resource_capacity_fn: builder.shared_resource_capacity_fn.unwrap(),
+        resource_demand_fn: builder.shared_resource_demand_fn.unwrap(),
+        is_partial_solution_fn: builder.is_partial_solution_fn.unwrap(),
+        supply: builder.shared_resource_supply,
+    };
+
+    (constraint, state)
+}
+
+parameterized_test! {can_update_supplied_resource_consumption, (is_supply_assigned, expected_available, expected_departure), {
+    can_update_supplied_resource_consumption_impl(is_supply_assigned, expected_available, expected_departure);
+}}
+
+can_update_supplied_resource_consumption! {
+    case_01_supply_assigned: (true, 8, 50.),
+    case_02_supply_not_assigned: (false, 0, 0.),
+}
+
+fn can_update_supplied_resource_consumption_impl(
+    is_supply_assigned: bool,
+    expected_available: i32,
+    expected_departure: f64,
+) {
+    let supply_route = if is_supply_assigned { vec![Supply(0, 50.)] } else { vec![Usage(1)] };
+    let mut solution_ctx =
+        create_solution_ctx(vec![(0, 10)], vec![supply_route, vec![SharedResource(0), Usage(2)]], 10, false);
+    if !is_supply_assigned {
+        solution_ctx.required.push(Job::Single(create_supply_activity(0, 0.).job.unwrap()));
+    }
+    let (_, state) = create_supplied_reload_parts(&solution_ctx);
+
+    state.accept_solution_state(&mut solution_ctx);
+
+    let route_ctx = &solution_ctx.routes[1];
+    let available = route_ctx
+        .state()
+        .get_activity_state::<SharedResourceStateKey, Option<SingleDimLoad>>(1)
+        .and_then(|resource| *resource)
+        .map(|resource| resource.value);
+    assert_eq!(available, Some(expected_available));
+    assert!(solution_ctx.unassigned.is_empty());
+    assert_eq!(route_ctx.route().tour.job_count(), 2);
+    assert_eq!(route_ctx.route().tour.start().unwrap().schedule.departure, expected_departure);
+    assert_eq!(route_ctx.route().tour.get(1).unwrap().place.time, DEFAULT_ACTIVITY_TIME_WINDOW);
+}
+
+parameterized_test! {can_constraint_supplied_resource, (route_idx, insertion_idx, target, target_tw_start, expected), {
+    can_constraint_supplied_resource_impl(route_idx, insertion_idx, target, target_tw_start, expected);
+}}
+
+can_constraint_supplied_resource! {
+    case_01_supply_in_consumer_route: (1, 0, Supply(0, 0.), 0., Some(VIOLATION_CODE)),
+    case_02_supply_after_deadline: (0, 0, Supply(0, 0.), 2000., Some(VIOLATION_CODE)),
+    case_03_job_delays_supply_after_deadline: (0, 0, Usage(1), 2000., Some(VIOLATION_CODE)),
+    case_04_job_does_not_delay_supply: (0, 0, Usage(1), 0., None),
+    case_05_job_after_supply: (0, 1, Usage(1), 2000., None),
+    case_06_consumer_after_supply: (1, 1, Usage(1), 0., None),
+}
+
+fn can_constraint_supplied_resource_impl(
+    route_idx: usize,
+    insertion_idx: usize,
+    target: ActivityType,
+    target_tw_start: f64,
+    expected: Option<ViolationCode>,
+) {
+    let mut solution_ctx =
+        create_solution_ctx(vec![(0, 10)], vec![vec![Supply(0, 50.)], vec![SharedResource(0), Usage(2)]], 10, false);
+    let (constraint, state) = create_supplied_reload_parts(&solution_ctx);
+    state.accept_solution_state(&mut solution_ctx);
+    let mut target = match target {
+        Usage(demand) => create_usage_activity(demand),
+        Supply(resource_id, departure) => create_supply_activity(resource_id, departure),
+        _ => unreachable!(),
+    };
+    target.place.time = TimeWindow::new(target_tw_start, target_tw_start + 1000.);
+    let route_ctx = &solution_ctx.routes[route_idx];
+    let activity_ctx = ActivityContext {
+        index: insertion_idx,
+        prev: route_ctx.route().tour.get(insertion_idx).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(insertion_idx + 1),
+    };
+
+    let result = constraint.evaluate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result.map(|result| result.code), expected)
+}
@@ -0,0 +1,151 @@
+use super::*;
+use crate::construction::enablers::LatestArrivalActivityState;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::{Location, TimeInterval, TimeWindow};
+use crate::models::problem::{VehicleDetail, VehiclePlace};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+// NOTE gives safety factor which is close to 1
+const ONE_SIGMA_PROBABILITY: f64 = 0.841_344_746;
+
+fn create_route_ctx(shift_end: Timestamp) -> RouteContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![TestVehicleBuilder::default()
+            .id("v1")
+            .details(vec![VehicleDetail {
+                start: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: Some(0.), latest: None } }),
+                end: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: None, latest: Some(shift_end) } }),
+            }])
+            .build()])
+        .build();
+
+    RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(ActivityBuilder::with_location(10).build())
+                .add_activity(ActivityBuilder::with_location(20).build())
+                .add_activity(ActivityBuilder::with_location(30).build())
+                .build(),
+        )
+        .build()
+}
+
+fn create_feature(uncertainty: Option<(f64, f64)>) -> Feature {
+    let uncertainty =
+        uncertainty.map(|(deviation, probability)| TravelUncertainty::new(deviation, probability).unwrap());
+
+    create_robust_schedule_feature(
+        "robust_schedule",
+        TestTransportCost::new_shared(),
+        TestActivityCost::new_shared(),
+        VIOLATION_CODE,
+        Arc::new(move |_| uncertainty),
+    )
+    .unwrap()
+}
+
+fn create_route_ctx_with_states(feature: &Feature, shift_end: Timestamp) -> RouteContext {
+    let mut route_ctx = create_route_ctx(shift_end);
+
+    let transport = TransportFeatureBuilder::new("transport")
+        .set_transport_cost(TestTransportCost::new_shared())
+        .set_activity_cost(TestActivityCost::new_shared())
+        .build_minimize_cost()
+        .unwrap();
+    transport.state.unwrap().accept_route_state(&mut route_ctx);
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_get_normal_quantile, (probability, expected), {
+    can_get_normal_quantile_impl(probability, expected);
+}}
+
+can_get_normal_quantile! {
+    case01: (0.5, 0.),
+    case02: (0.841_344_746, 1.),
+    case03: (0.95, 1.644_853_627),
+    case04: (0.975, 1.959_963_985),
+    case05: (0.999, 3.090_232_306),
+    case06: (0.01, -2.326_347_874),
+}
+
+fn can_get_normal_quantile_impl(probability: f64, expected: f64) {
+    let result = get_normal_quantile(probability);
+
+    assert!((result - expected).abs() < 1E-6, "result: {result}, expected: {expected}");
+}
+
+parameterized_test! {can_validate_travel_uncertainty, (deviation, probability, is_ok), {
+    can_validate_travel_uncertainty_impl(deviation, probability, is_ok);
+}}
+
+can_validate_travel_uncertainty! {
+    case01_valid: (0.2, 0.95, true),
+    case02_zero_deviation: (0., 0.5, true),
+    case03_negative_deviation: (-0.1, 0.95, false),
+    case04_low_probability: (0.2, 0.4, false),
+    case05_certain_probability: (0.2, 1., false),
+    case06_nan_deviation: (f64::NAN, 0.95, false),
+}
+
+fn can_validate_travel_uncertainty_impl(deviation: f64, probability: f64, is_ok: bool) {
+    let result = TravelUncertainty::new(deviation, probability);
+
+    assert_eq!(result.is_ok(), is_ok);
+}
+
+#[test]
+fn can_calculate_robust_states() {
+    let feature = create_feature(Some((0.1, ONE_SIGMA_PROBABILITY)));
+
+    let route_ctx = create_route_ctx_with_states(&feature, 100.);
+
+    let state = route_ctx.state();
+    let variances = (0..5).map(|idx| *state.get_travel_variance_at(idx).unwrap()).collect::<Vec<_>>();
+    let robust_arrivals = (1..5).map(|idx| *state.get_robust_latest_arrival_at(idx).unwrap()).collect::<Vec<_>>();
+    let mean_arrivals = (1..4).map(|idx| *state.get_latest_arrival_at(idx).unwrap()).collect::<Vec<_>>();
+    assert_eq!(variances.iter().map(|v| v.round()).collect::<Vec<_>>(), vec![0., 1., 2., 3., 12.]);
+    let buffer = 12_f64.sqrt();
+    robust_arrivals.iter().zip([50., 60., 70., 100.]).for_each(|(&actual, expected)| {
+        assert!((actual - (expected - buffer)).abs() < 1E-6, "actual: {actual}, expected: {}", expected - buffer);
+    });
+    assert_eq!(mean_arrivals, vec![50., 60., 70.]);
+}
+
+parameterized_test! {can_evaluate_activity, (uncertainty, target, prev_index, expected), {
+    can_evaluate_activity_impl(uncertainty, target, prev_index, expected);
+}}
+
+can_evaluate_activity! {
+    case01_fits_buffer: (Some((0.1, ONE_SIGMA_PROBABILITY)), (40, 1000.), 3, None),
+    case02_breaks_next_buffer: (Some((0.1, ONE_SIGMA_PROBABILITY)), (50, 1000.), 3, ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_no_buffer_needed: (Some((0.1, 0.5)), (50, 1000.), 3, None),
+    case04_no_uncertainty: (None, (50, 1000.), 3, None),
+    case05_breaks_target_buffer: (Some((0.1, ONE_SIGMA_PROBABILITY)), (35, 36.), 3, ConstraintViolation::skip(VIOLATION_CODE)),
+    case06_fits_target_buffer: (Some((0.1, ONE_SIGMA_PROBABILITY)), (35, 37.), 3, None),
+    case07_breaks_downstream_buffer: (Some((0.1, ONE_SIGMA_PROBABILITY)), (28, 1000.), 0, ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_evaluate_activity_impl(
+    uncertainty: Option<(f64, f64)>,
+    target: (Location, Timestamp),
+    prev_index: usize,
+    expected: Option<ConstraintViolation>,
+) {
+    let feature = create_feature(uncertainty);
+    let route_ctx = create_route_ctx_with_states(&feature, 100.);
+    let (location, end) = target;
+    let prev = route_ctx.route().tour.get(prev_index).unwrap();
+    let target = ActivityBuilder::with_location_and_tw(location, TimeWindow::new(0., end)).build();
+    let next = route_ctx.route().tour.get(prev_index + 1);
+    let activity_ctx = ActivityContext { index: prev_index, prev, target: &target, next };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
@@ -1,12 +1,15 @@
-use crate::construction::features::skills::create_skills_feature;
-use crate::construction::features::{JobSkills, JobSkillsDimension, VehicleSkillsDimension};
+use crate::construction::features::skills::{create_skills_feature, create_skills_overqualification_feature};
+use crate::construction::features::{
+    JobSkills, JobSkillsDimension, VehicleSkillLevelsDimension, VehicleSkillsDimension,
+};
 use crate::construction::heuristics::MoveContext;
+use crate::construction::heuristics::RouteContext;
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
 use crate::helpers::models::problem::{test_driver, FleetBuilder, TestSingleBuilder, TestVehicleBuilder};
 use crate::helpers::models::solution::{RouteBuilder, RouteContextBuilder};
 use crate::models::problem::{Job, Vehicle};
 use crate::models::{ConstraintViolation, ViolationCode};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::iter::FromIterator;
 
 const VIOLATION_CODE: ViolationCode = ViolationCode(1);
@@ -17,6 +20,7 @@ fn create_job_with_skills(all_of: Option<Vec<&str>>, one_of: Option<Vec<&str>>,
         all_of: all_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
         one_of: one_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
         none_of: none_of.map(|skills| skills.iter().map(|s| s.to_string()).collect()),
+        min_levels: None,
     });
 
     builder.build_as_job_ref()
@@ -33,6 +37,34 @@ fn create_vehicle_with_skills(skills: Option<Vec<&str>>) -> Vehicle {
     builder.id("v1").build()
 }
 
+fn create_job_with_levels(min_levels: Vec<(&str, u32)>) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    builder.dimens_mut().set_job_skills(
+        JobSkills::new(None, None, None)
+            .with_min_levels(min_levels.into_iter().map(|(skill, level)| (skill.to_string(), level)).collect()),
+    );
+
+    builder.build_as_job_ref()
+}
+
+fn create_vehicle_with_levels(skills: Vec<&str>, levels: Vec<(&str, u32)>) -> Vehicle {
+    let mut builder = TestVehicleBuilder::default();
+    builder.dimens_mut().set_vehicle_skills(
+        skills.iter().chain(levels.iter().map(|(skill, _)| skill)).map(|s| s.to_string()).collect(),
+    );
+    builder.dimens_mut().set_vehicle_skill_levels(
+        levels.into_iter().map(|(skill, level)| (skill.to_string(), level)).collect::<HashMap<_, _>>(),
+    );
+
+    builder.id("v1").build()
+}
+
+fn create_route_ctx(vehicle: Vehicle) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+
+    RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build()
+}
+
 fn failure() -> Option<ConstraintViolation> {
     ConstraintViolation::fail(VIOLATION_CODE)
 }
@@ -136,3 +168,90 @@ fn can_create_empty_skills_as_none() {
     assert!(skills.one_of.is_none());
     assert!(skills.none_of.is_none());
 }
+
+parameterized_test! {can_check_skill_levels, (min_levels, vehicle_skills, vehicle_levels, expected), {
+    can_check_skill_levels_impl(min_levels, vehicle_skills, vehicle_levels, expected);
+}}
+
+can_check_skill_levels! {
+    case_01_no_skill: (vec![("s1", 1)], vec![], vec![], failure()),
+    case_02_plain_skill_first_level: (vec![("s1", 1)], vec!["s1"], vec![], None),
+    case_03_plain_skill_higher_level: (vec![("s1", 2)], vec!["s1"], vec![], failure()),
+    case_04_same_level: (vec![("s1", 2)], vec![], vec![("s1", 2)], None),
+    case_05_higher_level: (vec![("s1", 2)], vec![], vec![("s1", 3)], None),
+    case_06_lower_level: (vec![("s1", 2)], vec![], vec![("s1", 1)], failure()),
+    case_07_multiple_one_lower: (vec![("s1", 2), ("s2", 2)], vec![], vec![("s1", 3), ("s2", 1)], failure()),
+    case_08_multiple: (vec![("s1", 2), ("s2", 1)], vec!["s2"], vec![("s1", 3)], None),
+}
+
+fn can_check_skill_levels_impl(
+    min_levels: Vec<(&str, u32)>,
+    vehicle_skills: Vec<&str>,
+    vehicle_levels: Vec<(&str, u32)>,
+    expected: Option<ConstraintViolation>,
+) {
+    let route_ctx = create_route_ctx(create_vehicle_with_levels(vehicle_skills, vehicle_levels));
+    let constraint = create_skills_feature("skills", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let actual = constraint.evaluate(&MoveContext::route(
+        &TestInsertionContextBuilder::default().build().solution,
+        &route_ctx,
+        &create_job_with_levels(min_levels),
+    ));
+
+    assert_eq!(actual, expected)
+}
+
+parameterized_test! {can_merge_skill_levels, (source, candidate, expected), {
+    can_merge_skill_levels_impl(source, candidate, expected);
+}}
+
+can_merge_skill_levels! {
+    case_01_same: (vec![("s1", 2)], vec![("s1", 2)], Ok(())),
+    case_02_source_higher: (vec![("s1", 3)], vec![("s1", 2)], Ok(())),
+    case_03_source_lower: (vec![("s1", 1)], vec![("s1", 2)], Err(VIOLATION_CODE)),
+    case_04_source_superset: (vec![("s1", 1), ("s2", 1)], vec![("s1", 1)], Ok(())),
+    case_05_source_subset: (vec![("s1", 1)], vec![("s1", 1), ("s2", 1)], Err(VIOLATION_CODE)),
+}
+
+fn can_merge_skill_levels_impl(
+    source: Vec<(&str, u32)>,
+    candidate: Vec<(&str, u32)>,
+    expected: Result<(), ViolationCode>,
+) {
+    let constraint = create_skills_feature("skills", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let result = constraint.merge(create_job_with_levels(source), create_job_with_levels(candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_estimate_overqualification, (min_levels, vehicle_skills, vehicle_levels, expected), {
+    can_estimate_overqualification_impl(min_levels, vehicle_skills, vehicle_levels, expected);
+}}
+
+can_estimate_overqualification! {
+    case_01_no_levels: (vec![], vec!["s1"], vec![("s2", 3)], 0.),
+    case_02_same_level: (vec![("s1", 2)], vec![], vec![("s1", 2)], 0.),
+    case_03_higher_level: (vec![("s1", 1)], vec![], vec![("s1", 3)], 2.),
+    case_04_plain_skill: (vec![("s1", 1)], vec!["s1"], vec![], 0.),
+    case_05_multiple: (vec![("s1", 1), ("s2", 2)], vec![], vec![("s1", 2), ("s2", 3), ("s3", 3)], 2.),
+}
+
+fn can_estimate_overqualification_impl(
+    min_levels: Vec<(&str, u32)>,
+    vehicle_skills: Vec<&str>,
+    vehicle_levels: Vec<(&str, u32)>,
+    expected: f64,
+) {
+    let route_ctx = create_route_ctx(create_vehicle_with_levels(vehicle_skills, vehicle_levels));
+    let objective = create_skills_overqualification_feature("overqualification").unwrap().objective.unwrap();
+
+    let estimate = objective.estimate(&MoveContext::route(
+        &TestInsertionContextBuilder::default().build().solution,
+        &route_ctx,
+        &create_job_with_levels(min_levels),
+    ));
+
+    assert_eq!(estimate, expected);
+}
@@ -1,6 +1,7 @@
 use super::*;
+use crate::construction::enablers::update_route_schedule;
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
-use crate::helpers::models::problem::{get_job_id, TestSingleBuilder};
+use crate::helpers::models::problem::{get_job_id, TestActivityCost, TestSingleBuilder, TestTransportCost};
 use crate::helpers::models::solution::*;
 use crate::models::problem::Single;
 
@@ -67,3 +68,165 @@ fn can_merge_value() {
 
     assert_eq!(merged.dimens().get_value::<ValueDimenKey, f64>().cloned(), Some(12.))
 }
+
+parameterized_test! {can_calculate_value_decay_factor, (points, time, expected), {
+    can_calculate_value_decay_factor_impl(points, time, expected);
+}}
+
+can_calculate_value_decay_factor! {
+    case_01_before_first: (vec![(10., 1.), (20., 0.)], 0., 1.),
+    case_02_at_first: (vec![(10., 1.), (20., 0.)], 10., 1.),
+    case_03_between: (vec![(10., 1.), (20., 0.)], 15., 0.5),
+    case_04_at_last: (vec![(10., 1.), (20., 0.)], 20., 0.),
+    case_05_after_last: (vec![(10., 1.), (20., 0.)], 30., 0.),
+    case_06_several_segments: (vec![(0., 1.), (10., 0.8), (20., 0.)], 5., 0.9),
+    case_07_single_point: (vec![(10., 0.5)], 20., 0.5),
+}
+
+fn can_calculate_value_decay_factor_impl(points: Vec<(Timestamp, f64)>, time: Timestamp, expected: f64) {
+    let decay = ValueDecay::new(points).unwrap();
+
+    assert!((decay.factor(time) - expected).abs() < 1E-9);
+}
+
+parameterized_test! {can_detect_invalid_value_decay, points, {
+    can_detect_invalid_value_decay_impl(points);
+}}
+
+can_detect_invalid_value_decay! {
+    case_01_empty: vec![],
+    case_02_unsorted: vec![(20., 1.), (10., 0.)],
+    case_03_duplicate_time: vec![(10., 1.), (10., 0.)],
+    case_04_negative: vec![(10., -1.)],
+    case_05_not_finite: vec![(10., f64::INFINITY)],
+}
+
+fn can_detect_invalid_value_decay_impl(points: Vec<(Timestamp, f64)>) {
+    assert!(ValueDecay::new(points).is_err());
+}
+
+fn create_decay_feature() -> Feature {
+    create_maximize_total_job_value_with_decay_feature(
+        "value",
+        JobReadValueFn::Left(Arc::new(|_| 100.)),
+        Arc::new(|job, _| job),
+        TestTransportCost::new_shared(),
+        VIOLATION_CODE,
+    )
+    .unwrap()
+}
+
+fn create_single(location: Location, has_decay: bool) -> Arc<Single> {
+    let mut builder = TestSingleBuilder::default();
+    builder.location(Some(location));
+    if has_decay {
+        builder.dimens_mut().set_job_value_decay(ValueDecay::new(vec![(0., 1.), (20., 0.)]).unwrap());
+    }
+
+    builder.build_shared()
+}
+
+fn create_route_ctx(feature: &Feature, activities: Vec<(Location, bool)>) -> RouteContext {
+    let activities = activities.into_iter().map(|(location, has_decay)| {
+        ActivityBuilder::with_location(location).job(Some(create_single(location, has_decay))).build()
+    });
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::with_default_vehicle().add_activities(activities).build())
+        .build();
+
+    update_route_schedule(&mut route_ctx, &TestActivityCost::default(), &TestTransportCost::default());
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_estimate_decayed_value, (target_location, target_decay, expected), {
+    can_estimate_decayed_value_impl(target_location, target_decay, expected);
+}}
+
+can_estimate_decayed_value! {
+    case_01_no_delay: (5, false, 0.),
+    case_02_delay: (15, false, 50.),
+    case_03_delay_with_decayed_target: (15, true, 25.),
+    case_04_no_delay_with_decayed_target: (5, true, -75.),
+}
+
+fn can_estimate_decayed_value_impl(target_location: Location, target_decay: bool, expected: Cost) {
+    let feature = create_decay_feature();
+    let route_ctx = create_route_ctx(&feature, vec![(10, true)]);
+    let route = route_ctx.route();
+    let target =
+        ActivityBuilder::with_location(target_location).job(Some(create_single(target_location, target_decay))).build();
+    let activity_ctx =
+        ActivityContext { index: 0, prev: route.tour.get(0).unwrap(), target: &target, next: route.tour.get(1) };
+
+    let result = feature.objective.unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert!((result - expected).abs() < 1E-9);
+}
+
+#[test]
+fn can_calculate_decayed_value_fitness() {
+    let feature = create_decay_feature();
+    let route_ctx = create_route_ctx(&feature, vec![(5, false), (10, true), (15, true)]);
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+
+    let result = feature.objective.unwrap().fitness(&insertion_ctx);
+
+    assert!((result - -175.).abs() < 1E-9);
+}
+
+#[test]
+fn can_skip_merge_of_decayed_jobs() {
+    let constraint = create_decay_feature().constraint.unwrap();
+    let source = Job::Single(create_single(1, true));
+    let candidate = Job::Single(create_single(2, false));
+
+    assert_eq!(constraint.merge(source, candidate).map(|_| ()), Err(VIOLATION_CODE));
+}
+
+fn create_partial_job(place_idx: usize, has_decay: bool) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    builder.location(Some(5)).dimens_mut().set_job_partial_ratios(vec![1., 0.5]);
+    if has_decay {
+        builder.dimens_mut().set_job_value_decay(ValueDecay::new(vec![(0., 1.), (20., 0.)]).unwrap());
+    }
+    let mut activity = ActivityBuilder::with_location(5).job(Some(builder.build_shared())).build();
+    activity.place.idx = place_idx;
+
+    activity
+}
+
+parameterized_test! {can_estimate_partial_job_value, (place_idx, has_decay, expected), {
+    can_estimate_partial_job_value_impl(place_idx, has_decay, expected);
+}}
+
+can_estimate_partial_job_value! {
+    case_01_full: (0, false, 0.),
+    case_02_half: (1, false, 50.),
+    case_03_full_with_decay: (0, true, -75.),
+    case_04_half_with_decay: (1, true, -37.5),
+}
+
+fn can_estimate_partial_job_value_impl(place_idx: usize, has_decay: bool, expected: Cost) {
+    let feature = if has_decay {
+        create_decay_feature()
+    } else {
+        create_maximize_total_job_value_feature(
+            "value",
+            JobReadValueFn::Left(Arc::new(|_| 100.)),
+            Arc::new(|job, _| job),
+            VIOLATION_CODE,
+        )
+        .unwrap()
+    };
+    let route_ctx = RouteContextBuilder::default().with_route(RouteBuilder::with_default_vehicle().build()).build();
+    let route = route_ctx.route();
+    let target = create_partial_job(place_idx, has_decay);
+    let activity_ctx =
+        ActivityContext { index: 0, prev: route.tour.get(0).unwrap(), target: &target, next: route.tour.get(1) };
+
+    let result = feature.objective.unwrap().estimate(&MoveContext::activity(&route_ctx, &activity_ctx));
+
+    assert!((result - expected).abs() < 1E-9);
+}
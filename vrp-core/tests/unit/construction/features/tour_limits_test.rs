@@ -167,3 +167,109 @@ mod traveling {
         assert_eq!(result, ConstraintViolation::skip(DURATION_CODE));
     }
 }
+
+mod leg {
+    use super::*;
+    use crate::models::common::*;
+    use crate::models::solution::Activity;
+
+    const DISTANCE_CODE: ViolationCode = ViolationCode(2);
+    const DURATION_CODE: ViolationCode = ViolationCode(3);
+
+    parameterized_test! {can_check_leg_limits, (prev, target, next, limit, expected), {
+        can_check_leg_limits_impl(prev, target, next, limit, expected);
+    }}
+
+    can_check_leg_limits! {
+        case01: (0, 10, Some(20), (Some(10.), None), None),
+        case02: (0, 11, Some(20), (Some(10.), None), ConstraintViolation::skip(DISTANCE_CODE)),
+        case03: (0, 9, Some(20), (Some(10.), None), ConstraintViolation::skip(DISTANCE_CODE)),
+        case04: (0, 11, None, (Some(10.), None), ConstraintViolation::skip(DISTANCE_CODE)),
+        case05: (0, 9, None, (Some(10.), None), None),
+
+        case06: (0, 10, Some(20), (None, Some(10.)), None),
+        case07: (0, 11, Some(20), (None, Some(10.)), ConstraintViolation::skip(DURATION_CODE)),
+        case08: (0, 9, Some(20), (None, Some(10.)), ConstraintViolation::skip(DURATION_CODE)),
+
+        case09: (0, 30, Some(20), (None, None), None),
+    }
+
+    fn can_check_leg_limits_impl(
+        prev: Location,
+        target: Location,
+        next: Option<Location>,
+        limit: (Option<Distance>, Option<Duration>),
+        expected: Option<ConstraintViolation>,
+    ) {
+        let route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&test_fleet(), "v1").build())
+            .build();
+        let constraint = create_leg_limit_feature(
+            "leg_limit",
+            TestTransportCost::new_shared(),
+            DISTANCE_CODE,
+            DURATION_CODE,
+            Arc::new(move |_| limit.0),
+            Arc::new(move |_| limit.1),
+        )
+        .unwrap()
+        .constraint
+        .unwrap();
+        let next = next.map(|next| ActivityBuilder::with_location(next).build());
+
+        let result = constraint.evaluate(&MoveContext::activity(
+            &route_ctx,
+            &ActivityContext {
+                index: 0,
+                prev: &ActivityBuilder::with_location(prev).build(),
+                target: &ActivityBuilder::with_location(target).build(),
+                next: next.as_ref(),
+            },
+        ));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn can_skip_activities_without_location_when_checking_leg_limits() {
+        let placeless_break = || {
+            ActivityBuilder::with_location(10)
+                .job(Some(TestSingleBuilder::default().location(None).build_shared()))
+                .build()
+        };
+        let route_ctx = RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .with_vehicle(&test_fleet(), "v1")
+                    .add_activities(vec![
+                        ActivityBuilder::with_location(10).build(),
+                        placeless_break(),
+                        ActivityBuilder::with_location(30).build(),
+                    ])
+                    .build(),
+            )
+            .build();
+        let constraint = create_leg_limit_feature(
+            "leg_limit",
+            TestTransportCost::new_shared(),
+            DISTANCE_CODE,
+            DURATION_CODE,
+            Arc::new(|_| Some(10.)),
+            Arc::new(|_| None),
+        )
+        .unwrap()
+        .constraint
+        .unwrap();
+        let tour = &route_ctx.route().tour;
+        let evaluate = |index: usize, target: &Activity| {
+            constraint.evaluate(&MoveContext::activity(
+                &route_ctx,
+                &ActivityContext { index, prev: tour.get(index).unwrap(), target, next: tour.get(index + 1) },
+            ))
+        };
+
+        assert_eq!(evaluate(1, &ActivityBuilder::with_location(15).build()), ConstraintViolation::skip(DISTANCE_CODE));
+        assert_eq!(evaluate(2, &ActivityBuilder::with_location(20).build()), None);
+        assert_eq!(evaluate(0, &placeless_break()), None);
+    }
+}
@@ -0,0 +1,226 @@
+use super::*;
+use crate::helpers::construction::features::create_simple_demand;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+const PARKING_LOCATION: Location = 5;
+
+custom_dimension!(TestParkingType typeof String);
+
+fn create_feature() -> Feature {
+    TrailerFeatureBuilder::<SingleDimLoad>::new("trailer")
+        .set_violation_code(VIOLATION_CODE)
+        .set_is_decouple_single(|single| single.dimens.get_test_parking_type().is_some_and(|t| t == "decouple"))
+        .set_is_recouple_single(|single| single.dimens.get_test_parking_type().is_some_and(|t| t == "recouple"))
+        .set_belongs_to_actor(|actor, job| {
+            job.as_multi().is_some_and(|_| actor.vehicle.dimens.get_vehicle_truck_capacity::<SingleDimLoad>().is_some())
+        })
+        .build()
+        .unwrap()
+}
+
+fn create_parking_job(id: &str) -> Job {
+    let create_single = |parking_type: &str| {
+        let mut builder = TestSingleBuilder::default();
+        builder.location(Some(PARKING_LOCATION)).dimens_mut().set_test_parking_type(parking_type.to_string());
+        builder.build_shared()
+    };
+
+    Job::Multi(test_multi_with_id(id, vec![create_single("decouple"), create_single("recouple")]))
+}
+
+fn create_job(id: &str, demand: i32, truck_only: bool) -> Job {
+    let mut builder = TestSingleBuilder::default();
+    builder.id(id).location(Some(1)).demand(create_simple_demand(-demand));
+    if truck_only {
+        builder.dimens_mut().set_job_truck_only(true);
+    }
+
+    builder.build_as_job_ref()
+}
+
+fn create_route_ctx(jobs: &[Job], truck_capacity: Option<i32>, feature: &Feature) -> RouteContext {
+    let mut vehicle_builder = TestVehicleBuilder::default();
+    vehicle_builder.id("v1").capacity(10);
+    if let Some(truck_capacity) = truck_capacity {
+        vehicle_builder.dimens_mut().set_vehicle_truck_capacity(SingleDimLoad::new(truck_capacity));
+    }
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle_builder.build()).build();
+
+    let activities = jobs.iter().map(|job| ActivityBuilder::with_location(1).job(job.as_single().cloned()).build());
+
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+        .build();
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+/// Creates a route with given jobs and wraps jobs in `[start, end]` range by a parking job.
+fn create_route_ctx_with_parking(
+    jobs: &[Job],
+    parking: Option<(&Job, usize, usize)>,
+    truck_capacity: Option<i32>,
+    feature: &Feature,
+) -> RouteContext {
+    let mut route_ctx = create_route_ctx(jobs, truck_capacity, feature);
+
+    if let Some((parking_job, start, end)) = parking {
+        let multi = parking_job.to_multi();
+        let create_activity =
+            |idx: usize| ActivityBuilder::with_location(PARKING_LOCATION).job(Some(multi.jobs[idx].clone())).build();
+
+        // NOTE +1 for departure, +1 for decouple activity
+        route_ctx.route_mut().tour.insert_at(create_activity(1), end + 2);
+        route_ctx.route_mut().tour.insert_at(create_activity(0), start + 1);
+        feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    }
+
+    route_ctx
+}
+
+fn evaluate_activity(
+    feature: &Feature,
+    route_ctx: &RouteContext,
+    index: usize,
+    target: &Activity,
+) -> Option<ViolationCode> {
+    let route = route_ctx.route();
+    feature
+        .constraint
+        .as_ref()
+        .unwrap()
+        .evaluate(&MoveContext::activity(
+            route_ctx,
+            &ActivityContext { index, prev: route.tour.get(index).unwrap(), target, next: route.tour.get(index + 1) },
+        ))
+        .map(|violation| violation.code)
+}
+
+parameterized_test! {can_evaluate_job_activity, (truck_capacity, index, demand, truck_only, expected), {
+    can_evaluate_job_activity_impl(truck_capacity, index, demand, truck_only, expected);
+}}
+
+can_evaluate_job_activity! {
+    case_01_truck_only_before_decouple: (Some(2), 0, 1, true, Some(VIOLATION_CODE)),
+    case_02_truck_only_after_decouple: (Some(2), 1, 1, true, None),
+    case_03_truck_only_inside_detached: (Some(2), 2, 1, true, None),
+    case_04_truck_only_after_recouple: (Some(2), 3, 1, true, Some(VIOLATION_CODE)),
+    case_05_truck_capacity_violation: (Some(2), 2, 2, true, Some(VIOLATION_CODE)),
+    case_06_regular_before_decouple: (Some(2), 0, 2, false, None),
+    case_07_regular_inside_detached: (Some(2), 2, 2, false, Some(VIOLATION_CODE)),
+    case_08_no_trailer: (None, 0, 1, true, None),
+}
+
+fn can_evaluate_job_activity_impl(
+    truck_capacity: Option<i32>,
+    index: usize,
+    demand: i32,
+    truck_only: bool,
+    expected: Option<ViolationCode>,
+) {
+    let feature = create_feature();
+    let parking = create_parking_job("parking");
+    let jobs = vec![create_job("job1", 1, true), create_job("job2", 1, false)];
+    let parking = truck_capacity.map(|_| (&parking, 0, 0));
+    let route_ctx = create_route_ctx_with_parking(&jobs, parking, truck_capacity, &feature);
+    let target = create_job("job3", demand, truck_only);
+    let target = ActivityBuilder::with_location(1).job(target.as_single().cloned()).build();
+
+    let result = evaluate_activity(&feature, &route_ctx, index, &target);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_parking_activity, (index, is_decouple, expected), {
+    can_evaluate_parking_activity_impl(index, is_decouple, expected);
+}}
+
+can_evaluate_parking_activity! {
+    case_01_decouple_before_other_parking: (0, true, None),
+    case_02_decouple_inside_other_parking: (1, true, Some(VIOLATION_CODE)),
+    case_03_decouple_after_other_parking: (3, true, None),
+    case_04_recouple_without_decouple: (4, false, Some(VIOLATION_CODE)),
+}
+
+fn can_evaluate_parking_activity_impl(index: usize, is_decouple: bool, expected: Option<ViolationCode>) {
+    let feature = create_feature();
+    let jobs = vec![create_job("job1", 1, true), create_job("job2", 1, false)];
+    let route_ctx = create_route_ctx_with_parking(&jobs, Some((&create_parking_job("p1"), 0, 0)), Some(2), &feature);
+    let other = create_parking_job("p2");
+    let single = other.to_multi().jobs[if is_decouple { 0 } else { 1 }].clone();
+    let target = ActivityBuilder::with_location(PARKING_LOCATION).job(Some(single)).build();
+
+    let result = evaluate_activity(&feature, &route_ctx, index, &target);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_evaluate_recouple_with_truck_capacity() {
+    let feature = create_feature();
+    let parking = create_parking_job("parking");
+    let jobs = vec![create_job("job1", 1, false), create_job("job2", 2, false)];
+    let mut route_ctx = create_route_ctx(&jobs, Some(2), &feature);
+    let decouple =
+        ActivityBuilder::with_location(PARKING_LOCATION).job(Some(parking.to_multi().jobs[0].clone())).build();
+    route_ctx.route_mut().tour.insert_at(decouple, 1);
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+    let recouple =
+        ActivityBuilder::with_location(PARKING_LOCATION).job(Some(parking.to_multi().jobs[1].clone())).build();
+
+    assert_eq!(evaluate_activity(&feature, &route_ctx, 1, &recouple), None);
+    assert_eq!(evaluate_activity(&feature, &route_ctx, 2, &recouple), None);
+    assert_eq!(evaluate_activity(&feature, &route_ctx, 3, &recouple), Some(VIOLATION_CODE));
+}
+
+#[test]
+fn can_promote_parking_on_truck_only_job_failure() {
+    let feature = create_feature();
+    let state = feature.state.as_ref().unwrap();
+    let parking = create_parking_job("parking");
+    let route_ctx = create_route_ctx(&[], Some(2), &feature);
+    let mut solution_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build().solution;
+    solution_ctx.ignored.push(parking.clone());
+
+    assert!(!state.notify_failure(&mut solution_ctx, &[0], &[create_job("job1", 1, false)]));
+    assert!(state.notify_failure(&mut solution_ctx, &[0], &[create_job("job1", 1, true)]));
+
+    assert!(solution_ctx.ignored.is_empty());
+    assert_eq!(solution_ctx.required.len(), 1);
+    assert!(solution_ctx.required.contains(&parking));
+    assert!(solution_ctx.locked.contains(&parking));
+}
+
+#[test]
+fn can_remove_empty_parking_on_solution_state() {
+    let feature = create_feature();
+    let state = feature.state.as_ref().unwrap();
+    let used = create_parking_job("used");
+    let empty = create_parking_job("empty");
+    let jobs = vec![create_job("job1", 1, true), create_job("job2", 1, false)];
+    let mut route_ctx = create_route_ctx_with_parking(&jobs, Some((&used, 0, 0)), Some(2), &feature);
+    let multi = empty.to_multi();
+    let last_idx = route_ctx.route().tour.total() - 1;
+    route_ctx
+        .route_mut()
+        .tour
+        .insert_at(ActivityBuilder::with_location(PARKING_LOCATION).job(Some(multi.jobs[1].clone())).build(), last_idx);
+    route_ctx
+        .route_mut()
+        .tour
+        .insert_at(ActivityBuilder::with_location(PARKING_LOCATION).job(Some(multi.jobs[0].clone())).build(), last_idx);
+    let mut solution_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build().solution;
+
+    state.accept_solution_state(&mut solution_ctx);
+
+    let tour = &solution_ctx.routes[0].route().tour;
+    assert!(tour.contains(&used));
+    assert!(!tour.contains(&empty));
+    assert_eq!(tour.job_activity_count(), 4);
+    assert_eq!(solution_ctx.ignored.len(), 1);
+    assert!(solution_ctx.ignored.contains(&empty));
+}
@@ -0,0 +1,278 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::models::common::TimeWindow;
+use crate::models::problem::JobIdDimension;
+use crate::models::solution::Place;
+use crate::prelude::Fleet;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+const PICKUP_LOCATION: Location = 10;
+const DELIVERY_LOCATION: Location = 30;
+const HANDOVER_PLACES: [(Location, Duration); 2] = [(20, 10.), (25, 10.)];
+
+struct TransferJobs {
+    direct: Arc<Multi>,
+    first: Arc<Multi>,
+    second: Arc<Multi>,
+}
+
+fn create_single(location: Location) -> Arc<Single> {
+    TestSingleBuilder::default().location(Some(location)).build_shared()
+}
+
+fn create_handover() -> Arc<Single> {
+    TestSingleBuilder::default()
+        .places(
+            HANDOVER_PLACES.iter().map(|&(location, duration)| (Some(location), duration, vec![(0., 1000.)])).collect(),
+        )
+        .build_shared()
+}
+
+fn create_multi(singles: Vec<Arc<Single>>, leg: TransferLeg) -> Arc<Multi> {
+    let mut dimens = Dimensions::default();
+    dimens.set_job_id("job1".to_string()).set_job_transfer(JobTransfer { id: 0, leg });
+
+    Multi::new_shared(singles, dimens)
+}
+
+fn create_transfer_jobs() -> TransferJobs {
+    TransferJobs {
+        direct: create_multi(
+            vec![create_single(PICKUP_LOCATION), create_single(DELIVERY_LOCATION)],
+            TransferLeg::Direct,
+        ),
+        first: create_multi(vec![create_single(PICKUP_LOCATION), create_handover()], TransferLeg::First),
+        second: create_multi(vec![create_handover(), create_single(DELIVERY_LOCATION)], TransferLeg::Second),
+    }
+}
+
+fn create_activity(single: &Arc<Single>, place_idx: usize) -> Activity {
+    let place = &single.places[place_idx];
+    let location = place.location.unwrap();
+
+    ActivityBuilder::default()
+        .place(Place { idx: place_idx, location, duration: place.duration, time: TimeWindow::new(0., 1000.) })
+        .job(Some(single.clone()))
+        .build()
+}
+
+fn get_activities(jobs: &TransferJobs, leg: TransferLeg, place_idx: usize) -> Vec<Activity> {
+    let multi = match leg {
+        TransferLeg::Direct => &jobs.direct,
+        TransferLeg::First => &jobs.first,
+        TransferLeg::Second => &jobs.second,
+    };
+
+    multi
+        .jobs
+        .iter()
+        .map(|single| create_activity(single, if single.places.len() > 1 { place_idx } else { 0 }))
+        .collect()
+}
+
+fn get_job(jobs: &TransferJobs, leg: TransferLeg) -> Job {
+    match leg {
+        TransferLeg::Direct => Job::Multi(jobs.direct.clone()),
+        TransferLeg::First => Job::Multi(jobs.first.clone()),
+        TransferLeg::Second => Job::Multi(jobs.second.clone()),
+    }
+}
+
+fn create_solution_ctx(jobs: &TransferJobs, assigned: Vec<(usize, TransferLeg)>, is_finished: bool) -> SolutionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .add_vehicle(test_vehicle_with_id("v2"))
+        .build();
+
+    let routes = (0..2)
+        .map(|route_idx| {
+            let activities = assigned
+                .iter()
+                .filter(|(idx, _)| *idx == route_idx)
+                .flat_map(|&(_, leg)| get_activities(jobs, leg, 0))
+                .collect::<Vec<_>>();
+
+            create_route_ctx(&fleet, format!("v{}", route_idx + 1).as_str(), activities)
+        })
+        .collect();
+
+    let not_assigned = [TransferLeg::Direct, TransferLeg::First, TransferLeg::Second]
+        .into_iter()
+        .filter(|leg| assigned.iter().all(|(_, assigned)| assigned != leg))
+        .map(|leg| get_job(jobs, leg));
+
+    let mut solution_ctx = TestInsertionContextBuilder::default().with_routes(routes).build().solution;
+    if is_finished {
+        solution_ctx.ignored.extend(not_assigned);
+    } else {
+        solution_ctx.required.extend(not_assigned);
+    }
+
+    solution_ctx
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle_id: &str, activities: Vec<Activity>) -> RouteContext {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(fleet, vehicle_id).add_activities(activities).build())
+        .build();
+    update_route_schedule(&mut route_ctx, &TestActivityCost::default(), &TestTransportCost::default());
+
+    route_ctx
+}
+
+fn create_feature() -> Feature {
+    create_transfer_feature("transfer", TestTransportCost::new_shared(), TestActivityCost::new_shared(), VIOLATION_CODE)
+        .expect("cannot create feature")
+}
+
+#[test]
+fn can_detect_handover_transfer() {
+    let jobs = create_transfer_jobs();
+    let get_transfers =
+        |multi: &Arc<Multi>| multi.jobs.iter().map(|single| get_handover_transfer(single)).collect::<Vec<_>>();
+
+    assert_eq!(get_transfers(&jobs.direct), vec![None, None]);
+    assert_eq!(get_transfers(&jobs.first), vec![None, Some(JobTransfer { id: 0, leg: TransferLeg::First })]);
+    assert_eq!(get_transfers(&jobs.second), vec![Some(JobTransfer { id: 0, leg: TransferLeg::Second }), None]);
+    assert_eq!(get_handover_transfer(&create_single(PICKUP_LOCATION)), None);
+}
+
+parameterized_test! {can_constraint_route, (assigned, candidate, route_idx, expected), {
+    can_constraint_route_impl(assigned, candidate, route_idx, expected);
+}}
+
+can_constraint_route! {
+    case_01_nothing_assigned_direct: (vec![], TransferLeg::Direct, 0, None),
+    case_02_nothing_assigned_first: (vec![], TransferLeg::First, 0, None),
+    case_03_direct_assigned_first: (vec![(0, TransferLeg::Direct)], TransferLeg::First, 1, Some(VIOLATION_CODE)),
+    case_04_first_assigned_direct: (vec![(0, TransferLeg::First)], TransferLeg::Direct, 1, Some(VIOLATION_CODE)),
+    case_05_second_assigned_direct: (vec![(1, TransferLeg::Second)], TransferLeg::Direct, 0, Some(VIOLATION_CODE)),
+    case_06_first_assigned_second_same: (vec![(0, TransferLeg::First)], TransferLeg::Second, 0, Some(VIOLATION_CODE)),
+    case_07_first_assigned_second_other: (vec![(0, TransferLeg::First)], TransferLeg::Second, 1, None),
+    case_08_second_assigned_first_other: (vec![(1, TransferLeg::Second)], TransferLeg::First, 0, None),
+}
+
+fn can_constraint_route_impl(
+    assigned: Vec<(usize, TransferLeg)>,
+    candidate: TransferLeg,
+    route_idx: usize,
+    expected: Option<ViolationCode>,
+) {
+    let jobs = create_transfer_jobs();
+    let mut solution_ctx = create_solution_ctx(&jobs, assigned, false);
+    let feature = create_feature();
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::route(
+        &solution_ctx,
+        &solution_ctx.routes[route_idx],
+        &get_job(&jobs, candidate),
+    ));
+
+    assert_eq!(result.map(|result| result.code), expected);
+}
+
+parameterized_test! {can_constraint_handover_place, (place_idx, expected), {
+    can_constraint_handover_place_impl(place_idx, expected);
+}}
+
+can_constraint_handover_place! {
+    case_01_same_place: (0, None),
+    case_02_different_place: (1, Some(VIOLATION_CODE)),
+}
+
+fn can_constraint_handover_place_impl(place_idx: usize, expected: Option<ViolationCode>) {
+    let jobs = create_transfer_jobs();
+    let mut solution_ctx = create_solution_ctx(&jobs, vec![(0, TransferLeg::First)], false);
+    let feature = create_feature();
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+    let route_ctx = &solution_ctx.routes[1];
+    let prev = route_ctx.route().tour.start().unwrap();
+    let target = create_activity(&jobs.second.jobs[0], place_idx);
+    let activity_ctx = ActivityContext { index: 0, prev, target: &target, next: route_ctx.route().tour.end() };
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(route_ctx, &activity_ctx));
+
+    assert_eq!(result.map(|result| result.code), expected);
+}
+
+#[test]
+fn can_synchronize_handover_schedules() {
+    let jobs = create_transfer_jobs();
+    let mut solution_ctx = create_solution_ctx(&jobs, vec![(0, TransferLeg::First), (1, TransferLeg::Second)], false);
+    let feature = create_feature();
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    let get_schedules = |route_ctx: &RouteContext| {
+        route_ctx
+            .route()
+            .tour
+            .all_activities()
+            .map(|activity| (activity.schedule.arrival, activity.schedule.departure))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(get_schedules(&solution_ctx.routes[0]), vec![(0., 0.), (10., 10.), (20., 30.), (50., 50.)]);
+    assert_eq!(get_schedules(&solution_ctx.routes[1]), vec![(0., 0.), (20., 40.), (50., 50.), (80., 80.)]);
+    // NOTE: second handover cannot start later than 950 as the vehicle should come back before 1000
+    assert_eq!(solution_ctx.routes[0].route().tour.get(2).unwrap().place.time.end, 940.);
+}
+
+parameterized_test! {can_finalize_transfers, (assigned, expected_jobs, expected_unassigned, expected_ignored), {
+    can_finalize_transfers_impl(assigned, expected_jobs, expected_unassigned, expected_ignored);
+}}
+
+can_finalize_transfers! {
+    case_01_both_legs: (vec![(0, TransferLeg::First), (1, TransferLeg::Second)], (1, 1), false, 1),
+    case_02_only_first_leg: (vec![(0, TransferLeg::First)], (0, 0), true, 2),
+    case_03_only_second_leg: (vec![(1, TransferLeg::Second)], (0, 0), true, 2),
+    case_04_direct: (vec![(0, TransferLeg::Direct)], (1, 0), false, 2),
+    case_05_nothing: (vec![], (0, 0), true, 2),
+}
+
+fn can_finalize_transfers_impl(
+    assigned: Vec<(usize, TransferLeg)>,
+    expected_jobs: (usize, usize),
+    expected_unassigned: bool,
+    expected_ignored: usize,
+) {
+    let jobs = create_transfer_jobs();
+    let mut solution_ctx = create_solution_ctx(&jobs, assigned, true);
+    let feature = create_feature();
+
+    // NOTE: finalization should not change already finalized solution
+    for _ in 0..2 {
+        feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+        let job_counts =
+            (solution_ctx.routes[0].route().tour.job_count(), solution_ctx.routes[1].route().tour.job_count());
+        assert_eq!(job_counts, expected_jobs);
+        assert_eq!(solution_ctx.unassigned.contains_key(&get_job(&jobs, TransferLeg::Direct)), expected_unassigned);
+        assert_eq!(solution_ctx.ignored.len(), expected_ignored);
+    }
+}
+
+parameterized_test! {can_keep_partial_transfer_with_required_leg, (assigned, required_leg), {
+    can_keep_partial_transfer_with_required_leg_impl(assigned, required_leg);
+}}
+
+can_keep_partial_transfer_with_required_leg! {
+    case_01_only_first_leg: ((0, TransferLeg::First), TransferLeg::Second),
+    case_02_only_second_leg: ((1, TransferLeg::Second), TransferLeg::First),
+}
+
+fn can_keep_partial_transfer_with_required_leg_impl(assigned: (usize, TransferLeg), required_leg: TransferLeg) {
+    let jobs = create_transfer_jobs();
+    let mut solution_ctx = create_solution_ctx(&jobs, vec![assigned], false);
+    let feature = create_feature();
+
+    feature.state.as_ref().unwrap().accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[assigned.0].route().tour.job_count(), 1);
+    assert_eq!(solution_ctx.required, vec![get_job(&jobs, required_leg)]);
+    assert_eq!(solution_ctx.ignored, vec![get_job(&jobs, TransferLeg::Direct)]);
+    assert!(solution_ctx.unassigned.is_empty());
+}
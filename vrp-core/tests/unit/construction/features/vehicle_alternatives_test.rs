@@ -0,0 +1,61 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{test_driver, FleetBuilder, TestSingleBuilder, TestVehicleBuilder};
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_test_fleet() -> Fleet {
+    let create_vehicle = |id: &str, group: Option<usize>| {
+        let mut builder = TestVehicleBuilder::default();
+        builder.id(id);
+        if let Some(group) = group {
+            builder.dimens_mut().set_vehicle_alternative_group(group);
+        }
+        builder.build()
+    };
+
+    FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![create_vehicle("v1", Some(0)), create_vehicle("v2", Some(0)), create_vehicle("v3", None)])
+        .build()
+}
+
+fn create_route_ctx(fleet: &Fleet, vehicle_id: &str, has_jobs: bool) -> RouteContext {
+    let mut route_builder = RouteBuilder::default();
+    route_builder.with_vehicle(fleet, vehicle_id);
+
+    if has_jobs {
+        route_builder.add_activity(
+            ActivityBuilder::with_location(1).job(Some(TestSingleBuilder::default().build_shared())).build(),
+        );
+    }
+
+    RouteContextBuilder::default().with_route(route_builder.build()).build()
+}
+
+parameterized_test! {can_evaluate_alternative_vehicles, (used, candidate, expected), {
+    can_evaluate_alternative_vehicles_impl(used, candidate, expected);
+}}
+
+can_evaluate_alternative_vehicles! {
+    case01_alternative_is_used: (vec![("v1", true)], "v2", Some(VIOLATION_CODE)),
+    case02_alternative_is_empty: (vec![("v1", false)], "v2", None),
+    case03_no_alternatives_used: (vec![("v3", true)], "v2", None),
+    case04_no_group: (vec![("v1", true), ("v2", true)], "v3", None),
+    case05_same_vehicle: (vec![("v1", true)], "v1", None),
+}
+
+fn can_evaluate_alternative_vehicles_impl(used: Vec<(&str, bool)>, candidate: &str, expected: Option<ViolationCode>) {
+    let fleet = create_test_fleet();
+    let routes =
+        used.into_iter().map(|(vehicle_id, has_jobs)| create_route_ctx(&fleet, vehicle_id, has_jobs)).collect();
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(routes).build();
+    let route_ctx = create_route_ctx(&fleet, candidate, false);
+    let job = TestSingleBuilder::default().build_as_job_ref();
+    let constraint = create_vehicle_alternatives_feature("alternatives", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let result = constraint.evaluate(&MoveContext::route(&insertion_ctx.solution, &route_ctx, &job));
+
+    assert_eq!(result, expected.map(|code| ConstraintViolation { code, stopped: true }));
+}
@@ -72,7 +72,7 @@ mod multi {
     use std::cmp::Ordering;
 
     fn from_vec(load: Vec<i32>) -> MultiDimLoad {
-        MultiDimLoad::new(load)
+        MultiDimLoad::new(load.into_iter().map(f64::from).collect())
     }
 
     #[test]
@@ -121,4 +121,31 @@ mod multi {
         assert!(!from_vec(vec![1, 0]).can_fit(&from_vec(vec![0, 1])));
         assert!(!from_vec(vec![3, 0, 2]).can_fit(&from_vec(vec![1, 1, 4])));
     }
+
+    #[test]
+    fn can_use_more_than_inline_amount_of_dimens() {
+        let capacity = from_vec((1..=12).collect());
+        let demand = from_vec(vec![1; 12]);
+
+        assert_eq!(capacity.size(), 12);
+        assert_eq!(capacity.clone() + demand.clone(), from_vec((2..=13).collect()));
+        assert_eq!(capacity.clone() - demand.clone(), from_vec((0..=11).collect()));
+        assert!(capacity.can_fit(&demand));
+        assert!(!demand.can_fit(&capacity));
+        assert!(!from_vec(vec![1; 8]).can_fit(&demand));
+        assert_eq!(demand.partial_cmp(&capacity), None);
+        assert_eq!(from_vec(vec![0; 8]).partial_cmp(&demand), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn can_use_fractional_values() {
+        let capacity = MultiDimLoad::new(vec![0.3, 10.5]);
+        let demand = MultiDimLoad::new(vec![0.1, 5.25]) + MultiDimLoad::new(vec![0.2, 5.25]);
+
+        assert_eq!(demand, capacity);
+        assert!(capacity.can_fit(&demand));
+        assert!(!capacity.can_fit(&(demand.clone() + MultiDimLoad::new(vec![0.000001]))));
+        assert_eq!(demand.as_vec(), vec![0.3, 10.5]);
+        assert_eq!((capacity * 0.5).as_vec(), vec![0.15, 5.25]);
+    }
 }
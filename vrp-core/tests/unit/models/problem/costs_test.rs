@@ -114,6 +114,264 @@ fn can_interpolate_durations() {
     assert_eq!(costs.distance_approx(&p1, 0, 1), 5.);
 }
 
+fn create_matrix_data_from_values(index: usize, durations: Vec<f64>, distances: Vec<f64>) -> MatrixData {
+    MatrixData { index, timestamp: None, durations, distances }
+}
+
+parameterized_test! {can_use_matrix_storage, (storage, value, expected), {
+    can_use_matrix_storage_impl(storage, value, expected);
+}}
+
+can_use_matrix_storage! {
+    case01_full: (MatrixStorage::Full, 10.25, 10.25),
+    case02_float32: (MatrixStorage::Float32, 10.25, 10.25),
+    case03_float32_precision: (MatrixStorage::Float32, 123456789.1, 123456792.),
+    case04_integer: (MatrixStorage::Integer, 10.25, 10.),
+    case05_integer_negative: (MatrixStorage::Integer, -5., -1.),
+}
+
+fn can_use_matrix_storage_impl(storage: MatrixStorage, value: f64, expected: f64) {
+    let profile = Profile::default();
+    let costs = create_matrix_transport_cost_with_storage(
+        vec![create_matrix_data_from_values(0, vec![0., value, value, 0.], vec![0., value, value, 0.])],
+        NoFallback,
+        storage,
+    )
+    .unwrap();
+
+    assert_eq!(costs.duration_approx(&profile, 0, 1), expected);
+    assert_eq!(costs.distance_approx(&profile, 1, 0), expected);
+    assert_eq!(costs.distance_approx(&profile, 1, 1), 0.);
+}
+
+#[test]
+fn can_detect_too_big_value_for_integer_storage() {
+    let result = create_matrix_transport_cost_with_storage(
+        vec![create_matrix_data_from_values(0, vec![0., 1E10, 1., 0.], vec![0.; 4])],
+        NoFallback,
+        MatrixStorage::Integer,
+    );
+
+    assert_eq!(result.err(), Some("matrix value is too big for integer storage: 10000000000".into()));
+}
+
+parameterized_test! {can_share_matrices_between_profiles, (storage, durations, distances, expected), {
+    can_share_matrices_between_profiles_impl(storage, durations, distances, expected);
+}}
+
+can_share_matrices_between_profiles! {
+    case01_full_same: (MatrixStorage::Full, vec![0., 10., 20., 0.], vec![0., 100., 200., 0.], (10., 100.)),
+    case02_full_scaled: (MatrixStorage::Full, vec![0., 5., 10., 0.], vec![0., 100., 200., 0.], (5., 100.)),
+    case03_full_different: (MatrixStorage::Full, vec![0., 5., 11., 0.], vec![0., 100., 201., 0.], (5., 100.)),
+    case04_integer_scaled: (MatrixStorage::Integer, vec![0., 15., 30., -1.], vec![0., 100., 200., 0.], (15., 100.)),
+    case05_integer_rounded: (MatrixStorage::Integer, vec![0., 3.3, 6.6, 0.], vec![0., 100., 200., 0.], (3., 100.)),
+    case06_float32_scaled: (MatrixStorage::Float32, vec![0., 2.5, 5., 0.], vec![0., 100., 200., 0.], (2.5, 100.)),
+}
+
+fn can_share_matrices_between_profiles_impl(
+    storage: MatrixStorage,
+    durations: Vec<f64>,
+    distances: Vec<f64>,
+    expected: (Duration, Distance),
+) {
+    let (p0, p1) = (Profile::new(0, None), Profile::new(1, None));
+    let costs = create_matrix_transport_cost_with_storage(
+        vec![
+            create_matrix_data_from_values(0, vec![0., 10., 20., -1.], vec![0., 100., 200., 0.]),
+            create_matrix_data_from_values(1, durations.clone(), distances.clone()),
+        ],
+        NoFallback,
+        storage,
+    )
+    .unwrap();
+
+    assert_eq!(costs.duration_approx(&p0, 0, 1), 10.);
+    assert_eq!(costs.distance_approx(&p0, 0, 1), 100.);
+    assert_eq!((costs.duration_approx(&p1, 0, 1), costs.distance_approx(&p1, 0, 1)), expected);
+    (0..4).for_each(|idx| {
+        let (from, to) = (idx / 2, idx % 2);
+        let duration = costs.duration_approx(&p1, from, to);
+        let expected = if storage == MatrixStorage::Integer { durations[idx].round().max(-1.) } else { durations[idx] };
+        assert_eq!(duration, expected);
+        assert_eq!(costs.distance_approx(&p1, from, to), distances[idx]);
+    });
+}
+
+struct TestMatrixValues(Vec<f64>);
+
+impl MatrixValues for TestMatrixValues {
+    fn size(&self) -> usize {
+        self.0.len()
+    }
+
+    fn get(&self, index: usize) -> Option<f64> {
+        self.0.get(index).copied()
+    }
+}
+
+#[test]
+fn can_use_custom_matrix_values() {
+    let profile = Profile::new(0, Some(2.));
+    let create_values = |values: Vec<f64>| -> Arc<dyn MatrixValues> { Arc::new(TestMatrixValues(values)) };
+
+    let costs = create_matrix_transport_cost_with_values(
+        vec![(create_values(vec![0., 10., 20., 0.]), create_values(vec![0., 100., 200., 0.]))],
+        NoFallback,
+    )
+    .unwrap();
+
+    assert_eq!(costs.duration_approx(&profile, 1, 0), 40.);
+    assert_eq!(costs.distance_approx(&profile, 1, 0), 200.);
+}
+
+#[test]
+fn can_detect_custom_matrix_values_size_mismatch() {
+    let create_values = |values: Vec<f64>| -> Arc<dyn MatrixValues> { Arc::new(TestMatrixValues(values)) };
+
+    let result = create_matrix_transport_cost_with_values(
+        vec![(create_values(vec![0., 10., 20., 0.]), create_values(vec![0., 100., 200.]))],
+        NoFallback,
+    );
+
+    assert_eq!(result.err(), Some("matrix values have different length".into()));
+}
+
+parameterized_test! {can_use_matrix_sources, storage, {
+    can_use_matrix_sources_impl(storage);
+}}
+
+can_use_matrix_sources! {
+    case01_full: MatrixStorage::Full,
+    case02_float32: MatrixStorage::Float32,
+    case03_integer: MatrixStorage::Integer,
+}
+
+fn can_use_matrix_sources_impl(storage: MatrixStorage) {
+    let (p0, p1, p2) = (Profile::new(0, None), Profile::new(1, None), Profile::new(2, None));
+    let durations = TestMatrixValues(vec![0., 10., -1., 0.]);
+    let distances = TestMatrixValues(vec![0., 100., 200., 0.]);
+    let slow_durations = TestMatrixValues(vec![0., 20., -1., 0.]);
+
+    let costs = create_matrix_transport_cost_with_sources(
+        vec![
+            MatrixSource::new(2, None, &slow_durations, &distances),
+            MatrixSource::new(0, None, &durations, &distances),
+            MatrixSource::new(1, None, &durations, &distances),
+        ],
+        NoFallback,
+        storage,
+    )
+    .unwrap();
+
+    for (profile, expected) in [(p0, 10.), (p1, 10.), (p2, 20.)] {
+        assert_eq!(costs.duration_approx(&profile, 0, 1), expected);
+        assert_eq!(costs.duration_approx(&profile, 1, 0), -1.);
+        assert_eq!(costs.distance_approx(&profile, 1, 0), 200.);
+    }
+}
+
+#[test]
+fn can_interpolate_durations_of_shared_matrix_sources() {
+    let (p0, p1) = (Profile::new(0, None), Profile::new(1, Some(2.)));
+    let (early, late, distances) = (
+        TestMatrixValues(vec![0., 100., 100., 0.]),
+        TestMatrixValues(vec![0., 200., 200., 0.]),
+        TestMatrixValues(vec![0., 1., 1., 0.]),
+    );
+
+    let costs = create_matrix_transport_cost_with_sources(
+        vec![
+            MatrixSource::new(0, Some(0.), &early, &distances),
+            MatrixSource::new(0, Some(10.), &late, &distances),
+            MatrixSource::new(1, Some(0.), &early, &distances),
+            MatrixSource::new(1, Some(10.), &late, &distances),
+        ],
+        NoFallback,
+        MatrixStorage::default(),
+    )
+    .unwrap();
+
+    assert_eq!(costs.duration_approx(&p0, 0, 1), 100.);
+    assert_eq!(costs.duration_approx(&p1, 0, 1), 200.);
+    assert_eq!(costs.distance_approx(&p1, 1, 0), 1.);
+}
+
+#[test]
+fn can_detect_matrix_sources_size_mismatch() {
+    let (durations, distances) = (TestMatrixValues(vec![0., 10., 20., 0.]), TestMatrixValues(vec![0., 100., 200.]));
+
+    let result = create_matrix_transport_cost_with_sources(
+        vec![MatrixSource::new(0, None, &durations, &distances)],
+        NoFallback,
+        MatrixStorage::default(),
+    );
+
+    assert_eq!(result.err(), Some("distance and duration collections have different length".into()));
+}
+
+fn create_matrix_file(name: &str, bytes: &[u8]) -> (std::path::PathBuf, File) {
+    let path = std::env::temp_dir().join(format!("vrp_core_{name}_{}.bin", std::process::id()));
+    std::fs::write(&path, bytes).expect("cannot write matrix file");
+
+    let file = File::open(&path).expect("cannot open matrix file");
+
+    (path, file)
+}
+
+parameterized_test! {can_read_file_matrix_values, (width, size, max_pages), {
+    can_read_file_matrix_values_impl(width, size, max_pages);
+}}
+
+can_read_file_matrix_values! {
+    case01_narrow_single_page: (4, 16, 1024),
+    case02_narrow_many_pages: (4, 10_000, 1024),
+    case03_wide_many_pages: (8, 10_000, 1024),
+    case04_narrow_evicted_pages: (4, 10_000, 1),
+    case05_wide_evicted_pages: (8, 10_000, 2),
+}
+
+fn can_read_file_matrix_values_impl(width: usize, size: usize, max_pages: usize) {
+    let offset = 3;
+    let values = (0..size as i64).map(|value| if value % 7 == 0 { -value } else { value }).collect::<Vec<_>>();
+    let bytes = vec![0_u8; offset]
+        .into_iter()
+        .chain(values.iter().flat_map(|&value| match width {
+            4 => (value as i32).to_le_bytes().to_vec(),
+            _ => value.to_le_bytes().to_vec(),
+        }))
+        .collect::<Vec<_>>();
+    let (path, file) = create_matrix_file(format!("file_values_{width}_{size}_{max_pages}").as_str(), bytes.as_slice());
+
+    let file_values = FileMatrixValues::new_with_limit(file, offset as u64, size, width, max_pages).unwrap();
+
+    assert_eq!(file_values.size(), size);
+    assert_eq!(file_values.get(size), None);
+    for index in (0..size).rev().step_by(3).chain(0..size) {
+        let expected = if values[index] < 0 { -1. } else { values[index] as f64 };
+        assert_eq!(file_values.get(index), Some(expected));
+    }
+    assert!(file_values.loader.lock().unwrap().loaded.len() <= max_pages);
+    std::fs::remove_file(path).unwrap();
+}
+
+parameterized_test! {can_detect_invalid_file_matrix_values, (width, size, expected), {
+    can_detect_invalid_file_matrix_values_impl(width, size, expected);
+}}
+
+can_detect_invalid_file_matrix_values! {
+    case01_wrong_width: (2, 4, "unsupported width of matrix values: 2"),
+    case02_small_file: (4, 5, "matrix file is too small for given amount of values"),
+}
+
+fn can_detect_invalid_file_matrix_values_impl(width: usize, size: usize, expected: &str) {
+    let (path, file) = create_matrix_file(format!("invalid_values_{width}_{size}").as_str(), &[0; 16]);
+
+    let result = FileMatrixValues::new(file, 0, size, width);
+
+    assert_eq!(result.err(), Some(expected.into()));
+    std::fs::remove_file(path).unwrap();
+}
+
 mod objective {
     use super::*;
     use crate::construction::heuristics::{InsertionContext, MoveContext};
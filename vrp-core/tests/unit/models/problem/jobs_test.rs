@@ -170,6 +170,63 @@ fn returns_proper_job_neighbours_impl(index: usize, expected: Vec<String>) {
     assert_eq!(result, expected);
 }
 
+struct SparseNeighboursCost {
+    neighbours: Vec<Vec<Location>>,
+}
+
+impl TransportCost for SparseNeighboursCost {
+    fn duration_approx(&self, _: &Profile, _: Location, _: Location) -> Duration {
+        0.
+    }
+
+    fn distance_approx(&self, _: &Profile, from: Location, to: Location) -> Distance {
+        fake_routing(from, to)
+    }
+
+    fn duration(&self, _: &Route, _: Location, _: Location, _: TravelTime) -> Duration {
+        0.
+    }
+
+    fn distance(&self, _: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
+        fake_routing(from, to)
+    }
+
+    fn neighbours(&self, _: &Profile, location: Location) -> Option<&[Location]> {
+        self.neighbours.get(location).map(|neighbours| neighbours.as_slice())
+    }
+}
+
+parameterized_test! {can_use_sparse_job_neighbours, (index, expected), {
+    can_use_sparse_job_neighbours_impl(index, expected.iter().map(|s| s.to_string()).collect());
+}}
+
+can_use_sparse_job_neighbours! {
+    case01_sparse: (0, vec!["s5", "s1", "s4", "s3"]),
+    case02_same_location: (1, vec!["s4", "s5", "s0"]),
+    case03_no_neighbours: (3, vec!["s5"]),
+    case04_no_location: (5, vec!["s0", "s1", "s2", "s3", "s4"]),
+}
+
+fn can_use_sparse_job_neighbours_impl(index: usize, expected: Vec<String>) {
+    let fleet = test_fleet();
+    let profile = fleet.profiles.first().cloned().unwrap();
+    let transport = SparseNeighboursCost { neighbours: vec![vec![1, 5], vec![0], vec![], vec![], vec![], vec![]] };
+    let species = vec![
+        TestSingleBuilder::default().id("s0").location(Some(0)).build_as_job_ref(),
+        TestSingleBuilder::default().id("s1").location(Some(1)).build_as_job_ref(),
+        TestSingleBuilder::default().id("s2").location(Some(2)).build_as_job_ref(),
+        TestSingleBuilder::default().id("s3").location(Some(5)).build_as_job_ref(),
+        TestSingleBuilder::default().id("s4").location(Some(1)).build_as_job_ref(),
+        TestSingleBuilder::default().id("s5").location(None).build_as_job_ref(),
+    ];
+    let jobs = Jobs::new(&fleet, species.clone(), &transport);
+
+    let result: Vec<String> =
+        jobs.neighbors(&profile, species.get(index).unwrap(), 0.0).map(|(j, _)| get_job_id(j).clone()).collect();
+
+    assert_eq!(result, expected);
+}
+
 parameterized_test! {returns_proper_job_ranks, (index, profile, expected), {
     returns_proper_job_ranks_impl(index, profile, expected);
 }}
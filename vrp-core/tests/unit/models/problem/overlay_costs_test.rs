@@ -0,0 +1,86 @@
+use super::*;
+use crate::helpers::models::solution::test_actor_with_profile;
+use crate::models::problem::create_matrix_transport_cost;
+use crate::models::problem::MatrixData;
+
+fn create_base() -> Arc<dyn TransportCost> {
+    let durations = vec![0., 10., 20., 10., 0., 10., 20., 10., 0.];
+    let distances = vec![0., 100., 200., 100., 0., 100., 200., 100., 0.];
+
+    create_matrix_transport_cost(vec![
+        MatrixData::new(0, None, durations.clone(), distances.clone()),
+        MatrixData::new(1, None, durations, distances),
+    ])
+    .unwrap()
+}
+
+fn create_costs() -> Arc<dyn TransportCost> {
+    create_overlay_transport_cost(
+        create_base(),
+        vec![TransportOverlay::new(
+            1,
+            vec![2],
+            vec![(0, 1, Some(30.), None), (1, 0, None, None), (2, 0, Some(5.), Some(50.))],
+        )],
+    )
+    .unwrap()
+}
+
+parameterized_test! {can_apply_overlay, (profile, from, to, expected), {
+    can_apply_overlay_impl(profile, from, to, expected);
+}}
+
+can_apply_overlay! {
+    case01_no_overlay: (0, 0, 2, (20., 200.)),
+    case02_same_location: (1, 2, 2, (0., 0.)),
+    case03_unreachable_to: (1, 0, 2, (-1., -1.)),
+    case04_unreachable_from: (1, 2, 1, (-1., -1.)),
+    case05_unreachable_edge: (1, 1, 0, (-1., -1.)),
+    case06_override_duration: (1, 0, 1, (30., 100.)),
+    case07_unreachable_wins_over_edge: (1, 2, 0, (-1., -1.)),
+}
+
+fn can_apply_overlay_impl(profile: usize, from: Location, to: Location, expected: (Duration, Distance)) {
+    let costs = create_costs();
+    let route = Route { actor: test_actor_with_profile(profile), tour: Default::default() };
+    let travel_time = TravelTime::Departure(0.);
+
+    let approx = (
+        costs.duration_approx(&route.actor.vehicle.profile, from, to),
+        costs.distance_approx(&route.actor.vehicle.profile, from, to),
+    );
+    let actual = (costs.duration(&route, from, to, travel_time), costs.distance(&route, from, to, travel_time));
+
+    assert_eq!(approx, expected);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn can_scale_overridden_duration() {
+    let costs = create_costs();
+
+    assert_eq!(costs.duration_approx(&Profile::new(1, Some(2.)), 0, 1), 60.);
+    assert_eq!(costs.duration_approx(&Profile::new(1, Some(2.)), 1, 2), -1.);
+    assert_eq!(costs.duration_approx(&Profile::new(0, Some(2.)), 1, 2), 20.);
+}
+
+parameterized_test! {can_detect_invalid_overlays, (overlays, expected), {
+    let result = create_overlay_transport_cost(create_base(), overlays);
+
+    assert_eq!(result.err().map(|err| err.to_string()), Some(expected.to_string()));
+}}
+
+can_detect_invalid_overlays! {
+    case01_duplicate: (
+        vec![TransportOverlay::new(1, vec![], vec![]), TransportOverlay::new(1, vec![0], vec![])],
+        "duplicate transport overlay for profile 1",
+    ),
+    case02_negative_duration: (
+        vec![TransportOverlay::new(1, vec![], vec![(0, 1, Some(-1.), None)])],
+        "overlay values should be non-negative for profile 1",
+    ),
+    case03_negative_distance: (
+        vec![TransportOverlay::new(0, vec![], vec![(0, 1, None, Some(f64::NAN))])],
+        "overlay values should be non-negative for profile 0",
+    ),
+}
@@ -0,0 +1,72 @@
+use super::*;
+
+fn create_coordinates() -> Vec<(f64, f64)> {
+    vec![(52.50, 13.40), (52.51, 13.40), (52.52, 13.40), (52.60, 13.40)]
+}
+
+fn create_entries() -> Vec<(Location, Location, Duration, Distance)> {
+    let distance = get_haversine_distance((52.50, 13.40), (52.51, 13.40));
+
+    vec![
+        (0, 1, distance / 10., distance * 1.5),
+        (1, 0, distance / 10., distance * 1.5),
+        (1, 2, distance / 10., distance * 1.5),
+        (2, 1, distance / 10., distance * 1.5),
+        (2, 3, -1., -1.),
+    ]
+}
+
+#[test]
+fn can_use_known_entries() {
+    let profile = Profile::default();
+    let costs =
+        create_sparse_matrix_transport_cost(create_coordinates(), vec![SparseMatrixData::new(0, create_entries())])
+            .unwrap();
+    let distance = get_haversine_distance((52.50, 13.40), (52.51, 13.40));
+
+    assert!((costs.distance_approx(&profile, 0, 1) - distance * 1.5).abs() < 1E-6);
+    assert!((costs.duration_approx(&profile, 1, 2) - distance / 10.).abs() < 1E-6);
+    assert_eq!(costs.distance_approx(&profile, 2, 3), -1.);
+    assert_eq!(costs.duration_approx(&profile, 2, 2), 0.);
+}
+
+#[test]
+fn can_approximate_missing_entries() {
+    let profile = Profile::new(0, Some(2.));
+    let costs =
+        create_sparse_matrix_transport_cost(create_coordinates(), vec![SparseMatrixData::new(0, create_entries())])
+            .unwrap();
+    let expected_distance = get_haversine_distance((52.50, 13.40), (52.60, 13.40)) * 1.5;
+    let expected_speed = 15.;
+
+    let distance = costs.distance_approx(&profile, 0, 3);
+    let duration = costs.duration_approx(&profile, 3, 0);
+
+    assert!((distance - expected_distance).abs() < 1E-6);
+    assert!((duration - 2. * expected_distance / expected_speed).abs() < 1E-6);
+}
+
+#[test]
+fn can_return_neighbours() {
+    let profile = Profile::default();
+    let costs =
+        create_sparse_matrix_transport_cost(create_coordinates(), vec![SparseMatrixData::new(0, create_entries())])
+            .unwrap();
+
+    assert_eq!(costs.neighbours(&profile, 1), Some([0, 2].as_slice()));
+    assert_eq!(costs.neighbours(&profile, 3), Some([].as_slice()));
+    assert_eq!(costs.neighbours(&Profile::new(1, None), 0), None);
+}
+
+parameterized_test! {can_detect_invalid_data, (costs, expected), {
+    let result = create_sparse_matrix_transport_cost(create_coordinates(), costs).err();
+
+    assert_eq!(result, Some(expected.into()));
+}}
+
+can_detect_invalid_data! {
+    case01_empty: (vec![], "no sparse matrix data found"),
+    case02_wrong_index: (vec![SparseMatrixData::new(1, create_entries())], "sparse matrix data should have exactly one entry per profile index"),
+    case03_unknown_location: (vec![SparseMatrixData::new(0, vec![(0, 4, 1., 1.)])], "sparse matrix data of profile 0 has unknown location"),
+    case04_no_valid_entries: (vec![SparseMatrixData::new(0, vec![(0, 1, -1., -1.)])], "cannot calibrate approximation for profile 0"),
+}
@@ -0,0 +1,101 @@
+use super::*;
+use crate::helpers::models::solution::test_actor_with_profile;
+use crate::models::problem::create_matrix_transport_cost;
+use crate::models::problem::MatrixData;
+
+fn create_base() -> Arc<dyn TransportCost> {
+    create_matrix_transport_cost(vec![
+        MatrixData::new(0, None, vec![0., 100., 200., 0.], vec![0., 1000., 2000., 0.]),
+        MatrixData::new(1, None, vec![0., 100., -1., 0.], vec![0., 1000., -1., 0.]),
+    ])
+    .unwrap()
+}
+
+fn create_route(profile: usize) -> Route {
+    Route { actor: test_actor_with_profile(profile), tour: Default::default() }
+}
+
+fn create_costs(edge_classes: Option<Vec<usize>>) -> Arc<dyn TransportCost> {
+    create_speed_profile_transport_cost(
+        create_base(),
+        vec![
+            // NOTE rush hour between 100 and 200 with half speed, after that speed is 2x faster
+            SpeedProfile::new(0, 0, vec![(100., 0.5), (200., 2.)]),
+            SpeedProfile::new(1, 1, vec![(0., 0.25)]),
+        ],
+        edge_classes,
+    )
+    .unwrap()
+}
+
+parameterized_test! {can_calculate_departure_duration, (departure, expected), {
+    let costs = create_costs(None);
+
+    let duration = costs.duration(&create_route(0), 0, 1, TravelTime::Departure(departure));
+
+    assert!((duration - expected).abs() < 1E-9, "{duration} != {expected}");
+}}
+
+can_calculate_departure_duration! {
+    case01_base_speed: (-200., 100.),
+    case02_enter_rush_hour: (50., 50. + 100.),
+    case03_rush_hour_to_fast: (100., 100. + 25.),
+    case04_rush_hour_partial: (150., 50. + 37.5),
+    case05_fast: (300., 50.),
+    case06_base_till_rush_hour: (0., 100.),
+}
+
+#[test]
+fn can_calculate_arrival_duration_consistently() {
+    let costs = create_costs(None);
+    let route = create_route(0);
+
+    (-100..400).step_by(7).map(|departure| departure as f64).for_each(|departure| {
+        let duration = costs.duration(&route, 0, 1, TravelTime::Departure(departure));
+        let arrival = departure + duration;
+
+        let backward = costs.duration(&route, 0, 1, TravelTime::Arrival(arrival));
+
+        assert!((duration - backward).abs() < 1E-9, "departure {departure}: {duration} != {backward}");
+    });
+}
+
+#[test]
+fn can_guarantee_fifo_property() {
+    let costs = create_costs(None);
+    let route = create_route(0);
+
+    let arrivals = (-100..400)
+        .map(|departure| departure as f64)
+        .map(|departure| departure + costs.duration(&route, 1, 0, TravelTime::Departure(departure)))
+        .collect::<Vec<_>>();
+
+    assert!(arrivals.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn can_use_edge_classes() {
+    let costs = create_costs(Some(vec![0, 1, 0, 0]));
+    let route = create_route(1);
+
+    assert_eq!(costs.duration(&route, 0, 1, TravelTime::Departure(10.)), 400.);
+    assert_eq!(costs.duration(&route, 1, 0, TravelTime::Departure(10.)), -1.);
+    assert_eq!(costs.duration_approx(&route.actor.vehicle.profile, 0, 1), 100.);
+    assert_eq!(costs.distance(&route, 0, 1, TravelTime::Departure(10.)), 1000.);
+}
+
+parameterized_test! {can_detect_invalid_speed_profiles, (speed_profiles, edge_classes, expected), {
+    let result = create_speed_profile_transport_cost(create_base(), speed_profiles, edge_classes).err();
+
+    assert_eq!(result, Some(expected.into()));
+}}
+
+can_detect_invalid_speed_profiles! {
+    case01_negative_factor: (vec![SpeedProfile::new(0, 0, vec![(0., -1.)])], None,
+                             "speed factor should be positive for profile 0 and class 0"),
+    case02_not_sorted: (vec![SpeedProfile::new(0, 0, vec![(10., 1.), (5., 2.)])], None,
+                        "speed bands should be sorted by start for profile 0 and class 0"),
+    case03_duplicate: (vec![SpeedProfile::new(1, 0, vec![(0., 1.)]), SpeedProfile::new(1, 0, vec![(0., 2.)])], None,
+                       "duplicate speed profile for profile 1 and class 0"),
+    case04_wrong_classes: (vec![], Some(vec![0, 1, 0]), "edge classes should be specified for each pair of locations"),
+}
@@ -9,13 +9,24 @@ use crate::utils::combine_error_results;
 use std::cmp::Ordering;
 use std::collections::HashSet;
 use vrp_core::construction::clustering::vicinity::ServingPolicy;
+use vrp_core::models::common::{Load, MultiDimLoad};
 use vrp_core::models::solution::Place;
 use vrp_core::prelude::{compare_floats, GenericResult};
 use vrp_core::utils::GenericError;
 
 /// Checks assignment of jobs and vehicles.
 pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_vehicles(ctx), check_jobs_presence(ctx), check_jobs_match(ctx), check_groups(ctx)])
+    combine_error_results(&[
+        check_vehicles(ctx),
+        check_shift_locations(ctx),
+        check_jobs_presence(ctx),
+        check_jobs_match(ctx),
+        check_groups(ctx),
+        check_conflicts(ctx),
+        check_skill_levels(ctx),
+        check_transfers(ctx),
+        check_trailers(ctx),
+    ])
 }
 
 /// Checks that vehicles in each tour are used once per shift and they are known in problem.
@@ -38,10 +49,58 @@ fn check_vehicles(ctx: &CheckerContext) -> GenericResult<()> {
     Ok(())
 }
 
+/// Checks that each tour starts and ends at vehicle shift location or one of its alternatives.
+fn check_shift_locations(ctx: &CheckerContext) -> GenericResult<()> {
+    let get_location_indices = |location: &Location, alternatives: Option<&Vec<ShiftLocation>>| {
+        std::iter::once(location)
+            .chain(alternatives.into_iter().flatten().map(|alternative| &alternative.location))
+            .filter_map(|location| ctx.coord_index.get_by_loc(location))
+            .collect::<HashSet<_>>()
+    };
+    let get_terminal_location = |stop: Option<&Stop>, activity_type: &str| {
+        stop.and_then(|stop| {
+            stop.activities()
+                .iter()
+                .find(|activity| activity.activity_type == activity_type)
+                .and_then(|activity| ctx.get_activity_location(stop, activity))
+        })
+    };
+
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = ctx.get_vehicle(&tour.vehicle_id)?;
+        let shift = vehicle
+            .shifts
+            .get(tour.shift_index)
+            .ok_or_else(|| format!("cannot find shift {} for tour '{}'", tour.shift_index, tour.vehicle_id))?;
+
+        let starts = get_location_indices(&shift.start.location, shift.start.alternatives.as_ref());
+        let ends = shift.end.as_ref().map(|end| get_location_indices(&end.location, end.alternatives.as_ref()));
+
+        let is_valid_start = get_terminal_location(tour.stops.first(), "departure")
+            .and_then(|location| ctx.coord_index.get_by_loc(&location))
+            .is_none_or(|idx| starts.contains(&idx));
+        let is_valid_end = ends
+            .zip(get_terminal_location(tour.stops.last(), "arrival"))
+            .is_none_or(|(ends, location)| ctx.coord_index.get_by_loc(&location).is_none_or(|idx| ends.contains(&idx)));
+
+        if is_valid_start && is_valid_end {
+            Ok(())
+        } else {
+            Err(format!(
+                "tour '{}' starts or ends at location which is not its shift location or one of its alternatives",
+                tour.vehicle_id
+            )
+            .into())
+        }
+    })
+}
+
 /// Checks job task rules.
 fn check_jobs_presence(ctx: &CheckerContext) -> GenericResult<()> {
     struct JobAssignment {
         pub tour_info: (String, usize),
+        pub transfer_tour_info: Option<(String, usize)>,
+        pub handovers: Vec<usize>,
         pub pickups: Vec<usize>,
         pub deliveries: Vec<usize>,
         pub replacements: Vec<usize>,
@@ -49,12 +108,15 @@ fn check_jobs_presence(ctx: &CheckerContext) -> GenericResult<()> {
     }
     let new_assignment = |tour_info: (String, usize)| JobAssignment {
         tour_info,
+        transfer_tour_info: None,
+        handovers: vec![],
         pickups: vec![],
         deliveries: vec![],
         replacements: vec![],
         services: vec![],
     };
-    let activity_types: HashSet<_> = vec!["pickup", "delivery", "service", "replacement"].into_iter().collect();
+    let activity_types: HashSet<_> =
+        vec!["pickup", "delivery", "service", "replacement", "handover"].into_iter().collect();
 
     let all_jobs = ctx.problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect::<HashMap<_, _>>();
     let mut used_jobs = HashMap::<String, JobAssignment>::new();
@@ -70,11 +132,23 @@ fn check_jobs_presence(ctx: &CheckerContext) -> GenericResult<()> {
                 let asgn =
                     used_jobs.entry(activity.job_id.clone()).or_insert_with(|| new_assignment(tour_info.clone()));
 
+                // NOTE transferred job is served by two tours
                 if asgn.tour_info != tour_info {
-                    return Err(GenericError::from(format!("job served in multiple tours: '{}'", activity.job_id)));
+                    let is_transferable = all_jobs.get(&activity.job_id).is_some_and(|job| job.transfers.is_some());
+                    match &asgn.transfer_tour_info {
+                        Some(transfer_tour_info) if *transfer_tour_info == tour_info => {}
+                        None if is_transferable => asgn.transfer_tour_info = Some(tour_info),
+                        _ => {
+                            return Err(GenericError::from(format!(
+                                "job served in multiple tours: '{}'",
+                                activity.job_id
+                            )))
+                        }
+                    }
                 }
 
                 match activity.activity_type.as_str() {
+                    "handover" => asgn.handovers.push(idx),
                     "pickup" => asgn.pickups.push(idx),
                     "delivery" => asgn.deliveries.push(idx),
                     "service" => asgn.services.push(idx),
@@ -101,20 +175,40 @@ fn check_jobs_presence(ctx: &CheckerContext) -> GenericResult<()> {
             )));
         }
 
-        if !asgn.deliveries.is_empty() && asgn.pickups.iter().max() > asgn.deliveries.iter().min() {
+        if asgn.transfer_tour_info.is_some() == asgn.handovers.is_empty() {
+            return Err(GenericError::from(format!("job served in multiple tours: '{id}'")));
+        }
+
+        if asgn.handovers.is_empty()
+            && !asgn.deliveries.is_empty()
+            && asgn.pickups.iter().max() > asgn.deliveries.iter().min()
+        {
             return Err(GenericError::from(format!("found pickup after delivery for '{id}'")));
         }
 
         Ok(())
     })?;
 
-    let all_unassigned_jobs = ctx
+    let (partial_jobs, unassigned_jobs): (Vec<_>, Vec<_>) = ctx
         .solution
         .unassigned
         .iter()
         .flat_map(|jobs| jobs.iter().filter(|job| !job.job_id.ends_with("_break")))
-        .map(|job| job.job_id.clone())
-        .collect::<Vec<_>>();
+        .partition(|job| job.remaining.is_some());
+
+    partial_jobs.iter().try_for_each::<_, GenericResult<_>>(|job| {
+        if all_jobs.get(&job.job_id).is_none_or(|job| job.divisible.is_none()) {
+            return Err(format!("job is not divisible, but served partially: '{}'", job.job_id).into());
+        }
+
+        if !used_jobs.contains_key(&job.job_id) {
+            return Err(format!("partially served job is not assigned: '{}'", job.job_id).into());
+        }
+
+        Ok(())
+    })?;
+
+    let all_unassigned_jobs = unassigned_jobs.into_iter().map(|job| job.job_id.clone()).collect::<Vec<_>>();
 
     let unique_unassigned_jobs = all_unassigned_jobs.iter().cloned().collect::<HashSet<_>>();
 
@@ -256,6 +350,170 @@ fn is_valid_job_info(
     }
 }
 
+/// Checks that trailer is decoupled and recoupled at the same parking place without nesting, truck only
+/// jobs are served while trailer of the vehicle is decoupled and truck alone capacity is not exceeded.
+fn check_trailers(ctx: &CheckerContext) -> GenericResult<()> {
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = ctx.get_vehicle(&tour.vehicle_id)?;
+        let has_trailer = vehicle.trailer.is_some();
+        let truck_capacity = MultiDimLoad::new(vehicle.shift_capacity(tour.shift_index).clone());
+
+        let activities = tour.stops.iter().flat_map(|stop| {
+            stop.activities().iter().map(move |activity| (stop, ctx.get_activity_location(stop, activity), activity))
+        });
+
+        let parking = activities.into_iter().try_fold::<_, _, GenericResult<_>>(
+            None,
+            |parking, (stop, location, activity)| match (activity.activity_type.as_str(), parking) {
+                ("decouple", None) => Ok(Some((location, Vec::new()))),
+                ("decouple", Some(_)) => Err(format!("nested trailer decouple in tour '{}'", tour.vehicle_id).into()),
+                ("recouple", Some((parking, detached))) if parking == location => {
+                    if truck_capacity.can_fit(&get_max_truck_load(ctx, tour, detached.as_slice())?) {
+                        Ok(None)
+                    } else {
+                        Err(format!(
+                            "truck capacity is exceeded while trailer is decoupled in tour '{}'",
+                            tour.vehicle_id
+                        )
+                        .into())
+                    }
+                }
+                ("recouple", _) => {
+                    Err(format!("trailer is recoupled not at its parking place in tour '{}'", tour.vehicle_id).into())
+                }
+                (_, None)
+                    if has_trailer
+                        && ctx.get_job_by_id(&activity.job_id).is_some_and(|job| job.truck_only == Some(true)) =>
+                {
+                    Err(format!("truck only job '{}' is served with trailer", activity.job_id).into())
+                }
+                (_, Some((parking, mut detached))) => {
+                    detached.push((stop, activity));
+                    Ok(Some((parking, detached)))
+                }
+                (_, None) => Ok(None),
+            },
+        )?;
+
+        if parking.is_some() {
+            Err(format!("trailer is not recoupled in tour '{}'", tour.vehicle_id).into())
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Checks that vehicle has skills with at least minimum levels required by jobs served in its tour.
+fn check_skill_levels(ctx: &CheckerContext) -> GenericResult<()> {
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = ctx.get_vehicle(&tour.vehicle_id)?;
+        let levels = vehicle
+            .shift_skills(tour.shift_index)
+            .iter()
+            .flat_map(|skills| skills.iter())
+            .map(|skill| match skill {
+                VehicleSkill::Plain(name) => (name.as_str(), 1),
+                VehicleSkill::Leveled(SkillLevel { name, level }) => (name.as_str(), *level),
+            })
+            .collect::<HashMap<_, _>>();
+
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .filter_map(|activity| ctx.get_job_by_id(&activity.job_id))
+            .filter(|job| {
+                job.skills.as_ref().and_then(|skills| skills.min_levels.as_ref()).is_some_and(|min_levels| {
+                    min_levels.iter().any(|min_level| {
+                        levels.get(min_level.name.as_str()).is_none_or(|&level| level < min_level.level)
+                    })
+                })
+            })
+            .try_for_each(|job| {
+                Err(format!("job '{}' requires higher skill levels than vehicle '{}' has", job.id, tour.vehicle_id)
+                    .into())
+            })
+    })
+}
+
+/// Checks that transferred jobs are handed over at the same transfer point in the right order and time.
+fn check_transfers(ctx: &CheckerContext) -> GenericResult<()> {
+    struct Handover<'a> {
+        stop: &'a Stop,
+        activity: &'a Activity,
+        is_drop: bool,
+        is_ordered: bool,
+    }
+
+    let handovers = ctx.solution.tours.iter().fold(HashMap::<_, Vec<_>>::default(), |mut acc, tour| {
+        let activities = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .collect::<Vec<_>>();
+        let find_idx = |job_id: &str, activity_type: &str| {
+            activities
+                .iter()
+                .position(|(_, activity)| activity.job_id == job_id && activity.activity_type == activity_type)
+        };
+
+        activities.iter().enumerate().filter(|(_, (_, activity))| activity.activity_type == "handover").for_each(
+            |(idx, &(stop, activity))| {
+                let pickup_idx = find_idx(&activity.job_id, "pickup");
+                let delivery_idx = find_idx(&activity.job_id, "delivery");
+                let is_ordered = pickup_idx.is_none_or(|pickup_idx| pickup_idx < idx)
+                    && delivery_idx.is_none_or(|delivery_idx| idx < delivery_idx);
+
+                acc.entry(activity.job_id.clone()).or_default().push(Handover {
+                    stop,
+                    activity,
+                    is_drop: pickup_idx.is_some(),
+                    is_ordered,
+                });
+            },
+        );
+
+        acc
+    });
+
+    handovers.into_iter().try_for_each(|(job_id, handovers)| {
+        let job = ctx.get_job_by_id(&job_id).ok_or_else(|| format!("cannot find job with id '{job_id}'"))?;
+
+        let (drop, pick) = match handovers.as_slice() {
+            [first, second] if first.is_drop != second.is_drop => {
+                if first.is_drop {
+                    (first, second)
+                } else {
+                    (second, first)
+                }
+            }
+            _ => return Err(format!("invalid handover activities for job '{job_id}'").into()),
+        };
+
+        if !drop.is_ordered || !pick.is_ordered {
+            return Err(format!("handover is not in between pickup and delivery for job '{job_id}'").into());
+        }
+
+        let is_known_transfer = drop.activity.job_tag.as_ref().is_some_and(|tag| {
+            job.transfers.iter().flatten().any(|transfer| transfer == tag)
+                && pick.activity.job_tag.as_ref() == Some(tag)
+        });
+        if !is_known_transfer
+            || ctx.get_activity_location(drop.stop, drop.activity)
+                != ctx.get_activity_location(pick.stop, pick.activity)
+        {
+            return Err(format!("job '{job_id}' is handed over at different transfer points").into());
+        }
+
+        let drop_end = ctx.get_activity_time(drop.stop, drop.activity).end;
+        let pick_start = ctx.get_activity_time(pick.stop, pick.activity).start;
+        if compare_floats(pick_start, drop_end) == Ordering::Less {
+            return Err(format!("job '{job_id}' is picked up at transfer point before it is dropped").into());
+        }
+
+        Ok(())
+    })
+}
+
 fn check_groups(ctx: &CheckerContext) -> GenericResult<()> {
     let violations = ctx
         .solution
@@ -288,3 +546,32 @@ fn check_groups(ctx: &CheckerContext) -> GenericResult<()> {
         Err(format!("job groups are not respected: '{err_info}'").into())
     }
 }
+
+fn check_conflicts(ctx: &CheckerContext) -> GenericResult<()> {
+    let violations = ctx
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| {
+            let job_ids = tour
+                .stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter())
+                .map(|activity| activity.job_id.as_str())
+                .collect::<HashSet<_>>();
+
+            job_ids
+                .iter()
+                .filter_map(|job_id| ctx.get_job_by_id(job_id))
+                .filter(|job| job.conflicts.iter().flatten().any(|other_id| job_ids.contains(other_id.as_str())))
+                .map(|job| job.id.clone())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("job conflicts are not respected: '{}'", violations.join(",")).into())
+    }
+}
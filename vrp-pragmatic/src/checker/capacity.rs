@@ -11,13 +11,18 @@ use vrp_core::prelude::GenericResult;
 /// Checks that vehicle load is assigned correctly. The following rules are checked:
 /// * max vehicle's capacity is not violated
 /// * load change is correct
+/// * strict backhaul policy is respected
 pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_vehicle_load_assignment(context), check_resource_consumption(context)])
+    combine_error_results(&[
+        check_vehicle_load_assignment(context),
+        check_resource_consumption(context),
+        check_backhauls(context),
+    ])
 }
 
 fn check_vehicle_load_assignment(context: &CheckerContext) -> GenericResult<()> {
     context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
-        let capacity = MultiDimLoad::new(context.get_vehicle(&tour.vehicle_id)?.capacity.clone());
+        let capacity = MultiDimLoad::new(context.get_vehicle(&tour.vehicle_id)?.shift_total_capacity(tour.shift_index));
         let intervals = get_intervals(context, tour);
 
         intervals
@@ -28,11 +33,11 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> GenericResult<()>
                     (acc, MultiDimLoad::default()),
                     |acc, (activity, activity_type)| {
                         let activity_type = activity_type?;
-                        let demand = get_demand(context, &activity, &activity_type)?;
+                        let demand = get_demand(context, tour, &activity, &activity_type)?;
                         Ok(match demand {
                             (DemandType::StaticDelivery, demand) => (acc.0 + demand, acc.1),
                             (DemandType::StaticPickup, demand) => (acc.0, acc.1 + demand),
-                            (DemandType::StaticPickupDelivery, demand) => (acc.0 + demand, acc.1 + demand),
+                            (DemandType::StaticPickupDelivery, demand) => (acc.0 + demand.clone(), acc.1 + demand),
                             _ => acc,
                         })
                     },
@@ -53,9 +58,9 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> GenericResult<()>
                                 let activity_type = context.get_activity_type(tour, to, activity)?;
                                 let (demand_type, demand) =
                                     if activity.activity_type == "arrival" || activity.activity_type == "reload" {
-                                        (DemandType::StaticDelivery, end_pickup)
+                                        (DemandType::StaticDelivery, end_pickup.clone())
                                     } else {
-                                        get_demand(context, activity, &activity_type)?
+                                        get_demand(context, tour, activity, &activity_type)?
                                     };
 
                                 Ok(match demand_type {
@@ -67,7 +72,7 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> GenericResult<()>
                         )?;
 
                         let is_from_valid = from_load == acc;
-                        let is_to_valid = to_load == from_load + change;
+                        let is_to_valid = to_load == from_load.clone() + change;
 
                         if is_from_valid && is_to_valid {
                             Ok(to_load)
@@ -89,6 +94,7 @@ fn check_vehicle_load_assignment(context: &CheckerContext) -> GenericResult<()>
 }
 
 fn check_resource_consumption(context: &CheckerContext) -> GenericResult<()> {
+    let satellites = get_satellites(context);
     let resources = context
         .problem
         .fleet
@@ -97,6 +103,10 @@ fn check_resource_consumption(context: &CheckerContext) -> GenericResult<()> {
         .flat_map(|resources| resources.iter().cloned())
         .map(|resource| match resource {
             VehicleResource::Reload { id, capacity } => (id, MultiDimLoad::new(capacity)),
+            VehicleResource::Satellite { id, .. } => {
+                let capacity = satellites.get(&id).map(|(_, task)| task.demand.clone().unwrap_or_default());
+                (id, MultiDimLoad::new(capacity.unwrap_or_default()))
+            }
         })
         .collect::<HashMap<_, _>>();
 
@@ -106,47 +116,62 @@ fn check_resource_consumption(context: &CheckerContext) -> GenericResult<()> {
         .iter()
         .flat_map(|tour| {
             get_intervals(context, tour).into_iter().filter_map(|interval| {
-                let resource_id = interval.first().and_then(|(_, (start, _))| {
-                    start
+                let (resource_id, start_time) = interval.first().and_then(|&(leg_idx, (start, _))| {
+                    let reload = start
                         .activities()
                         .iter()
-                        .filter_map(|activity| context.get_activity_type(tour, start, activity).ok())
-                        .filter_map(|activity| match activity {
-                            ActivityType::Reload(reload) => Some(reload),
-                            _ => None,
+                        .filter_map(|activity| {
+                            context.get_activity_type(tour, start, activity).ok().map(|a_type| (activity, a_type))
                         })
-                        .filter_map(|reload| reload.resource_id.as_ref().cloned())
-                        .next()
-                });
-
-                if let Some(resource_id) = resource_id {
-                    let consumption = get_activities_from_interval(context, tour, interval.as_slice())
-                        .filter_map(|(activity, activity_type)| Some(activity).zip(activity_type.ok()))
-                        .filter_map(|(activity, activity_type)| get_demand(context, &activity, &activity_type).ok())
-                        .filter_map(|(demand_type, demand_value)| match demand_type {
-                            DemandType::StaticDelivery => Some(demand_value),
+                        .filter_map(|(activity, activity_type)| match activity_type {
+                            ActivityType::Reload(reload) => Some((activity, reload)),
                             _ => None,
                         })
-                        .fold(MultiDimLoad::default(), |acc, demand| acc + demand);
-                    Some((resource_id, consumption))
-                } else {
-                    None
-                }
+                        .filter_map(|(activity, reload)| {
+                            let time = context.get_activity_time(start, activity);
+                            reload.resource_id.as_ref().map(|resource_id| (resource_id.clone(), time.end))
+                        })
+                        .next();
+
+                    // NOTE vehicle which starts at satellite's location loads its cargo there
+                    reload.or_else(|| {
+                        let location = start.location().filter(|_| leg_idx == 0)?;
+                        satellites
+                            .iter()
+                            .find(|(_, (satellite_location, _))| satellite_location == location)
+                            .map(|(resource_id, _)| (resource_id.clone(), parse_time(&start.schedule().departure)))
+                    })
+                })?;
+
+                let consumption = get_activities_from_interval(context, tour, interval.as_slice())
+                    .filter_map(|(activity, activity_type)| Some(activity).zip(activity_type.ok()))
+                    .filter_map(|(activity, activity_type)| get_demand(context, tour, &activity, &activity_type).ok())
+                    .filter_map(|(demand_type, demand_value)| match demand_type {
+                        DemandType::StaticDelivery => Some(demand_value),
+                        _ => None,
+                    })
+                    .fold(MultiDimLoad::default(), |acc, demand| acc + demand);
+
+                Some((resource_id, consumption, start_time))
             })
         })
-        .fold(HashMap::default(), |mut acc, (resource_id, consumption)| {
+        .try_fold(HashMap::<String, MultiDimLoad>::default(), |mut acc, (resource_id, consumption, start_time)| {
+            if consumption.is_not_empty() && satellites.contains_key(&resource_id) {
+                check_satellite_supply(context, &satellites, &resource_id, start_time)?;
+            }
+
             let entry = acc.entry(resource_id).or_default();
-            *entry = *entry + consumption;
+            *entry = entry.clone() + consumption;
 
-            acc
-        });
+            Ok::<_, GenericError>(acc)
+        })?;
 
     consumption.into_iter().try_for_each(|(resource_id, consumed)| {
-        let available = *resources.get(&resource_id).ok_or_else(|| {
+        let available = resources.get(&resource_id).ok_or_else(|| {
             GenericError::from(format!("cannot find resource '{resource_id}' in list of available resources"))
         })?;
 
-        if consumed > available {
+        if consumed > *available {
             Err(GenericError::from(format!(
                 "consumed more resource '{resource_id}' than available: {consumed} vs {available}"
             )))
@@ -156,6 +181,133 @@ fn check_resource_consumption(context: &CheckerContext) -> GenericResult<()> {
     })
 }
 
+/// Checks that vehicles with strict backhaul policy serve all deliveries before any pickup.
+fn check_backhauls(context: &CheckerContext) -> GenericResult<()> {
+    context.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+        if !matches!(vehicle.backhaul, Some(VehicleBackhaul::Strict)) {
+            return Ok(());
+        }
+
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .try_fold(false, |has_pickup, (stop, activity)| {
+                let activity_type = context.get_activity_type(tour, stop, activity)?;
+                let (demand_type, demand) = get_demand(context, tour, activity, &activity_type)?;
+
+                match demand_type {
+                    DemandType::StaticPickup if demand.is_not_empty() => Ok(true),
+                    DemandType::StaticDelivery if has_pickup && demand.is_not_empty() => Err(format!(
+                        "backhaul policy violation: delivery of job '{}' is served after pickup, vehicle id '{}', shift index: {}",
+                        activity.job_id, tour.vehicle_id, tour.shift_index
+                    )
+                    .into()),
+                    _ => Ok(has_pickup),
+                }
+            })
+            .map(|_| ())
+    })
+}
+
+/// Returns satellite's location and supply job task.
+fn get_satellites(context: &CheckerContext) -> HashMap<String, (Location, JobTask)> {
+    context
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Satellite { id, job_id } => context
+                .get_job_by_id(job_id)
+                .and_then(|job| job.deliveries.as_ref())
+                .and_then(|deliveries| deliveries.first())
+                .and_then(|task| task.places.first().map(|place| (id.clone(), (place.location.clone(), task.clone())))),
+            VehicleResource::Reload { .. } => None,
+        })
+        .collect()
+}
+
+/// Checks that satellite's cargo is delivered before it is consumed.
+fn check_satellite_supply(
+    context: &CheckerContext,
+    satellites: &HashMap<String, (Location, JobTask)>,
+    resource_id: &str,
+    start_time: f64,
+) -> GenericResult<()> {
+    let job_id = context
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .find_map(|resource| match resource {
+            VehicleResource::Satellite { id, job_id } if id == resource_id && satellites.contains_key(id) => {
+                Some(job_id.clone())
+            }
+            _ => None,
+        })
+        .ok_or_else(|| format!("cannot find satellite '{resource_id}'"))?;
+
+    let supply_time = context
+        .solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .find(|(_, activity)| activity.job_id == job_id)
+        .map(|(stop, activity)| context.get_activity_time(stop, activity).end)
+        .ok_or_else(|| format!("satellite '{resource_id}' is used, but its cargo job '{job_id}' is not assigned"))?;
+
+    if start_time < supply_time {
+        Err(format!("satellite '{resource_id}' is used before its cargo job '{job_id}' is served").into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns max load carried by the truck alone while serving given activities after trailer is decoupled.
+/// Goods which are not needed for these activities are assumed to be left in the trailer.
+pub(crate) fn get_max_truck_load(
+    context: &CheckerContext,
+    tour: &Tour,
+    activities: &[(&Stop, &Activity)],
+) -> GenericResult<MultiDimLoad> {
+    let demands = activities
+        .iter()
+        .map(|&(stop, activity)| {
+            let activity_type = context.get_activity_type(tour, stop, activity)?;
+            get_demand(context, tour, activity, &activity_type)
+        })
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    // NOTE dynamic delivery has to be taken at decouple place when its pickup happens before
+    let start = demands.iter().enumerate().fold(MultiDimLoad::default(), |acc, (idx, (demand_type, demand))| {
+        let is_picked_before = || activities[..idx].iter().all(|(_, other)| other.job_id != activities[idx].1.job_id);
+
+        match demand_type {
+            DemandType::StaticDelivery | DemandType::StaticPickupDelivery => acc + demand.clone(),
+            DemandType::DynamicDelivery if is_picked_before() => acc + demand.clone(),
+            _ => acc,
+        }
+    });
+
+    Ok(demands
+        .into_iter()
+        .fold((start.clone(), start), |(current, max), (demand_type, demand)| {
+            let current = match demand_type {
+                DemandType::StaticDelivery | DemandType::DynamicDelivery => current - demand,
+                DemandType::StaticPickup | DemandType::DynamicPickup => current + demand,
+                DemandType::None | DemandType::StaticPickupDelivery => current,
+            };
+            let max = max.max_load(current.clone());
+
+            (current, max)
+        })
+        .1)
+}
+
 enum DemandType {
     None,
     StaticPickup,
@@ -167,10 +319,11 @@ enum DemandType {
 
 fn get_demand(
     context: &CheckerContext,
+    tour: &Tour,
     activity: &Activity,
     activity_type: &ActivityType,
 ) -> GenericResult<(DemandType, MultiDimLoad)> {
-    let (is_dynamic, demand) = context.visit_job(
+    let (is_dynamic, parts, demand) = context.visit_job(
         activity,
         activity_type,
         |job, task| {
@@ -178,17 +331,33 @@ fn get_demand(
                 && job.deliveries.as_ref().map_or(false, |p| !p.is_empty());
             let demand = task.demand.clone().map_or_else(MultiDimLoad::default, MultiDimLoad::new);
 
-            (is_dynamic, demand)
+            (is_dynamic, job.divisible.as_ref().map(|divisible| divisible.parts), demand)
         },
-        || (false, MultiDimLoad::default()),
+        || (false, None, MultiDimLoad::default()),
     )?;
 
+    // NOTE divisible job can be served partially, so its demand depends on the served place
+    let demand = match parts {
+        Some(parts) if parts > 0 => get_divisible_demand(context, activity, parts, demand),
+        _ => demand,
+    };
+
+    // NOTE handover drops the cargo in the tour which picks it up and picks it up in the tour which delivers it
+    let is_handover_drop = activity.activity_type == "handover"
+        && tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .any(|other| other.job_id == activity.job_id && other.activity_type == "pickup");
+
     let demand_type = match (is_dynamic, activity.activity_type.as_ref()) {
         (_, "replacement") => DemandType::StaticPickupDelivery,
         (true, "pickup") => DemandType::DynamicPickup,
         (true, "delivery") => DemandType::DynamicDelivery,
         (false, "pickup") => DemandType::StaticPickup,
         (false, "delivery") => DemandType::StaticDelivery,
+        (_, "handover") if is_handover_drop => DemandType::DynamicDelivery,
+        (_, "handover") => DemandType::DynamicPickup,
         _ => DemandType::None,
     };
 
@@ -250,3 +419,23 @@ fn get_activities_from_interval<'a>(
 fn is_reload_stop(context: &CheckerContext, stop: &Stop) -> bool {
     context.get_stop_activity_types(stop).first().map_or(false, |a| a == "reload")
 }
+
+/// Returns a demand of the served part of the divisible job: the amount of not served parts is reported
+/// in the list of unassigned jobs.
+fn get_divisible_demand(
+    context: &CheckerContext,
+    activity: &Activity,
+    parts: usize,
+    demand: MultiDimLoad,
+) -> MultiDimLoad {
+    let remaining = context
+        .solution
+        .unassigned
+        .iter()
+        .flatten()
+        .find(|job| job.job_id == activity.job_id)
+        .and_then(|job| job.remaining.as_ref())
+        .map_or(0, |remaining| remaining.parts);
+
+    demand * (parts.saturating_sub(remaining) as f64 / parts as f64)
+}
@@ -9,7 +9,12 @@ use vrp_core::prelude::GenericResult;
 
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
 pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_shift_limits(context), check_shift_time(context), check_recharge_limits(context)])
+    combine_error_results(&[
+        check_shift_limits(context),
+        check_leg_limits(context),
+        check_shift_time(context),
+        check_recharge_limits(context),
+    ])
 }
 
 /// Check that shift limits are not violated:
@@ -19,7 +24,7 @@ fn check_shift_limits(context: &CheckerContext) -> GenericResult<()> {
     context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
         let vehicle = context.get_vehicle(&tour.vehicle_id)?;
 
-        if let Some(ref limits) = vehicle.limits {
+        if let Some(limits) = vehicle.shift_limits(tour.shift_index) {
             if let Some(max_distance) = limits.max_distance {
                 if tour.statistic.distance as f64 > max_distance {
                     return Err(format!(
@@ -58,6 +63,50 @@ fn check_shift_limits(context: &CheckerContext) -> GenericResult<()> {
     })
 }
 
+/// Check that leg limits are not violated:
+/// * max distance between two consecutive stops
+/// * max duration between two consecutive stops
+fn check_leg_limits(context: &CheckerContext) -> GenericResult<()> {
+    context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
+        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+
+        let Some(limits) = vehicle.shift_limits(tour.shift_index) else { return Ok(()) };
+
+        // NOTE leg is a travel between two consecutive point stops: transit stops do not split it
+        let stops = tour.stops.iter().filter_map(|stop| stop.as_point()).collect::<Vec<_>>();
+
+        if let Some(max_leg_distance) = limits.max_leg_distance {
+            if let Some(distance) = stops
+                .windows(2)
+                .map(|stops| (stops[1].distance - stops[0].distance) as f64)
+                .find(|&distance| distance > max_leg_distance)
+            {
+                return Err(format!(
+                    "max leg distance limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                    max_leg_distance, distance, tour.vehicle_id, tour.shift_index
+                )
+                .into());
+            }
+        }
+
+        if let Some(max_leg_duration) = limits.max_leg_duration {
+            if let Some(duration) = stops
+                .windows(2)
+                .map(|stops| parse_time(&stops[1].time.arrival) - parse_time(&stops[0].time.departure))
+                .find(|&duration| duration > max_leg_duration)
+            {
+                return Err(format!(
+                    "max leg duration limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                    max_leg_duration, duration, tour.vehicle_id, tour.shift_index
+                )
+                .into());
+            }
+        }
+
+        Ok(())
+    })
+}
+
 fn check_shift_time(context: &CheckerContext) -> GenericResult<()> {
     context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
         let vehicle = context.get_vehicle(&tour.vehicle_id)?;
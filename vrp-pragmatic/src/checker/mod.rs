@@ -7,7 +7,7 @@ mod checker_test;
 
 use crate::format::problem::*;
 use crate::format::solution::*;
-use crate::format::{CoordIndex, Location};
+use crate::format::{localize_problem, CoordIndex, Location};
 use crate::parse_time;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
@@ -39,10 +39,11 @@ pub struct CheckerContext {
 #[allow(dead_code)] // NOTE: keep data in each variant for future use
 enum ActivityType {
     Terminal,
-    Job(Job),
+    Job(Box<Job>),
     Break(VehicleBreak),
     Reload(VehicleReload),
     Recharge(VehicleRechargeStation),
+    Parking(VehicleTrailerParking),
 }
 
 impl CheckerContext {
@@ -53,9 +54,14 @@ impl CheckerContext {
         matrices: Option<Vec<Matrix>>,
         solution: Solution,
     ) -> Result<Self, Vec<GenericError>> {
+        let problem = localize_problem(problem);
         let job_map = problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect();
         let clustering = core_problem.extras.get_cluster_config().map(|config| config.as_ref().clone());
         let coord_index = CoordIndex::new(&problem);
+        let matrices = matrices
+            .map(|matrices| apply_matrix_overlays(&problem, matrices, &coord_index))
+            .transpose()
+            .map_err(|err| vec![err])?;
         let profile_index = if matrices.is_none() {
             HashMap::new()
         } else {
@@ -175,10 +181,10 @@ impl CheckerContext {
         match activity.activity_type.as_str() {
             "departure" | "arrival" => Ok(ActivityType::Terminal),
 
-            "pickup" | "delivery" | "service" | "replacement" => {
+            "pickup" | "delivery" | "service" | "replacement" | "handover" => {
                 self.job_map.get(activity.job_id.as_str()).map_or_else(
                     || Err(format!("cannot find job with id '{}'", activity.job_id).into()),
-                    |job| Ok(ActivityType::Job(job.clone())),
+                    |job| Ok(ActivityType::Job(Box::new(job.clone()))),
                 )
             }
 
@@ -217,6 +223,18 @@ impl CheckerContext {
                 .map(|r| ActivityType::Recharge(r.clone()))
                 .ok_or_else(|| format!("cannot find recharge for tour '{}'", tour.vehicle_id).into()),
 
+            "decouple" | "recouple" => self
+                .get_vehicle(&tour.vehicle_id)?
+                .trailer
+                .as_ref()
+                .and_then(|trailer| {
+                    trailer.parkings.iter().find(|p| {
+                        location.as_ref().is_some_and(|location| p.location == *location) && p.tag == activity.job_tag
+                    })
+                })
+                .map(|p| ActivityType::Parking(p.clone()))
+                .ok_or_else(|| format!("cannot find trailer parking for tour '{}'", tour.vehicle_id).into()),
+
             _ => Err(format!("unknown activity type: '{}'", activity.activity_type).into()),
         }
     }
@@ -386,7 +404,8 @@ fn match_job_task<'a>(
     tasks_fn: impl Fn(&'a Vec<JobTask>) -> Option<&'a JobTask>,
 ) -> Option<&'a JobTask> {
     let tasks = match activity_type {
-        "pickup" => job.pickups.as_ref(),
+        // NOTE handover moves the same cargo as pickup
+        "pickup" | "handover" => job.pickups.as_ref(),
         "delivery" => job.deliveries.as_ref(),
         "service" => job.services.as_ref(),
         "replacement" => job.replacements.as_ref(),
@@ -431,6 +450,79 @@ fn get_matrices(matrices: &Option<Vec<Matrix>>) -> GenericResult<&Vec<Matrix>> {
     Ok(matrices)
 }
 
+/// Creates routing matrices for profiles with overlay using routing matrices of their base profiles. Matrices are
+/// returned in the order of profiles.
+fn apply_matrix_overlays(
+    problem: &Problem,
+    matrices: Vec<Matrix>,
+    coord_index: &CoordIndex,
+) -> GenericResult<Vec<Matrix>> {
+    const UNREACHABLE_ERROR_CODE: i64 = 1;
+
+    if problem.fleet.profiles.iter().all(|profile| profile.overlay.is_none()) {
+        return Ok(matrices);
+    }
+
+    let base_profiles =
+        problem.fleet.profiles.iter().filter(|profile| profile.overlay.is_none()).map(|profile| profile.name.as_str());
+    let base_matrices = base_profiles.zip(matrices).collect::<HashMap<_, _>>();
+
+    problem
+        .fleet
+        .profiles
+        .iter()
+        .map(|profile| {
+            let Some(overlay) = profile.overlay.as_ref() else {
+                return base_matrices
+                    .get(profile.name.as_str())
+                    .cloned()
+                    .ok_or_else(|| format!("cannot find matrix for '{}' profile", profile.name).into());
+            };
+
+            let mut matrix = base_matrices
+                .get(overlay.base.as_str())
+                .cloned()
+                .ok_or_else(|| format!("cannot find matrix for '{}' base profile", overlay.base))?;
+            let size = get_matrix_size(std::slice::from_ref(&matrix));
+            let mut error_codes = matrix.error_codes.take().unwrap_or_else(|| vec![0; size * size]);
+
+            overlay.unreachable.iter().flatten().filter_map(|location| coord_index.get_by_loc(location)).for_each(
+                |location| {
+                    (0..size).filter(|&other| other != location).for_each(|other| {
+                        error_codes[location * size + other] = UNREACHABLE_ERROR_CODE;
+                        error_codes[other * size + location] = UNREACHABLE_ERROR_CODE;
+                    })
+                },
+            );
+
+            overlay.edges.iter().flatten().for_each(|edge| {
+                let (Some(from), Some(to)) = (coord_index.get_by_loc(&edge.from), coord_index.get_by_loc(&edge.to))
+                else {
+                    return;
+                };
+                let idx = from * size + to;
+
+                match (edge.duration, edge.distance) {
+                    (None, None) => error_codes[idx] = UNREACHABLE_ERROR_CODE,
+                    (duration, distance) => {
+                        if let Some(duration) = duration {
+                            matrix.travel_times[idx] = duration.round() as i64;
+                        }
+                        if let Some(distance) = distance {
+                            matrix.distances[idx] = distance.round() as i64;
+                        }
+                    }
+                }
+            });
+
+            matrix.profile = Some(profile.name.clone());
+            matrix.error_codes = Some(error_codes);
+
+            Ok(matrix)
+        })
+        .collect()
+}
+
 fn get_profile_index(problem: &Problem, matrices: &[Matrix]) -> GenericResult<HashMap<String, usize>> {
     let profiles = problem.fleet.profiles.len();
     if profiles != matrices.len() {
@@ -455,7 +547,7 @@ mod assignment;
 use crate::checker::assignment::check_assignment;
 
 mod capacity;
-use crate::checker::capacity::check_vehicle_load;
+use crate::checker::capacity::{check_vehicle_load, get_max_truck_load};
 
 mod limits;
 use crate::checker::limits::check_limits;
@@ -42,14 +42,17 @@ impl CoordIndex {
                     index.add(&place.location);
                 });
         });
+        problem.plan.transfers.iter().flatten().for_each(|transfer| index.add(&transfer.location));
 
         // process fleet
         problem.fleet.vehicles.iter().for_each(|vehicle| {
             vehicle.shifts.iter().for_each(|shift| {
                 index.add(&shift.start.location);
+                shift.start.alternatives.iter().flatten().for_each(|alternative| index.add(&alternative.location));
 
                 if let Some(end) = &shift.end {
                     index.add(&end.location);
+                    end.alternatives.iter().flatten().for_each(|alternative| index.add(&alternative.location));
                 }
 
                 if let Some(breaks) = &shift.breaks {
@@ -72,6 +75,10 @@ impl CoordIndex {
                     recharges.stations.iter().for_each(|station| index.add(&station.location));
                 }
             });
+
+            vehicle.trailer.iter().flat_map(|trailer| trailer.parkings.iter()).for_each(|parking| {
+                index.add(&parking.location);
+            });
         });
 
         index.max_matrix_index = index.direct_index.len().max(1) - 1;
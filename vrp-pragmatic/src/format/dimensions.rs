@@ -10,6 +10,10 @@ custom_dimension!(ShiftIndex typeof usize);
 
 custom_dimension!(TourSize typeof usize);
 
+custom_dimension!(ShiftStartTag typeof String);
+
+custom_dimension!(ShiftEndTag typeof String);
+
 custom_dimension!(PlaceTags typeof Vec<(usize, String)>);
 
 custom_dimension!(JobOrder typeof i32);
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
 use vrp_core::construction::enablers::ReservedTimesIndex;
-use vrp_core::models::common::{Distance, Duration};
+use vrp_core::models::common::Duration;
 use vrp_core::models::problem::{Job as CoreJob, Single, VehicleIdDimension};
 use vrp_core::models::solution::Route;
 use vrp_core::models::{Extras as CoreExtras, Problem as CoreProblem, ViolationCode};
@@ -23,6 +23,10 @@ pub use self::dimensions::*;
 mod location_fallback;
 pub use self::location_fallback::*;
 
+mod timezones;
+pub use self::timezones::TimezoneIndex;
+pub(crate) use self::timezones::*;
+
 pub mod problem;
 pub mod solution;
 
@@ -195,19 +199,28 @@ const GROUP_CONSTRAINT_CODE: ViolationCode = ViolationCode(12);
 const COMPATIBILITY_CONSTRAINT_CODE: ViolationCode = ViolationCode(13);
 const RELOAD_RESOURCE_CONSTRAINT_CODE: ViolationCode = ViolationCode(14);
 const RECHARGE_CONSTRAINT_CODE: ViolationCode = ViolationCode(15);
+const VEHICLE_ALTERNATIVE_CONSTRAINT_CODE: ViolationCode = ViolationCode(16);
+const TRANSFER_CONSTRAINT_CODE: ViolationCode = ViolationCode(17);
+const CONFLICT_CONSTRAINT_CODE: ViolationCode = ViolationCode(18);
+const LEG_DISTANCE_LIMIT_CONSTRAINT_CODE: ViolationCode = ViolationCode(19);
+const LEG_DURATION_LIMIT_CONSTRAINT_CODE: ViolationCode = ViolationCode(20);
+const TRAILER_CONSTRAINT_CODE: ViolationCode = ViolationCode(21);
+const BACKHAUL_CONSTRAINT_CODE: ViolationCode = ViolationCode(22);
+const ROBUST_SCHEDULE_CONSTRAINT_CODE: ViolationCode = ViolationCode(23);
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
 
-pub use self::properties::{CoordIndexExtraProperty, JobIndexExtraProperty};
+pub use self::properties::{CoordIndexExtraProperty, JobIndexExtraProperty, TimezoneIndexExtraProperty};
 
 mod properties {
-    use crate::format::{CoordIndex, JobIndex};
+    use crate::format::{CoordIndex, JobIndex, TimezoneIndex};
     use vrp_core::custom_extra_property;
     use vrp_core::models::Extras;
 
     custom_extra_property!(JobIndex typeof JobIndex);
     custom_extra_property!(CoordIndex typeof CoordIndex);
+    custom_extra_property!(TimezoneIndex typeof TimezoneIndex);
 }
 
 /// Get job and coord indices from extras
@@ -218,6 +231,11 @@ pub fn get_indices(extras: &CoreExtras) -> Result<(Arc<JobIndex>, Arc<CoordIndex
     Ok((job_index, coord_index))
 }
 
+/// Returns a job index key of the transfer leg (1 or 2) of the job with given id.
+pub(crate) fn get_transfer_leg_key(job_id: &str, leg: usize) -> String {
+    format!("{job_id}_transfer_{leg}")
+}
+
 /// Checks whether the given single job can be assigned to the given route taking into consideration
 /// its id and shift index.
 pub(crate) fn is_correct_vehicle(route: &Route, single: &Single) -> bool {
@@ -0,0 +1,293 @@
+//! Specifies logic to read and write routing matrix in a compact binary format.
+//!
+//! All numbers are stored in little-endian byte order:
+//!
+//! | size          | description                                                                 |
+//! |---------------|-----------------------------------------------------------------------------|
+//! | 4             | magic bytes: `VRPM`                                                         |
+//! | 1             | format version, currently `1`                                               |
+//! | 1             | flags: bit 0 is set when payload is zlib compressed, bit 1 when error codes are present, bit 2 when values are stored as `i64` |
+//! | 2 + N         | profile name: length as `u16` and UTF-8 bytes, zero length means no profile |
+//! | 2 + N         | timestamp in RFC3339: length as `u16` and UTF-8 bytes, zero length means no timestamp |
+//! | 4             | amount of locations `n` as `u32`                                            |
+//! | payload       | `n * n` travel times, `n * n` distances and optionally `n * n` error codes as `i32` or `i64` |
+//!
+//! Values are written as `i32` when all of them fit into its range, otherwise as `i64`.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/binary_matrix_test.rs"]
+mod binary_matrix_test;
+
+use super::Matrix;
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Error, ErrorKind, Read, Result, Write};
+use std::path::Path;
+use std::sync::Arc;
+use vrp_core::models::problem::{FileMatrixValues, MatrixValues};
+
+/// Magic bytes which are used to detect routing matrix in binary format.
+pub const BINARY_MATRIX_MAGIC: &[u8; 4] = b"VRPM";
+
+const BINARY_MATRIX_VERSION: u8 = 1;
+const COMPRESSED_FLAG: u8 = 0b01;
+const ERROR_CODES_FLAG: u8 = 0b10;
+const WIDE_VALUES_FLAG: u8 = 0b100;
+const KNOWN_FLAGS: u8 = COMPRESSED_FLAG | ERROR_CODES_FLAG | WIDE_VALUES_FLAG;
+
+/// Travel times, distances and optional error codes.
+type MatrixPayload = (Vec<i64>, Vec<i64>, Option<Vec<i64>>);
+
+/// Keeps values of binary routing matrix which are read from the file on demand.
+pub struct BinaryMatrixValues {
+    /// A routing profile name.
+    pub profile: Option<String>,
+    /// Travel durations.
+    pub durations: Arc<dyn MatrixValues>,
+    /// Travel distances.
+    pub distances: Arc<dyn MatrixValues>,
+}
+
+/// Checks whether data provided by reader starts with binary matrix magic bytes. Nothing is consumed.
+pub fn is_binary_matrix<R: BufRead>(reader: &mut R) -> Result<bool> {
+    reader.fill_buf().map(|buffer| buffer.starts_with(BINARY_MATRIX_MAGIC))
+}
+
+/// Checks whether data provided by reader is a binary matrix with zlib compressed payload. Nothing is consumed.
+pub fn is_compressed_binary_matrix<R: BufRead>(reader: &mut R) -> Result<bool> {
+    reader.fill_buf().map(|buffer| {
+        buffer.starts_with(BINARY_MATRIX_MAGIC) && buffer.get(5).is_some_and(|flags| flags & COMPRESSED_FLAG != 0)
+    })
+}
+
+/// Reads routing matrix in binary format. Values are decoded on the fly, so no intermediate copy of
+/// the whole (decompressed) payload is kept in memory.
+pub fn read_binary_matrix<R: Read>(mut reader: R) -> Result<Matrix> {
+    let mut magic = [0_u8; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != BINARY_MATRIX_MAGIC {
+        return Err(Error::new(ErrorKind::InvalidData, "unknown magic bytes"));
+    }
+
+    let [version, flags] = read_bytes::<2>(&mut reader)?;
+    if version != BINARY_MATRIX_VERSION {
+        return Err(Error::new(ErrorKind::InvalidData, format!("unsupported version: {version}")));
+    }
+
+    if flags & !KNOWN_FLAGS != 0 {
+        return Err(Error::new(ErrorKind::InvalidData, format!("unsupported flags: {flags:#010b}")));
+    }
+
+    let profile = read_string(&mut reader)?;
+    let timestamp = read_string(&mut reader)?;
+    let size = u32::from_le_bytes(read_bytes::<4>(&mut reader)?) as usize;
+    let length = size
+        .checked_mul(size)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("too many locations: {size}")))?;
+
+    let is_wide = flags & WIDE_VALUES_FLAG != 0;
+    let read_payload = |reader: &mut dyn Read| -> Result<MatrixPayload> {
+        let travel_times = read_values(reader, length, is_wide)?;
+        let distances = read_values(reader, length, is_wide)?;
+        let error_codes =
+            if flags & ERROR_CODES_FLAG != 0 { Some(read_values(reader, length, is_wide)?) } else { None };
+
+        Ok((travel_times, distances, error_codes))
+    };
+
+    let (travel_times, distances, error_codes) = if flags & COMPRESSED_FLAG != 0 {
+        read_payload(&mut ZlibDecoder::new(reader))?
+    } else {
+        read_payload(&mut reader)?
+    };
+
+    Ok(Matrix { profile, timestamp, travel_times, distances, error_codes })
+}
+
+/// Opens routing matrix in uncompressed binary format as file backed values, so they are not loaded into memory.
+/// Values can be passed to `create_matrix_transport_cost_with_values` function of `vrp-core` crate. Time aware
+/// matrices are not supported.
+pub fn open_binary_matrix_values(path: &Path) -> Result<BinaryMatrixValues> {
+    let mut reader = BufReader::new(File::open(path)?);
+
+    let mut magic = [0_u8; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != BINARY_MATRIX_MAGIC {
+        return Err(Error::new(ErrorKind::InvalidData, "unknown magic bytes"));
+    }
+
+    let [version, flags] = read_bytes::<2>(&mut reader)?;
+    if version != BINARY_MATRIX_VERSION || flags & !KNOWN_FLAGS != 0 {
+        return Err(Error::new(ErrorKind::InvalidData, format!("unsupported version or flags: {version}, {flags}")));
+    }
+
+    if flags & COMPRESSED_FLAG != 0 {
+        return Err(Error::new(ErrorKind::InvalidData, "compressed binary matrix cannot be read on demand"));
+    }
+
+    let profile = read_string(&mut reader)?;
+    if read_string(&mut reader)?.is_some() {
+        return Err(Error::new(ErrorKind::InvalidData, "time aware binary matrix cannot be read on demand"));
+    }
+
+    let size = u32::from_le_bytes(read_bytes::<4>(&mut reader)?) as u64;
+    let header = 4 + 2 + 2 + profile.as_ref().map_or(0, |profile| profile.len()) as u64 + 2 + 4;
+    let width = if flags & WIDE_VALUES_FLAG != 0 { 8 } else { 4 };
+    let length = size * size;
+
+    let open = |array: u64| -> Result<FileMatrixValues> {
+        FileMatrixValues::new(File::open(path)?, header + array * length * width as u64, length as usize, width)
+            .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))
+    };
+
+    let (durations, distances): (Arc<dyn MatrixValues>, Arc<dyn MatrixValues>) = if flags & ERROR_CODES_FLAG != 0 {
+        (
+            Arc::new(UnreachableMatrixValues { values: open(0)?, error_codes: open(2)? }),
+            Arc::new(UnreachableMatrixValues { values: open(1)?, error_codes: open(2)? }),
+        )
+    } else {
+        (Arc::new(open(0)?), Arc::new(open(1)?))
+    };
+
+    Ok(BinaryMatrixValues { profile, durations, distances })
+}
+
+/// Writes routing matrix in binary format, optionally compressing its payload.
+pub fn write_binary_matrix<W: Write>(matrix: &Matrix, is_compressed: bool, mut writer: W) -> Result<()> {
+    let length = matrix.travel_times.len();
+    let size = (length as f64).sqrt().round() as usize;
+
+    if size * size != length
+        || matrix.distances.len() != length
+        || matrix.error_codes.as_ref().is_some_and(|codes| codes.len() != length)
+    {
+        return Err(Error::new(ErrorKind::InvalidInput, "matrix is not square or has arrays of different length"));
+    }
+
+    let is_wide = matrix
+        .travel_times
+        .iter()
+        .chain(matrix.distances.iter())
+        .chain(matrix.error_codes.iter().flatten())
+        .any(|&value| i32::try_from(value).is_err());
+
+    let flags = if is_compressed { COMPRESSED_FLAG } else { 0 }
+        | if matrix.error_codes.is_some() { ERROR_CODES_FLAG } else { 0 }
+        | if is_wide { WIDE_VALUES_FLAG } else { 0 };
+
+    writer.write_all(BINARY_MATRIX_MAGIC)?;
+    writer.write_all(&[BINARY_MATRIX_VERSION, flags])?;
+    write_string(&mut writer, matrix.profile.as_deref())?;
+    write_string(&mut writer, matrix.timestamp.as_deref())?;
+    writer.write_all(&(size as u32).to_le_bytes())?;
+
+    let write_payload = |writer: &mut dyn Write| -> Result<()> {
+        write_values(writer, matrix.travel_times.as_slice(), is_wide)?;
+        write_values(writer, matrix.distances.as_slice(), is_wide)?;
+        matrix.error_codes.as_ref().map_or(Ok(()), |codes| write_values(writer, codes.as_slice(), is_wide))
+    };
+
+    if is_compressed {
+        let mut encoder = ZlibEncoder::new(&mut writer, Compression::default());
+        write_payload(&mut encoder)?;
+        encoder.finish()?;
+    } else {
+        write_payload(&mut writer)?;
+    }
+
+    writer.flush()
+}
+
+fn read_bytes<const N: usize>(reader: &mut impl Read) -> Result<[u8; N]> {
+    let mut buffer = [0_u8; N];
+    reader.read_exact(&mut buffer)?;
+
+    Ok(buffer)
+}
+
+fn read_string(reader: &mut impl Read) -> Result<Option<String>> {
+    let length = u16::from_le_bytes(read_bytes::<2>(reader)?) as usize;
+    if length == 0 {
+        return Ok(None);
+    }
+
+    let mut buffer = vec![0_u8; length];
+    reader.read_exact(buffer.as_mut_slice())?;
+
+    String::from_utf8(buffer).map(Some).map_err(|err| Error::new(ErrorKind::InvalidData, err))
+}
+
+fn read_values(reader: &mut dyn Read, length: usize, is_wide: bool) -> Result<Vec<i64>> {
+    const CHUNK_SIZE: usize = 16 * 1024;
+
+    let width = if is_wide { 8 } else { 4 };
+
+    // NOTE do not trust the header blindly when preallocating memory
+    let mut values = Vec::with_capacity(length.min(1 << 24));
+    let mut buffer = [0_u8; CHUNK_SIZE * 8];
+
+    while values.len() < length {
+        let bytes = (length - values.len()).min(CHUNK_SIZE) * width;
+        reader.read_exact(&mut buffer[..bytes])?;
+
+        let chunks = buffer[..bytes].chunks_exact(width);
+        if is_wide {
+            values.extend(chunks.map(|value| i64::from_le_bytes(value.try_into().unwrap())));
+        } else {
+            values.extend(chunks.map(|value| i32::from_le_bytes(value.try_into().unwrap()) as i64));
+        }
+    }
+
+    Ok(values)
+}
+
+fn write_string(writer: &mut impl Write, value: Option<&str>) -> Result<()> {
+    let value = value.unwrap_or_default().as_bytes();
+    let length = u16::try_from(value.len()).map_err(|_| Error::new(ErrorKind::InvalidInput, "string is too long"))?;
+
+    writer.write_all(&length.to_le_bytes())?;
+    writer.write_all(value)
+}
+
+fn write_values(writer: &mut dyn Write, values: &[i64], is_wide: bool) -> Result<()> {
+    values.chunks(16 * 1024).try_for_each(|chunk| {
+        let mut bytes = Vec::with_capacity(chunk.len() * if is_wide { 8 } else { 4 });
+
+        chunk.iter().try_for_each(|&value| {
+            if is_wide {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            } else {
+                let value = i32::try_from(value).map_err(|_| {
+                    Error::new(ErrorKind::InvalidInput, format!("value is out of range for binary matrix: {value}"))
+                })?;
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+
+            Ok::<_, Error>(())
+        })?;
+
+        writer.write_all(bytes.as_slice())
+    })
+}
+
+/// Marks values with positive error code as unreachable.
+struct UnreachableMatrixValues {
+    values: FileMatrixValues,
+    error_codes: FileMatrixValues,
+}
+
+impl MatrixValues for UnreachableMatrixValues {
+    fn size(&self) -> usize {
+        self.values.size()
+    }
+
+    fn get(&self, index: usize) -> Option<f64> {
+        if self.error_codes.get(index).is_some_and(|code| code > 0.) {
+            Some(-1.)
+        } else {
+            self.values.get(index)
+        }
+    }
+}
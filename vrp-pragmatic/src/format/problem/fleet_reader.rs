@@ -5,12 +5,14 @@ mod fleet_reader_test;
 use super::*;
 use crate::format::UnknownLocationFallback;
 use crate::get_unique_locations;
-use crate::utils::get_approx_transportation;
+use crate::utils::{get_approx_transportation, ApproxProfile};
 use crate::Location as ApiLocation;
-use std::cmp::Ordering;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use vrp_core::construction::enablers::create_typed_actor_groups;
-use vrp_core::construction::features::{VehicleCapacityDimension, VehicleSkillsDimension};
+use vrp_core::construction::features::{
+    BackhaulPolicy, VehicleAlternativeGroupDimension, VehicleBackhaulPolicyDimension, VehicleCapacityDimension,
+    VehicleSkillLevelsDimension, VehicleSkillsDimension, VehicleTruckCapacityDimension,
+};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::*;
 
@@ -37,133 +39,285 @@ pub(super) fn create_transport_costs(
     }
 
     let matrix_profiles = get_profile_index_map(api_problem);
-    if matrix_profiles.len() > matrices.len() {
+    let overlays = api_problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.overlay.as_ref().map(|overlay| (profile, overlay)))
+        .collect::<Vec<_>>();
+    // NOTE profiles with overlay reuse routing matrix of their base profile
+    let base_profiles = api_problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| profile.overlay.is_none())
+        .map(|profile| matrix_profiles[&profile.name])
+        .collect::<Vec<_>>();
+
+    if base_profiles.len() > matrices.len() {
         return Err(format!(
             "not enough routing matrices specified for fleet profiles defined: \
              {} must be less or equal to {}",
-            matrix_profiles.len(),
+            base_profiles.len(),
             matrices.len()
         )
         .into());
     }
 
-    let matrix_data = matrices
+    if let Some((profile, _)) = overlays.iter().find(|(profile, _)| {
+        matrices.iter().any(|matrix| matrix.profile.as_ref().is_some_and(|name| *name == profile.name))
+    }) {
+        return Err(
+            format!("routing matrix should not be specified for profile with overlay: '{}'", profile.name).into()
+        );
+    }
+
+    let values = matrices
         .iter()
-        .enumerate()
-        .map(|(idx, matrix)| {
-            let profile = matrix.profile.as_ref().and_then(|p| matrix_profiles.get(p)).cloned().unwrap_or(idx);
-            (profile, matrix.timestamp.clone(), matrix)
+        .map(|matrix| {
+            let error_codes = matrix.error_codes.as_deref();
+            (
+                ApiMatrixValues { values: matrix.travel_times.as_slice(), error_codes },
+                ApiMatrixValues { values: matrix.distances.as_slice(), error_codes },
+            )
         })
-        .map(|(profile, timestamp, matrix)| {
-            let (durations, distances) = if let Some(error_codes) = &matrix.error_codes {
-                let capacity = matrix.distances.len();
-
-                let mut durations: Vec<Duration> = Vec::with_capacity(capacity);
-                let mut distances: Vec<Distance> = Vec::with_capacity(capacity);
-                for (i, error) in error_codes.iter().enumerate() {
-                    if *error > 0 {
-                        durations.push(-1.);
-                        distances.push(-1.);
-                    } else {
-                        durations.push(*matrix.travel_times.get(i).unwrap() as f64);
-                        distances.push(*matrix.distances.get(i).unwrap() as f64);
-                    }
-                }
-                (durations, distances)
-            } else {
-                (
-                    matrix.travel_times.iter().map(|d| *d as f64).collect(),
-                    matrix.distances.iter().map(|d| *d as f64).collect(),
-                )
-            };
+        .collect::<Vec<_>>();
+
+    let mut sources = matrices
+        .iter()
+        .zip(values.iter())
+        .enumerate()
+        .map(|(idx, (matrix, (durations, distances)))| {
+            let profile = matrix
+                .profile
+                .as_ref()
+                .and_then(|p| matrix_profiles.get(p))
+                .cloned()
+                .unwrap_or_else(|| base_profiles.get(idx).cloned().unwrap_or(idx));
+            let timestamp = matrix.timestamp.as_ref().map(|t| parse_time(t));
 
-            MatrixData::new(profile, timestamp.map(|t| parse_time(&t)), durations, distances)
+            MatrixSource::new(profile, timestamp, durations, distances)
         })
         .collect::<Vec<_>>();
 
-    let matrix_indices = matrix_data.iter().map(|data| data.index).collect::<HashSet<_>>().len();
+    for (profile, overlay) in overlays.iter() {
+        let base_index =
+            matrix_profiles.get(&overlay.base).filter(|index| base_profiles.contains(index)).ok_or_else(|| {
+                format!("unknown base profile '{}' in overlay of '{}' profile", overlay.base, profile.name)
+            })?;
+        let index = matrix_profiles[&profile.name];
+
+        // NOTE matrix values of base profile are referenced, so they are shared between profiles in matrix storage
+        let overlay_sources = sources
+            .iter()
+            .filter(|source| source.index == *base_index)
+            .map(|source| MatrixSource::new(index, source.timestamp, source.durations, source.distances))
+            .collect::<Vec<_>>();
+
+        sources.extend(overlay_sources);
+    }
+
+    let matrix_indices = sources.iter().map(|source| source.index).collect::<HashSet<_>>().len();
     if matrix_profiles.len() != matrix_indices {
         return Err("amount of fleet profiles does not match matrix profiles".into());
     }
 
-    if coord_index.has_custom() {
-        create_matrix_transport_cost_with_fallback(matrix_data, UnknownLocationFallback::new(coord_index))
+    // NOTE api matrices have integer values, so they can be kept in compact storage without precision loss
+    let storage = match api_problem.matrix_storage {
+        Some(MatrixStorageType::Compact) => MatrixStorage::Integer,
+        Some(MatrixStorageType::Full) | None => MatrixStorage::Full,
+    };
+
+    let transport = if coord_index.has_custom() {
+        create_matrix_transport_cost_with_sources(sources, UnknownLocationFallback::new(coord_index.clone()), storage)
+    } else {
+        create_matrix_transport_cost_with_sources(sources, NoFallback, storage)
+    }?;
+
+    let transport = if overlays.is_empty() {
+        transport
+    } else {
+        let get_indices = |locations: &[ApiLocation]| {
+            locations.iter().filter_map(|location| coord_index.get_by_loc(location)).collect::<Vec<_>>()
+        };
+
+        let overlays = overlays
+            .iter()
+            .map(|(profile, overlay)| {
+                let unreachable = get_indices(overlay.unreachable.as_deref().unwrap_or_default());
+                let edges = overlay
+                    .edges
+                    .iter()
+                    .flatten()
+                    .filter_map(|edge| {
+                        let from = coord_index.get_by_loc(&edge.from)?;
+                        let to = coord_index.get_by_loc(&edge.to)?;
+
+                        Some((from, to, edge.duration, edge.distance))
+                    })
+                    .collect();
+
+                TransportOverlay::new(matrix_profiles[&profile.name], unreachable, edges)
+            })
+            .collect();
+
+        create_overlay_transport_cost(transport, overlays)?
+    };
+
+    let speed_profiles = api_problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| {
+            profile.speed_bands.as_ref().map(|bands| {
+                let bands = bands.iter().map(|band| (parse_time(&band.start), band.factor)).collect();
+                SpeedProfile::new(matrix_profiles[&profile.name], 0, bands)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    if speed_profiles.is_empty() {
+        Ok(transport)
+    } else if matrices.iter().any(|matrix| matrix.timestamp.is_some()) {
+        Err("speed bands cannot be used with time dependent routing matrices".into())
     } else {
-        create_matrix_transport_cost(matrix_data)
+        create_speed_profile_transport_cost(transport, speed_profiles, None)
     }
 }
 
 pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: &CoordIndex) -> CoreFleet {
     let profile_indices = get_profile_index_map(api_problem);
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
+    let mut alternative_group = 0;
 
     api_problem.fleet.vehicles.iter().for_each(|vehicle| {
-        let costs = Costs {
-            fixed: vehicle.costs.fixed.unwrap_or(0.),
-            per_distance: vehicle.costs.distance,
-            per_driving_time: vehicle.costs.time,
-            per_waiting_time: vehicle.costs.time,
-            per_service_time: vehicle.costs.time,
-        };
-
         let index = *profile_indices.get(&vehicle.profile.matrix).unwrap();
         let profile = Profile::new(index, vehicle.profile.scale);
 
-        let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
-
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
+            let costs = {
+                let costs = vehicle.shift_costs(shift_index);
+                Costs {
+                    fixed: costs.fixed.unwrap_or(0.),
+                    per_distance: costs.distance,
+                    per_driving_time: costs.time,
+                    per_waiting_time: costs.time,
+                    per_service_time: costs.time,
+                }
+            };
+
+            let capacity = vehicle.shift_total_capacity(shift_index);
+            let truck_capacity = vehicle.trailer.as_ref().map(|_| vehicle.shift_capacity(shift_index));
+            let skills = vehicle.shift_skills(shift_index);
+            let tour_size = vehicle.shift_limits(shift_index).and_then(|l| l.tour_size);
+
             let start = {
-                let location = coord_index.get_by_loc(&shift.start.location).unwrap();
                 let earliest = parse_time(&shift.start.earliest);
                 let latest = shift.start.latest.as_ref().map(|time| parse_time(time));
-                (location, earliest, latest)
+                (earliest, latest)
             };
 
-            let end = shift.end.as_ref().map(|end| {
-                let location = coord_index.get_by_loc(&end.location).unwrap();
-                let time = parse_time(&end.latest);
-                (location, time)
-            });
+            let end = shift.end.as_ref().map(|end| parse_time(&end.latest));
+
+            let start_places = get_shift_places(&shift.start.location, shift.start.alternatives.as_ref(), coord_index);
+            let end_places = shift.end.as_ref().map_or_else(
+                || vec![None],
+                |end| {
+                    get_shift_places(&end.location, end.alternatives.as_ref(), coord_index)
+                        .into_iter()
+                        .map(Some)
+                        .collect()
+                },
+            );
 
-            let details = vec![VehicleDetail {
-                start: Some(VehiclePlace {
-                    location: start.0,
-                    time: TimeInterval { earliest: Some(start.1), latest: start.2 },
-                }),
-                end: end.map(|(location, time)| VehiclePlace {
-                    location,
-                    time: TimeInterval { earliest: None, latest: Some(time) },
-                }),
-            }];
+            // NOTE each combination of start and end locations is modeled as a separate vehicle,
+            // amount of combinations is limited by E1309 validation rule
+            let alternatives = start_places
+                .iter()
+                .flat_map(|start_place| end_places.iter().map(move |end_place| (start_place, end_place)))
+                .collect::<Vec<_>>();
+            let has_alternatives = alternatives.len() > 1;
 
             vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
-                let mut dimens: Dimensions = Default::default();
+                alternatives.iter().for_each(|(start_place, end_place)| {
+                    let mut dimens: Dimensions = Default::default();
 
-                dimens
-                    .set_vehicle_type(vehicle.type_id.clone())
-                    .set_shift_index(shift_index)
-                    .set_vehicle_id(vehicle_id.to_string());
+                    dimens
+                        .set_vehicle_type(vehicle.type_id.clone())
+                        .set_shift_index(shift_index)
+                        .set_vehicle_id(vehicle_id.to_string());
 
-                if let Some(tour_size) = tour_size {
-                    dimens.set_tour_size(tour_size);
-                }
+                    if has_alternatives {
+                        dimens.set_vehicle_alternative_group(alternative_group);
+                    }
 
-                if props.has_multi_dimen_capacity {
-                    dimens.set_vehicle_capacity(MultiDimLoad::new(vehicle.capacity.clone()));
-                } else {
-                    dimens.set_vehicle_capacity(SingleDimLoad::new(*vehicle.capacity.first().unwrap()));
-                }
+                    if let Some(tag) = start_place.tag.clone() {
+                        dimens.set_shift_start_tag(tag);
+                    }
 
-                if let Some(skills) = vehicle.skills.as_ref() {
-                    dimens.set_vehicle_skills(skills.iter().cloned().collect::<HashSet<_>>());
-                }
+                    if let Some(tag) = end_place.as_ref().and_then(|end_place| end_place.tag.clone()) {
+                        dimens.set_shift_end_tag(tag);
+                    }
+
+                    if let Some(tour_size) = tour_size {
+                        dimens.set_tour_size(tour_size);
+                    }
+
+                    if props.has_multi_dimen_capacity {
+                        dimens.set_vehicle_capacity(MultiDimLoad::new(capacity.clone()));
+                    } else {
+                        dimens.set_vehicle_capacity(SingleDimLoad::new(*capacity.first().unwrap() as i32));
+                    }
+
+                    match truck_capacity {
+                        Some(truck_capacity) if props.has_multi_dimen_capacity => {
+                            dimens.set_vehicle_truck_capacity(MultiDimLoad::new(truck_capacity.clone()));
+                        }
+                        Some(truck_capacity) => {
+                            let truck_capacity = truck_capacity.first().copied().unwrap_or_default() as i32;
+                            dimens.set_vehicle_truck_capacity(SingleDimLoad::new(truck_capacity));
+                        }
+                        None => {}
+                    }
+
+                    if let Some(backhaul) = vehicle.backhaul.as_ref() {
+                        dimens.set_vehicle_backhaul_policy(match backhaul {
+                            VehicleBackhaul::Strict => BackhaulPolicy::Strict,
+                            VehicleBackhaul::Mixed { penalty } => BackhaulPolicy::Mixed { penalty: *penalty },
+                        });
+                    }
 
-                vehicles.push(Arc::new(Vehicle {
-                    profile: profile.clone(),
-                    costs: costs.clone(),
-                    dimens,
-                    details: details.clone(),
-                }));
+                    if let Some(skills) = skills {
+                        let (names, levels) = get_vehicle_skills(skills);
+                        dimens.set_vehicle_skills(names);
+
+                        if !levels.is_empty() {
+                            dimens.set_vehicle_skill_levels(levels);
+                        }
+                    }
+
+                    let details = vec![VehicleDetail {
+                        start: Some(VehiclePlace {
+                            location: start_place.location,
+                            time: TimeInterval { earliest: Some(start.0), latest: start.1 },
+                        }),
+                        end: end_place.as_ref().zip(end).map(|(end_place, time)| VehiclePlace {
+                            location: end_place.location,
+                            time: TimeInterval { earliest: None, latest: Some(time) },
+                        }),
+                    }];
+
+                    let place_cost = start_place.cost + end_place.as_ref().map_or(0., |end_place| end_place.cost);
+
+                    vehicles.push(Arc::new(Vehicle {
+                        profile: profile.clone(),
+                        costs: Costs { fixed: costs.fixed + place_cost, ..costs.clone() },
+                        dimens,
+                        details,
+                    }));
+                });
+
+                alternative_group += 1;
             });
         }
     });
@@ -187,35 +341,88 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
     })
 }
 
+/// Provides access to integer values of api matrix: values with positive error code are read as unreachable.
+struct ApiMatrixValues<'a> {
+    values: &'a [i64],
+    error_codes: Option<&'a [i64]>,
+}
+
+impl MatrixValues for ApiMatrixValues<'_> {
+    fn size(&self) -> usize {
+        self.values.len()
+    }
+
+    fn get(&self, index: usize) -> Option<f64> {
+        if self.error_codes.and_then(|codes| codes.get(index)).is_some_and(|&code| code > 0) {
+            Some(-1.)
+        } else {
+            self.values.get(index).map(|&value| value as f64)
+        }
+    }
+}
+
+/// Specifies a shift start or end place with its extra cost and tag.
+struct ShiftPlace {
+    location: usize,
+    cost: Cost,
+    tag: Option<String>,
+}
+
+fn get_shift_places(
+    location: &ApiLocation,
+    alternatives: Option<&Vec<ShiftLocation>>,
+    coord_index: &CoordIndex,
+) -> Vec<ShiftPlace> {
+    std::iter::once(ShiftPlace { location: coord_index.get_by_loc(location).unwrap(), cost: 0., tag: None })
+        .chain(alternatives.into_iter().flatten().map(|alternative| ShiftPlace {
+            location: coord_index.get_by_loc(&alternative.location).unwrap(),
+            cost: alternative.cost.unwrap_or(0.),
+            tag: alternative.tag.clone(),
+        }))
+        .collect()
+}
+
 /// Creates a matrices using approximation.
 pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
     const DEFAULT_SPEED: f64 = 10.;
-    // get each speed value once
-    let speeds = problem
-        .fleet
-        .profiles
-        .iter()
-        .map(|profile| profile.speed.unwrap_or(DEFAULT_SPEED))
-        .map(|speed| speed.to_bits())
-        .collect::<HashSet<u64>>();
-    let speeds = speeds.into_iter().map(f64::from_bits).collect::<Vec<_>>();
+    const DEFAULT_DETOUR_FACTOR: f64 = 1.;
+
+    let get_approx_profile = |profile: &MatrixProfile| {
+        let distance_model = profile.distance_model.as_ref();
+        ApproxProfile::new(
+            distance_model.map_or(DistanceType::Haversine, |model| model.distance_type),
+            distance_model.and_then(|model| model.detour_factor).unwrap_or(DEFAULT_DETOUR_FACTOR),
+            profile.speed.unwrap_or(DEFAULT_SPEED),
+        )
+    };
+
+    let base_profiles = problem.fleet.profiles.iter().filter(|profile| profile.overlay.is_none()).collect::<Vec<_>>();
+
+    // get each approximation profile once
+    let approx_profiles = base_profiles.iter().map(|profile| get_approx_profile(profile)).fold(
+        Vec::<ApproxProfile>::default(),
+        |mut acc, approx_profile| {
+            if !acc.contains(&approx_profile) {
+                acc.push(approx_profile);
+            }
+            acc
+        },
+    );
 
     let locations = get_unique_locations(problem)
         .into_iter()
         .filter(|location| !matches!(location, ApiLocation::Custom { .. }))
         .collect::<Vec<_>>();
-    let approx_data = get_approx_transportation(&locations, speeds.as_slice());
+    let approx_data = get_approx_transportation(&locations, approx_profiles.as_slice());
 
-    problem
-        .fleet
-        .profiles
-        .iter()
-        .map(move |profile| {
-            let speed = profile.speed.unwrap_or(DEFAULT_SPEED);
-            let idx = speeds
+    base_profiles
+        .into_iter()
+        .map(|profile| {
+            let approx_profile = get_approx_profile(profile);
+            let idx = approx_profiles
                 .iter()
-                .position(|s| compare_floats(*s, speed) == Ordering::Equal)
-                .expect("Cannot find profile speed");
+                .position(|other| *other == approx_profile)
+                .expect("Cannot find approximation profile");
 
             Matrix {
                 profile: Some(profile.name.clone()),
@@ -227,3 +434,19 @@ pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
         })
         .collect()
 }
+
+fn get_vehicle_skills(skills: &[VehicleSkill]) -> (HashSet<String>, HashMap<String, u32>) {
+    skills.iter().fold((HashSet::default(), HashMap::default()), |(mut names, mut levels), skill| {
+        match skill {
+            VehicleSkill::Plain(name) => {
+                names.insert(name.clone());
+            }
+            VehicleSkill::Leveled(SkillLevel { name, level }) => {
+                names.insert(name.clone());
+                levels.insert(name.clone(), *level);
+            }
+        }
+
+        (names, levels)
+    })
+}
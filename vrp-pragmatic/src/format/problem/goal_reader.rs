@@ -1,10 +1,15 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/goal_reader_test.rs"]
+mod goal_reader_test;
+
 use super::*;
+use crate::format::problem::fleet_reader::get_profile_index_map;
 use std::ops::Mul;
 use vrp_core::construction::clustering::vicinity::ClusterInfoDimension;
 use vrp_core::construction::enablers::FeatureCombinator;
 use vrp_core::construction::features::*;
-use vrp_core::models::common::{Demand, LoadOps, MultiDimLoad, SingleDimLoad};
-use vrp_core::models::problem::{Actor, Single, TransportCost};
+use vrp_core::models::common::{Cost, Demand, LoadOps, MultiDimLoad, SingleDimLoad};
+use vrp_core::models::problem::{Actor, JobIdDimension, Single, TransportCost};
 use vrp_core::models::solution::Route;
 use vrp_core::models::{Feature, FeatureObjective, GoalBuilder, GoalContext, GoalContextBuilder};
 use vrp_core::rosomaxa::evolution::objectives::dominance_order;
@@ -28,6 +33,14 @@ pub(super) fn create_goal_context(
         features.push(get_tour_limit_feature("tour_limit", api_problem, blocks.transport.clone())?)
     }
 
+    if props.has_leg_travel_limits {
+        features.push(get_leg_limit_feature("leg_limit", api_problem, blocks.transport.clone())?)
+    }
+
+    if props.has_travel_uncertainty {
+        features.push(get_robust_schedule_feature("robust_schedule", api_problem, blocks)?)
+    }
+
     if props.has_breaks {
         features.push(create_optional_break_feature("break")?)
     }
@@ -36,6 +49,14 @@ pub(super) fn create_goal_context(
         features.push(get_recharge_feature("recharge", api_problem, blocks.transport.clone())?);
     }
 
+    if props.has_trailers {
+        features.push(get_trailer_feature("trailer", props)?);
+    }
+
+    if props.has_backhauls && !features.iter().any(|f| f.name == "backhaul") {
+        features.push(get_backhaul_feature("backhaul", props)?);
+    }
+
     if props.has_order && !features.iter().any(|f| f.name == "tour_order") {
         features.push(create_tour_order_hard_feature("tour_order", TOUR_ORDER_CONSTRAINT_CODE, get_tour_order_fn())?)
     }
@@ -44,6 +65,10 @@ pub(super) fn create_goal_context(
         features.push(create_compatibility_feature("compatibility", COMPATIBILITY_CONSTRAINT_CODE)?);
     }
 
+    if props.has_conflicts {
+        features.push(create_job_conflicts_feature("conflicts", CONFLICT_CONSTRAINT_CODE)?);
+    }
+
     if props.has_group {
         features.push(create_group_feature("group", blocks.jobs.size(), GROUP_CONSTRAINT_CODE)?);
     }
@@ -69,6 +94,20 @@ pub(super) fn create_goal_context(
         )?);
     }
 
+    if props.has_shift_alternatives {
+        features
+            .push(create_vehicle_alternatives_feature("vehicle_alternatives", VEHICLE_ALTERNATIVE_CONSTRAINT_CODE)?);
+    }
+
+    if props.has_transfers {
+        features.push(create_transfer_feature(
+            "transfer",
+            blocks.transport.clone(),
+            blocks.activity.clone(),
+            TRANSFER_CONSTRAINT_CODE,
+        )?);
+    }
+
     GoalContextBuilder::with_features(&features)?.set_main_goal(goal_builder.build()?).build()
 }
 
@@ -117,32 +156,7 @@ fn get_objective_feature_layer(
             .build_minimize_duration(),
         Objective::MinimizeTours => create_minimize_tours_feature("min_tours"),
         Objective::MaximizeTours => create_maximize_tours_feature("max_tours"),
-        Objective::MaximizeValue { breaks } => create_maximize_total_job_value_feature(
-            "max_value",
-            JobReadValueFn::Left(Arc::new({
-                let break_value = *breaks;
-                move |job| {
-                    job.dimens().get_job_value().copied().unwrap_or_else(|| {
-                        job.dimens()
-                            .get_job_type()
-                            .zip(break_value)
-                            .filter(|(job_type, _)| *job_type == "break")
-                            .map(|(_, break_value)| break_value)
-                            .unwrap_or(0.)
-                    })
-                }
-            })),
-            Arc::new(|job, value| match job {
-                CoreJob::Single(single) => {
-                    let mut dimens = single.dimens.clone();
-                    dimens.set_job_value(value);
-
-                    CoreJob::Single(Arc::new(Single { places: single.places.clone(), dimens }))
-                }
-                _ => job.clone(),
-            }),
-            ViolationCode::unknown(),
-        ),
+        Objective::MaximizeValue { breaks } => get_maximize_value_feature("max_value", *breaks, blocks, props),
         Objective::MinimizeUnassigned { breaks } => MinimizeUnassignedBuilder::new("min_unassigned")
             .set_job_estimator({
                 let break_value = *breaks;
@@ -168,8 +182,8 @@ fn get_objective_feature_layer(
                     |loaded, capacity| {
                         let mut max_ratio = 0_f64;
 
-                        for (idx, value) in capacity.load.iter().enumerate() {
-                            let ratio = loaded.load[idx] as f64 / *value as f64;
+                        for idx in 0..capacity.size() {
+                            let ratio = loaded.get(idx) / capacity.get(idx);
                             max_ratio = max_ratio.max(ratio);
                         }
 
@@ -193,6 +207,9 @@ fn get_objective_feature_layer(
         }
         Objective::TourOrder => create_tour_order_soft_feature("tour_order", get_tour_order_fn()),
         Objective::FastService => get_fast_service_feature("fast_service", blocks),
+        Objective::JobAffinity => create_job_affinity_feature("job_affinity"),
+        Objective::Backhaul => get_backhaul_feature("backhaul", props),
+        Objective::MinimizeOverqualification => create_skills_overqualification_feature("min_overqualification"),
         Objective::MultiObjective { objectives, strategy: composition_type } => {
             let features = objectives
                 .iter()
@@ -218,8 +235,23 @@ fn get_objectives(api_problem: &ApiProblem, props: &ProblemProperties) -> Vec<Ob
     if let Some(objectives) = api_problem.objectives.clone() {
         objectives
     } else {
+        // NOTE affinity and mixed backhaul are soft: their penalties are traded off against cost
+        let soft_objectives = std::iter::once(Objective::MinimizeCost)
+            .chain(props.has_affinity.then_some(Objective::JobAffinity))
+            .chain(props.has_mixed_backhauls.then_some(Objective::Backhaul))
+            .collect::<Vec<_>>();
+
+        let cost_objective = if soft_objectives.len() > 1 {
+            Objective::MultiObjective {
+                strategy: MultiStrategy::WeightedFitness { weights: vec![1.; soft_objectives.len()] },
+                objectives: soft_objectives,
+            }
+        } else {
+            Objective::MinimizeCost
+        };
+
         let mut objectives =
-            vec![Objective::MinimizeUnassigned { breaks: Some(1.) }, Objective::MinimizeTours, Objective::MinimizeCost];
+            vec![Objective::MinimizeUnassigned { breaks: Some(1.) }, Objective::MinimizeTours, cost_objective];
 
         if props.has_value {
             objectives.insert(0, Objective::MaximizeValue { breaks: None })
@@ -266,6 +298,19 @@ fn get_features_with_goal(feature_layers: &[FeatureLayer]) -> GenericResult<(Vec
     })
 }
 
+fn check_weights(objectives: &[Arc<dyn FeatureObjective>], weights: &[f64]) -> GenericResult<()> {
+    if objectives.len() != weights.len() {
+        return Err(format!(
+            "weighted sum requires same amount of weights as objective count: {} vs {}",
+            weights.len(),
+            objectives.len()
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
 fn eval_multi_objective_strategy(
     objectives: &[Arc<dyn FeatureObjective>],
     composition_type: &MultiStrategy,
@@ -279,14 +324,7 @@ fn eval_multi_objective_strategy(
         ),
 
         MultiStrategy::WeightedSum { weights } => {
-            if objectives.len() != weights.len() {
-                return Err(format!(
-                    "weighted sum requires same amount of weights as objective count: {} vs {}",
-                    weights.len(),
-                    objectives.len()
-                )
-                .into());
-            }
+            check_weights(objectives, weights)?;
 
             builder.add_multi(
                 objectives,
@@ -297,6 +335,30 @@ fn eval_multi_objective_strategy(
                 },
             )
         }
+
+        MultiStrategy::WeightedFitness { weights } => {
+            check_weights(objectives, weights)?;
+
+            let fitness_weights = weights.clone();
+            let estimate_weights = weights.clone();
+
+            builder.add_multi(
+                objectives,
+                move |os, a, b| {
+                    let weighted_fitness = |solution| {
+                        os.iter()
+                            .zip(fitness_weights.iter())
+                            .map(|(o, weight)| o.fitness(solution) * weight)
+                            .sum::<Cost>()
+                    };
+
+                    compare_floats(weighted_fitness(a), weighted_fitness(b))
+                },
+                move |os, move_ctx| {
+                    os.iter().zip(estimate_weights.iter()).map(|(o, weight)| o.estimate(move_ctx) * weight).sum()
+                },
+            )
+        }
     })
 }
 
@@ -312,7 +374,7 @@ fn get_capacity_feature(
             create_capacity_with_reload_feature::<MultiDimLoad>(name, api_problem, blocks, MultiDimLoad::new)
         } else {
             create_capacity_with_reload_feature::<SingleDimLoad>(name, api_problem, blocks, |capacity| {
-                SingleDimLoad::new(capacity.first().cloned().unwrap_or_default())
+                SingleDimLoad::new(capacity.first().cloned().unwrap_or_default() as i32)
             })
         }
     } else if props.has_multi_dimen_capacity {
@@ -342,7 +404,7 @@ fn create_capacity_with_reload_feature<T: LoadOps + SharedResource + Mul<f64, Ou
     name: &str,
     api_problem: &ApiProblem,
     blocks: &ProblemBlocks,
-    capacity_map: fn(Vec<i32>) -> T,
+    capacity_map: fn(Vec<f64>) -> T,
 ) -> GenericResult<Feature> {
     const RELOAD_THRESHOLD: f64 = 0.9;
 
@@ -352,7 +414,7 @@ fn create_capacity_with_reload_feature<T: LoadOps + SharedResource + Mul<f64, Ou
 
     let builder = ReloadFeatureFactory::new(name)
         .set_capacity_code(CAPACITY_CONSTRAINT_CODE)
-        .set_load_schedule_threshold(move |capacity: &T| *capacity * RELOAD_THRESHOLD)
+        .set_load_schedule_threshold(move |capacity: &T| capacity.clone() * RELOAD_THRESHOLD)
         .set_is_reload_single(is_reload_single)
         .set_belongs_to_route(|route: &Route, job: &CoreJob| {
             job.as_single()
@@ -361,24 +423,39 @@ fn create_capacity_with_reload_feature<T: LoadOps + SharedResource + Mul<f64, Ou
 
     let job_index = blocks.job_index.as_ref().ok_or("misconfiguration in goal reader: job index is not set")?;
     let reload_resources = get_reload_resources(api_problem, job_index, capacity_map);
+    let (satellite_supplies, satellite_starts) = get_satellite_resources(api_problem, job_index, capacity_map);
 
-    if reload_resources.is_empty() {
+    if reload_resources.is_empty() && satellite_supplies.is_empty() {
         builder.build_simple()
     } else {
         let total_jobs = blocks.jobs.size();
-        builder
+        let builder = builder
             .set_resource_code(RELOAD_RESOURCE_CONSTRAINT_CODE)
-            .set_shared_demand_capacity(|single| single.dimens.get_job_demand().map(|demand| demand.delivery.0))
-            .set_shared_resource_capacity(move |activity| {
-                activity
-                    .job
-                    .as_ref()
-                    .filter(|single| is_reload_single(single.as_ref()))
-                    .and_then(|single| reload_resources.get(&CoreJob::Single(single.clone())).cloned())
+            .set_shared_demand_capacity(|single| {
+                single.dimens.get_job_demand().map(|demand: &Demand<T>| demand.delivery.0.clone())
             })
-            .set_load_schedule_threshold(move |capacity: &T| *capacity * RELOAD_THRESHOLD)
-            .set_is_partial_solution(move |solution_ctx| solution_ctx.get_jobs_amount() != total_jobs)
-            .build_shared()
+            .set_shared_resource_capacity(move |activity| match activity.job.as_ref() {
+                Some(single) if is_reload_single(single.as_ref()) => {
+                    reload_resources.get(&CoreJob::Single(single.clone())).cloned()
+                }
+                Some(_) => None,
+                // NOTE vehicles which start at satellite's location load their cargo there
+                None => satellite_starts.get(&activity.place.location).cloned(),
+            })
+            .set_load_schedule_threshold(move |capacity: &T| capacity.clone() * RELOAD_THRESHOLD)
+            .set_is_partial_solution(move |solution_ctx| solution_ctx.get_jobs_amount() != total_jobs);
+
+        if satellite_supplies.is_empty() {
+            builder.build_shared()
+        } else {
+            builder
+                .set_shared_resource_supply(
+                    move |single| single.dimens.get_job_id().and_then(|job_id| satellite_supplies.get(job_id)).copied(),
+                    blocks.transport.clone(),
+                    blocks.activity.clone(),
+                )
+                .build_shared()
+        }
     }
 }
 
@@ -387,39 +464,80 @@ fn get_tour_limit_feature(
     api_problem: &ApiProblem,
     transport: Arc<dyn TransportCost>,
 ) -> GenericResult<Feature> {
-    let (distances, durations) = api_problem
-        .fleet
-        .vehicles
-        .iter()
-        .filter_map(|vehicle| vehicle.limits.as_ref().map(|limits| (vehicle, limits)))
-        .fold((HashMap::new(), HashMap::new()), |(mut distances, mut durations), (vehicle, limits)| {
-            limits.max_distance.iter().for_each(|max_distance| {
-                distances.insert(vehicle.type_id.clone(), *max_distance);
-            });
-
-            limits.max_duration.iter().for_each(|max_duration| {
-                durations.insert(vehicle.type_id.clone(), *max_duration);
-            });
-
-            (distances, durations)
-        });
-
-    let get_limit = |limit_map: HashMap<String, f64>| {
-        Arc::new(move |actor: &Actor| {
-            actor.vehicle.dimens.get_vehicle_type().and_then(|v_type| limit_map.get(v_type)).cloned()
-        })
-    };
-
     create_travel_limit_feature(
         name,
         transport.clone(),
         DISTANCE_LIMIT_CONSTRAINT_CODE,
         DURATION_LIMIT_CONSTRAINT_CODE,
-        get_limit(distances),
-        get_limit(durations),
+        get_vehicle_limit_fn(api_problem, |limits| limits.max_distance),
+        get_vehicle_limit_fn(api_problem, |limits| limits.max_duration),
     )
 }
 
+fn get_leg_limit_feature(
+    name: &str,
+    api_problem: &ApiProblem,
+    transport: Arc<dyn TransportCost>,
+) -> GenericResult<Feature> {
+    create_leg_limit_feature(
+        name,
+        transport,
+        LEG_DISTANCE_LIMIT_CONSTRAINT_CODE,
+        LEG_DURATION_LIMIT_CONSTRAINT_CODE,
+        get_vehicle_limit_fn(api_problem, |limits| limits.max_leg_distance),
+        get_vehicle_limit_fn(api_problem, |limits| limits.max_leg_duration),
+    )
+}
+
+fn get_robust_schedule_feature(name: &str, api_problem: &ApiProblem, blocks: &ProblemBlocks) -> GenericResult<Feature> {
+    let profile_index = get_profile_index_map(api_problem);
+    let uncertainties = api_problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.travel_time_uncertainty.as_ref().map(|uncertainty| (profile, uncertainty)))
+        .map(|(profile, uncertainty)| {
+            let uncertainty = TravelUncertainty::new(uncertainty.deviation, uncertainty.probability)?;
+            Ok((*profile_index.get(&profile.name).unwrap(), uncertainty))
+        })
+        .collect::<GenericResult<HashMap<_, _>>>()?;
+
+    create_robust_schedule_feature(
+        name,
+        blocks.transport.clone(),
+        blocks.activity.clone(),
+        ROBUST_SCHEDULE_CONSTRAINT_CODE,
+        Arc::new(move |actor| uncertainties.get(&actor.vehicle.profile.index).copied()),
+    )
+}
+
+fn get_vehicle_limit_fn(
+    api_problem: &ApiProblem,
+    limit_fn: impl Fn(&VehicleLimits) -> Option<f64>,
+) -> TravelLimitFn<f64> {
+    let limit_index: HashMap<_, HashMap<_, _>> =
+        api_problem.fleet.vehicles.iter().fold(HashMap::default(), |mut acc, vehicle| {
+            (0..vehicle.shifts.len())
+                .filter_map(|shift_idx| {
+                    vehicle.shift_limits(shift_idx).and_then(&limit_fn).map(|limit| (shift_idx, limit))
+                })
+                .for_each(|(shift_idx, limit)| {
+                    acc.entry(vehicle.type_id.clone()).or_default().insert(shift_idx, limit);
+                });
+
+            acc
+        });
+
+    Arc::new(move |actor: &Actor| {
+        actor
+            .vehicle
+            .dimens
+            .get_vehicle_type()
+            .zip(actor.vehicle.dimens.get_shift_index().copied())
+            .and_then(|(type_id, shift_idx)| limit_index.get(type_id).and_then(|idx| idx.get(&shift_idx).copied()))
+    })
+}
+
 fn get_recharge_feature(
     name: &str,
     api_problem: &ApiProblem,
@@ -461,32 +579,92 @@ fn get_recharge_feature(
         .build()
 }
 
+fn get_maximize_value_feature(
+    name: &str,
+    breaks: Option<f64>,
+    blocks: &ProblemBlocks,
+    props: &ProblemProperties,
+) -> GenericResult<Feature> {
+    let job_read_value_fn = JobReadValueFn::Left(Arc::new(move |job| {
+        job.dimens().get_job_value().copied().unwrap_or_else(|| {
+            job.dimens()
+                .get_job_type()
+                .zip(breaks)
+                .filter(|(job_type, _)| *job_type == "break")
+                .map(|(_, break_value)| break_value)
+                .unwrap_or(0.)
+        })
+    }));
+    let job_write_value_fn = Arc::new(|job: CoreJob, value| match &job {
+        CoreJob::Single(single) => {
+            let mut dimens = single.dimens.clone();
+            dimens.set_job_value(value);
+
+            CoreJob::Single(Arc::new(Single { places: single.places.clone(), dimens }))
+        }
+        _ => job,
+    });
+
+    if props.has_value_decay {
+        create_maximize_total_job_value_with_decay_feature(
+            name,
+            job_read_value_fn,
+            job_write_value_fn,
+            blocks.transport.clone(),
+            ViolationCode::unknown(),
+        )
+    } else {
+        create_maximize_total_job_value_feature(name, job_read_value_fn, job_write_value_fn, ViolationCode::unknown())
+    }
+}
+
+fn get_backhaul_feature(name: &str, props: &ProblemProperties) -> GenericResult<Feature> {
+    if props.has_multi_dimen_capacity {
+        create_backhaul_feature::<MultiDimLoad>(name, BACKHAUL_CONSTRAINT_CODE)
+    } else {
+        create_backhaul_feature::<SingleDimLoad>(name, BACKHAUL_CONSTRAINT_CODE)
+    }
+}
+
+fn get_trailer_feature(name: &str, props: &ProblemProperties) -> GenericResult<Feature> {
+    fn is_parking_single(single: &Single, parking_type: &str) -> bool {
+        single.dimens.get_job_type().is_some_and(|job_type| job_type == parking_type)
+    }
+
+    fn belongs_to_actor(actor: &Actor, job: &CoreJob) -> bool {
+        let vehicle = &actor.vehicle;
+
+        job.dimens().get_job_type().is_some_and(|job_type| job_type == "parking")
+            && job.dimens().get_vehicle_id() == vehicle.dimens.get_vehicle_id()
+            && job.dimens().get_shift_index() == vehicle.dimens.get_shift_index()
+    }
+
+    if props.has_multi_dimen_capacity {
+        TrailerFeatureBuilder::<MultiDimLoad>::new(name)
+            .set_violation_code(TRAILER_CONSTRAINT_CODE)
+            .set_is_decouple_single(|single| is_parking_single(single, "decouple"))
+            .set_is_recouple_single(|single| is_parking_single(single, "recouple"))
+            .set_belongs_to_actor(belongs_to_actor)
+            .build()
+    } else {
+        TrailerFeatureBuilder::<SingleDimLoad>::new(name)
+            .set_violation_code(TRAILER_CONSTRAINT_CODE)
+            .set_is_decouple_single(|single| is_parking_single(single, "decouple"))
+            .set_is_recouple_single(|single| is_parking_single(single, "recouple"))
+            .set_belongs_to_actor(belongs_to_actor)
+            .build()
+    }
+}
+
 fn get_reload_resources<T>(
     api_problem: &ApiProblem,
     job_index: &JobIndex,
-    capacity_map: fn(Vec<i32>) -> T,
+    capacity_map: fn(Vec<f64>) -> T,
 ) -> HashMap<CoreJob, (T, SharedResourceId)>
 where
     T: LoadOps + SharedResource,
 {
-    // get available resources
-    let available_resources = api_problem
-        .fleet
-        .resources
-        .as_ref()
-        .iter()
-        .flat_map(|resources| resources.iter())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, capacity } => (id.clone(), capacity.clone()),
-        })
-        .collect::<Vec<_>>();
-    let total_resources_specified = available_resources.len();
-    let available_resources = available_resources
-        .into_iter()
-        .enumerate()
-        .map(|(idx, (id, capacity))| (id, (idx, capacity)))
-        .collect::<HashMap<_, _>>();
-    assert_eq!(total_resources_specified, available_resources.len());
+    let available_resources = get_available_resources(api_problem);
 
     // get reload resources
     api_problem
@@ -524,6 +702,83 @@ where
         .collect()
 }
 
+/// Returns shared resources with their index and capacity.
+fn get_available_resources(api_problem: &ApiProblem) -> HashMap<String, (SharedResourceId, Vec<f64>)> {
+    let available_resources = api_problem
+        .fleet
+        .resources
+        .as_ref()
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .map(|resource| match resource {
+            VehicleResource::Reload { id, capacity } => (id.clone(), capacity.clone()),
+            VehicleResource::Satellite { id, job_id } => {
+                let capacity =
+                    get_satellite_job(api_problem, job_id).and_then(|task| task.demand.clone()).unwrap_or_default();
+                (id.clone(), capacity)
+            }
+        })
+        .collect::<Vec<_>>();
+    let total_resources_specified = available_resources.len();
+    let available_resources = available_resources
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (id, capacity))| (id, (idx, capacity)))
+        .collect::<HashMap<_, _>>();
+    assert_eq!(total_resources_specified, available_resources.len());
+
+    available_resources
+}
+
+/// Returns satellite's supply jobs and satellite locations with their capacity.
+#[allow(clippy::type_complexity)]
+fn get_satellite_resources<T>(
+    api_problem: &ApiProblem,
+    job_index: &JobIndex,
+    capacity_map: fn(Vec<f64>) -> T,
+) -> (HashMap<String, SharedResourceId>, HashMap<usize, (T, SharedResourceId)>)
+where
+    T: LoadOps + SharedResource,
+{
+    let available_resources = get_available_resources(api_problem);
+
+    api_problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Satellite { id, job_id } => available_resources
+                .get(id)
+                .zip(job_index.get(job_id))
+                .map(|((resource_id, capacity), job)| (*resource_id, capacity.clone(), job_id.clone(), job)),
+            VehicleResource::Reload { .. } => None,
+        })
+        .fold(
+            (HashMap::default(), HashMap::default()),
+            |(mut supplies, mut starts), (resource_id, capacity, job_id, job)| {
+                let location =
+                    job.as_single().and_then(|single| single.places.first()).and_then(|place| place.location);
+                if let Some(location) = location {
+                    starts.insert(location, (capacity_map(capacity), resource_id));
+                }
+                supplies.insert(job_id, resource_id);
+
+                (supplies, starts)
+            },
+        )
+}
+
+fn get_satellite_job<'a>(api_problem: &'a ApiProblem, job_id: &str) -> Option<&'a JobTask> {
+    api_problem
+        .plan
+        .jobs
+        .iter()
+        .find(|job| job.id == job_id)
+        .and_then(|job| job.deliveries.as_ref())
+        .and_then(|deliveries| deliveries.first())
+}
+
 fn create_optional_break_feature(name: &str) -> GenericResult<Feature> {
     fn is_break_job(single: &Single) -> bool {
         single.dimens.get_job_type().map_or(false, |job_type| job_type == "break")
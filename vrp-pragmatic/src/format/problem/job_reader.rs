@@ -1,14 +1,18 @@
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::JobSkills as ApiJobSkills;
 use crate::format::problem::*;
-use crate::format::{JobIndex, Location};
+use crate::format::{get_transfer_leg_key, JobIndex, Location};
 use crate::utils::VariableJobPermutation;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::iter::repeat_n;
+use std::ops::Mul;
 use std::sync::Arc;
 use vrp_core::{
     construction::features::{
-        BreakPolicy, JobCompatibilityDimension, JobDemandDimension, JobGroupDimension, JobSkills as FeatureJobSkills,
-        JobSkillsDimension,
+        BreakPolicy, JobAffinity as FeatureJobAffinity, JobAffinityDimension, JobCompatibilityDimension,
+        JobConflictsDimension, JobDemandDimension, JobGroupDimension, JobPartialRatiosDimension,
+        JobSkills as FeatureJobSkills, JobSkillsDimension, JobTransfer, JobTransferDimension, JobTruckOnlyDimension,
+        JobValueDecayDimension, TransferLeg, ValueDecay,
     },
     models::common::*,
     models::problem::{
@@ -21,6 +25,8 @@ use vrp_core::{
 const MULTI_JOB_SAMPLE_SIZE: usize = 3;
 
 type PlaceData = (Option<Location>, Duration, Vec<TimeSpan>, Option<String>);
+/// Maps job id to ids of the jobs it is in conflict with.
+type ConflictIndex<'a> = HashMap<&'a str, HashSet<String>>;
 type ApiJob = crate::format::problem::Job;
 
 pub(super) fn read_jobs_with_extra_locks(
@@ -121,8 +127,8 @@ fn read_required_jobs(
         let demand = match activity_type {
             "pickup" => Demand { pickup: demand, delivery: absent },
             "delivery" => Demand { pickup: absent, delivery: demand },
-            "replacement" => Demand { pickup: demand, delivery: demand },
-            "service" => Demand { pickup: absent, delivery: absent },
+            "replacement" => Demand { pickup: demand.clone(), delivery: demand },
+            "service" => Demand { pickup: absent.clone(), delivery: absent },
             _ => panic!("invalid activity type."),
         };
 
@@ -135,7 +141,42 @@ fn read_required_jobs(
         get_single_with_dimens(places, demand, &task.order, activity_type, has_multi_dimens, coord_index)
     };
 
-    api_problem.plan.jobs.iter().for_each(|job| {
+    let transfer_points = api_problem
+        .plan
+        .transfers
+        .iter()
+        .flatten()
+        .map(|transfer| (transfer.id.as_str(), transfer))
+        .collect::<HashMap<_, _>>();
+
+    // NOTE handover drops the job at transfer point in the first leg and picks it up there in the second one
+    let get_handover_single = |job: &ApiJob, transfers: &[&TransferPoint], is_drop: bool| {
+        let absent = (empty(), empty());
+        let capacity = job
+            .pickups
+            .iter()
+            .flatten()
+            .next()
+            .and_then(|task| task.demand.clone())
+            .map_or_else(empty, MultiDimLoad::new);
+
+        let demand = if is_drop {
+            Demand { pickup: absent, delivery: (empty(), capacity) }
+        } else {
+            Demand { pickup: (empty(), capacity), delivery: absent }
+        };
+
+        let places = transfers
+            .iter()
+            .map(|tp| (Some(tp.location.clone()), tp.duration, parse_times(&tp.times), Some(tp.id.clone())))
+            .collect();
+
+        get_single_with_dimens(places, demand, &None, "handover", has_multi_dimens, coord_index)
+    };
+
+    let conflicts = get_conflict_index(api_problem);
+
+    api_problem.plan.jobs.iter().enumerate().for_each(|(transfer_id, job)| {
         let pickups = job.pickups.as_ref().map_or(0, |p| p.len());
         let deliveries = job.deliveries.as_ref().map_or(0, |p| p.len());
         let is_static_demand = pickups == 0 || deliveries == 0;
@@ -161,11 +202,46 @@ fn read_required_jobs(
 
         assert!(!singles.is_empty());
 
+        let transfers = job
+            .transfers
+            .iter()
+            .flatten()
+            .filter_map(|transfer_id| transfer_points.get(transfer_id.as_str()).copied())
+            .collect::<Vec<_>>();
+
+        if !transfers.is_empty() && singles.len() == 2 && pickups == 1 && deliveries == 1 {
+            let get_task_single = |tasks: &Option<Vec<JobTask>>, activity_type: &str| {
+                tasks.iter().flatten().next().map(|task| get_single_from_task(task, activity_type, false)).unwrap()
+            };
+
+            let direct =
+                get_transfer_job(job, &conflicts, singles, JobTransfer { id: transfer_id, leg: TransferLeg::Direct });
+            let first = get_transfer_job(
+                job,
+                &conflicts,
+                vec![get_task_single(&job.pickups, "pickup"), get_handover_single(job, &transfers, true)],
+                JobTransfer { id: transfer_id, leg: TransferLeg::First },
+            );
+            let second = get_transfer_job(
+                job,
+                &conflicts,
+                vec![get_handover_single(job, &transfers, false), get_task_single(&job.deliveries, "delivery")],
+                JobTransfer { id: transfer_id, leg: TransferLeg::Second },
+            );
+
+            job_index.insert(job.id.clone(), direct.clone());
+            job_index.insert(get_transfer_leg_key(&job.id, 1), first.clone());
+            job_index.insert(get_transfer_leg_key(&job.id, 2), second.clone());
+            jobs.extend([direct, first, second]);
+
+            return;
+        }
+
         let problem_job = if singles.len() > 1 {
             let deliveries_start_index = job.pickups.as_ref().map_or(0, |p| p.len());
-            get_multi_job(job, singles, deliveries_start_index, random)
+            get_multi_job(job, &conflicts, singles, deliveries_start_index, random)
         } else {
-            get_single_job(job, singles.into_iter().next().unwrap())
+            get_single_job(job, &conflicts, singles.into_iter().next().unwrap())
         };
 
         job_index.insert(job.id.clone(), problem_job.clone());
@@ -195,6 +271,10 @@ fn read_conditional_jobs(
             if let Some(recharges) = &shift.recharges {
                 read_recharges(coord_index, job_index, &mut jobs, vehicle, shift_index, recharges);
             }
+
+            if let Some(trailer) = &vehicle.trailer {
+                read_trailer_parkings(coord_index, job_index, &mut jobs, vehicle, shift_index, trailer);
+            }
         }
     });
 
@@ -277,6 +357,7 @@ fn read_reloads(
             duration: reload.duration,
             times: reload.times.clone(),
             tag: reload.tag.clone(),
+            timezone: None,
         }),
     )
 }
@@ -300,6 +381,54 @@ fn read_recharges(
     )
 }
 
+/// Reads trailer parking places as multi jobs with decouple and recouple activities.
+fn read_trailer_parkings(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    trailer: &VehicleTrailer,
+) {
+    (1..)
+        .zip(trailer.parkings.iter())
+        .flat_map(|(place_idx, place)| {
+            vehicle
+                .vehicle_ids
+                .iter()
+                .map(|vehicle_id| {
+                    let job_id = format!("{vehicle_id}_parking_{shift_index}_{place_idx}");
+                    let times = parse_times(&place.times);
+
+                    let singles = ["decouple", "recouple"]
+                        .iter()
+                        .map(|job_type| {
+                            let places =
+                                vec![(Some(place.location.clone()), place.duration, times.clone(), place.tag.clone())];
+                            let mut single = get_single(places, coord_index);
+                            single.dimens.set_job_type(job_type.to_string());
+
+                            Arc::new(single)
+                        })
+                        .collect();
+
+                    let mut dimens = Dimensions::default();
+                    dimens
+                        .set_job_id(job_id.clone())
+                        .set_job_type("parking".to_string())
+                        .set_shift_index(shift_index)
+                        .set_vehicle_id(vehicle_id.clone());
+
+                    (job_id, Job::Multi(Multi::new_shared(singles, dimens)))
+                })
+                .collect::<Vec<_>>()
+        })
+        .for_each(|(job_id, job)| {
+            job_index.insert(job_id, job.clone());
+            jobs.push(job);
+        });
+}
+
 fn read_specific_job_places(
     job_type: &str,
     coord_index: &CoordIndex,
@@ -399,8 +528,14 @@ fn get_single_with_dimens(
         dimens.set_job_demand(demand)
     } else {
         dimens.set_job_demand(Demand {
-            pickup: (SingleDimLoad::new(demand.pickup.0.load[0]), SingleDimLoad::new(demand.pickup.1.load[0])),
-            delivery: (SingleDimLoad::new(demand.delivery.0.load[0]), SingleDimLoad::new(demand.delivery.1.load[0])),
+            pickup: (
+                SingleDimLoad::new(demand.pickup.0.get(0) as i32),
+                SingleDimLoad::new(demand.pickup.1.get(0) as i32),
+            ),
+            delivery: (
+                SingleDimLoad::new(demand.delivery.0.get(0) as i32),
+                SingleDimLoad::new(demand.delivery.1.get(0) as i32),
+            ),
         })
     }
     .set_job_type(activity_type.to_string());
@@ -412,13 +547,19 @@ fn get_single_with_dimens(
     single
 }
 
-fn fill_dimens(job: &ApiJob, dimens: &mut Dimensions) {
+fn fill_dimens(job: &ApiJob, conflicts: &ConflictIndex, dimens: &mut Dimensions) {
     dimens.set_job_id(job.id.clone());
 
     if let Some(value) = job.value {
         dimens.set_job_value(value);
     }
 
+    if let Some(decay) = job.value_decay.as_ref().and_then(|points| {
+        ValueDecay::new(points.iter().map(|point| (parse_time(&point.time), point.factor)).collect()).ok()
+    }) {
+        dimens.set_job_value_decay(decay);
+    }
+
     if let Some(group) = job.group.clone() {
         dimens.set_job_group(group);
     }
@@ -427,21 +568,102 @@ fn fill_dimens(job: &ApiJob, dimens: &mut Dimensions) {
         dimens.set_job_compatibility(compat);
     }
 
+    if let Some(conflicts) = conflicts.get(job.id.as_str()) {
+        dimens.set_job_conflicts(conflicts.clone());
+    }
+
+    if let Some(affinity) = job.affinity.as_ref() {
+        dimens.set_job_affinity(FeatureJobAffinity {
+            group: affinity.group.clone(),
+            weight: affinity.weight.unwrap_or(1.),
+        });
+    }
+
     if let Some(skills) = get_skills(&job.skills) {
         dimens.set_job_skills(skills);
     }
+
+    if job.truck_only.unwrap_or(false) {
+        dimens.set_job_truck_only(true);
+    }
 }
 
-fn get_single_job(job: &ApiJob, single: Single) -> Job {
-    let mut single = single;
-    fill_dimens(job, &mut single.dimens);
+fn get_single_job(job: &ApiJob, conflicts: &ConflictIndex, single: Single) -> Job {
+    let mut single = match job.divisible.as_ref() {
+        Some(divisible) => get_divisible_single(single, divisible),
+        None => single,
+    };
+    fill_dimens(job, conflicts, &mut single.dimens);
 
     Job::Single(Arc::new(single))
 }
 
-fn get_multi_job(job: &ApiJob, singles: Vec<Single>, deliveries_start_index: usize, random: &Arc<dyn Random>) -> Job {
+/// Adds places with partial demand and duration to the single job: each original place is repeated for every
+/// amount of parts in descending order. Total amount of places is limited by E1114 validation rule as each of
+/// them is evaluated on insertion.
+fn get_divisible_single(single: Single, divisible: &JobDivisible) -> Single {
+    let Single { places: original_places, mut dimens } = single;
+    let (parts, original_len) = (divisible.parts, original_places.len());
+
+    let ratios = (1..=parts)
+        .rev()
+        .map(|part| part as f64 / parts as f64)
+        .flat_map(|ratio| repeat_n(ratio, original_len))
+        .collect::<Vec<_>>();
+
+    let places = ratios
+        .iter()
+        .zip(original_places.iter().cycle())
+        .map(|(ratio, place)| Place { duration: place.duration * ratio, ..place.clone() })
+        .collect::<Vec<_>>();
+
+    let tags = dimens
+        .get_place_tags()
+        .map(|tags| {
+            (0..places.len())
+                .filter_map(|idx| {
+                    tags.iter().find(|(tag_idx, _)| *tag_idx == idx % original_len).map(|(_, tag)| (idx, tag.clone()))
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    if let Some(demands) = get_place_demands::<MultiDimLoad>(&dimens, &ratios) {
+        dimens.set_job_place_demands(demands);
+    } else if let Some(demands) = get_place_demands::<SingleDimLoad>(&dimens, &ratios) {
+        dimens.set_job_place_demands(demands);
+    }
+
+    dimens.set_place_tags(tags).set_job_partial_ratios(ratios);
+
+    Single { places, dimens }
+}
+
+/// Returns job demand divided proportionally to the given ratios.
+fn get_place_demands<T>(dimens: &Dimensions, ratios: &[f64]) -> Option<Vec<Demand<T>>>
+where
+    T: LoadOps + Mul<f64, Output = T>,
+{
+    dimens.get_job_demand::<T>().map(|demand| {
+        ratios
+            .iter()
+            .map(|&ratio| Demand {
+                pickup: (demand.pickup.0.clone() * ratio, demand.pickup.1.clone() * ratio),
+                delivery: (demand.delivery.0.clone() * ratio, demand.delivery.1.clone() * ratio),
+            })
+            .collect()
+    })
+}
+
+fn get_multi_job(
+    job: &ApiJob,
+    conflicts: &ConflictIndex,
+    singles: Vec<Single>,
+    deliveries_start_index: usize,
+    random: &Arc<dyn Random>,
+) -> Job {
     let mut dimens: Dimensions = Default::default();
-    fill_dimens(job, &mut dimens);
+    fill_dimens(job, conflicts, &mut dimens);
 
     let singles = singles.into_iter().map(Arc::new).collect::<Vec<_>>();
 
@@ -464,6 +686,32 @@ fn get_multi_job(job: &ApiJob, singles: Vec<Single>, deliveries_start_index: usi
     Job::Multi(multi)
 }
 
+fn get_transfer_job(job: &ApiJob, conflicts: &ConflictIndex, singles: Vec<Single>, transfer: JobTransfer) -> Job {
+    let mut dimens: Dimensions = Default::default();
+    fill_dimens(job, conflicts, &mut dimens);
+    dimens.set_job_transfer(transfer);
+
+    // NOTE job value is collected only once: by direct job or by the first leg
+    if transfer.leg == TransferLeg::Second && job.value.is_some() {
+        dimens.set_job_value(0.);
+    }
+
+    Job::Multi(Multi::new_shared(singles.into_iter().map(Arc::new).collect(), dimens))
+}
+
+/// Returns job conflicts in both directions: a job conflicts with all jobs it specifies and all jobs
+/// which specify it.
+fn get_conflict_index(api_problem: &ApiProblem) -> ConflictIndex<'_> {
+    api_problem.plan.jobs.iter().fold(ConflictIndex::default(), |mut acc, job| {
+        job.conflicts.iter().flatten().filter(|other_id| **other_id != job.id).for_each(|other_id| {
+            acc.entry(job.id.as_str()).or_default().insert(other_id.clone());
+            acc.entry(other_id.as_str()).or_default().insert(job.id.clone());
+        });
+
+        acc
+    })
+}
+
 fn create_condition(vehicle_id: String, shift_index: usize) -> Arc<dyn Fn(&Actor) -> bool + Sync + Send> {
     Arc::new(move |actor: &Actor| {
         *actor.vehicle.dimens.get_vehicle_id().unwrap() == vehicle_id
@@ -472,9 +720,11 @@ fn create_condition(vehicle_id: String, shift_index: usize) -> Arc<dyn Fn(&Actor
 }
 
 fn get_skills(skills: &Option<ApiJobSkills>) -> Option<FeatureJobSkills> {
-    skills
-        .as_ref()
-        .map(|skills| FeatureJobSkills::new(skills.all_of.clone(), skills.one_of.clone(), skills.none_of.clone()))
+    skills.as_ref().map(|skills| {
+        FeatureJobSkills::new(skills.all_of.clone(), skills.one_of.clone(), skills.none_of.clone()).with_min_levels(
+            skills.min_levels.iter().flatten().map(|skill| (skill.name.clone(), skill.level)).collect(),
+        )
+    })
 }
 
 fn empty() -> MultiDimLoad {
@@ -1,4 +1,4 @@
-//! Specifies logic to read problem and routing matrix from json input.
+//! Specifies logic to read problem and routing matrix from json or binary input.
 
 use super::*;
 use crate::parse_time;
@@ -14,6 +14,9 @@ pub(crate) type ApiProblem = Problem;
 mod model;
 pub use self::model::*;
 
+mod binary_matrix;
+pub use self::binary_matrix::*;
+
 #[cfg(test)]
 #[path = "../../../tests/unit/format/problem/reader_test.rs"]
 mod reader_test;
@@ -110,9 +113,19 @@ struct ProblemProperties {
     has_order: bool,
     has_group: bool,
     has_value: bool,
+    has_value_decay: bool,
     has_compatibility: bool,
+    has_affinity: bool,
+    has_conflicts: bool,
     has_tour_size_limits: bool,
     has_tour_travel_limits: bool,
+    has_leg_travel_limits: bool,
+    has_shift_alternatives: bool,
+    has_transfers: bool,
+    has_trailers: bool,
+    has_backhauls: bool,
+    has_mixed_backhauls: bool,
+    has_travel_uncertainty: bool,
 }
 
 /// Keeps track of materialized problem building blocks.
@@ -4,6 +4,7 @@ mod model_test;
 
 extern crate serde_json;
 
+use super::{is_binary_matrix, read_binary_matrix};
 use crate::format::{FormatError, Location, MultiFormatError};
 use serde::{Deserialize, Serialize};
 use std::io::{BufReader, BufWriter, Error, Read, Write};
@@ -51,6 +52,29 @@ pub struct JobSkills {
     /// Vehicle should have none of these skills defined.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub none_of: Option<Vec<String>>,
+    /// Vehicle should have all of these skills with at least specified level.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_levels: Option<Vec<SkillLevel>>,
+}
+
+/// A skill with its proficiency level.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct SkillLevel {
+    /// A skill name.
+    pub name: String,
+    /// A skill level, starting from 1.
+    pub level: u32,
+}
+
+/// A job affinity: a soft preference to serve the job in the same tour with other jobs of the same group.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobAffinity {
+    /// An affinity group.
+    pub group: String,
+    /// A penalty weight applied when the job is served apart from the most of its group jobs.
+    /// Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
 }
 
 /// Specifies a place for sub job.
@@ -67,6 +91,10 @@ pub struct JobPlace {
     /// You can use it to identify used place in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+    /// An IANA time zone name (e.g. `Europe/Berlin`) used to interpret local date times of the place.
+    /// If omitted, problem's time zone is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
 }
 
 /// Specifies a job task.
@@ -74,9 +102,9 @@ pub struct JobPlace {
 pub struct JobTask {
     /// A list of possible places where given task can be performed.
     pub places: Vec<JobPlace>,
-    /// Job place demand.
+    /// Job place demand. Values can be fractional.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub demand: Option<Vec<i32>>,
+    pub demand: Option<Vec<f64>>,
     /// An order, bigger value - later assignment in the route.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub order: Option<i32>,
@@ -115,6 +143,10 @@ pub struct Job {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<f64>,
 
+    /// Job value decay: a piecewise linear function of arrival time which defines a multiplier of job value.
+    #[serde(rename = "valueDecay", skip_serializing_if = "Option::is_none")]
+    pub value_decay: Option<Vec<JobValueDecayPoint>>,
+
     /// Job group: jobs of the same group are assigned to the same tour or unassigned.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub group: Option<String>,
@@ -122,6 +154,63 @@ pub struct Job {
     /// A compatibility group: jobs with different compatibility cannot be assigned to the same tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compatibility: Option<String>,
+
+    /// A list of job ids which cannot be assigned to the same tour with this job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conflicts: Option<Vec<String>>,
+
+    /// A job affinity: jobs of the same affinity group are preferred to be assigned to the same tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affinity: Option<JobAffinity>,
+
+    /// A list of transfer point ids where the job can be handed over from one vehicle to another.
+    /// Applicable only for the job with one pickup and one delivery.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfers: Option<Vec<String>>,
+
+    /// Specifies whether the job is accessible only for the truck alone: vehicle with a trailer
+    /// has to decouple it at some parking place before serving the job.
+    #[serde(rename = "truckOnly", skip_serializing_if = "Option::is_none")]
+    pub truck_only: Option<bool>,
+
+    /// Specifies that the job can be served partially when full service is not feasible.
+    /// Applicable only for the job with one task: its demand and duration are divided proportionally.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub divisible: Option<JobDivisible>,
+}
+
+/// Specifies how a job can be divided for partial service.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobDivisible {
+    /// Amount of equal parts of the job demand and duration: the job can be served partially by any number of parts.
+    pub parts: usize,
+}
+
+/// Specifies a point of job value decay function.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct JobValueDecayPoint {
+    /// A point time.
+    pub time: String,
+    /// A job value multiplier at given time.
+    pub factor: f64,
+}
+
+/// Specifies a transfer point (hub) where a job can be handed over from one vehicle to another.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct TransferPoint {
+    /// A unique transfer point id.
+    pub id: String,
+    /// A transfer point location.
+    pub location: Location,
+    /// A handover duration (service time) used by both vehicles.
+    pub duration: f64,
+    /// A list of transfer point time windows with time specified in RFC3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<Vec<Vec<String>>>,
+    /// An IANA time zone name (e.g. `Europe/Berlin`) used to interpret local date times of the transfer point.
+    /// If omitted, problem's time zone is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
 }
 
 // region Clustering
@@ -225,6 +314,10 @@ pub struct Plan {
     /// Specifies clustering parameters.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub clustering: Option<Clustering>,
+
+    /// List of transfer points where jobs can be handed over between vehicles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfers: Option<Vec<TransferPoint>>,
 }
 
 // endregion
@@ -259,6 +352,16 @@ pub struct ShiftStart {
 
     /// Shift start location.
     pub location: Location,
+
+    /// An IANA time zone name used to interpret local date times of shift start, breaks and reloads.
+    /// If omitted, problem's time zone is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
+    /// Alternative shift start locations. If specified, the solver picks the best start location
+    /// for the tour among shift start location and its alternatives.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alternatives: Option<Vec<ShiftLocation>>,
 }
 
 /// Specifies vehicle shift end.
@@ -274,6 +377,31 @@ pub struct ShiftEnd {
 
     /// Shift end location.
     pub location: Location,
+
+    /// An IANA time zone name used to interpret local date times of shift end.
+    /// If omitted, time zone of shift start is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
+    /// Alternative shift end locations. If specified, the solver picks the best end location
+    /// for the tour among shift end location and its alternatives.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alternatives: Option<Vec<ShiftLocation>>,
+}
+
+/// Specifies an alternative location of vehicle shift start or end.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct ShiftLocation {
+    /// Alternative location.
+    pub location: Location,
+
+    /// An extra cost applied when the tour uses this location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cost: Option<f64>,
+
+    /// A tag which is returned in solution's departure or arrival activity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
 }
 
 /// Specifies vehicle shift.
@@ -298,6 +426,22 @@ pub struct VehicleShift {
     /// Vehicle recharge stations information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recharges: Option<VehicleRecharges>,
+
+    /// Vehicle capacity in this shift. When omitted, vehicle type capacity is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<Vec<f64>>,
+
+    /// Vehicle skills in this shift. When omitted, vehicle type skills are used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skills: Option<Vec<VehicleSkill>>,
+
+    /// Vehicle limits in this shift. When omitted, vehicle type limits are used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limits: Option<VehicleLimits>,
+
+    /// Vehicle costs in this shift. When omitted, vehicle type costs are used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub costs: Option<VehicleCosts>,
 }
 
 /// Specifies a place where vehicle can load or unload cargo.
@@ -337,6 +481,34 @@ pub struct VehicleRecharges {
 /// Specifies type alias for vehicle recharge station.
 pub type VehicleRechargeStation = JobPlace;
 
+/// Specifies a detachable trailer towed by the vehicle.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleTrailer {
+    /// Trailer capacity. Vehicle type capacity is considered as capacity of the truck alone.
+    pub capacity: Vec<f64>,
+
+    /// Specifies list of parking places where the trailer can be decoupled and recoupled later.
+    pub parkings: Vec<VehicleTrailerParking>,
+}
+
+/// Specifies type alias for trailer parking place. Its duration is applied to decouple and
+/// recouple activities separately.
+pub type VehicleTrailerParking = JobPlace;
+
+/// Specifies a backhaul policy of the vehicle: whether deliveries have to be finished before any pickup.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum VehicleBackhaul {
+    /// Pickups are never served before deliveries in the tour.
+    Strict,
+    /// Deliveries can be served after pickups, but each such delivery is penalized.
+    Mixed {
+        /// A penalty per delivery served after the first pickup in the tour.
+        penalty: f64,
+    },
+}
+
 /// Vehicle limits.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -356,6 +528,16 @@ pub struct VehicleLimits {
     /// No job activities restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tour_size: Option<usize>,
+
+    /// Max traveling distance between two consecutive stops.
+    /// No leg distance restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_leg_distance: Option<f64>,
+
+    /// Max traveling duration between two consecutive stops.
+    /// No leg duration restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_leg_duration: Option<f64>,
 }
 
 /// Vehicle optional break time variant.
@@ -436,6 +618,16 @@ pub enum VehicleBreak {
     },
 }
 
+/// Specifies a vehicle skill.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(untagged)]
+pub enum VehicleSkill {
+    /// A skill without level: it is considered as a skill of the first level.
+    Plain(String),
+    /// A skill with proficiency level.
+    Leveled(SkillLevel),
+}
+
 /// Specifies a vehicle type.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -455,16 +647,24 @@ pub struct VehicleType {
     /// Vehicle shifts.
     pub shifts: Vec<VehicleShift>,
 
-    /// Vehicle capacity.
-    pub capacity: Vec<i32>,
+    /// Vehicle capacity. Values can be fractional.
+    pub capacity: Vec<f64>,
 
     /// Vehicle skills.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub skills: Option<Vec<String>>,
+    pub skills: Option<Vec<VehicleSkill>>,
 
     /// Vehicle limits.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limits: Option<VehicleLimits>,
+
+    /// Vehicle trailer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailer: Option<VehicleTrailer>,
+
+    /// Vehicle backhaul policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backhaul: Option<VehicleBackhaul>,
 }
 
 /// Specifies a vehicle profile.
@@ -489,6 +689,106 @@ pub struct MatrixProfile {
     /// Default value is 10.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<f64>,
+
+    /// Speed bands which change travel durations of the profile depending on departure time.
+    #[serde(rename = "speedBands", skip_serializing_if = "Option::is_none")]
+    pub speed_bands: Option<Vec<SpeedBand>>,
+
+    /// Specifies routing restrictions applied on top of routing matrix of another profile. When set, no routing
+    /// matrix should be supplied for the profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overlay: Option<MatrixOverlay>,
+
+    /// Specifies a distance model used to approximate routing when routing matrix is not specified.
+    /// Default is haversine distance.
+    #[serde(rename = "distanceModel", skip_serializing_if = "Option::is_none")]
+    pub distance_model: Option<DistanceModel>,
+
+    /// Specifies travel time uncertainty of the profile. When set, each activity has to be reached within its time
+    /// window with desired probability.
+    #[serde(rename = "travelTimeUncertainty", skip_serializing_if = "Option::is_none")]
+    pub travel_time_uncertainty: Option<TravelTimeUncertainty>,
+}
+
+/// Specifies travel time uncertainty of the profile.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct TravelTimeUncertainty {
+    /// A standard deviation of travel durations relative to their value, e.g. 0.2 for ±20%.
+    pub deviation: f64,
+
+    /// A minimum probability of arrival within time window at each activity, e.g. 0.95.
+    pub probability: f64,
+}
+
+/// Specifies how distances are approximated when routing matrix is not specified.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct DistanceModel {
+    /// A distance type.
+    #[serde(rename = "type")]
+    pub distance_type: DistanceType,
+
+    /// A factor applied to calculated distances, e.g. to take into account road network detours.
+    /// Default value is 1.
+    #[serde(rename = "detourFactor", skip_serializing_if = "Option::is_none")]
+    pub detour_factor: Option<f64>,
+}
+
+/// Specifies distance type used to approximate routing.
+#[derive(Clone, Copy, Deserialize, Debug, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum DistanceType {
+    /// Great-circle distance between geo coordinates on a sphere.
+    Haversine,
+    /// Shortest distance between geo coordinates on WGS-84 ellipsoid.
+    Geodesic,
+    /// Straight line distance between planar coordinates in meters: `lng` is used as `x` and `lat` as `y`.
+    Euclidean,
+    /// Sum of axis distances between planar coordinates in meters: `lng` is used as `x` and `lat` as `y`.
+    Manhattan,
+}
+
+/// Specifies a speed factor applied to routing durations starting from given time till the start of next band.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct SpeedBand {
+    /// A start time of the band in RFC3339.
+    pub start: String,
+
+    /// A speed factor relative to speed used in routing matrix: e.g. `0.5` means two times slower.
+    pub factor: f64,
+}
+
+/// Specifies routing restrictions of a matrix profile which reuses routing matrix of another profile.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct MatrixOverlay {
+    /// A name of profile which routing matrix is reused.
+    pub base: String,
+
+    /// Locations which cannot be entered or left.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unreachable: Option<Vec<Location>>,
+
+    /// Travel durations and distances which override values of base routing matrix.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<MatrixOverlayEdge>>,
+}
+
+/// Specifies overridden routing values between two locations. A missing value is taken from base routing matrix,
+/// when both values are missing, the edge is unreachable.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct MatrixOverlayEdge {
+    /// A start location.
+    pub from: Location,
+
+    /// An end location.
+    pub to: Location,
+
+    /// A travel duration in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<f64>,
+
+    /// A travel distance in meters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
 }
 
 /// Specifies vehicle resource type.
@@ -501,7 +801,19 @@ pub enum VehicleResource {
         /// Resource id.
         id: String,
         /// A total resource capacity.
-        capacity: Vec<i32>,
+        capacity: Vec<f64>,
+    },
+
+    /// A satellite: a transfer location where cargo is delivered by a first-level vehicle and picked
+    /// up by second-level vehicles which start there or have reloads with the same resource id.
+    #[serde(rename(deserialize = "satellite", serialize = "satellite"))]
+    Satellite {
+        /// Resource id.
+        id: String,
+        /// An id of delivery job which brings cargo to the satellite. Its location and demand
+        /// define the satellite's location and capacity.
+        #[serde(rename(deserialize = "jobId", serialize = "jobId"))]
+        job_id: String,
     },
 }
 
@@ -584,6 +896,15 @@ pub enum Objective {
     /// An objective to prefer jobs to be served as soon as possible.
     FastService,
 
+    /// An objective to prefer jobs of the same affinity group to be served in the same tour.
+    JobAffinity,
+
+    /// An objective to minimize penalty of deliveries served after pickups by vehicles with mixed backhaul policy.
+    Backhaul,
+
+    /// An objective to prefer vehicles with skill levels closer to minimum levels required by jobs.
+    MinimizeOverqualification,
+
     /// A multi objective allows to define multiple competitive objectives at the same layer of hierarchy.
     MultiObjective {
         /// An objective composition type.
@@ -606,6 +927,13 @@ pub enum MultiStrategy {
         /// Individual weights. Size of vector must be the same as amount of objective functions.
         weights: Vec<f64>,
     },
+
+    /// A weighted fitness type compares solutions by linear combination of weights and the corresponding
+    /// fitness values, so objectives are traded off against each other.
+    WeightedFitness {
+        /// Individual weights. Size of vector must be the same as amount of objective functions.
+        weights: Vec<f64>,
+    },
 }
 
 // endregion
@@ -624,6 +952,27 @@ pub struct Problem {
     /// Specifies objective functions in lexicographical order.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub objectives: Option<Vec<Objective>>,
+
+    /// Specifies a default IANA time zone name (e.g. `America/New_York`). When set, date times without
+    /// offset (e.g. `2020-07-04T09:00:00`) are interpreted as local wall clock time and solution times
+    /// are written in local time with offset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
+    /// Specifies how routing matrix values are kept in memory. Default is `full`.
+    #[serde(rename = "matrixStorage", skip_serializing_if = "Option::is_none")]
+    pub matrix_storage: Option<MatrixStorageType>,
+}
+
+/// Specifies how routing matrix values are kept in memory.
+#[derive(Clone, Copy, Deserialize, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum MatrixStorageType {
+    /// Values are kept as 64 bit floats.
+    Full,
+    /// Values are kept as 32 bit unsigned integers which halves memory usage. Values of profiles with overlay
+    /// and equal matrices of different profiles are stored only once.
+    Compact,
 }
 
 /// A routing matrix.
@@ -662,6 +1011,43 @@ impl Job {
     }
 }
 
+impl VehicleType {
+    /// Returns vehicle capacity in the shift with given index.
+    pub fn shift_capacity(&self, shift_index: usize) -> &Vec<f64> {
+        self.shifts.get(shift_index).and_then(|shift| shift.capacity.as_ref()).unwrap_or(&self.capacity)
+    }
+
+    /// Returns capacity of the vehicle together with its trailer (if any) in the shift with given index.
+    pub fn shift_total_capacity(&self, shift_index: usize) -> Vec<f64> {
+        let capacity = self.shift_capacity(shift_index);
+        let Some(trailer) = self.trailer.as_ref() else { return capacity.clone() };
+
+        (0..capacity.len().max(trailer.capacity.len()))
+            .map(|idx| capacity.get(idx).copied().unwrap_or(0.) + trailer.capacity.get(idx).copied().unwrap_or(0.))
+            .collect()
+    }
+
+    /// Returns vehicle skills in the shift with given index.
+    pub fn shift_skills(&self, shift_index: usize) -> Option<&Vec<VehicleSkill>> {
+        self.shifts.get(shift_index).and_then(|shift| shift.skills.as_ref()).or(self.skills.as_ref())
+    }
+
+    /// Returns vehicle limits in the shift with given index.
+    pub fn shift_limits(&self, shift_index: usize) -> Option<&VehicleLimits> {
+        self.shifts.get(shift_index).and_then(|shift| shift.limits.as_ref()).or(self.limits.as_ref())
+    }
+
+    /// Returns vehicle costs in the shift with given index.
+    pub fn shift_costs(&self, shift_index: usize) -> &VehicleCosts {
+        self.shifts.get(shift_index).and_then(|shift| shift.costs.as_ref()).unwrap_or(&self.costs)
+    }
+
+    /// Returns iterator over vehicle limits defined on vehicle type and its shifts.
+    pub fn all_limits_iter(&self) -> impl Iterator<Item = &VehicleLimits> {
+        self.limits.iter().chain(self.shifts.iter().filter_map(|shift| shift.limits.as_ref()))
+    }
+}
+
 /// Deserializes problem in json format from `BufReader`.
 pub fn deserialize_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, MultiFormatError> {
     serde_json::from_reader(reader).map_err(|err| {
@@ -674,8 +1060,20 @@ pub fn deserialize_problem<R: Read>(reader: BufReader<R>) -> Result<Problem, Mul
     })
 }
 
-/// Deserializes routing matrix in json format from `BufReader`.
-pub fn deserialize_matrix<R: Read>(reader: BufReader<R>) -> Result<Matrix, MultiFormatError> {
+/// Deserializes routing matrix from `BufReader`. Both json and binary formats are supported: the binary one is
+/// detected by its magic bytes, see [`read_binary_matrix`](super::read_binary_matrix).
+pub fn deserialize_matrix<R: Read>(mut reader: BufReader<R>) -> Result<Matrix, MultiFormatError> {
+    if is_binary_matrix(&mut reader).unwrap_or(false) {
+        return read_binary_matrix(reader).map_err(|err| {
+            vec![FormatError::new(
+                "E0001".to_string(),
+                "cannot deserialize matrix".to_string(),
+                format!("check input binary matrix: '{err}'"),
+            )]
+            .into()
+        });
+    }
+
     serde_json::from_reader(reader).map_err(|err| {
         vec![FormatError::new(
             "E0001".to_string(),
@@ -3,7 +3,7 @@ use crate::format::problem::clustering_reader::create_cluster_config;
 use crate::format::problem::fleet_reader::*;
 use crate::format::problem::goal_reader::create_goal_context;
 use crate::format::problem::job_reader::{read_jobs_with_extra_locks, read_locks};
-use crate::format::{FormatError, JobIndex};
+use crate::format::{localize_problem, FormatError, JobIndex, TimezoneIndex, TimezoneIndexExtraProperty};
 use crate::validation::ValidationContext;
 use crate::{parse_time, CoordIndex};
 use vrp_core::construction::enablers::*;
@@ -30,10 +30,16 @@ pub(super) fn map_to_problem(
     matrices: Vec<Matrix>,
     coord_index: CoordIndex,
 ) -> Result<CoreProblem, MultiFormatError> {
-    ValidationContext::new(&api_problem, Some(&matrices), &coord_index).validate()?;
+    let api_problem = localize_problem(api_problem);
+
+    ValidationContext::new(&api_problem, Some(&matrices), &coord_index).validate_localized()?;
 
     let mut extras = Extras::default();
 
+    if let Some(timezone_index) = TimezoneIndex::new(&api_problem, &coord_index) {
+        extras.set_timezone_index(Arc::new(timezone_index));
+    }
+
     extras.set_coord_index(Arc::new(coord_index));
 
     let coord_index = extras.get_coord_index().expect("cannot get coord index");
@@ -123,13 +129,26 @@ fn to_multi_format_error(error: GenericError) -> MultiFormatError {
 }
 
 fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> ProblemProperties {
-    let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some());
-    let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| t.capacity.len() > 1)
-        || api_problem
-            .plan
-            .jobs
-            .iter()
-            .any(|job| job.all_tasks_iter().any(|task| task.demand.as_ref().map_or(false, |d| d.len() > 1)));
+    let has_unreachable_locations = matrices.iter().any(|m| m.error_codes.is_some())
+        || api_problem.fleet.profiles.iter().filter_map(|profile| profile.overlay.as_ref()).any(|overlay| {
+            overlay.unreachable.as_ref().is_some_and(|locations| !locations.is_empty())
+                || overlay.edges.iter().flatten().any(|edge| edge.duration.is_none() && edge.distance.is_none())
+        });
+    // NOTE fractional values or values which do not fit into i32 are supported only by multi dimensional load
+    let is_multi_dimen_load =
+        |load: &[f64]| load.len() > 1 || load.iter().any(|value| value.fract() != 0. || value.abs() > i32::MAX as f64);
+    let has_multi_dimen_capacity = api_problem.fleet.vehicles.iter().any(|t| {
+        is_multi_dimen_load(&t.capacity)
+            || t.shifts.iter().filter_map(|shift| shift.capacity.as_ref()).any(|c| is_multi_dimen_load(c))
+            || t.trailer.as_ref().is_some_and(|trailer| is_multi_dimen_load(&trailer.capacity))
+    }) || api_problem.fleet.resources.iter().flatten().any(|resource| match resource {
+        VehicleResource::Reload { capacity, .. } => is_multi_dimen_load(capacity),
+        VehicleResource::Satellite { .. } => false,
+    }) || api_problem
+        .plan
+        .jobs
+        .iter()
+        .any(|job| job.all_tasks_iter().any(|task| task.demand.as_ref().map_or(false, |d| is_multi_dimen_load(d))));
     let has_skills = api_problem.plan.jobs.iter().any(|job| job.skills.is_some());
 
     let shift_has_fn = |shift_has: fn(&VehicleShift) -> bool| {
@@ -137,7 +156,13 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
     };
 
     let has_breaks = shift_has_fn(|s| s.breaks.as_ref().map_or(false, |b| !b.is_empty()));
-    let has_reloads = shift_has_fn(|s| s.reloads.as_ref().map_or(false, |r| !r.is_empty()));
+    let has_satellites = api_problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .any(|resource| matches!(resource, VehicleResource::Satellite { .. }));
+    let has_reloads = shift_has_fn(|s| s.reloads.as_ref().map_or(false, |r| !r.is_empty())) || has_satellites;
     let has_recharges = shift_has_fn(|s| s.recharges.as_ref().is_some());
 
     let has_order = api_problem
@@ -150,15 +175,37 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
 
     let has_group = api_problem.plan.jobs.iter().any(|job| job.group.is_some());
     let has_value = api_problem.plan.jobs.iter().filter_map(|job| job.value).any(|value| value != 0.);
+    let has_value_decay = api_problem.plan.jobs.iter().any(|job| job.value_decay.is_some());
     let has_compatibility = api_problem.plan.jobs.iter().any(|job| job.compatibility.is_some());
-    let has_tour_size_limits =
-        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().map_or(false, |l| l.tour_size.is_some()));
+    let has_affinity = api_problem.plan.jobs.iter().any(|job| job.affinity.is_some());
+    let has_conflicts =
+        api_problem.plan.jobs.iter().any(|job| job.conflicts.as_ref().is_some_and(|conflicts| !conflicts.is_empty()));
+    let limits_has_fn = |limits_has: fn(&VehicleLimits) -> bool| {
+        api_problem.fleet.vehicles.iter().any(|t| t.all_limits_iter().any(limits_has))
+    };
 
-    let has_tour_travel_limits = api_problem
-        .fleet
-        .vehicles
-        .iter()
-        .any(|v| v.limits.as_ref().map_or(false, |l| l.max_duration.or(l.max_distance).is_some()));
+    let has_tour_size_limits = limits_has_fn(|l| l.tour_size.is_some());
+    let has_tour_travel_limits = limits_has_fn(|l| l.max_duration.or(l.max_distance).is_some());
+    let has_leg_travel_limits = limits_has_fn(|l| l.max_leg_duration.or(l.max_leg_distance).is_some());
+
+    let has_shift_alternatives = shift_has_fn(|s| {
+        s.start.alternatives.as_ref().is_some_and(|a| !a.is_empty())
+            || s.end.as_ref().and_then(|end| end.alternatives.as_ref()).is_some_and(|a| !a.is_empty())
+    });
+
+    let has_transfers = api_problem.plan.transfers.as_ref().is_some_and(|transfers| !transfers.is_empty())
+        && api_problem
+            .plan
+            .jobs
+            .iter()
+            .any(|job| job.transfers.as_ref().is_some_and(|transfers| !transfers.is_empty()));
+
+    let has_trailers = api_problem.fleet.vehicles.iter().any(|t| t.trailer.is_some());
+    let has_backhauls = api_problem.fleet.vehicles.iter().any(|t| t.backhaul.is_some());
+    let has_mixed_backhauls =
+        api_problem.fleet.vehicles.iter().any(|t| matches!(t.backhaul, Some(VehicleBackhaul::Mixed { .. })));
+    let has_travel_uncertainty =
+        api_problem.fleet.profiles.iter().any(|profile| profile.travel_time_uncertainty.is_some());
 
     ProblemProperties {
         has_multi_dimen_capacity,
@@ -170,9 +217,19 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_order,
         has_group,
         has_value,
+        has_value_decay,
         has_compatibility,
+        has_affinity,
+        has_conflicts,
         has_tour_size_limits,
         has_tour_travel_limits,
+        has_leg_travel_limits,
+        has_shift_alternatives,
+        has_transfers,
+        has_trailers,
+        has_backhauls,
+        has_mixed_backhauls,
+        has_travel_uncertainty,
     }
 }
 
@@ -2,12 +2,13 @@ use crate::format::problem::VehicleBreak;
 use crate::format::problem::{Problem as FormatProblem, VehicleRequiredBreakTime};
 use crate::format::solution::{Activity as FormatActivity, Schedule as FormatSchedule, Tour as FormatTour};
 use crate::format::solution::{PointStop, TransitStop};
-use crate::format::{CoordIndex, JobIndex, PlaceTagsDimension};
+use crate::format::{get_transfer_leg_key, CoordIndex, JobIndex, JobTypeDimension, PlaceTagsDimension};
 use crate::parse_time;
 use std::cmp::Ordering;
 use std::collections::HashSet;
 use std::iter::once;
 use std::sync::Arc;
+use vrp_core::construction::features::JobTransferDimension;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{Job, JobIdDimension, Single};
 use vrp_core::models::solution::{Activity, Place};
@@ -38,11 +39,18 @@ pub(crate) fn try_match_point_job(
 
     match activity.activity_type.as_str() {
         "departure" | "arrival" => Ok(None),
-        "pickup" | "delivery" | "replacement" | "service" => {
-            let job =
-                job_index.get(&activity.job_id).ok_or_else(|| format!("unknown job id: '{}'", activity.job_id))?;
+        "pickup" | "delivery" | "replacement" | "service" | "handover" => {
+            let job_key = get_job_key(tour, activity);
+            let job = job_index.get(&job_key).ok_or_else(|| format!("unknown job id: '{}'", activity.job_id))?;
             let singles: Box<dyn Iterator<Item = &Arc<_>>> = match job {
                 Job::Single(single) => Box::new(once(single)),
+                // NOTE transferable job has one task of each activity type
+                Job::Multi(multi) if multi.dimens.get_job_transfer().is_some() => Box::new(
+                    multi
+                        .jobs
+                        .iter()
+                        .filter(|single| single.dimens.get_job_type().is_some_and(|t| *t == activity.activity_type)),
+                ),
                 Job::Multi(multi) => {
                     let tags = multi
                         .jobs
@@ -80,6 +88,25 @@ pub(crate) fn try_match_point_job(
                 .next()
                 .ok_or_else(|| format!("cannot match '{}' for '{}'", ctx.act_type, tour.vehicle_id))?,
         )),
+        "decouple" | "recouple" => Ok(Some(
+            (1..)
+                .map(|idx| format!("{}_parking_{}_{}", tour.vehicle_id, tour.shift_index, idx))
+                .map(|job_id| job_index.get(&job_id))
+                .take_while(|job| job.is_some())
+                .filter_map(|job| job.and_then(|job| job.as_multi().map(|multi| (job.clone(), multi.clone()))))
+                .filter_map(|(job, multi)| {
+                    multi
+                        .jobs
+                        .iter()
+                        .find(|single| single.dimens.get_job_type().is_some_and(|t| *t == activity.activity_type))
+                        .and_then(|single| {
+                            match_place(single, false, &ctx)
+                                .map(|place| JobInfo(job.clone(), single.clone(), place, ctx.time.clone()))
+                        })
+                })
+                .next()
+                .ok_or_else(|| format!("cannot match '{}' for '{}'", ctx.act_type, tour.vehicle_id))?,
+        )),
         _ => Err(format!("unknown activity type: {}", activity.activity_type).into()),
     }
 }
@@ -151,7 +178,7 @@ fn match_place(single: &Arc<Single>, is_job_activity: bool, activity_ctx: &Activ
             .places
             .iter()
             .enumerate()
-            .find(|(_, place)| {
+            .filter(|(_, place)| {
                 let is_same_location = place.location.map_or(true, |l| l == activity_ctx.location);
                 let is_proper_time =
                     place.times.iter().any(|time| time.intersects(activity_ctx.route_start_time, &activity_ctx.time));
@@ -174,6 +201,15 @@ fn match_place(single: &Arc<Single>, is_job_activity: bool, activity_ctx: &Activ
                 };
 
                 Place { idx, location: activity_ctx.location, duration: place.duration, time }
+            })
+            // NOTE places of divisible job differ only by duration, so prefer the one which fits activity time
+            .min_by(|left, right| {
+                let get_diff = |place: &Place| {
+                    let service_time = activity_ctx.time.end - activity_ctx.time.start.max(place.time.start);
+                    (service_time - place.duration).abs()
+                };
+
+                compare_floats(get_diff(left), get_diff(right))
             }),
         _ => None,
     }
@@ -222,6 +258,25 @@ pub(super) fn get_job_tag(single: &Single, place: (Location, (TimeWindow, Timest
     })
 }
 
+/// Returns a job index key of the job activity: a transferred job is served by two tours and each of them
+/// has its own leg job.
+fn get_job_key(tour: &FormatTour, activity: &FormatActivity) -> String {
+    let has_activity = |activity_type: &str| {
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .any(|other| other.job_id == activity.job_id && other.activity_type == activity_type)
+    };
+
+    match activity.activity_type.as_str() {
+        "handover" if has_activity("pickup") => get_transfer_leg_key(&activity.job_id, 1),
+        "handover" => get_transfer_leg_key(&activity.job_id, 2),
+        "pickup" if has_activity("handover") => get_transfer_leg_key(&activity.job_id, 1),
+        "delivery" if has_activity("handover") => get_transfer_leg_key(&activity.job_id, 2),
+        _ => activity.job_id.clone(),
+    }
+}
+
 fn get_job_id(single: &Arc<Single>) -> String {
     Activity {
         place: Place { idx: 0, location: 0, duration: 0.0, time: TimeWindow::new(0., 0.) },
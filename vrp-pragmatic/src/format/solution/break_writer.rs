@@ -163,6 +163,6 @@ fn insert_break(
 
 #[derive(Clone)]
 enum BreakInsertion {
-    TransitBreakUsed { leg_idx: usize, load: Vec<i32> },
+    TransitBreakUsed { leg_idx: usize, load: Vec<f64> },
     TransitBreakMoved { leg_idx: usize, break_tw: TimeWindow },
 }
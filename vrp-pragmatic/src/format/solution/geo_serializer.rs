@@ -143,6 +143,10 @@ fn get_marker_symbol(stop: &PointStop) -> String {
         return "charging-station".to_string();
     }
 
+    if ["decouple", "recouple"].iter().any(contains_activity_type) {
+        return "parking".to_string();
+    }
+
     if contains_activity_type(&"break") {
         return "beer".to_string();
     }
@@ -33,14 +33,17 @@ pub fn read_init_solution<R: Read>(
     let mut registry = Registry::new(&problem.fleet, random);
     let mut added_jobs = HashSet::default();
 
-    let actor_index = registry.all().map(|actor| (get_actor_key(actor.as_ref()), actor)).collect::<HashMap<_, _>>();
+    let actor_index = registry.all().fold(HashMap::<_, Vec<_>>::default(), |mut acc, actor| {
+        acc.entry(get_actor_key(actor.as_ref())).or_default().push(actor);
+        acc
+    });
     let (job_index, coord_index) = get_indices(&problem.extras)?;
 
     let routes =
         solution.tours.iter().try_fold::<_, _, Result<_, GenericError>>(Vec::<_>::default(), |mut routes, tour| {
             let actor_key = (tour.vehicle_id.clone(), tour.type_id.clone(), tour.shift_index);
-            let actor =
-                actor_index.get(&actor_key).ok_or_else(|| format!("cannot find vehicle for {actor_key:?}"))?.clone();
+            let actors = actor_index.get(&actor_key).ok_or_else(|| format!("cannot find vehicle for {actor_key:?}"))?;
+            let actor = find_tour_actor(actors, tour, coord_index.as_ref())?;
             registry.use_actor(&actor);
 
             let mut core_route = create_core_route(actor, tour)?;
@@ -68,6 +71,8 @@ pub fn read_init_solution<R: Read>(
         .unassigned
         .unwrap_or_default()
         .iter()
+        // NOTE partially served job is already added to the tour
+        .filter(|unassigned_job| unassigned_job.remaining.is_none())
         .try_fold::<Vec<_>, _, Result<_, GenericError>>(Default::default(), |mut acc, unassigned_job| {
             let job = job_index
                 .get(&unassigned_job.job_id)
@@ -147,6 +152,41 @@ fn get_actor_key(actor: &Actor) -> ActorKey {
     (vehicle_id, type_id, shift_index)
 }
 
+/// Finds an actor which start and end locations match the tour (vehicle can have alternative depots).
+fn find_tour_actor(
+    actors: &[Arc<Actor>],
+    tour: &FormatTour,
+    coord_index: &CoordIndex,
+) -> Result<Arc<Actor>, GenericError> {
+    // NOTE incomplete tours are matched with any actor: their errors are reported later
+    let get_terminal_location = |stop: Option<&FormatStop>, activity_type: &str| {
+        stop.filter(|stop| stop.activities().iter().any(|activity| activity.activity_type == activity_type))
+            .and_then(|stop| stop.location())
+            .and_then(|location| coord_index.get_by_loc(location))
+    };
+
+    let start_location = get_terminal_location(tour.stops.first(), "departure");
+    let end_location = get_terminal_location(tour.stops.last(), "arrival");
+
+    actors
+        .iter()
+        .find(|actor| {
+            let detail = &actor.detail;
+            start_location.is_none_or(|location| detail.start.as_ref().is_some_and(|place| place.location == location))
+                && end_location
+                    .is_none_or(|location| detail.end.as_ref().is_none_or(|place| place.location == location))
+        })
+        .cloned()
+        .ok_or_else(|| {
+            format!(
+                "tour of vehicle '{}' with shift index {} starts or ends at location which is not its shift location \
+                 or one of its alternatives",
+                tour.vehicle_id, tour.shift_index
+            )
+            .into()
+        })
+}
+
 fn create_core_route(actor: Arc<Actor>, format_tour: &FormatTour) -> Result<Route, GenericError> {
     let mut core_tour = CoreTour::new(&actor);
 
@@ -101,6 +101,25 @@ fn map_code_reason(code: ViolationCode) -> (&'static str, &'static str) {
             ("RELOAD_RESOURCE_CONSTRAINT", "cannot be assigned due to reload resource constraint")
         }
         RECHARGE_CONSTRAINT_CODE => ("RECHARGE_CONSTRAINT_CODE", "cannot be assigned due to recharge constraint"),
+        VEHICLE_ALTERNATIVE_CONSTRAINT_CODE => {
+            ("VEHICLE_ALTERNATIVE_CONSTRAINT", "cannot be assigned due to vehicle alternative constraint")
+        }
+        TRANSFER_CONSTRAINT_CODE => ("TRANSFER_CONSTRAINT", "cannot be assigned due to transfer constraint"),
+        CONFLICT_CONSTRAINT_CODE => ("CONFLICT_CONSTRAINT", "cannot be assigned due to conflict with other jobs"),
+        LEG_DISTANCE_LIMIT_CONSTRAINT_CODE => {
+            ("MAX_LEG_DISTANCE_CONSTRAINT", "cannot be assigned due to max leg distance constraint of vehicle")
+        }
+        LEG_DURATION_LIMIT_CONSTRAINT_CODE => {
+            ("MAX_LEG_DURATION_CONSTRAINT", "cannot be assigned due to max leg duration constraint of vehicle")
+        }
+        TRAILER_CONSTRAINT_CODE => (
+            "TRAILER_CONSTRAINT",
+            "cannot be assigned due to truck access or truck capacity constraint of vehicle with trailer",
+        ),
+        BACKHAUL_CONSTRAINT_CODE => ("BACKHAUL_CONSTRAINT", "cannot be assigned due to backhaul constraint of vehicle"),
+        ROBUST_SCHEDULE_CONSTRAINT_CODE => {
+            ("ROBUST_SCHEDULE_CONSTRAINT", "cannot be visited within time window with required probability")
+        }
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
@@ -122,6 +141,14 @@ fn map_reason_code(reason: &str) -> ViolationCode {
         "COMPATIBILITY_CONSTRAINT" => COMPATIBILITY_CONSTRAINT_CODE,
         "RELOAD_RESOURCE_CONSTRAINT" => RELOAD_RESOURCE_CONSTRAINT_CODE,
         "RECHARGE_CONSTRAINT_CODE" => RECHARGE_CONSTRAINT_CODE,
+        "VEHICLE_ALTERNATIVE_CONSTRAINT" => VEHICLE_ALTERNATIVE_CONSTRAINT_CODE,
+        "TRANSFER_CONSTRAINT" => TRANSFER_CONSTRAINT_CODE,
+        "CONFLICT_CONSTRAINT" => CONFLICT_CONSTRAINT_CODE,
+        "MAX_LEG_DISTANCE_CONSTRAINT" => LEG_DISTANCE_LIMIT_CONSTRAINT_CODE,
+        "MAX_LEG_DURATION_CONSTRAINT" => LEG_DURATION_LIMIT_CONSTRAINT_CODE,
+        "TRAILER_CONSTRAINT" => TRAILER_CONSTRAINT_CODE,
+        "BACKHAUL_CONSTRAINT" => BACKHAUL_CONSTRAINT_CODE,
+        "ROBUST_SCHEDULE_CONSTRAINT" => ROBUST_SCHEDULE_CONSTRAINT_CODE,
         _ => ViolationCode::unknown(),
     }
 }
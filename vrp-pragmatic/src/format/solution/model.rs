@@ -1,7 +1,8 @@
 use super::FeatureCollection;
 use crate::format::{CoordIndex, Location};
 use crate::{format_time, parse_time};
-use serde::{Deserialize, Serialize};
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Serialize, Serializer};
 use std::io::{BufReader, BufWriter, Error, Read, Write};
 use vrp_core::models::common::{Duration, Timestamp};
 use vrp_core::models::solution::Commute as DomainCommute;
@@ -131,7 +132,7 @@ impl Stop {
     }
 
     /// Returns stop's load.
-    pub fn load(&self) -> &Vec<i32> {
+    pub fn load(&self) -> &Vec<f64> {
         match self {
             Self::Transit(transit) => &transit.load,
             Self::Point(point) => &point.load,
@@ -139,7 +140,7 @@ impl Stop {
     }
 
     /// Returns stop's load as mutable.
-    pub fn load_mut(&mut self) -> &mut Vec<i32> {
+    pub fn load_mut(&mut self) -> &mut Vec<f64> {
         match self {
             Self::Transit(transit) => &mut transit.load,
             Self::Point(point) => &mut point.load,
@@ -193,7 +194,8 @@ pub struct TransitStop {
     /// Stop schedule.
     pub time: Schedule,
     /// Vehicle load after departure from this stop.
-    pub load: Vec<i32>,
+    #[serde(serialize_with = "serialize_load")]
+    pub load: Vec<f64>,
     /// Activities performed at the stop.
     pub activities: Vec<Activity>,
 }
@@ -208,7 +210,8 @@ pub struct PointStop {
     /// Distance traveled since departure from start.
     pub distance: i64,
     /// Vehicle load after departure from this stop.
-    pub load: Vec<i32>,
+    #[serde(serialize_with = "serialize_load")]
+    pub load: Vec<f64>,
     /// Parking time.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parking: Option<Interval>,
@@ -256,13 +259,28 @@ pub struct UnassignedJobDetail {
 }
 
 /// Unassigned job.
-#[derive(Clone, Deserialize, Serialize, Eq, PartialEq, Debug)]
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct UnassignedJob {
     /// Job id.
     pub job_id: String,
     /// Possible reasons.
     pub reasons: Vec<UnassignedJobReason>,
+    /// A not served remainder of the partially served job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining: Option<UnassignedJobRemaining>,
+}
+
+/// A not served remainder of the partially served job.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct UnassignedJobRemaining {
+    /// Amount of not served parts.
+    pub parts: usize,
+    /// Not served demand.
+    #[serde(default, skip_serializing_if = "Vec::is_empty", serialize_with = "serialize_load")]
+    pub demand: Vec<f64>,
+    /// Not served service duration.
+    pub duration: f64,
 }
 
 /// Specifies a type of violation.
@@ -364,6 +382,19 @@ pub struct Solution {
     pub extras: Option<Extras>,
 }
 
+/// Serializes load values: integral values are written as integers to keep output of integer demand unchanged.
+fn serialize_load<S: Serializer>(load: &[f64], serializer: S) -> Result<S::Ok, S::Error> {
+    let mut seq = serializer.serialize_seq(Some(load.len()))?;
+    load.iter().try_for_each(|&value| {
+        if value.fract() == 0. && value.abs() < i64::MAX as f64 {
+            seq.serialize_element(&(value as i64))
+        } else {
+            seq.serialize_element(&value)
+        }
+    })?;
+    seq.end()
+}
+
 /// Serializes solution into json format.
 pub fn serialize_solution<W: Write>(solution: &Solution, writer: &mut BufWriter<W>) -> Result<(), Error> {
     serde_json::to_writer_pretty(writer, solution).map_err(Error::from)
@@ -5,16 +5,16 @@ mod writer_test;
 use crate::format::solution::activity_matcher::get_job_tag;
 use crate::format::solution::model::Timing;
 use crate::format::solution::*;
-use crate::format::CoordIndex;
+use crate::format::{CoordIndex, TimezoneIndexExtraProperty};
 use vrp_core::construction::enablers::{get_route_intervals, ReservedTimesIndex};
-use vrp_core::construction::features::JobDemandDimension;
+use vrp_core::construction::features::{get_activity_demand, get_partial_ratio, JobPartialRatiosDimension};
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{JobIdDimension, Multi, TravelTime, VehicleIdDimension};
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::rosomaxa::evolution::TelemetryMetrics;
 use vrp_core::solver::processing::{ClusterConfigExtraProperty, ReservedTimesExtraProperty};
-use vrp_core::utils::CollectGroupBy;
+use vrp_core::utils::{compare_floats, CollectGroupBy};
 
 struct Leg {
     pub last_detail: Option<(DomainLocation, Timestamp)>,
@@ -44,12 +44,16 @@ pub(crate) fn create_solution(
     let reserved_times_index = problem.extras.get_reserved_times();
     let reserved_times_index = reserved_times_index.as_ref().unwrap_or(&empty_reserved_times);
 
-    let tours = solution
+    let mut tours = solution
         .routes
         .iter()
         .map(|r| create_tour(problem, r, &coord_index, reserved_times_index))
         .collect::<Vec<Tour>>();
 
+    if let Some(timezone_index) = problem.extras.get_timezone_index() {
+        tours.iter_mut().for_each(|tour| timezone_index.localize_tour(tour, &coord_index));
+    }
+
     let statistic = tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
 
     let unassigned = create_unassigned(solution);
@@ -89,10 +93,8 @@ fn create_tour(
         let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
             (leg.load.unwrap_or_default(), MultiDimLoad::default()),
             |acc, activity| {
-                let (delivery, pickup) = activity
-                    .job
-                    .as_ref()
-                    .and_then(|job| get_capacity(&job.dimens).map(|d| (d.delivery.0, d.pickup.0)))
+                let (delivery, pickup) = get_activity_capacity(activity)
+                    .map(|d| (d.delivery.0, d.pickup.0))
                     .unwrap_or((MultiDimLoad::default(), MultiDimLoad::default()));
                 (acc.0 + delivery, acc.1 + pickup)
             },
@@ -120,7 +122,7 @@ fn create_tour(
                     } else {
                         None
                     },
-                    job_tag: None,
+                    job_tag: vehicle.dimens.get_shift_start_tag().cloned(),
                     commute: None,
                 }],
                 parking: None,
@@ -139,19 +141,22 @@ fn create_tour(
                     leg.load.unwrap()
                 } else {
                     // NOTE arrival must have zero load
-                    let dimen_size = leg.load.unwrap().size;
-                    MultiDimLoad::new(vec![0; dimen_size])
+                    let dimen_size = leg.load.as_ref().unwrap().size();
+                    MultiDimLoad::new(vec![0.; dimen_size])
                 };
 
                 let activity_type = activity_type.unwrap_or_else(|| "arrival".to_string());
                 let is_break = activity_type == "break";
 
-                let job_tag = act.job.as_ref().and_then(|single| {
-                    get_job_tag(single, (act.place.location, (act.place.time.clone(), start.schedule.departure)))
-                        .cloned()
-                });
+                let job_tag = match act.job.as_ref() {
+                    Some(single) => {
+                        get_job_tag(single, (act.place.location, (act.place.time.clone(), start.schedule.departure)))
+                            .cloned()
+                    }
+                    None => vehicle.dimens.get_shift_end_tag().cloned(),
+                };
                 let job_id = match activity_type.as_str() {
-                    "pickup" | "delivery" | "replacement" | "service" => {
+                    "pickup" | "delivery" | "replacement" | "service" | "handover" => {
                         let single = act.job.as_ref().unwrap();
                         let id = single.dimens.get_job_id().cloned();
                         id.unwrap_or_else(|| Multi::roots(single).unwrap().dimens.get_job_id().unwrap().clone())
@@ -319,8 +324,7 @@ fn format_schedule(schedule: &DomainSchedule) -> ApiSchedule {
 }
 
 fn calculate_load(current: MultiDimLoad, act: &Activity) -> MultiDimLoad {
-    let job = act.job.as_ref();
-    let demand = job.and_then(|job| get_capacity(&job.dimens)).unwrap_or_default();
+    let demand = get_activity_capacity(act).unwrap_or_default();
     current - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1
 }
 
@@ -372,8 +376,9 @@ fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
                 _ => create_simple_reasons(ViolationCode(0)),
             };
 
-            UnassignedJob { job_id, reasons }
+            UnassignedJob { job_id, reasons, remaining: None }
         })
+        .chain(create_partially_served(solution))
         .collect::<Vec<_>>();
 
     if unassigned.is_empty() {
@@ -383,6 +388,37 @@ fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
     }
 }
 
+/// Returns jobs which are served partially with their remaining parts, demand and service duration.
+fn create_partially_served(solution: &DomainSolution) -> impl Iterator<Item = UnassignedJob> + '_ {
+    solution.routes.iter().flat_map(|route| route.tour.all_activities()).filter_map(|activity| {
+        let single = activity.job.as_ref()?;
+        let ratio = get_partial_ratio(activity).filter(|&ratio| ratio > 0. && ratio < 1.)?;
+        let job_id = single.dimens.get_job_id()?.clone();
+
+        // NOTE the smallest ratio corresponds to one part of the job
+        let min_ratio =
+            single.dimens.get_job_partial_ratios()?.iter().copied().min_by(|a, b| compare_floats(*a, *b))?;
+        let parts = ((1. - ratio) / min_ratio).round() as usize;
+        let scale = parts as f64 * min_ratio / ratio;
+
+        let demand = get_activity_capacity(activity)
+            .map(|demand| demand.pickup.0 + demand.pickup.1 + demand.delivery.0 + demand.delivery.1)
+            .filter(|demand| demand.size() > 0)
+            .map(|demand| (demand * scale).as_vec())
+            .unwrap_or_default();
+
+        Some(UnassignedJob {
+            job_id,
+            reasons: vec![UnassignedJobReason {
+                code: "PARTIAL_SERVICE".to_string(),
+                description: "job is served partially".to_string(),
+                details: None,
+            }],
+            remaining: Some(UnassignedJobRemaining { parts, demand, duration: activity.place.duration * scale }),
+        })
+    })
+}
+
 fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
     // NOTE at the moment only break violation is mapped
     let violations = solution
@@ -406,9 +442,10 @@ fn get_activity_type(activity: &Activity) -> Option<&String> {
     activity.job.as_ref().and_then(|single| single.dimens.get_job_type())
 }
 
-fn get_capacity(dimens: &Dimensions) -> Option<Demand<MultiDimLoad>> {
+/// Returns a demand of the job served by the activity as multidimensional load.
+fn get_activity_capacity(activity: &Activity) -> Option<Demand<MultiDimLoad>> {
     // NOTE: try to detect whether dimensions stores multidimensional demand
-    let demand: Option<Demand<MultiDimLoad>> = dimens.get_job_demand().cloned();
+    let demand: Option<Demand<MultiDimLoad>> = get_activity_demand(activity).cloned();
     if let Some(demand) = demand {
         return Some(demand);
     }
@@ -417,10 +454,10 @@ fn get_capacity(dimens: &Dimensions) -> Option<Demand<MultiDimLoad>> {
         if capacity.value == 0 {
             MultiDimLoad::default()
         } else {
-            MultiDimLoad::new(vec![capacity.value])
+            MultiDimLoad::new(vec![capacity.value as f64])
         }
     };
-    dimens.get_job_demand().map(|demand: &Demand<SingleDimLoad>| Demand {
+    get_activity_demand(activity).map(|demand: &Demand<SingleDimLoad>| Demand {
         pickup: (create_capacity(demand.pickup.0), create_capacity(demand.pickup.1)),
         delivery: (create_capacity(demand.delivery.0), create_capacity(demand.delivery.1)),
     })
@@ -0,0 +1,306 @@
+//! Provides logic to work with time zones: it resolves local date times of the problem definition and
+//! writes solution times in local time of stop locations.
+
+#[cfg(test)]
+#[path = "../../tests/unit/format/timezones_test.rs"]
+mod timezones_test;
+
+use crate::format::problem::*;
+use crate::format::solution::{Stop, Tour};
+use crate::format::CoordIndex;
+use crate::{format_time, parse_time};
+use std::collections::HashMap;
+use time::format_description::well_known::Rfc3339;
+use time::format_description::FormatItem;
+use time::macros::format_description;
+use time::{OffsetDateTime, PrimitiveDateTime};
+use time_tz::{timezones, Offset, OffsetResult, PrimitiveDateTimeExt, TimeZone, Tz};
+
+/// A format of local date time (without offset).
+const LOCAL_TIME_FORMAT: &[FormatItem<'static>] =
+    format_description!("[year]-[month]-[day]T[hour]:[minute]:[second][optional [.[subsecond]]]");
+
+/// Keeps track of time zones used by problem locations.
+pub struct TimezoneIndex {
+    default: Option<&'static Tz>,
+    locations: HashMap<usize, &'static Tz>,
+}
+
+impl TimezoneIndex {
+    /// Creates a new instance of `TimezoneIndex`. Returns `None` when problem has no time zones specified.
+    pub fn new(problem: &Problem, coord_index: &CoordIndex) -> Option<Self> {
+        if !has_timezones(problem) {
+            return None;
+        }
+
+        let default = get_timezone(&problem.timezone, None);
+
+        let job_places = problem
+            .plan
+            .jobs
+            .iter()
+            .flat_map(|job| job.all_tasks_iter())
+            .flat_map(|task| task.places.iter())
+            .map(|place| (&place.location, get_timezone(&place.timezone, default)))
+            .chain(
+                problem
+                    .plan
+                    .transfers
+                    .iter()
+                    .flatten()
+                    .map(|transfer| (&transfer.location, get_timezone(&transfer.timezone, default))),
+            )
+            .chain(
+                get_trailer_parkings(problem)
+                    .map(|parking| (&parking.location, get_timezone(&parking.timezone, default))),
+            );
+
+        let shift_places = problem.fleet.vehicles.iter().flat_map(|vehicle| vehicle.shifts.iter()).flat_map(|shift| {
+            let start_tz = get_timezone(&shift.start.timezone, default);
+
+            let start_places = std::iter::once(&shift.start.location)
+                .chain(shift.start.alternatives.iter().flatten().map(|alternative| &alternative.location))
+                .map(move |location| (location, start_tz));
+
+            let end_places = shift.end.iter().flat_map(move |end| {
+                let end_tz = get_timezone(&end.timezone, start_tz);
+                std::iter::once(&end.location)
+                    .chain(end.alternatives.iter().flatten().map(|alternative| &alternative.location))
+                    .map(move |location| (location, end_tz))
+            });
+
+            start_places
+                .chain(end_places)
+                .chain(shift.reloads.iter().flatten().map(move |reload| (&reload.location, start_tz)))
+                .chain(
+                    shift
+                        .recharges
+                        .iter()
+                        .flat_map(|recharges| recharges.stations.iter())
+                        .map(move |station| (&station.location, get_timezone(&station.timezone, start_tz))),
+                )
+        });
+
+        let locations = job_places
+            .chain(shift_places)
+            .filter_map(|(location, timezone)| coord_index.get_by_loc(location).zip(timezone))
+            .fold(HashMap::new(), |mut acc, (location, timezone)| {
+                acc.entry(location).or_insert(timezone);
+                acc
+            });
+
+        Some(Self { default, locations })
+    }
+
+    /// Returns a time zone of given location, fallbacks to problem's time zone if it is not known.
+    pub fn get(&self, location: usize) -> Option<&'static Tz> {
+        self.locations.get(&location).copied().or(self.default)
+    }
+
+    /// Converts all times of the tour to local time of its stop locations.
+    pub(crate) fn localize_tour(&self, tour: &mut Tour, coord_index: &CoordIndex) {
+        // NOTE transit stops have no location, so time zone of previous stop is used
+        tour.stops.iter_mut().fold(self.default, |last_tz, stop| {
+            let timezone =
+                stop.location().and_then(|location| coord_index.get_by_loc(location)).and_then(|idx| self.get(idx));
+            let timezone = timezone.or(last_tz);
+
+            if let Some(timezone) = timezone {
+                localize_stop(stop, timezone);
+            }
+
+            timezone
+        });
+    }
+}
+
+/// Checks whether problem has any time zone specified.
+pub(crate) fn has_timezones(problem: &Problem) -> bool {
+    problem.timezone.is_some()
+        || problem
+            .plan
+            .jobs
+            .iter()
+            .flat_map(|job| job.all_tasks_iter())
+            .any(|task| task.places.iter().any(|place| place.timezone.is_some()))
+        || problem.plan.transfers.iter().flatten().any(|transfer| transfer.timezone.is_some())
+        || get_trailer_parkings(problem).any(|parking| parking.timezone.is_some())
+        || problem.fleet.vehicles.iter().flat_map(|vehicle| vehicle.shifts.iter()).any(|shift| {
+            shift.start.timezone.is_some()
+                || shift.end.as_ref().is_some_and(|end| end.timezone.is_some())
+                || shift
+                    .recharges
+                    .iter()
+                    .flat_map(|recharges| recharges.stations.iter())
+                    .any(|station| station.timezone.is_some())
+        })
+}
+
+/// Checks whether given time zone name is a known IANA time zone.
+pub(crate) fn is_known_timezone(name: &str) -> bool {
+    timezones::get_by_name(name).is_some()
+}
+
+/// Converts all local date times (without offset) in the problem definition to RFC3339 date times using
+/// time zones specified on problem, job place or vehicle shift level. Date times which cannot be resolved
+/// are kept untouched, so they are reported by validation.
+pub(crate) fn localize_problem(mut problem: Problem) -> Problem {
+    if !has_timezones(&problem) {
+        return problem;
+    }
+
+    let default = get_timezone(&problem.timezone, None);
+
+    problem.plan.jobs.iter_mut().for_each(|job| {
+        job.pickups
+            .iter_mut()
+            .chain(job.deliveries.iter_mut())
+            .chain(job.replacements.iter_mut())
+            .chain(job.services.iter_mut())
+            .flatten()
+            .flat_map(|task| task.places.iter_mut())
+            .for_each(|place| localize_place(place, default));
+
+        job.value_decay.iter_mut().flatten().for_each(|point| localize_time(&mut point.time, default));
+    });
+
+    problem.plan.transfers.iter_mut().flatten().for_each(|transfer| {
+        let timezone = get_timezone(&transfer.timezone, default);
+        transfer.times.iter_mut().flatten().flatten().for_each(|time| localize_time(time, timezone));
+    });
+
+    problem
+        .fleet
+        .profiles
+        .iter_mut()
+        .flat_map(|profile| profile.speed_bands.iter_mut().flatten())
+        .for_each(|band| localize_time(&mut band.start, default));
+
+    problem
+        .fleet
+        .vehicles
+        .iter_mut()
+        .filter_map(|vehicle| vehicle.trailer.as_mut())
+        .flat_map(|trailer| trailer.parkings.iter_mut())
+        .for_each(|parking| localize_place(parking, default));
+
+    problem.fleet.vehicles.iter_mut().flat_map(|vehicle| vehicle.shifts.iter_mut()).for_each(|shift| {
+        let start_tz = get_timezone(&shift.start.timezone, default);
+
+        localize_time(&mut shift.start.earliest, start_tz);
+        shift.start.latest.iter_mut().for_each(|time| localize_time(time, start_tz));
+
+        if let Some(end) = shift.end.as_mut() {
+            let end_tz = get_timezone(&end.timezone, start_tz);
+            end.earliest.iter_mut().for_each(|time| localize_time(time, end_tz));
+            localize_time(&mut end.latest, end_tz);
+        }
+
+        shift.breaks.iter_mut().flatten().for_each(|vehicle_break| match vehicle_break {
+            VehicleBreak::Optional { time: VehicleOptionalBreakTime::TimeWindow(tw), .. } => {
+                tw.iter_mut().for_each(|time| localize_time(time, start_tz))
+            }
+            VehicleBreak::Required { time: VehicleRequiredBreakTime::ExactTime { earliest, latest }, .. } => {
+                localize_time(earliest, start_tz);
+                localize_time(latest, start_tz);
+            }
+            _ => {}
+        });
+
+        shift
+            .reloads
+            .iter_mut()
+            .flatten()
+            .flat_map(|reload| reload.times.iter_mut().flatten())
+            .for_each(|tw| tw.iter_mut().for_each(|time| localize_time(time, start_tz)));
+
+        shift
+            .recharges
+            .iter_mut()
+            .flat_map(|recharges| recharges.stations.iter_mut())
+            .for_each(|station| localize_place(station, start_tz));
+    });
+
+    problem
+}
+
+/// Formats timestamp as RFC3339 date time with offset of given time zone. Falls back to UTC when
+/// the timestamp cannot be represented in the time zone.
+pub(crate) fn format_time_with_timezone(time: f64, timezone: &Tz) -> String {
+    OffsetDateTime::from_unix_timestamp(time as i64)
+        .ok()
+        .and_then(|utc_time| {
+            let offset = timezone.get_offset_utc(&utc_time).to_utc();
+            utc_time.checked_to_offset(offset)
+        })
+        .and_then(|local_time| local_time.format(&Rfc3339).ok())
+        .unwrap_or_else(|| format_time(time))
+}
+
+fn get_trailer_parkings(problem: &Problem) -> impl Iterator<Item = &VehicleTrailerParking> + '_ {
+    problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| vehicle.trailer.as_ref())
+        .flat_map(|trailer| trailer.parkings.iter())
+}
+
+fn get_timezone(name: &Option<String>, fallback: Option<&'static Tz>) -> Option<&'static Tz> {
+    name.as_ref().map_or(fallback, |name| timezones::get_by_name(name))
+}
+
+fn localize_place(place: &mut JobPlace, fallback: Option<&'static Tz>) {
+    let timezone = get_timezone(&place.timezone, fallback);
+    place.times.iter_mut().flatten().flatten().for_each(|time| localize_time(time, timezone));
+}
+
+fn localize_time(time: &mut String, timezone: Option<&'static Tz>) {
+    let Some(timezone) = timezone else { return };
+    let Ok(local) = PrimitiveDateTime::parse(time, LOCAL_TIME_FORMAT) else { return };
+
+    let offset_time = match local.assume_timezone(timezone) {
+        // NOTE ambiguous local time (clocks are turned back) resolves to the earliest one
+        OffsetResult::Some(offset_time) | OffsetResult::Ambiguous(offset_time, _) => offset_time,
+        // NOTE local time is skipped (clocks are turned forward): shift it forward by the gap length
+        OffsetResult::None => {
+            let before = (local - time::Duration::DAY).assume_utc();
+            local.assume_offset(timezone.get_offset_utc(&before).to_utc())
+        }
+    };
+
+    if let Ok(offset_time) = offset_time.format(&Rfc3339) {
+        *time = offset_time;
+    }
+}
+
+fn localize_stop(stop: &mut Stop, timezone: &Tz) {
+    let convert = |time: &mut String| *time = format_time_with_timezone(parse_time(time), timezone);
+
+    let schedule = stop.schedule_mut();
+    convert(&mut schedule.arrival);
+    convert(&mut schedule.departure);
+
+    if let Stop::Point(point) = stop {
+        point.parking.iter_mut().for_each(|parking| {
+            convert(&mut parking.start);
+            convert(&mut parking.end);
+        });
+    }
+
+    stop.activities_mut().iter_mut().for_each(|activity| {
+        activity.time.iter_mut().for_each(|interval| {
+            convert(&mut interval.start);
+            convert(&mut interval.end);
+        });
+
+        activity
+            .commute
+            .iter_mut()
+            .flat_map(|commute| commute.forward.iter_mut().chain(commute.backward.iter_mut()))
+            .for_each(|info| {
+                convert(&mut info.time.start);
+                convert(&mut info.time.end);
+            });
+    });
+}
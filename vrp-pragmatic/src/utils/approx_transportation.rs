@@ -2,53 +2,154 @@
 #[path = "../../tests/unit/utils/approx_transportation_test.rs"]
 mod approx_transportation_test;
 
+use crate::format::problem::DistanceType;
 use crate::format::{CustomLocationType, Location};
+use std::collections::HashMap;
 use vrp_core::models::common::Distance;
 use vrp_core::utils::parallel_collect;
 
-/// Gets approximated durations and distances rounded to nearest integer.
-pub fn get_approx_transportation(locations: &[Location], speeds: &[f64]) -> Vec<(Vec<i64>, Vec<i64>)> {
-    assert!(!speeds.is_empty());
-    assert!(speeds.iter().all(|&speed| speed > 0.));
-
-    let distances = locations
-        .iter()
-        .flat_map(|l1| locations.iter().map(move |l2| get_haversine_distance(l1, l2)))
-        .collect::<Vec<_>>();
-
-    let distances_rounded = distances.iter().map(|distance| distance.round() as i64).collect::<Vec<_>>();
+/// Specifies how routing is approximated for a single profile.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ApproxProfile {
+    /// A distance type.
+    pub distance_type: DistanceType,
+    /// A factor applied to calculated distances.
+    pub detour_factor: f64,
+    /// A speed in meters per second.
+    pub speed: f64,
+}
 
-    parallel_collect(speeds, |speed| {
-        let durations = distances.iter().map(|distance| (distance / speed).round() as i64).collect::<Vec<_>>();
+impl ApproxProfile {
+    /// Creates a new instance of `ApproxProfile`.
+    pub fn new(distance_type: DistanceType, detour_factor: f64, speed: f64) -> Self {
+        Self { distance_type, detour_factor, speed }
+    }
+}
 
-        (durations, distances_rounded.clone())
+/// Gets approximated durations and distances rounded to nearest integer for each profile.
+pub fn get_approx_transportation(locations: &[Location], profiles: &[ApproxProfile]) -> Vec<(Vec<i64>, Vec<i64>)> {
+    assert!(!profiles.is_empty());
+    assert!(profiles.iter().all(|profile| profile.speed > 0. && profile.detour_factor > 0.));
+
+    // NOTE calculate distances only once per distance type
+    let distances = profiles.iter().fold(HashMap::<DistanceType, Vec<f64>>::default(), |mut acc, profile| {
+        acc.entry(profile.distance_type).or_insert_with(|| {
+            locations
+                .iter()
+                .flat_map(|l1| locations.iter().map(move |l2| get_distance(profile.distance_type, l1, l2)))
+                .collect()
+        });
+        acc
+    });
+
+    parallel_collect(profiles, |profile| {
+        let distances = distances[&profile.distance_type].iter().map(|distance| distance * profile.detour_factor);
+
+        distances
+            .map(|distance| ((distance / profile.speed).round() as i64, distance.round() as i64))
+            .unzip::<_, _, Vec<_>, Vec<_>>()
     })
 }
 
+/// Gets distance between two points using given distance type.
+pub(crate) fn get_distance(distance_type: DistanceType, p1: &Location, p2: &Location) -> f64 {
+    match distance_type {
+        DistanceType::Haversine => get_haversine_distance(p1, p2),
+        DistanceType::Geodesic => get_geodesic_distance(p1, p2),
+        DistanceType::Euclidean => get_planar_distance(p1, p2, |dx, dy| (dx * dx + dy * dy).sqrt()),
+        DistanceType::Manhattan => get_planar_distance(p1, p2, |dx, dy| dx.abs() + dy.abs()),
+    }
+}
+
 /// Gets distance between two points using haversine formula.
 pub(crate) fn get_haversine_distance(p1: &Location, p2: &Location) -> f64 {
-    if matches!(p1, Location::Custom { r#type: CustomLocationType::Unknown })
-        || matches!(p2, Location::Custom { r#type: CustomLocationType::Unknown })
-    {
+    if is_unknown(p1) || is_unknown(p2) {
+        return Distance::default();
+    }
+
+    vrp_core::utils::get_haversine_distance(as_lat_lon(p1.clone()), as_lat_lon(p2.clone()))
+}
+
+/// Gets distance between two points on WGS-84 ellipsoid using Vincenty's inverse formula. Falls back to haversine
+/// formula when the formula does not converge (nearly antipodal points).
+pub(crate) fn get_geodesic_distance(p1: &Location, p2: &Location) -> f64 {
+    const WGS84_A: f64 = 6_378_137.0;
+    const WGS84_F: f64 = 1. / 298.257_223_563;
+    const WGS84_B: f64 = WGS84_A * (1. - WGS84_F);
+    const MAX_ITERATIONS: usize = 200;
+
+    if is_unknown(p1) || is_unknown(p2) {
         return Distance::default();
     }
 
     let (p1_lat, p1_lng) = as_lat_lon(p1.clone());
     let (p2_lat, p2_lng) = as_lat_lon(p2.clone());
 
-    let d_lat = degree_rad(p1_lat - p2_lat);
-    let d_lng = degree_rad(p1_lng - p2_lng);
+    let l = degree_rad(p2_lng - p1_lng);
+    let u1 = ((1. - WGS84_F) * degree_rad(p1_lat).tan()).atan();
+    let u2 = ((1. - WGS84_F) * degree_rad(p2_lat).tan()).atan();
+    let (sin_u1, cos_u1) = u1.sin_cos();
+    let (sin_u2, cos_u2) = u2.sin_cos();
+
+    let mut lambda = l;
+    for _ in 0..MAX_ITERATIONS {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        let sin_sigma =
+            ((cos_u2 * sin_lambda).powi(2) + (cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda).powi(2)).sqrt();
+        if sin_sigma == 0. {
+            return 0.;
+        }
+
+        let cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+        let sigma = sin_sigma.atan2(cos_sigma);
+        let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+        let cos_sq_alpha = 1. - sin_alpha * sin_alpha;
+        // NOTE cos_sq_alpha is zero when both points are on equator
+        let cos_2sigma_m = if cos_sq_alpha == 0. { 0. } else { cos_sigma - 2. * sin_u1 * sin_u2 / cos_sq_alpha };
+        let c = WGS84_F / 16. * cos_sq_alpha * (4. + WGS84_F * (4. - 3. * cos_sq_alpha));
+
+        let lambda_prev = lambda;
+        lambda = l
+            + (1. - c)
+                * WGS84_F
+                * sin_alpha
+                * (sigma + c * sin_sigma * (cos_2sigma_m + c * cos_sigma * (-1. + 2. * cos_2sigma_m.powi(2))));
+
+        if (lambda - lambda_prev).abs() < 1E-12 {
+            let u_sq = cos_sq_alpha * (WGS84_A.powi(2) - WGS84_B.powi(2)) / WGS84_B.powi(2);
+            let a = 1. + u_sq / 16384. * (4096. + u_sq * (-768. + u_sq * (320. - 175. * u_sq)));
+            let b = u_sq / 1024. * (256. + u_sq * (-128. + u_sq * (74. - 47. * u_sq)));
+            let delta_sigma = b
+                * sin_sigma
+                * (cos_2sigma_m
+                    + b / 4.
+                        * (cos_sigma * (-1. + 2. * cos_2sigma_m.powi(2))
+                            - b / 6.
+                                * cos_2sigma_m
+                                * (-3. + 4. * sin_sigma.powi(2))
+                                * (-3. + 4. * cos_2sigma_m.powi(2))));
+
+            return WGS84_B * a * (sigma - delta_sigma);
+        }
+    }
 
-    let lat1 = degree_rad(p1_lat);
-    let lat2 = degree_rad(p2_lat);
+    get_haversine_distance(p1, p2)
+}
 
-    let a =
-        (d_lat / 2.).sin() * (d_lat / 2.).sin() + (d_lng / 2.).sin() * (d_lng / 2.).sin() * (lat1).cos() * (lat2).cos();
-    let c = 2. * a.sqrt().atan2((1. - a).sqrt());
+/// Gets distance between two points with planar coordinates: `lng` is used as `x` and `lat` as `y`.
+fn get_planar_distance(p1: &Location, p2: &Location, distance_fn: impl Fn(f64, f64) -> f64) -> f64 {
+    if is_unknown(p1) || is_unknown(p2) {
+        return Distance::default();
+    }
 
-    let radius = wgs84_earth_radius(d_lat);
+    let (p1_y, p1_x) = as_lat_lon(p1.clone());
+    let (p2_y, p2_x) = as_lat_lon(p2.clone());
 
-    radius * c
+    distance_fn(p2_x - p1_x, p2_y - p1_y)
+}
+
+fn is_unknown(location: &Location) -> bool {
+    matches!(location, Location::Custom { r#type: CustomLocationType::Unknown })
 }
 
 /// Converts degrees to radians.
@@ -57,21 +158,6 @@ fn degree_rad(degrees: f64) -> f64 {
     std::f64::consts::PI * degrees / 180.
 }
 
-#[inline(always)]
-fn wgs84_earth_radius(lat: f64) -> f64 {
-    // semi-axes of WGS-84 geoidal reference
-    const WGS84_A: f64 = 6_378_137.0; // major semiaxis [m]
-    const WGS84_B: f64 = 6_356_752.3; // minor semiaxis [m]
-
-    // http://en.wikipedia.org/wiki/Earth_radius
-    let an = WGS84_A * WGS84_A * lat.cos();
-    let bn = WGS84_B * WGS84_B * lat.sin();
-    let ad = WGS84_A * lat.cos();
-    let bd = WGS84_B * lat.sin();
-
-    ((an * an + bn * bn) / (ad * ad + bd * bd)).sqrt()
-}
-
 fn as_lat_lon(location: Location) -> (f64, f64) {
     match location {
         Location::Coordinate { lat, lng } => (lat, lng),
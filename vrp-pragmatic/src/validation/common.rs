@@ -69,3 +69,10 @@ pub fn get_duplicates<'a>(items: impl Iterator<Item = &'a String>) -> Option<Vec
         Some(duplicates)
     }
 }
+
+/// Checks that skill levels are positive and skill names are unique.
+pub fn check_skill_levels<'a>(mut skills: impl Iterator<Item = (&'a String, Option<u32>)>) -> bool {
+    let mut names = HashSet::<_>::default();
+
+    skills.all(|(name, level)| names.insert(name) && level.is_none_or(|level| level > 0))
+}
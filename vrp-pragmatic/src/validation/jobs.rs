@@ -3,7 +3,10 @@
 mod jobs_test;
 
 use super::*;
+use crate::parse_time_safe;
 use crate::utils::combine_error_results;
+use std::collections::{HashMap, HashSet};
+use vrp_core::construction::features::ValueDecay;
 use vrp_core::models::common::MultiDimLoad;
 
 /// Checks that plan has no jobs with duplicate ids.
@@ -46,7 +49,7 @@ fn check_e1101_correct_job_types_demand(ctx: &ValidationContext) -> Result<(), F
 
 /// Checks that sum of pickup/delivery demand should be equal.
 fn check_e1102_multiple_pickups_deliveries_demand(ctx: &ValidationContext) -> Result<(), FormatError> {
-    let has_tasks = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().map_or(false, |tasks| !tasks.is_empty());
+    let has_tasks = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().is_some_and(|tasks| !tasks.is_empty());
     let get_demand = |tasks: &Option<Vec<JobTask>>| {
         if let Some(tasks) = tasks {
             tasks.iter().map(|task| task.demand.clone().map_or_else(MultiDimLoad::default, MultiDimLoad::new)).sum()
@@ -163,7 +166,7 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
         .filter(|job| {
             ctx.tasks(job)
                 .iter()
-                .any(|task| task.demand.as_ref().map_or(false, |demand| demand.iter().any(|&dim| dim < 0)))
+                .any(|task| task.demand.as_ref().map_or(false, |demand| demand.iter().any(|&dim| dim < 0.)))
         })
         .map(|job| job.id.clone())
         .collect::<Vec<_>>();
@@ -179,6 +182,212 @@ fn check_e1107_negative_demand(ctx: &ValidationContext) -> Result<(), FormatErro
     }
 }
 
+/// Checks that transfer points are defined correctly.
+fn check_e1108_correct_transfer_points(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let transfers = ctx.problem.plan.transfers.iter().flatten().collect::<Vec<_>>();
+
+    let mut ids = get_duplicates(transfers.iter().map(|transfer| &transfer.id)).unwrap_or_default();
+    ids.extend(
+        transfers
+            .iter()
+            .filter(|transfer| {
+                transfer.duration.is_sign_negative()
+                    || transfer.times.as_ref().is_some_and(|tws| !check_raw_time_windows(tws, false))
+            })
+            .map(|transfer| transfer.id.clone()),
+    );
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1108".to_string(),
+            "invalid transfer points".to_string(),
+            format!(
+                "use unique ids, non negative duration and correct time windows for transfer points: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that jobs with transfers are defined correctly.
+fn check_e1109_correct_job_transfers(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let transfer_ids = ctx.problem.plan.transfers.iter().flatten().map(|transfer| &transfer.id).collect::<HashSet<_>>();
+    let relation_job_ids =
+        ctx.problem.plan.relations.iter().flatten().flat_map(|relation| relation.jobs.iter()).collect::<HashSet<_>>();
+    let task_size = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().map_or(0, |tasks| tasks.len());
+
+    let ids = ctx
+        .jobs()
+        .filter_map(|job| job.transfers.as_ref().map(|transfers| (job, transfers)))
+        .filter(|(job, transfers)| {
+            transfers.is_empty()
+                || transfers.iter().any(|transfer_id| !transfer_ids.contains(transfer_id))
+                || task_size(&job.pickups) != 1
+                || task_size(&job.deliveries) != 1
+                || task_size(&job.replacements) != 0
+                || task_size(&job.services) != 0
+                || job.group.is_some()
+                || relation_job_ids.contains(&job.id)
+        })
+        .map(|(job, _)| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1109".to_string(),
+            "invalid job transfers".to_string(),
+            format!(
+                "use known transfer point ids only for jobs with one pickup and one delivery, \
+                 not used in groups or relations, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that job affinity has non-empty group and positive weight.
+fn check_e1110_correct_job_affinity(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.affinity.as_ref().is_some_and(|affinity| {
+                affinity.group.is_empty() || affinity.weight.is_some_and(|weight| weight <= 0. || !weight.is_finite())
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1110".to_string(),
+            "invalid job affinity".to_string(),
+            format!("use non-empty affinity group and positive weight, jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job conflicts refer to other known jobs which are not in the same group.
+fn check_e1111_correct_job_conflicts(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let jobs = ctx.jobs().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
+
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.conflicts.iter().flatten().any(|other_id| {
+                jobs.get(other_id.as_str())
+                    .is_none_or(|other| other.id == job.id || (job.group.is_some() && job.group == other.group))
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1111".to_string(),
+            "invalid job conflicts".to_string(),
+            format!("use ids of other existing jobs not from the same group as conflicts, jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job skill levels are positive and defined once per skill.
+fn check_e1112_correct_job_skill_levels(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids =
+        ctx.jobs()
+            .filter(|job| {
+                job.skills.as_ref().and_then(|skills| skills.min_levels.as_ref()).is_some_and(|levels| {
+                    !check_skill_levels(levels.iter().map(|skill| (&skill.name, Some(skill.level))))
+                })
+            })
+            .map(|job| job.id.clone())
+            .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1112".to_string(),
+            "invalid job skill levels".to_string(),
+            format!("use positive minimum skill levels with unique skill names, jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that job value decay has valid points and is used together with job value.
+fn check_e1113_correct_job_value_decay(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.value_decay.as_ref().is_some_and(|points| {
+                let points = points
+                    .iter()
+                    .map(|point| parse_time_safe(&point.time).map(|time| (time, point.factor)))
+                    .collect::<Result<Vec<_>, _>>();
+
+                job.value.is_none() || points.map_or(true, |points| ValueDecay::new(points).is_err())
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1113".to_string(),
+            "invalid job value decay".to_string(),
+            format!(
+                "specify job value and use value decay points sorted by time with non-negative factors, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
+const MAX_DIVISIBLE_PLACES: usize = 32;
+
+/// Checks that divisible job has only one task and amount of its parts is in allowed range.
+fn check_e1114_correct_job_divisible(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.divisible.as_ref().is_some_and(|divisible| {
+                let tasks = [&job.pickups, &job.deliveries, &job.replacements, &job.services]
+                    .into_iter()
+                    .flat_map(|tasks| tasks.iter().flatten())
+                    .collect::<Vec<_>>();
+                let places = tasks.first().map_or(0, |task| task.places.len());
+
+                // NOTE each part of each place is an alternative place evaluated on insertion, so it is limited
+                tasks.len() != 1 || divisible.parts == 0 || divisible.parts * places > MAX_DIVISIBLE_PLACES
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1114".to_string(),
+            "invalid divisible job".to_string(),
+            format!(
+                "use divisible property only with one task and at least one part, amount of parts multiplied by \
+                 amount of places should not exceed {MAX_DIVISIBLE_PLACES}, jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     combine_error_results(&[
@@ -190,6 +399,13 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
         check_e1105_empty_jobs(ctx),
         check_e1106_negative_duration(ctx),
         check_e1107_negative_demand(ctx),
+        check_e1108_correct_transfer_points(ctx),
+        check_e1109_correct_job_transfers(ctx),
+        check_e1110_correct_job_affinity(ctx),
+        check_e1111_correct_job_conflicts(ctx),
+        check_e1112_correct_job_skill_levels(ctx),
+        check_e1113_correct_job_value_decay(ctx),
+        check_e1114_correct_job_divisible(ctx),
     ])
     .map_err(From::from)
 }
@@ -1,7 +1,7 @@
 //! This module provides functionality to validate problem definition for logical correctness.
 
 use crate::format::problem::*;
-use crate::format::{CoordIndex, FormatError, MultiFormatError};
+use crate::format::{has_timezones, localize_problem, CoordIndex, FormatError, MultiFormatError};
 
 /// A validation context which keeps essential information.
 pub struct ValidationContext<'a> {
@@ -31,6 +31,9 @@ use self::relations::validate_relations;
 
 mod routing;
 use self::routing::validate_routing;
+
+mod timezones;
+use self::timezones::validate_timezones;
 use std::collections::HashMap;
 
 impl<'a> ValidationContext<'a> {
@@ -46,6 +49,17 @@ impl<'a> ValidationContext<'a> {
 
     /// Validates problem on set of rules.
     pub fn validate(&self) -> Result<(), MultiFormatError> {
+        // NOTE local date times have to be resolved using time zones before checking time related rules
+        if has_timezones(self.problem) {
+            let problem = localize_problem(self.problem.clone());
+            ValidationContext::new(&problem, self.matrices, self.coord_index).validate_localized()
+        } else {
+            self.validate_localized()
+        }
+    }
+
+    /// Validates problem which local date times are already resolved using time zones.
+    pub(crate) fn validate_localized(&self) -> Result<(), MultiFormatError> {
         let multi_err: MultiFormatError = validate_jobs(self)
             .err()
             .into_iter()
@@ -53,6 +67,7 @@ impl<'a> ValidationContext<'a> {
             .chain(validate_objectives(self).err())
             .chain(validate_routing(self).err())
             .chain(validate_relations(self).err())
+            .chain(validate_timezones(self).err())
             .flatten()
             .collect::<Vec<_>>()
             .into();
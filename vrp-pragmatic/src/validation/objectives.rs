@@ -166,6 +166,95 @@ fn check_e1607_jobs_with_value_but_no_objective(
     }
 }
 
+/// Checks that job affinity objective can be specified only when job with affinity is used.
+fn check_e1608_no_jobs_with_affinity_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_affinity_objective = get_objectives_flattened(objectives).any(|objective| matches!(objective, JobAffinity));
+    let has_no_jobs_with_affinity = !ctx.problem.plan.jobs.iter().any(|job| job.affinity.is_some());
+
+    if has_affinity_objective && has_no_jobs_with_affinity {
+        Err(FormatError::new(
+            "E1608".to_string(),
+            "redundant job affinity objective".to_string(),
+            "specify at least one job with affinity or delete 'job-affinity' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that job affinity objective is specified when some jobs have affinity property set.
+fn check_e1609_jobs_with_affinity_but_no_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    if objectives.is_empty() {
+        return Ok(());
+    }
+
+    let has_no_affinity_objective =
+        !get_objectives_flattened(objectives).any(|objective| matches!(objective, JobAffinity));
+    let has_jobs_with_affinity = ctx.problem.plan.jobs.iter().any(|job| job.affinity.is_some());
+
+    if has_no_affinity_objective && has_jobs_with_affinity {
+        Err(FormatError::new(
+            "E1609".to_string(),
+            "missing job affinity objective".to_string(),
+            "specify 'job-affinity' objective, remove objectives property or remove affinity property from jobs"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that backhaul objective can be specified only when vehicle with mixed backhaul policy is used.
+fn check_e1610_no_vehicles_with_backhaul_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let has_backhaul_objective = get_objectives_flattened(objectives).any(|objective| matches!(objective, Backhaul));
+
+    if has_backhaul_objective && !has_mixed_backhauls(ctx) {
+        Err(FormatError::new(
+            "E1610".to_string(),
+            "redundant backhaul objective".to_string(),
+            "specify at least one vehicle with mixed backhaul policy or delete 'backhaul' objective".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that backhaul objective is specified when some vehicles have mixed backhaul policy.
+fn check_e1611_vehicles_with_backhaul_but_no_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    if objectives.is_empty() {
+        return Ok(());
+    }
+
+    let has_no_backhaul_objective =
+        !get_objectives_flattened(objectives).any(|objective| matches!(objective, Backhaul));
+
+    if has_no_backhaul_objective && has_mixed_backhauls(ctx) {
+        Err(FormatError::new(
+            "E1611".to_string(),
+            "missing backhaul objective".to_string(),
+            "specify 'backhaul' objective, remove objectives property or use strict backhaul policy".to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn has_mixed_backhauls(ctx: &ValidationContext) -> bool {
+    ctx.vehicles().any(|vehicle| matches!(vehicle.backhaul, Some(VehicleBackhaul::Mixed { .. })))
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().collect())
 }
@@ -188,6 +277,10 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), MultiFormatErr
             check_e1605_check_positive_value_and_order(ctx),
             check_e1606_check_multiple_cost_objectives(&objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
+            check_e1608_no_jobs_with_affinity_objective(ctx, &objectives),
+            check_e1609_jobs_with_affinity_but_no_objective(ctx, &objectives),
+            check_e1610_no_vehicles_with_backhaul_objective(ctx, &objectives),
+            check_e1611_vehicles_with_backhaul_but_no_objective(ctx, &objectives),
         ])
         .map_err(From::from)
     } else {
@@ -3,6 +3,7 @@
 mod routing_test;
 
 use super::*;
+use crate::parse_time_safe;
 use crate::utils::combine_error_results;
 use std::collections::HashSet;
 
@@ -114,6 +115,149 @@ fn check_e1505_profiles_exist(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
+/// Checks that speed bands have proper start times and positive factors and are not used with time aware matrices.
+fn check_e1506_speed_bands(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_timestamps = ctx.matrices.is_some_and(|matrices| matrices.iter().any(|matrix| matrix.timestamp.is_some()));
+
+    let invalid_profiles = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.speed_bands.as_ref().map(|bands| (profile, bands)))
+        .filter(|(_, bands)| {
+            let starts = bands.iter().map(|band| parse_time_safe(&band.start)).collect::<Result<Vec<_>, _>>();
+            let is_valid = starts.is_ok_and(|starts| starts.windows(2).all(|pair| pair[0] < pair[1]))
+                && bands.iter().all(|band| band.factor.is_finite() && band.factor > 0.);
+
+            has_timestamps || !is_valid
+        })
+        .map(|(profile, _)| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if invalid_profiles.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1506".to_string(),
+            "invalid speed bands in matrix profile".to_string(),
+            format!(
+                "ensure that speed bands of profiles '{}' have sorted start times, positive factors and \
+                 routing matrices have no timestamp",
+                invalid_profiles.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that matrix profile overlays refer to existing profiles without overlay, have no own routing matrix and
+/// have non-negative values.
+fn check_e1507_matrix_overlays(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let base_profiles = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter(|profile| profile.overlay.is_none())
+        .map(|profile| profile.name.as_str())
+        .collect::<HashSet<_>>();
+
+    let invalid_profiles = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.overlay.as_ref().map(|overlay| (profile, overlay)))
+        .filter(|(profile, overlay)| {
+            let has_matrix = ctx.matrices.is_some_and(|matrices| {
+                matrices.iter().any(|matrix| matrix.profile.as_ref().is_some_and(|name| *name == profile.name))
+            });
+            let has_invalid_values = overlay.edges.iter().flatten().any(|edge| {
+                edge.duration.iter().chain(edge.distance.iter()).any(|value| !value.is_finite() || *value < 0.)
+            });
+
+            !base_profiles.contains(overlay.base.as_str()) || has_matrix || has_invalid_values
+        })
+        .map(|(profile, _)| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if invalid_profiles.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1507".to_string(),
+            "invalid overlay in matrix profile".to_string(),
+            format!(
+                "ensure that overlays of profiles '{}' refer to a profile without overlay, have non-negative values and \
+                 no routing matrix is specified for these profiles",
+                invalid_profiles.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that distance models have positive detour factor and speed and are not used with profile overlays.
+fn check_e1508_distance_models(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let is_invalid = |value: f64| !value.is_finite() || value <= 0.;
+
+    let invalid_profiles = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.distance_model.as_ref().map(|model| (profile, model)))
+        .filter(|(profile, model)| {
+            profile.overlay.is_some()
+                || model.detour_factor.is_some_and(is_invalid)
+                || profile.speed.is_some_and(is_invalid)
+        })
+        .map(|(profile, _)| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if invalid_profiles.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1508".to_string(),
+            "invalid distance model in matrix profile".to_string(),
+            format!(
+                "ensure that profiles '{}' have positive detour factor and speed, and no overlay",
+                invalid_profiles.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that travel time uncertainty has non-negative deviation and probability in [0.5, 1) range.
+fn check_e1509_travel_time_uncertainty(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let invalid_profiles = ctx
+        .problem
+        .fleet
+        .profiles
+        .iter()
+        .filter_map(|profile| profile.travel_time_uncertainty.as_ref().map(|uncertainty| (profile, uncertainty)))
+        .filter(|(_, uncertainty)| {
+            !uncertainty.deviation.is_finite()
+                || uncertainty.deviation < 0.
+                || !(0.5..1.).contains(&uncertainty.probability)
+        })
+        .map(|(profile, _)| profile.name.clone())
+        .collect::<Vec<_>>();
+
+    if invalid_profiles.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1509".to_string(),
+            "invalid travel time uncertainty in matrix profile".to_string(),
+            format!(
+                "ensure that profiles '{}' have non-negative deviation and probability in [0.5, 1) range",
+                invalid_profiles.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates routing rules.
 pub fn validate_routing(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     let location_types = (ctx.coord_index.has_coordinates(), ctx.coord_index.has_indices());
@@ -125,6 +269,10 @@ pub fn validate_routing(ctx: &ValidationContext) -> Result<(), MultiFormatError>
         check_e1503_no_matrix_when_indices_used(ctx, location_types),
         check_e1504_index_size_mismatch(ctx),
         check_e1505_profiles_exist(ctx),
+        check_e1506_speed_bands(ctx),
+        check_e1507_matrix_overlays(ctx),
+        check_e1508_distance_models(ctx),
+        check_e1509_travel_time_uncertainty(ctx),
     ])
     .map_err(From::from)
 }
@@ -0,0 +1,59 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/timezones_test.rs"]
+mod timezones_test;
+
+use super::*;
+use crate::format::is_known_timezone;
+use crate::utils::combine_error_results;
+
+/// Checks that only known IANA time zones are used.
+fn check_e1400_unknown_timezones(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let job_timezones = ctx.jobs().flat_map(|job| {
+        job.all_tasks_iter().flat_map(|task| task.places.iter()).filter_map(|place| place.timezone.as_ref())
+    });
+
+    let shift_timezones = ctx.vehicles().flat_map(|vehicle| vehicle.shifts.iter()).flat_map(|shift| {
+        shift.start.timezone.iter().chain(shift.end.iter().filter_map(|end| end.timezone.as_ref())).chain(
+            shift
+                .recharges
+                .iter()
+                .flat_map(|recharges| recharges.stations.iter())
+                .filter_map(|station| station.timezone.as_ref()),
+        )
+    });
+
+    let mut names = ctx
+        .problem
+        .timezone
+        .iter()
+        .chain(job_timezones)
+        .chain(ctx.problem.plan.transfers.iter().flatten().filter_map(|transfer| transfer.timezone.as_ref()))
+        .chain(shift_timezones)
+        .chain(
+            ctx.vehicles()
+                .filter_map(|vehicle| vehicle.trailer.as_ref())
+                .flat_map(|trailer| trailer.parkings.iter())
+                .filter_map(|parking| parking.timezone.as_ref()),
+        )
+        .filter(|name| !is_known_timezone(name))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    names.sort();
+    names.dedup();
+
+    if names.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1400".to_string(),
+            "unknown time zone".to_string(),
+            format!("use IANA time zone names instead of: '{}'", names.join(", ")),
+        ))
+    }
+}
+
+/// Validates time zones used in the problem.
+pub fn validate_timezones(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
+    combine_error_results(&[check_e1400_unknown_timezones(ctx)]).map_err(From::from)
+}
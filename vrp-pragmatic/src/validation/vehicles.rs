@@ -4,7 +4,7 @@ mod vehicles_test;
 
 use super::*;
 use crate::utils::combine_error_results;
-use crate::validation::common::get_time_windows;
+use crate::validation::common::{check_raw_time_windows, get_time_windows};
 use crate::{parse_time, parse_time_safe};
 use std::cmp::Ordering;
 use std::collections::HashSet;
@@ -159,8 +159,10 @@ fn check_e1306_vehicle_has_no_zero_costs(ctx: &ValidationContext) -> Result<(),
     let type_ids = ctx
         .vehicles()
         .filter(|vehicle| {
-            compare_floats(vehicle.costs.time, 0.) == Ordering::Equal
-                && compare_floats(vehicle.costs.distance, 0.) == Ordering::Equal
+            (0..vehicle.shifts.len()).map(|shift_idx| vehicle.shift_costs(shift_idx)).any(|costs| {
+                compare_floats(costs.time, 0.) == Ordering::Equal
+                    && compare_floats(costs.distance, 0.) == Ordering::Equal
+            })
         })
         .map(|vehicle| vehicle.type_id.to_string())
         .collect::<Vec<_>>();
@@ -223,7 +225,7 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
         .iter()
         .flat_map(|resources| resources.iter())
         .map(|resource| match resource {
-            VehicleResource::Reload { id, .. } => id.to_string(),
+            VehicleResource::Reload { id, .. } | VehicleResource::Satellite { id, .. } => id.to_string(),
         })
         .collect::<Vec<_>>();
 
@@ -264,6 +266,160 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+/// A max amount of start and end location combinations of vehicle shift: each combination is modeled as a separate
+/// vehicle internally.
+const MAX_SHIFT_ALTERNATIVES: usize = 16;
+
+fn check_e1309_vehicle_shift_alternatives(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            let start_size = shift.start.alternatives.as_ref().map_or(1, |alternatives| alternatives.len() + 1);
+            let end_size = shift.end.as_ref().and_then(|end| end.alternatives.as_ref()).map_or(1, |a| a.len() + 1);
+
+            start_size * end_size <= MAX_SHIFT_ALTERNATIVES
+                && shift.start.alternatives.iter().chain(shift.end.iter().flat_map(|end| end.alternatives.iter())).all(
+                    |alternatives| {
+                        !alternatives.is_empty()
+                            && alternatives.iter().all(|alternative| alternative.cost.is_none_or(|cost| cost >= 0.))
+                    },
+                )
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "invalid alternative locations in vehicle shift".to_string(),
+            format!(
+                "ensure that alternatives are not empty, have non-negative costs and there are not more than \
+                 {MAX_SHIFT_ALTERNATIVES} combinations of start and end locations, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1310_vehicle_satellite_resources(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let job_ids = ctx
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .filter_map(|resource| match resource {
+            VehicleResource::Satellite { job_id, .. } => Some(job_id.as_str()),
+            VehicleResource::Reload { .. } => None,
+        })
+        .collect::<Vec<_>>();
+
+    let is_valid_job = |job_id: &str| {
+        ctx.jobs().find(|job| job.id == job_id).is_some_and(|job| {
+            let has_single_place = job
+                .deliveries
+                .as_ref()
+                .is_some_and(|deliveries| deliveries.len() == 1 && deliveries[0].places.len() == 1);
+
+            has_single_place && ctx.tasks(job).len() == 1
+        })
+    };
+
+    let invalid_job_ids = job_ids.iter().filter(|job_id| !is_valid_job(job_id)).cloned().collect::<Vec<_>>();
+    let has_duplicates = job_ids.iter().collect::<HashSet<_>>().len() != job_ids.len();
+
+    if invalid_job_ids.is_empty() && !has_duplicates {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid satellite resource".to_string(),
+            format!(
+                "ensure that each satellite refers to its own delivery job with a single place, job ids: '{}'",
+                if invalid_job_ids.is_empty() { job_ids } else { invalid_job_ids }.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1311_vehicle_skill_levels(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.skills.iter().chain(vehicle.shifts.iter().filter_map(|shift| shift.skills.as_ref())).any(|skills| {
+                !check_skill_levels(skills.iter().map(|skill| match skill {
+                    VehicleSkill::Plain(name) => (name, None),
+                    VehicleSkill::Leveled(skill) => (&skill.name, Some(skill.level)),
+                }))
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid vehicle skill levels".to_string(),
+            format!("use positive skill levels with unique skill names, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that vehicle trailer is valid.
+fn check_e1312_vehicle_trailer(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.trailer.as_ref().is_some_and(|trailer| {
+                trailer.parkings.is_empty()
+                    || trailer.capacity.iter().any(|value| *value < 0.)
+                    || trailer.parkings.iter().any(|parking| {
+                        parking.duration < 0.
+                            || parking.times.as_ref().is_some_and(|tws| !check_raw_time_windows(tws, false))
+                    })
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1312".to_string(),
+            "invalid vehicle trailer".to_string(),
+            format!(
+                "ensure that trailer has non-negative capacity and at least one valid parking, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle backhaul policy is valid.
+fn check_e1313_vehicle_backhaul(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            matches!(vehicle.backhaul, Some(VehicleBackhaul::Mixed { penalty }) if !penalty.is_finite() || penalty < 0.)
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1313".to_string(),
+            "invalid vehicle backhaul policy".to_string(),
+            format!("ensure that mixed backhaul penalty is not negative, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
 type CheckShiftFn = Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>;
 
 fn get_invalid_type_ids(ctx: &ValidationContext, check_shift_fn: CheckShiftFn) -> Vec<String> {
@@ -311,6 +467,11 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), MultiFormatError
         check_e1306_vehicle_has_no_zero_costs(ctx),
         check_e1307_vehicle_offset_break_rescheduling(ctx),
         check_e1308_vehicle_reload_resources(ctx),
+        check_e1309_vehicle_shift_alternatives(ctx),
+        check_e1310_vehicle_satellite_resources(ctx),
+        check_e1311_vehicle_skill_levels(ctx),
+        check_e1312_vehicle_trailer(ctx),
+        check_e1313_vehicle_backhaul(ctx),
     ])
     .map_err(From::from)
 }
@@ -72,7 +72,7 @@ prop_compose! {
                 ..plan
             },
             fleet,
-            objectives: None,
+            objectives: None, timezone: None, matrix_storage: None,
         }
     }
 }
@@ -49,7 +49,7 @@ prop_compose! {
         Problem {
             plan,
             fleet,
-            objectives: None,
+            objectives: None, timezone: None, matrix_storage: None,
         }
     }
 }
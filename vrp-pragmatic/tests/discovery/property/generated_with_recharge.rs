@@ -36,7 +36,7 @@ prop_compose! {
 
         // set capacity to high and have only one vehicle of such type to have a higher probability
         // for recharge to be kicked in
-        vehicle.capacity = vec![10000];
+        vehicle.capacity = vec![10000.];
         vehicle.vehicle_ids = vec![format!("{}_1", vehicle.type_id)];
 
         vehicle.shifts.first_mut().unwrap().end = None;
@@ -76,7 +76,7 @@ prop_compose! {
         Problem {
             plan,
             fleet,
-            objectives: None,
+            objectives: None, timezone: None, matrix_storage: None,
         }
     }
 }
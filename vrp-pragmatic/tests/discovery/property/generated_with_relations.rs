@@ -16,7 +16,16 @@ fn vehicle_type_prototype() -> impl Strategy<Value = VehicleType> {
         generate_shifts(
             generate_shift(
                 generate_location(&DEFAULT_BOUNDING_BOX).prop_flat_map(|location| {
-                    Just((ShiftStart { earliest: default_time_plus_offset(9), latest: None, location }, None))
+                    Just((
+                        ShiftStart {
+                            earliest: default_time_plus_offset(9),
+                            latest: None,
+                            location,
+                            timezone: None,
+                            alternatives: None,
+                        },
+                        None,
+                    ))
                 }),
                 default_breaks_prototype(),
                 generate_no_reloads(),
@@ -68,7 +77,7 @@ prop_compose! {
                 ..plan
             },
             fleet,
-            objectives: None,
+            objectives: None, timezone: None, matrix_storage: None,
         }
     }
 }
@@ -0,0 +1,69 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use std::collections::HashSet;
+
+fn create_affinity_job(id: &str, location: (f64, f64), weight: f64) -> Job {
+    Job {
+        affinity: Some(JobAffinity { group: "one".to_string(), weight: Some(weight) }),
+        ..create_delivery_job(id, location)
+    }
+}
+
+parameterized_test! {can_prefer_jobs_with_affinity_in_the_same_tour, (weight, expected), {
+    can_prefer_jobs_with_affinity_in_the_same_tour_impl(weight, expected);
+}}
+
+can_prefer_jobs_with_affinity_in_the_same_tour! {
+    case01_high_weight: (100., true),
+    case02_low_weight: (0.1, false),
+}
+
+fn can_prefer_jobs_with_affinity_in_the_same_tour_impl(weight: f64, expected: bool) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_affinity_job("job1", (1., 0.), weight),
+                create_delivery_job("job2", (2., 0.)),
+                create_affinity_job("job3", (9., 0.), weight),
+                create_delivery_job("job4", (8., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    type_id: "type1".to_string(),
+                    vehicle_ids: vec!["type1_1".to_string()],
+                    shifts: vec![create_default_vehicle_shift_with_locations((0., 0.), (0., 0.))],
+                    capacity: vec![2.],
+                    ..create_default_vehicle_type()
+                },
+                VehicleType {
+                    type_id: "type2".to_string(),
+                    vehicle_ids: vec!["type2_1".to_string()],
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    capacity: vec![2.],
+                    ..create_default_vehicle_type()
+                },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let is_same_tour = solution.tours.iter().any(|tour| {
+        tour.stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .map(|activity| activity.job_id.as_str())
+            .filter(|id| *id == "job1" || *id == "job3")
+            .collect::<HashSet<_>>()
+            .len()
+            == 2
+    });
+    assert_eq!(is_same_tour, expected);
+}
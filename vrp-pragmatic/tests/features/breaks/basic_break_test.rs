@@ -43,30 +43,30 @@ fn can_assign_break_between_jobs() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((5., 0.))
                             .schedule_stamp(5., 6.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(5)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((6., 0.))
                             .schedule_stamp(7., 9.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(6)
                             .build_single_tag("break", "break", "break_tag"),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(13., 14.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(10)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(24., 24.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(20)
                             .build_arrival(),
                     ])
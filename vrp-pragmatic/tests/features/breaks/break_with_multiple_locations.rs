@@ -13,7 +13,13 @@ fn can_assign_break_using_second_place() {
             vehicles: vec![VehicleType {
                 costs: create_default_vehicle_costs(),
                 shifts: vec![VehicleShift {
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (30., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (30., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(10.), format_time(30.)]),
                         places: vec![
@@ -51,30 +57,30 @@ fn can_assign_break_using_second_place() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(10., 11.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(10)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((11., 0.))
                             .schedule_stamp(12., 14.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(11)
                             .build_single_tag("break", "break", "second"),
                         StopBuilder::default()
                             .coordinate((20., 0.))
                             .schedule_stamp(23., 24.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(20)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((30., 0.))
                             .schedule_stamp(34., 34.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(30)
                             .build_arrival(),
                     ])
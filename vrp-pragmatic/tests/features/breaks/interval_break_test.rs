@@ -21,6 +21,8 @@ fn can_assign_interval_break_between_jobs() {
                         earliest: format_time(0.),
                         latest: Some(format_time(0.)),
                         location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
                     },
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: VehicleOptionalBreakTime::TimeOffset(vec![5., 10.]),
@@ -34,6 +36,8 @@ fn can_assign_interval_break_between_jobs() {
             ..create_default_fleet()
         },
         objectives: create_test_objectives(),
+        timezone: None,
+        matrix_storage: None,
     };
     let matrix = create_matrix_from_problem(&problem);
 
@@ -48,12 +52,12 @@ fn can_assign_interval_break_between_jobs() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((5., 0.))
                             .schedule_stamp(5., 8.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(5)
                             .activity(
                                 ActivityBuilder::delivery()
@@ -67,13 +71,13 @@ fn can_assign_interval_break_between_jobs() {
                         StopBuilder::default()
                             .coordinate((15., 0.))
                             .schedule_stamp(18., 19.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(15)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(34., 34.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(30)
                             .build_arrival(),
                     ])
@@ -103,8 +107,16 @@ fn can_assign_interval_break_with_reload() {
                         earliest: format_time(0.),
                         latest: Some(format_time(0.)),
                         location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
                     },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (30., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (30., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: VehicleOptionalBreakTime::TimeOffset(vec![8., 12.]),
                         places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
@@ -117,8 +129,12 @@ fn can_assign_interval_break_with_reload() {
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    capacity: None,
+                    skills: None,
+                    limits: None,
+                    costs: None,
                 }],
-                capacity: vec![2],
+                capacity: vec![2.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -139,12 +155,12 @@ fn can_assign_interval_break_with_reload() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(10., 13.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(10)
                             .activity(
                                 ActivityBuilder::delivery()
@@ -158,31 +174,31 @@ fn can_assign_interval_break_with_reload() {
                         StopBuilder::default()
                             .coordinate((15., 0.))
                             .schedule_stamp(18., 19.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(15)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(34., 37.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .distance(30)
                             .build_single("reload", "reload"),
                         StopBuilder::default()
                             .coordinate((20., 0.))
                             .schedule_stamp(57., 58.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(50)
                             .build_single("job3", "delivery"),
                         StopBuilder::default()
                             .coordinate((25., 0.))
                             .schedule_stamp(63., 64.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(55)
                             .build_single("job4", "delivery"),
                         StopBuilder::default()
                             .coordinate((30., 0.))
                             .schedule_stamp(69., 69.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(60)
                             .build_arrival(),
                     ])
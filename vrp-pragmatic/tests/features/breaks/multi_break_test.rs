@@ -16,6 +16,8 @@ fn can_use_two_breaks() {
                         earliest: format_time(0.),
                         latest: Some(format_time(0.)),
                         location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
                     },
                     breaks: Some(vec![
                         VehicleBreak::Optional {
@@ -54,24 +56,24 @@ fn can_use_two_breaks() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((5., 0.))
                             .schedule_stamp(5., 6.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(5)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((6., 0.))
                             .schedule_stamp(7., 9.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(6)
                             .build_single("break", "break"),
                         StopBuilder::default()
                             .coordinate((99., 0.))
                             .schedule_stamp(102., 105.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(99)
                             .activity(
                                 ActivityBuilder::delivery()
@@ -87,7 +89,7 @@ fn can_use_two_breaks() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(204., 204.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(198)
                             .build_arrival(),
                     ])
@@ -23,6 +23,8 @@ fn create_vehicle_shift_with_breaks(breaks: Vec<VehicleBreak>) -> VehicleShift {
             earliest: format_time(0.),
             latest: Some(format_time(0.)),
             location: Location::Coordinate { lat: 0., lng: 0. },
+            timezone: None,
+            alternatives: None,
         },
         end: None,
         breaks: Some(breaks),
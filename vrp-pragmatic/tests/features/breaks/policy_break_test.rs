@@ -23,11 +23,19 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
             vehicles: vec![
                 VehicleType {
                     shifts: vec![VehicleShift {
-                        start: ShiftStart { earliest: format_time(0.), latest: None, location: (100., 0.).to_loc() },
+                        start: ShiftStart {
+                            earliest: format_time(0.),
+                            latest: None,
+                            location: (100., 0.).to_loc(),
+                            timezone: None,
+                            alternatives: None,
+                        },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: format_time(1000.),
                             location: (100., 0.).to_loc(),
+                            timezone: None,
+                            alternatives: None,
                         }),
                         breaks: Some(vec![VehicleBreak::Optional {
                             time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(5.), format_time(8.)]),
@@ -63,24 +71,24 @@ fn can_skip_break_when_vehicle_not_used_impl(policy: Option<VehicleOptionalBreak
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(10., 11.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(10)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((5., 0.))
                             .schedule_stamp(16., 17.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(15)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(22., 22.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(20)
                             .build_arrival(),
                     ])
@@ -137,18 +145,18 @@ fn can_skip_break_when_jobs_completed_impl(policy: Option<VehicleOptionalBreakPo
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(1., 11.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(1)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(12., 12.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(2)
                             .build_arrival(),
                     ])
@@ -215,30 +223,30 @@ fn can_skip_second_break_when_jobs_completed_impl(policy: Option<VehicleOptional
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((5., 0.))
                             .schedule_stamp(5., 6.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(5)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((6., 0.))
                             .schedule_stamp(7., 9.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(6)
                             .build_single("break", "break"),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(13., 14.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(10)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(24., 24.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(20)
                             .build_arrival(),
                     ])
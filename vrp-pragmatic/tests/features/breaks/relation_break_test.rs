@@ -80,30 +80,30 @@ fn can_use_break_between_two_jobs_in_relation_impl(relation_type: RelationType,
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(1., 2.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(1)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((3., 0.))
                             .schedule_stamp(4., 6.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(3)
                             .build_single("break", "break"),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(7., 8.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(4)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(10., 10.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(6)
                             .build_arrival(),
                     ])
@@ -135,30 +135,30 @@ fn can_use_break_last_in_relation_impl(relation_type: RelationType, jobs: Vec<St
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(1., 2.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(1)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(3., 4.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(2)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((3., 0.))
                             .schedule_stamp(5., 7.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(3)
                             .build_single("break", "break"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(10., 10.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(6)
                             .build_arrival(),
                     ])
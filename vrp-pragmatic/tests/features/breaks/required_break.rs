@@ -3,7 +3,13 @@ use crate::format_time;
 use crate::helpers::*;
 
 fn create_shift_start() -> ShiftStart {
-    ShiftStart { earliest: format_time(0.), latest: Some(format_time(0.)), location: (0., 0.).to_loc() }
+    ShiftStart {
+        earliest: format_time(0.),
+        latest: Some(format_time(0.)),
+        location: (0., 0.).to_loc(),
+        timezone: None,
+        alternatives: None,
+    }
 }
 
 fn create_problem(jobs: Vec<Job>, vehicle_break: VehicleBreak, is_open: bool) -> Problem {
@@ -50,25 +56,25 @@ fn can_assign_break_during_travel() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((5., 0.))
                             .schedule_stamp(5., 6.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(5)
                             .build_single("job1", "delivery"),
-                        StopBuilder::new_transit().schedule_stamp(7., 9.).load(vec![1]).build_single("break", "break"),
+                        StopBuilder::new_transit().schedule_stamp(7., 9.).load(vec![1.]).build_single("break", "break"),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(13., 14.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(10)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(24., 24.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(20)
                             .build_arrival(),
                     ])
@@ -103,12 +109,12 @@ fn can_assign_break_during_activity() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((5., 0.))
                             .schedule_stamp(5., 10.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(5)
                             .activity(
                                 ActivityBuilder::delivery()
@@ -122,7 +128,7 @@ fn can_assign_break_during_activity() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(15., 15.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(10)
                             .build_arrival(),
                     ])
@@ -157,19 +163,19 @@ fn can_handle_required_break_when_its_start_falls_at_activity_end() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((5., 0.))
                             .schedule_stamp(5., 6.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(5)
                             .build_single("job1", "delivery"),
-                        StopBuilder::new_transit().schedule_stamp(6., 8.).load(vec![1]).build_single("break", "break"),
+                        StopBuilder::new_transit().schedule_stamp(6., 8.).load(vec![1.]).build_single("break", "break"),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(13., 14.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(10)
                             .build_single("job2", "delivery"),
                     ])
@@ -223,12 +229,12 @@ fn can_reschedule_break_early_from_transport_to_activity() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((5., 0.))
                             .schedule_stamp(5., 8.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(5)
                             .activity(
                                 ActivityBuilder::delivery()
@@ -242,7 +248,7 @@ fn can_reschedule_break_early_from_transport_to_activity() {
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(13., 14.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(10)
                             .build_single("job2", "delivery"),
                     ])
@@ -0,0 +1,52 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_backhaul_problem(backhaul: Option<VehicleBackhaul>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_pickup_job("job1", (1., 0.)),
+                create_delivery_job("job2", (5., 0.)),
+                create_delivery_job("job3", (6., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                backhaul,
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_use_backhaul_policy, (backhaul, expected_pickup_idx), {
+    can_use_backhaul_policy_impl(backhaul, expected_pickup_idx);
+}}
+
+can_use_backhaul_policy! {
+    case01_no_policy: (None, 0),
+    case02_strict: (Some(VehicleBackhaul::Strict), 2),
+    case03_mixed_with_high_penalty: (Some(VehicleBackhaul::Mixed { penalty: 100. }), 2),
+    case04_mixed_with_low_penalty: (Some(VehicleBackhaul::Mixed { penalty: 1. }), 0),
+}
+
+fn can_use_backhaul_policy_impl(backhaul: Option<VehicleBackhaul>, expected_pickup_idx: usize) {
+    let problem = create_backhaul_problem(backhaul);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let job_ids = get_ids_from_tour(&solution.tours[0])
+        .into_iter()
+        .flatten()
+        .filter(|id| id.starts_with("job"))
+        .collect::<Vec<_>>();
+    assert_eq!(job_ids.len(), 3);
+    assert_eq!(job_ids.iter().position(|id| id == "job1"), Some(expected_pickup_idx));
+}
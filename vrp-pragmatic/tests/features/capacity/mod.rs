@@ -1 +1,2 @@
+mod backhaul_capacity_test;
 mod simple_capacity_test;
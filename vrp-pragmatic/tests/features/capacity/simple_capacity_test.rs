@@ -6,14 +6,14 @@ fn can_schedule_pickup_at_tour_end() {
     let problem = Problem {
         plan: Plan {
             jobs: vec![
-                create_delivery_job_with_demand("job1", (1., 0.), vec![1]),
-                create_delivery_job_with_demand("job2", (2., 0.), vec![1]),
-                create_pickup_job_with_demand("job3", (3., 0.), vec![2]),
+                create_delivery_job_with_demand("job1", (1., 0.), vec![1.]),
+                create_delivery_job_with_demand("job2", (2., 0.), vec![1.]),
+                create_pickup_job_with_demand("job3", (3., 0.), vec![2.]),
             ],
             ..create_empty_plan()
         },
         fleet: Fleet {
-            vehicles: vec![VehicleType { capacity: vec![2], ..create_default_vehicle_type() }],
+            vehicles: vec![VehicleType { capacity: vec![2.], ..create_default_vehicle_type() }],
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -24,3 +24,29 @@ fn can_schedule_pickup_at_tour_end() {
 
     assert!(solution.unassigned.is_none())
 }
+
+#[test]
+fn can_use_fractional_demand_and_capacity() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("job1", (1., 0.), vec![0.1]),
+                create_delivery_job_with_demand("job2", (2., 0.), vec![0.2]),
+                create_delivery_job_with_demand("job3", (3., 0.), vec![0.25]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![0.3], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations(problem, Some(vec![matrix]), 50);
+
+    assert_eq!(solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job3"]);
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].stops[0].load(), &vec![0.3]);
+}
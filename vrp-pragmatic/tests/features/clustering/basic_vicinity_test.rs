@@ -86,13 +86,13 @@ fn can_cluster_simple_jobs_impl(
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![4])
+                            .load(vec![4.])
                             .build_departure(),
                         stop2.into(),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(stop3_schedule.0, stop3_schedule.1)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(10)
                             .build_single("job4", "delivery"),
                     ])
@@ -221,7 +221,7 @@ fn can_handle_two_clusters_impl(
                             StopBuilder::default()
                                 .coordinate((0., 0.))
                                 .schedule_stamp(0., 0.)
-                                .load(vec![4])
+                                .load(vec![4.])
                                 .build_departure(),
                         )
                         .chain(stops.into_iter().map(StopData::into))
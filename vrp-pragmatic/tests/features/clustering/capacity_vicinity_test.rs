@@ -49,13 +49,13 @@ fn can_mix_pickup_delivery_jobs() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![3])
+                            .load(vec![3.])
                             .build_departure(),
                         stop2.into(),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(stop3_schedule.0, stop3_schedule.1)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(10)
                             .build_single("job4", "delivery"),
                     ])
@@ -137,7 +137,7 @@ fn can_vary_cluster_size_based_on_capacity_impl(
                             StopBuilder::default()
                                 .coordinate((0., 0.))
                                 .schedule_stamp(0., 0.)
-                                .load(vec![capacity])
+                                .load(vec![capacity as f64])
                                 .build_departure(),
                         )
                         .chain(stops.into_iter().map(StopData::into))
@@ -159,6 +159,7 @@ fn can_vary_cluster_size_based_on_capacity_impl(
                                 shift_index: 0,
                             }]),
                         }],
+                        remaining: None,
                     })
                     .collect()
             }))
@@ -82,7 +82,7 @@ impl From<StopData> for Stop {
             } else {
                 None
             },
-            load: vec![stop.load],
+            load: vec![stop.load as f64],
             activities: stop.activities.into_iter().map(ActivityData::into).collect(),
         })
     }
@@ -121,7 +121,7 @@ fn create_test_problem(jobs_data: &[(f64, &str)], capacity: i32, clustering: Clu
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                ..create_vehicle_with_capacity("my_vehicle", vec![capacity])
+                ..create_vehicle_with_capacity("my_vehicle", vec![capacity as f64])
             }],
             ..create_default_fleet()
         },
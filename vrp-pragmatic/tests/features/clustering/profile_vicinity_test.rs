@@ -40,7 +40,7 @@ fn can_use_scale_on_profile() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![capacity])
+                            .load(vec![capacity as f64])
                             .build_departure(),
                         stop2.into(),
                     ])
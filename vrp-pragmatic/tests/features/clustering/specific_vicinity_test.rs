@@ -30,11 +30,15 @@ fn can_handle_parking_with_no_clusters_and_job_time_windows() {
                         earliest: "1970-01-01T09:00:00Z".to_string(),
                         latest: None,
                         location: Location::Coordinate { lat: 52.497, lng: 13.547 },
+                        timezone: None,
+                        alternatives: None,
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: "1970-01-01T18:00:00Z".to_string(),
                         location: Location::Coordinate { lat: 52.497, lng: 13.547 },
+                        timezone: None,
+                        alternatives: None,
                     }),
                     ..create_default_vehicle_shift()
                 }],
@@ -105,11 +109,15 @@ fn can_handle_waiting_time_with_parking_impl(
                         earliest: "1970-01-01T09:00:00Z".to_string(),
                         latest: None,
                         location: vehicle_location.clone(),
+                        timezone: None,
+                        alternatives: None,
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: "1970-01-01T18:00:00Z".to_string(),
                         location: vehicle_location,
+                        timezone: None,
+                        alternatives: None,
                     }),
                     ..create_default_vehicle_shift()
                 }],
@@ -118,6 +126,8 @@ fn can_handle_waiting_time_with_parking_impl(
             ..create_default_fleet()
         },
         objectives: None,
+        timezone: None,
+        matrix_storage: None,
     };
 
     let matrices = create_approx_matrices(&problem);
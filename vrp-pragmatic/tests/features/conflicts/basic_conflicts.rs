@@ -0,0 +1,87 @@
+use crate::format::problem::*;
+use crate::format::solution::{UnassignedJobDetail, UnassignedJobReason};
+use crate::helpers::*;
+
+fn create_delivery_job_with_conflicts(id: &str, location: (f64, f64), conflicts: Vec<&str>) -> Job {
+    Job {
+        conflicts: Some(conflicts.into_iter().map(|id| id.to_string()).collect()),
+        ..create_delivery_job(id, location)
+    }
+}
+
+#[test]
+fn can_separate_conflicting_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_conflicts("job1", (1., 0.), vec!["job3"]),
+                create_delivery_job("job2", (8., 0.)),
+                create_delivery_job("job3", (2., 0.)),
+                create_delivery_job("job4", (9., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    type_id: "type1".to_string(),
+                    vehicle_ids: vec!["type1_1".to_string()],
+                    shifts: vec![create_default_vehicle_shift_with_locations((0., 0.), (0., 0.))],
+                    capacity: vec![2.],
+                    ..create_default_vehicle_type()
+                },
+                VehicleType {
+                    type_id: "type2".to_string(),
+                    vehicle_ids: vec!["type2_1".to_string()],
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    capacity: vec![2.],
+                    ..create_default_vehicle_type()
+                },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 2);
+    assert!(solution.unassigned.is_none());
+    assert!(solution.tours.iter().all(|tour| {
+        get_ids_from_tour(tour).iter().flatten().filter(|id| *id == "job1" || *id == "job3").count() == 1
+    }));
+}
+
+#[test]
+fn can_unassign_job_due_to_conflict() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job_with_conflicts("job2", (2., 0.), vec!["job1"]),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![2.], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.as_ref().map_or(0, |u| u.len()), 1);
+    let reasons = solution.unassigned.iter().flatten().flat_map(|u| u.reasons.iter().cloned()).collect::<Vec<_>>();
+    assert_eq!(
+        reasons,
+        vec![UnassignedJobReason {
+            code: "CONFLICT_CONSTRAINT".to_string(),
+            description: "cannot be assigned due to conflict with other jobs".to_string(),
+            details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }])
+        }]
+    );
+}
@@ -17,17 +17,41 @@ fn can_use_multiple_times_from_vehicle_and_job() {
             vehicles: vec![VehicleType {
                 shifts: vec![
                     VehicleShift {
-                        start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                        end: Some(ShiftEnd { earliest: None, latest: format_time(99.), location: (0., 0.).to_loc() }),
+                        start: ShiftStart {
+                            earliest: format_time(0.),
+                            latest: None,
+                            location: (0., 0.).to_loc(),
+                            timezone: None,
+                            alternatives: None,
+                        },
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: format_time(99.),
+                            location: (0., 0.).to_loc(),
+                            timezone: None,
+                            alternatives: None,
+                        }),
                         ..create_default_vehicle_shift()
                     },
                     VehicleShift {
-                        start: ShiftStart { earliest: format_time(100.), latest: None, location: (0., 0.).to_loc() },
-                        end: Some(ShiftEnd { earliest: None, latest: format_time(200.), location: (0., 0.).to_loc() }),
+                        start: ShiftStart {
+                            earliest: format_time(100.),
+                            latest: None,
+                            location: (0., 0.).to_loc(),
+                            timezone: None,
+                            alternatives: None,
+                        },
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: format_time(200.),
+                            location: (0., 0.).to_loc(),
+                            timezone: None,
+                            alternatives: None,
+                        }),
                         ..create_default_vehicle_shift()
                     },
                 ],
-                capacity: vec![1],
+                capacity: vec![1.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -47,18 +71,18 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(10., 11.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(10)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(21., 21.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(20)
                             .build_arrival(),
                     ])
@@ -72,18 +96,18 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(100., 100.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(110., 111.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(10)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(121., 121.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(20)
                             .build_arrival(),
                     ])
@@ -115,12 +139,14 @@ fn can_prefer_first_days_with_minimize_arrival_time_objective() {
                             earliest: format_time(*earliest),
                             latest: None,
                             location: (0., 0.).to_loc(),
+                            timezone: None,
+                            alternatives: None,
                         },
                         end: None,
                         ..create_default_vehicle_shift()
                     })
                     .collect(),
-                capacity: vec![1],
+                capacity: vec![1.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -0,0 +1,82 @@
+use crate::format::problem::*;
+use crate::format::Location;
+use crate::helpers::*;
+
+fn create_problem(overlay: MatrixOverlay) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                profile: create_vehicle_profile_with_name("truck"),
+                ..create_default_vehicle_type()
+            }],
+            profiles: vec![
+                MatrixProfile {
+                    name: "car".to_string(),
+                    speed: None,
+                    speed_bands: None,
+                    overlay: None,
+                    distance_model: None,
+                    travel_time_uncertainty: None,
+                },
+                MatrixProfile {
+                    name: "truck".to_string(),
+                    speed: None,
+                    speed_bands: None,
+                    overlay: Some(overlay),
+                    distance_model: None,
+                    travel_time_uncertainty: None,
+                },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_use_overlay_with_unreachable_location() {
+    let problem = create_problem(MatrixOverlay {
+        base: "car".to_string(),
+        unreachable: Some(vec![Location::new_coordinate(2., 0.)]),
+        edges: None,
+    });
+    let matrix = Matrix { profile: Some("car".to_string()), ..create_matrix_from_problem(&problem) };
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec!["job1"], vec!["arrival"]]);
+    let unassigned = solution.unassigned.expect("should have unassigned jobs");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job2");
+    assert_eq!(unassigned[0].reasons[0].code, "REACHABLE_CONSTRAINT");
+}
+
+#[test]
+fn can_use_overlay_with_overridden_edge() {
+    let problem = create_problem(MatrixOverlay {
+        base: "car".to_string(),
+        unreachable: None,
+        edges: Some(vec![MatrixOverlayEdge {
+            from: Location::new_coordinate(0., 0.),
+            to: Location::new_coordinate(2., 0.),
+            duration: Some(1.),
+            distance: Some(1.),
+        }]),
+    });
+    let matrix = Matrix { profile: Some("car".to_string()), ..create_matrix_from_problem(&problem) };
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![vec!["departure"], vec!["job2"], vec!["job1"], vec!["arrival"]]
+    );
+    assert_eq!(solution.tours[0].statistic.distance, 3);
+}
@@ -1,5 +1,10 @@
 mod basic_multi_shift;
 mod basic_open_end;
+mod matrix_overlay;
 mod multi_dimens;
 mod profile_variation;
+mod shift_alternatives;
+mod shift_overrides;
+mod trailer;
+mod travel_uncertainty;
 mod unreachable_jobs;
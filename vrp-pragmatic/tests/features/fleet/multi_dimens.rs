@@ -7,15 +7,15 @@ fn can_use_two_dimensions() {
     let problem = Problem {
         plan: Plan {
             jobs: vec![
-                create_delivery_job_with_demand("job1", (1., 0.), vec![0, 1]),
-                create_delivery_job_with_demand("job2", (2., 0.), vec![1, 0]),
+                create_delivery_job_with_demand("job1", (1., 0.), vec![0., 1.]),
+                create_delivery_job_with_demand("job2", (2., 0.), vec![1., 0.]),
             ],
             ..create_empty_plan()
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                capacity: vec![1, 1],
+                capacity: vec![1., 1.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -35,18 +35,18 @@ fn can_use_two_dimensions() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![1, 1])
+                            .load(vec![1., 1.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(1., 2.)
-                            .load(vec![1, 0])
+                            .load(vec![1., 0.])
                             .distance(1)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(3., 4.)
-                            .load(vec![0, 0])
+                            .load(vec![0., 0.])
                             .distance(2)
                             .build_single("job2", "delivery"),
                     ])
@@ -60,11 +60,14 @@ fn can_use_two_dimensions() {
 #[test]
 fn can_unassign_due_to_dimension_mismatch() {
     let problem = Problem {
-        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", (1., 0.), vec![0, 1])], ..create_empty_plan() },
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_demand("job1", (1., 0.), vec![0., 1.])],
+            ..create_empty_plan()
+        },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                capacity: vec![1],
+                capacity: vec![1.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -84,7 +87,8 @@ fn can_unassign_due_to_dimension_mismatch() {
                     code: "CAPACITY_CONSTRAINT".to_string(),
                     description: "does not fit into any vehicle due to capacity".to_string(),
                     details: None,
-                }]
+                }],
+                remaining: None,
             }]))
             .build()
     );
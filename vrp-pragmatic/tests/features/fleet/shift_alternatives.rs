@@ -0,0 +1,87 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format::Location;
+use crate::helpers::*;
+
+fn create_shift_location(location: (f64, f64), cost: Option<f64>, tag: &str) -> ShiftLocation {
+    ShiftLocation { location: location.to_loc(), cost, tag: Some(tag.to_string()) }
+}
+
+fn create_problem_with_alternatives(start_cost: Option<f64>, capacity: f64) -> Problem {
+    let shift = create_default_vehicle_shift();
+
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (11., 0.)), create_delivery_job("job2", (12., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string()],
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        alternatives: Some(vec![
+                            create_shift_location((10., 0.), start_cost, "hub2"),
+                            create_shift_location((20., 0.), None, "hub3"),
+                        ]),
+                        ..shift.start.clone()
+                    },
+                    end: shift.end.clone().map(|end| ShiftEnd {
+                        alternatives: Some(vec![create_shift_location((12., 0.), None, "hub4")]),
+                        ..end
+                    }),
+                    ..shift
+                }],
+                capacity: vec![capacity],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_terminal(solution: &Solution, activity_type: &str) -> (Location, Option<String>) {
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .find(|(_, activity)| activity.activity_type == activity_type)
+        .map(|(stop, activity)| (stop.location().cloned().unwrap(), activity.job_tag.clone()))
+        .unwrap()
+}
+
+#[test]
+fn can_select_best_start_and_end_locations() {
+    let problem = create_problem_with_alternatives(None, 10.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_terminal(&solution, "departure"), ((10., 0.).to_loc(), Some("hub2".to_string())));
+    assert_eq!(get_terminal(&solution, "arrival"), ((12., 0.).to_loc(), Some("hub4".to_string())));
+}
+
+#[test]
+fn can_consider_alternative_location_cost() {
+    let problem = create_problem_with_alternatives(Some(1000.), 10.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_terminal(&solution, "departure"), ((20., 0.).to_loc(), Some("hub3".to_string())));
+}
+
+#[test]
+fn can_use_vehicle_only_once_with_alternatives() {
+    let problem = create_problem_with_alternatives(None, 1.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.as_ref().map(|unassigned| unassigned.len()), Some(1));
+}
@@ -0,0 +1,133 @@
+use crate::format::problem::*;
+use crate::format::ShiftIndexDimension;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_shift(start: f64, end: f64) -> VehicleShift {
+    VehicleShift {
+        start: ShiftStart {
+            earliest: format_time(start),
+            latest: None,
+            location: (0., 0.).to_loc(),
+            timezone: None,
+            alternatives: None,
+        },
+        end: Some(ShiftEnd {
+            earliest: None,
+            latest: format_time(end),
+            location: (0., 0.).to_loc(),
+            timezone: None,
+            alternatives: None,
+        }),
+        ..create_default_vehicle_shift()
+    }
+}
+
+#[test]
+fn can_use_capacity_and_skills_from_shift() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_demand("job1", (10., 0.), vec![2.]),
+                create_delivery_job_with_skills("job2", (10., 0.), all_of_skills(vec!["fridge".to_string()])),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![
+                    VehicleShift {
+                        skills: Some(vec![VehicleSkill::Plain("fridge".to_string())]),
+                        ..create_shift(0., 99.)
+                    },
+                    VehicleShift { capacity: Some(vec![2.]), ..create_shift(100., 200.) },
+                ],
+                capacity: vec![1.],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    solution.tours.iter().for_each(|tour| {
+        let expected_job = if tour.shift_index == 0 { "job2" } else { "job1" };
+        assert_eq!(get_ids_from_tour(tour)[1], vec![expected_job.to_string()]);
+    });
+}
+
+#[test]
+fn can_use_limits_from_shift() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    limits: Some(VehicleLimits {
+                        max_distance: None,
+                        max_duration: None,
+                        tour_size: Some(1),
+                        max_leg_distance: None,
+                        max_leg_duration: None,
+                    }),
+                    ..create_shift(0., 99.)
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.as_ref().map(|unassigned| unassigned.len()), Some(1));
+    assert_eq!(solution.unassigned.as_ref().unwrap()[0].reasons[0].code, "TOUR_SIZE_CONSTRAINT");
+}
+
+#[test]
+fn can_read_costs_from_shift() {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![
+                    create_shift(0., 99.),
+                    VehicleShift {
+                        costs: Some(VehicleCosts { fixed: Some(100.), distance: 2., time: 3. }),
+                        ..create_shift(100., 200.)
+                    },
+                ],
+                costs: VehicleCosts { fixed: Some(10.), distance: 1., time: 1. },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let core_problem = (problem, vec![matrix]).read_pragmatic().unwrap();
+
+    let mut costs = core_problem
+        .fleet
+        .vehicles
+        .iter()
+        .map(|vehicle| {
+            let costs = &vehicle.costs;
+            (*vehicle.dimens.get_shift_index().unwrap(), costs.fixed, costs.per_distance, costs.per_driving_time)
+        })
+        .collect::<Vec<_>>();
+    costs.sort_by_key(|(shift_idx, ..)| *shift_idx);
+
+    assert_eq!(costs, vec![(0, 10., 1., 1.), (1, 100., 2., 3.)]);
+}
@@ -0,0 +1,94 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_vehicle_with_trailer(parking: (f64, f64)) -> VehicleType {
+    VehicleType {
+        capacity: vec![1.],
+        trailer: Some(VehicleTrailer {
+            capacity: vec![1.],
+            parkings: vec![VehicleTrailerParking {
+                location: parking.to_loc(),
+                duration: 5.,
+                times: None,
+                tag: Some("parking".to_string()),
+                timezone: None,
+            }],
+        }),
+        ..create_default_vehicle_type()
+    }
+}
+
+fn create_truck_only_job(id: &str, location: (f64, f64)) -> Job {
+    Job { truck_only: Some(true), ..create_delivery_job(id, location) }
+}
+
+fn get_activities(solution: &Solution) -> Vec<(String, String)> {
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .map(|activity| (activity.job_id.clone(), activity.activity_type.clone()))
+        .collect()
+}
+
+#[test]
+fn can_serve_truck_only_job_with_trailer_detached() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (3., 0.)), create_truck_only_job("job2", (10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_trailer((5., 0.))], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let activities = get_activities(&solution);
+    let position = |job_id: &str| activities.iter().position(|(id, _)| id == job_id).unwrap();
+    assert_eq!(activities.len(), 6);
+    assert!(position("decouple") < position("job2") && position("job2") < position("recouple"));
+    assert_eq!(activities.iter().filter(|(_, activity_type)| activity_type == "delivery").count(), 2);
+}
+
+#[test]
+fn can_keep_trailer_attached_without_truck_only_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (3., 0.)), create_delivery_job("job2", (10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_trailer((5., 0.))], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert!(get_activities(&solution).iter().all(|(job_id, _)| job_id != "decouple" && job_id != "recouple"));
+}
+
+#[test]
+fn can_use_truck_capacity_when_trailer_is_detached() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_truck_only_job("job1", (10., 0.)), create_truck_only_job("job2", (11., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_trailer((5., 0.))], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.as_ref().map_or(0, |unassigned| unassigned.len()), 1);
+    assert_eq!(solution.unassigned.as_ref().unwrap()[0].reasons[0].code, "TRAILER_CONSTRAINT".to_string());
+}
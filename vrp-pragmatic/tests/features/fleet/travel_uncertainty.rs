@@ -0,0 +1,56 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_problem(job2_end: i32, travel_time_uncertainty: Option<TravelTimeUncertainty>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (5., 0.)),
+                create_delivery_job_with_times("job2", (10., 0.), vec![(0, job2_end)], 0.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                speed_bands: None,
+                overlay: None,
+                distance_model: None,
+                travel_time_uncertainty,
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_use_travel_time_uncertainty, (job2_end, travel_time_uncertainty, expected_unassigned), {
+    can_use_travel_time_uncertainty_impl(job2_end, travel_time_uncertainty, expected_unassigned);
+}}
+
+can_use_travel_time_uncertainty! {
+    case01_no_uncertainty: (11, None, None),
+    case02_not_enough_slack: (11, Some(TravelTimeUncertainty { deviation: 0.2, probability: 0.95 }), Some("ROBUST_SCHEDULE_CONSTRAINT")),
+    case03_enough_slack: (14, Some(TravelTimeUncertainty { deviation: 0.2, probability: 0.95 }), None),
+    case04_low_probability: (11, Some(TravelTimeUncertainty { deviation: 0.2, probability: 0.5 }), None),
+}
+
+fn can_use_travel_time_uncertainty_impl(
+    job2_end: i32,
+    travel_time_uncertainty: Option<TravelTimeUncertainty>,
+    expected_unassigned: Option<&str>,
+) {
+    let problem = create_problem(job2_end, travel_time_uncertainty);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let unassigned = solution.unassigned.map(|unassigned| {
+        assert_eq!(unassigned.len(), 1);
+        assert_eq!(unassigned[0].job_id, "job2");
+        unassigned[0].reasons[0].code.clone()
+    });
+    assert_eq!(unassigned.as_deref(), expected_unassigned);
+}
@@ -28,7 +28,8 @@ fn can_use_vehicle_with_open_end() {
                     code: "REACHABLE_CONSTRAINT".to_string(),
                     description: "location unreachable".to_string(),
                     details: None,
-                }]
+                }],
+                remaining: None,
             }]))
             .build()
     );
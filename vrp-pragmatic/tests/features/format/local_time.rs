@@ -0,0 +1,81 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_local_job(id: &str, location: (f64, f64), times: (&str, &str), timezone: Option<&str>) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace {
+                times: Some(vec![vec![times.0.to_string(), times.1.to_string()]]),
+                timezone: timezone.map(|tz| tz.to_string()),
+                ..create_job_place(location, None)
+            }],
+            demand: Some(vec![1.]),
+            order: None,
+        }]),
+        ..create_job(id)
+    }
+}
+
+#[test]
+fn can_use_local_times_with_multiple_timezones() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_local_job("job1", (1., 0.), ("2024-07-15T09:00:00", "2024-07-15T09:05:00"), None),
+                create_local_job(
+                    "job2",
+                    (2., 0.),
+                    ("2024-07-15T08:10:00", "2024-07-15T09:00:00"),
+                    Some("Europe/London"),
+                ),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: "2024-07-15T08:00:00".to_string(),
+                        latest: Some("2024-07-15T08:00:00".to_string()),
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: "2024-07-15T18:00:00".to_string(),
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        timezone: Some("Europe/Berlin".to_string()),
+        matrix_storage: None,
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let schedules = solution.tours[0]
+        .stops
+        .iter()
+        .map(|stop| (stop.schedule().arrival.as_str(), stop.schedule().departure.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        schedules,
+        vec![
+            ("2024-07-15T08:00:00+02:00", "2024-07-15T08:00:00+02:00"),
+            ("2024-07-15T08:00:01+02:00", "2024-07-15T09:00:01+02:00"),
+            ("2024-07-15T08:00:02+01:00", "2024-07-15T08:10:01+01:00"),
+            ("2024-07-15T09:10:03+02:00", "2024-07-15T09:10:03+02:00"),
+        ]
+    );
+}
@@ -20,6 +20,8 @@ fn can_use_unknown_location() {
                         earliest: format_time(0.),
                         latest: None,
                         location: Location::Custom { r#type: CustomLocationType::Unknown },
+                        timezone: None,
+                        alternatives: None,
                     },
                     ..create_default_open_vehicle_shift()
                 }],
@@ -45,17 +47,17 @@ fn can_use_unknown_location() {
             .tour(
                 TourBuilder::default()
                     .stops(vec![
-                        StopBuilder::default().custom_unknown().schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                        StopBuilder::default().custom_unknown().schedule_stamp(0., 0.).load(vec![2.]).build_departure(),
                         StopBuilder::default()
                             .coordinate((5., 0.))
                             .schedule_stamp(0., 1.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(0)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(6., 7.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(5)
                             .build_single("job2", "delivery"),
                     ])
@@ -17,6 +17,8 @@ fn can_use_location_index() {
                         earliest: format_time(0.),
                         latest: None,
                         location: Location::Reference { index: 2 },
+                        timezone: None,
+                        alternatives: None,
                     },
                     ..create_default_open_vehicle_shift()
                 }],
@@ -42,17 +44,17 @@ fn can_use_location_index() {
             .tour(
                 TourBuilder::default()
                     .stops(vec![
-                        StopBuilder::default().reference(2).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                        StopBuilder::default().reference(2).schedule_stamp(0., 0.).load(vec![2.]).build_departure(),
                         StopBuilder::default()
                             .reference(1)
                             .schedule_stamp(2., 3.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(2)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .reference(0)
                             .schedule_stamp(4., 5.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(3)
                             .build_single("job1", "delivery"),
                     ])
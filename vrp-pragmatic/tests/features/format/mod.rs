@@ -1,2 +1,3 @@
+mod local_time;
 mod location_custom;
 mod location_index;
@@ -9,7 +9,13 @@ fn can_limit_by_max_distance() {
         plan: Plan { jobs: vec![create_delivery_job("job1", (100., 0.))], ..create_empty_plan() },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                limits: Some(VehicleLimits { max_distance: Some(99.), max_duration: None, tour_size: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(99.),
+                    max_duration: None,
+                    tour_size: None,
+                    max_leg_distance: None,
+                    max_leg_duration: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -35,7 +41,8 @@ fn can_limit_by_max_distance() {
                     code: "MAX_DISTANCE_CONSTRAINT".to_string(),
                     description: "cannot be assigned due to max distance constraint of vehicle".to_string(),
                     details: None
-                }]
+                }],
+                remaining: None,
             }]))
             .build()
     );
@@ -48,11 +55,29 @@ fn can_handle_empty_route() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (10., 0.).to_loc() }),
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (10., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     ..create_default_open_vehicle_shift()
                 }],
-                limits: Some(VehicleLimits { max_distance: Some(9.), max_duration: None, tour_size: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(9.),
+                    max_duration: None,
+                    tour_size: None,
+                    max_leg_distance: None,
+                    max_leg_duration: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -72,7 +97,8 @@ fn can_handle_empty_route() {
                     code: "MAX_DISTANCE_CONSTRAINT".to_string(),
                     description: "cannot be assigned due to max distance constraint of vehicle".to_string(),
                     details: None,
-                }]
+                }],
+                remaining: None,
             }]))
             .build()
     );
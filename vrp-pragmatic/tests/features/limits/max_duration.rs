@@ -4,7 +4,13 @@ use crate::helpers::*;
 
 fn create_vehicle_type_with_max_duration_limit(max_duration: f64) -> VehicleType {
     VehicleType {
-        limits: Some(VehicleLimits { max_distance: None, max_duration: Some(max_duration), tour_size: None }),
+        limits: Some(VehicleLimits {
+            max_distance: None,
+            max_duration: Some(max_duration),
+            tour_size: None,
+            max_leg_distance: None,
+            max_leg_duration: None,
+        }),
         ..create_default_vehicle_type()
     }
 }
@@ -35,7 +41,8 @@ fn can_limit_one_job_by_max_duration() {
                     code: "MAX_DURATION_CONSTRAINT".to_string(),
                     description: "cannot be assigned due to max duration constraint of vehicle".to_string(),
                     details: None
-                }]
+                }],
+                remaining: None,
             }]))
             .build()
     );
@@ -70,30 +77,30 @@ fn can_skip_job_from_multiple_because_of_max_duration() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![3])
+                            .load(vec![3.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((3., 0.))
                             .schedule_stamp(3., 13.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .distance(3)
                             .build_single("job3", "delivery"),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(14., 24.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(4)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(25., 35.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(5)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(36., 36.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(6)
                             .build_arrival(),
                     ])
@@ -110,7 +117,8 @@ fn can_skip_job_from_multiple_because_of_max_duration() {
                             vehicle_id: "my_vehicle_1".to_string(),
                             shift_index: 0
                         }]),
-                    }]
+                    }],
+                    remaining: None,
                 },
                 UnassignedJob {
                     job_id: "job5".to_string(),
@@ -121,7 +129,8 @@ fn can_skip_job_from_multiple_because_of_max_duration() {
                             vehicle_id: "my_vehicle_1".to_string(),
                             shift_index: 0
                         }]),
-                    }]
+                    }],
+                    remaining: None,
                 }
             ]))
             .build()
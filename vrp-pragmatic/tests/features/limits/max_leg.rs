@@ -0,0 +1,53 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_test_problem(max_leg_distance: Option<f64>, max_leg_duration: Option<f64>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (3., 0.)),
+                create_delivery_job("job2", (6., 0.)),
+                create_delivery_job("job3", (20., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_leg_distance,
+                    max_leg_duration,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_limit_by_max_leg, (max_leg_distance, max_leg_duration, expected_code), {
+    can_limit_by_max_leg_impl(max_leg_distance, max_leg_duration, expected_code);
+}}
+
+can_limit_by_max_leg! {
+    case01_distance: (Some(5.), None, "MAX_LEG_DISTANCE_CONSTRAINT"),
+    case02_duration: (None, Some(5.), "MAX_LEG_DURATION_CONSTRAINT"),
+}
+
+fn can_limit_by_max_leg_impl(max_leg_distance: Option<f64>, max_leg_duration: Option<f64>, expected_code: &str) {
+    let problem = create_test_problem(max_leg_distance, max_leg_duration);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec!["job1"], vec!["job2"]]);
+    assert_eq!(solution.unassigned.as_ref().map_or(0, |unassigned| unassigned.len()), 1);
+    let unassigned = &solution.unassigned.as_ref().unwrap()[0];
+    assert_eq!(unassigned.job_id, "job3");
+    assert_eq!(unassigned.reasons.first().map(|reason| reason.code.as_str()), Some(expected_code));
+}
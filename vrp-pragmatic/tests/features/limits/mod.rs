@@ -1,3 +1,4 @@
 mod max_distance;
 mod max_duration;
+mod max_leg;
 mod tour_size;
@@ -16,7 +16,13 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                limits: Some(VehicleLimits { max_distance: None, max_duration: None, tour_size: Some(2) }),
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: Some(2),
+                    max_leg_distance: None,
+                    max_leg_duration: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -36,18 +42,18 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(1., 2.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(1)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(3., 4.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(2)
                             .build_single("job2", "delivery")
                     ])
@@ -60,7 +66,8 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                     code: "TOUR_SIZE_CONSTRAINT".to_string(),
                     description: "cannot be assigned due to tour size constraint of vehicle".to_string(),
                     details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
-                }]
+                }],
+                remaining: None,
             }]))
             .build()
     );
@@ -1,10 +1,12 @@
 //! This module contains feature tests: minimalistic tests which check features in isolation
 //! and their combination.
 
+mod affinity;
 mod breaks;
 mod capacity;
 mod clustering;
 mod compatibility;
+mod conflicts;
 mod fleet;
 mod format;
 mod group;
@@ -18,5 +20,6 @@ mod reload;
 mod skills;
 mod timing;
 mod tour_shape;
+mod transfer;
 mod unassigned;
 mod work_balance;
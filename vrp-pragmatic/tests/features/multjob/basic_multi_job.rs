@@ -9,13 +9,13 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                 create_delivery_job("simple", (1., 0.)),
                 create_multi_job(
                     "multi",
-                    vec![((2., 0.), 1., vec![1]), ((8., 0.), 1., vec![1])],
-                    vec![((6., 0.), 1., vec![2])],
+                    vec![((2., 0.), 1., vec![1.]), ((8., 0.), 1., vec![1.])],
+                    vec![((6., 0.), 1., vec![2.])],
                 ),
             ],
             ..create_empty_plan()
         },
-        fleet: Fleet { vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])], ..create_default_fleet() },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2.])], ..create_default_fleet() },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -31,36 +31,36 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(1., 2.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(1)
                             .build_single("simple", "delivery"),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(3., 4.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(2)
                             .build_single_tag("multi", "pickup", "p1"),
                         StopBuilder::default()
                             .coordinate((8., 0.))
                             .schedule_stamp(10., 11.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .distance(8)
                             .build_single_tag("multi", "pickup", "p2"),
                         StopBuilder::default()
                             .coordinate((6., 0.))
                             .schedule_stamp(13., 14.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(10)
                             .build_single_tag("multi", "delivery", "d1"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(20., 20.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(16)
                             .build_arrival(),
                     ])
@@ -77,12 +77,12 @@ fn can_assign_multi_job_in_pickup_effective_way() {
         plan: Plan {
             jobs: vec![create_multi_job(
                 "multi",
-                vec![((4., 0.), 1., vec![1]), ((2., 0.), 1., vec![1])],
-                vec![((6., 0.), 1., vec![2])],
+                vec![((4., 0.), 1., vec![1.]), ((2., 0.), 1., vec![1.])],
+                vec![((6., 0.), 1., vec![2.])],
             )],
             ..create_empty_plan()
         },
-        fleet: Fleet { vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])], ..create_default_fleet() },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2.])], ..create_default_fleet() },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -98,30 +98,30 @@ fn can_assign_multi_job_in_pickup_effective_way() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(2., 3.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(2)
                             .build_single_tag("multi", "pickup", "p2"),
                         StopBuilder::default()
                             .coordinate((4., 0.))
                             .schedule_stamp(5., 6.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .distance(4)
                             .build_single_tag("multi", "pickup", "p1"),
                         StopBuilder::default()
                             .coordinate((6., 0.))
                             .schedule_stamp(8., 9.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(6)
                             .build_single_tag("multi", "delivery", "d1"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(15., 15.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(12)
                             .build_arrival(),
                     ])
@@ -16,7 +16,13 @@ fn can_assign_replacement_job() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (4., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (4., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
@@ -38,30 +44,30 @@ fn can_assign_replacement_job() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(1., 2.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(1)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(3., 4.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(2)
                             .build_single("job2", "replacement"),
                         StopBuilder::default()
                             .coordinate((3., 0.))
                             .schedule_stamp(5., 6.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .distance(3)
                             .build_single("job3", "pickup"),
                         StopBuilder::default()
                             .coordinate((4., 0.))
                             .schedule_stamp(7., 7.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(4)
                             .build_arrival(),
                     ])
@@ -9,18 +9,18 @@ fn can_handle_limited_capacity() {
             jobs: vec![
                 create_multi_job(
                     "multi_1",
-                    vec![((1., 0.), 1., vec![1]), ((2., 0.), 1., vec![1])],
-                    vec![((10., 0.), 1., vec![2])],
+                    vec![((1., 0.), 1., vec![1.]), ((2., 0.), 1., vec![1.])],
+                    vec![((10., 0.), 1., vec![2.])],
                 ),
                 create_multi_job(
                     "multi_2",
-                    vec![((3., 0.), 1., vec![1]), ((4., 0.), 1., vec![1])],
-                    vec![((11., 0.), 1., vec![2])],
+                    vec![((3., 0.), 1., vec![1.]), ((4., 0.), 1., vec![1.])],
+                    vec![((11., 0.), 1., vec![2.])],
                 ),
             ],
             ..create_empty_plan()
         },
-        fleet: Fleet { vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])], ..create_default_fleet() },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2.])], ..create_default_fleet() },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -6,7 +6,7 @@ use crate::helpers::*;
 fn can_use_only_deliveries_as_static_demand() {
     let problem = Problem {
         plan: Plan {
-            jobs: vec![create_multi_job("job1", vec![], vec![((8., 0.), 2., vec![1]), ((2., 0.), 1., vec![1])])],
+            jobs: vec![create_multi_job("job1", vec![], vec![((8., 0.), 2., vec![1.]), ((2., 0.), 1., vec![1.])])],
             ..create_empty_plan()
         },
         fleet: Fleet {
@@ -31,18 +31,18 @@ fn can_use_only_deliveries_as_static_demand() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(2., 3.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(2)
                             .build_single_tag("job1", "delivery", "d2"),
                         StopBuilder::default()
                             .coordinate((8., 0.))
                             .schedule_stamp(9., 11.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(8)
                             .build_single_tag("job1", "delivery", "d1"),
                     ])
@@ -57,13 +57,19 @@ fn can_use_only_deliveries_as_static_demand() {
 fn can_use_only_pickups_as_static_demand() {
     let problem = Problem {
         plan: Plan {
-            jobs: vec![create_multi_job("job1", vec![((8., 0.), 2., vec![1]), ((2., 0.), 1., vec![1])], vec![])],
+            jobs: vec![create_multi_job("job1", vec![((8., 0.), 2., vec![1.]), ((2., 0.), 1., vec![1.])], vec![])],
             ..create_empty_plan()
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (10., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (10., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
@@ -85,24 +91,24 @@ fn can_use_only_pickups_as_static_demand() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(2., 3.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(2)
                             .build_single_tag("job1", "pickup", "p2"),
                         StopBuilder::default()
                             .coordinate((8., 0.))
                             .schedule_stamp(9., 11.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .distance(8)
                             .build_single_tag("job1", "pickup", "p1"),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(13., 13.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(10)
                             .build_arrival(),
                     ])
@@ -8,12 +8,12 @@ fn can_unassign_multi_job_due_to_capacity() {
         plan: Plan {
             jobs: vec![create_multi_job(
                 "multi",
-                vec![((2., 0.), 1., vec![2]), ((8., 0.), 1., vec![1])],
-                vec![((6., 0.), 1., vec![3])],
+                vec![((2., 0.), 1., vec![2.]), ((8., 0.), 1., vec![1.])],
+                vec![((6., 0.), 1., vec![3.])],
             )],
             ..create_empty_plan()
         },
-        fleet: Fleet { vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])], ..create_default_fleet() },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2.])], ..create_default_fleet() },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -29,7 +29,8 @@ fn can_unassign_multi_job_due_to_capacity() {
                     code: "CAPACITY_CONSTRAINT".to_string(),
                     description: "does not fit into any vehicle due to capacity".to_string(),
                     details: None,
-                }]
+                }],
+                remaining: None,
             }]))
             .build()
     );
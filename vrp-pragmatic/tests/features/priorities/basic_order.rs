@@ -15,7 +15,13 @@ fn create_test_plan_with_three_jobs() -> Plan {
 }
 
 fn create_test_limit() -> Option<VehicleLimits> {
-    Some(VehicleLimits { max_distance: Some(15.), max_duration: None, tour_size: None })
+    Some(VehicleLimits {
+        max_distance: Some(15.),
+        max_duration: None,
+        tour_size: None,
+        max_leg_distance: None,
+        max_leg_duration: None,
+    })
 }
 
 fn create_order_objective(is_constrained: bool) -> Vec<Objective> {
@@ -60,30 +66,30 @@ fn can_follow_orders() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![3])
+                            .load(vec![3.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((5., 0.))
                             .schedule_stamp(5., 6.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .distance(5)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(9., 10.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(8)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((7., 0.))
                             .schedule_stamp(15., 16.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(13)
                             .build_single("job3", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(23., 23.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(20)
                             .build_arrival(),
                     ])
@@ -134,7 +140,8 @@ fn can_follow_order_when_prioritized_property_set() {
                 code: "TOUR_ORDER_CONSTRAINT".to_string(),
                 description: "cannot be assigned due to tour order constraint".to_string(),
                 details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
-            }]
+            }],
+            remaining: None,
         }])
     );
 }
@@ -143,8 +150,14 @@ fn can_follow_order_when_prioritized_property_set() {
 fn can_handle_order_between_special_activities() {
     let create_test_job = |id: &str, location: (f64, f64), order: i32| Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: location.to_loc(), duration: 100., tag: None }],
-            demand: Some(vec![1]),
+            places: vec![JobPlace {
+                times: None,
+                location: location.to_loc(),
+                duration: 100.,
+                tag: None,
+                timezone: None,
+            }],
+            demand: Some(vec![1.]),
             order: Some(order),
         }]),
         ..create_job(id)
@@ -157,7 +170,13 @@ fn can_handle_order_between_special_activities() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (10., 0.).to_loc() }),
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (10., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(100.), format_time(200.)]),
                         places: vec![VehicleOptionalBreakPlace {
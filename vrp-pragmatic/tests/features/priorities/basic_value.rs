@@ -23,7 +23,7 @@ fn can_prefer_jobs_with_more_value_impl(objectives: Option<Vec<Objective>>) {
             ..create_empty_plan()
         },
         fleet: Fleet {
-            vehicles: vec![VehicleType { capacity: vec![1], ..create_default_vehicle_type() }],
+            vehicles: vec![VehicleType { capacity: vec![1.], ..create_default_vehicle_type() }],
             ..create_default_fleet()
         },
         objectives,
@@ -45,7 +45,8 @@ fn can_prefer_jobs_with_more_value_impl(objectives: Option<Vec<Objective>>) {
                 code: "CAPACITY_CONSTRAINT".to_string(),
                 description: "does not fit into any vehicle due to capacity".to_string(),
                 details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }])
-            }]
+            }],
+            remaining: None,
         }
     );
 }
@@ -0,0 +1,42 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_prefer_jobs_with_more_value_at_arrival, (has_decay, expected_job_id), {
+    can_prefer_jobs_with_more_value_at_arrival_impl(has_decay, expected_job_id);
+}}
+
+can_prefer_jobs_with_more_value_at_arrival! {
+    case01_no_decay: (false, "job2"),
+    case02_decay: (true, "job1"),
+}
+
+fn can_prefer_jobs_with_more_value_at_arrival_impl(has_decay: bool, expected_job_id: &str) {
+    let value_decay = has_decay.then(|| {
+        vec![
+            JobValueDecayPoint { time: format_time(0.), factor: 1. },
+            JobValueDecayPoint { time: format_time(6.), factor: 0. },
+        ]
+    });
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_value("job1", (1., 0.), 100.),
+                Job { value_decay, ..create_delivery_job_with_value("job2", (5., 0.), 150.) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { capacity: vec![1.], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.iter().flatten().count(), 1);
+    assert!(get_ids_from_tour(&solution.tours[0]).into_iter().flatten().any(|id| id == expected_job_id));
+}
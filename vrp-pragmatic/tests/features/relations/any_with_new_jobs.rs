@@ -21,7 +21,7 @@ fn create_and_solve_problem_with_three_jobs(any_relation_jobs: Vec<String>) -> S
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![create_default_open_vehicle_shift()],
-                capacity: vec![3],
+                capacity: vec![3.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -46,24 +46,24 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![3])
+                            .load(vec![3.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(1., 2.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .distance(1)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(3., 4.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(2)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((3., 0.))
                             .schedule_stamp(5., 6.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(3)
                             .build_single("job3", "delivery"),
                     ])
@@ -46,54 +46,54 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![7])
+                            .load(vec![7.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((4., 0.))
                             .schedule_stamp(4., 5.)
-                            .load(vec![6])
+                            .load(vec![6.])
                             .distance(4)
                             .build_single("job4", "delivery"),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(7., 8.)
-                            .load(vec![5])
+                            .load(vec![5.])
                             .distance(6)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((6., 0.))
                             .schedule_stamp(12., 13.)
-                            .load(vec![4])
+                            .load(vec![4.])
                             .distance(10)
                             .build_single("job6", "delivery"),
                         StopBuilder::default()
                             .coordinate((7., 0.))
                             .schedule_stamp(14., 15.)
-                            .load(vec![3])
+                            .load(vec![3.])
                             .distance(11)
                             .build_single("job7", "delivery"),
                         StopBuilder::default()
                             .coordinate((5., 0.))
                             .schedule_stamp(17., 18.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .distance(13)
                             .build_single("job5", "delivery"),
                         StopBuilder::default()
                             .coordinate((3., 0.))
                             .schedule_stamp(20., 21.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(15)
                             .build_single("job3", "delivery"),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(23., 24.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(17)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(25., 25.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(18)
                             .build_arrival(),
                     ])
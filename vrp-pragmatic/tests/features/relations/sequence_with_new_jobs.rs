@@ -23,7 +23,7 @@ fn can_use_sequence_relation_with_strict_time_windows() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 vehicle_ids: vec!["my_vehicle_1".to_string()],
-                capacity: vec![10],
+                capacity: vec![10.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -43,42 +43,42 @@ fn can_use_sequence_relation_with_strict_time_windows() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 10.)
-                            .load(vec![5])
+                            .load(vec![5.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((20., 0.))
                             .schedule_stamp(30., 40.)
-                            .load(vec![4])
+                            .load(vec![4.])
                             .distance(20)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((30., 0.))
                             .schedule_stamp(50., 60.)
-                            .load(vec![3])
+                            .load(vec![3.])
                             .distance(30)
                             .build_single("job3", "delivery"),
                         StopBuilder::default()
                             .coordinate((50., 0.))
                             .schedule_stamp(80., 90.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .distance(50)
                             .build_single("job5", "delivery"),
                         StopBuilder::default()
                             .coordinate((40., 0.))
                             .schedule_stamp(100., 110.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(60)
                             .build_single("job4", "delivery"),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(140., 160.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(90)
                             .build_single_time("job1", "delivery", (150., 160.)),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(170., 170.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(100)
                             .build_arrival(),
                     ])
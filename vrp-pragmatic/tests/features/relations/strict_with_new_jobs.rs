@@ -36,7 +36,7 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
-                capacity: vec![5],
+                capacity: vec![5.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -56,42 +56,42 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![5])
+                            .load(vec![5.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(1., 2.)
-                            .load(vec![4])
+                            .load(vec![4.])
                             .distance(1)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((6., 0.))
                             .schedule_stamp(7., 8.)
-                            .load(vec![3])
+                            .load(vec![3.])
                             .distance(6)
                             .build_single("job6", "delivery"),
                         StopBuilder::default()
                             .coordinate((4., 0.))
                             .schedule_stamp(10., 11.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .distance(8)
                             .build_single("job4", "delivery"),
                         StopBuilder::default()
                             .coordinate((8., 0.))
                             .schedule_stamp(15., 16.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(12)
                             .build_single("job8", "delivery"),
                         StopBuilder::default()
                             .coordinate((9., 0.))
                             .schedule_stamp(17., 18.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(13)
                             .build_single("job9", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(27., 27.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(22)
                             .build_arrival(),
                     ])
@@ -105,42 +105,42 @@ fn can_use_two_strict_relations_with_two_vehicles_with_new_jobs() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![5])
+                            .load(vec![5.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(2., 3.)
-                            .load(vec![4])
+                            .load(vec![4.])
                             .distance(2)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((3., 0.))
                             .schedule_stamp(4., 5.)
-                            .load(vec![3])
+                            .load(vec![3.])
                             .distance(3)
                             .build_single("job3", "delivery"),
                         StopBuilder::default()
                             .coordinate((5., 0.))
                             .schedule_stamp(7., 8.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .distance(5)
                             .build_single("job5", "delivery"),
                         StopBuilder::default()
                             .coordinate((7., 0.))
                             .schedule_stamp(10., 11.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(7)
                             .build_single("job7", "delivery"),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(14., 15.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(10)
                             .build_single("job10", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(25., 25.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(20)
                             .build_arrival(),
                     ])
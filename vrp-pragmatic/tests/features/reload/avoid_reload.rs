@@ -18,8 +18,8 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                 create_delivery_job("simple", (1., 0.)),
                 create_multi_job(
                     "multi",
-                    vec![((2., 0.), 1., vec![1]), ((8., 0.), 1., vec![1])],
-                    vec![((6., 0.), 1., vec![2])],
+                    vec![((2., 0.), 1., vec![1.]), ((8., 0.), 1., vec![1.])],
+                    vec![((6., 0.), 1., vec![2.])],
                 ),
             ],
             ..create_empty_plan()
@@ -27,8 +27,20 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (0., 0.).to_loc() }),
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         location: (0., 0.).to_loc(),
@@ -36,8 +48,12 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    capacity: None,
+                    skills: None,
+                    limits: None,
+                    costs: None,
                 }],
-                capacity: vec![2],
+                capacity: vec![2.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -57,36 +73,36 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(1., 2.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(1)
                             .build_single("simple", "delivery"),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(3., 4.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(2)
                             .build_single_tag("multi", "pickup", "p1"),
                         StopBuilder::default()
                             .coordinate((8., 0.))
                             .schedule_stamp(10., 11.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .distance(8)
                             .build_single_tag("multi", "pickup", "p2"),
                         StopBuilder::default()
                             .coordinate((6., 0.))
                             .schedule_stamp(13., 14.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(10)
                             .build_single_tag("multi", "delivery", "d1"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(20., 20.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(16)
                             .build_arrival(),
                     ])
@@ -24,7 +24,8 @@ can_use_vehicle_with_two_tours_and_two_jobs! {
                         code: "CAPACITY_CONSTRAINT".to_string(),
                         description: "does not fit into any vehicle due to capacity".to_string(),
                         details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
-                    }]
+                    }],
+                    remaining: None,
                 }
              ])),
 }
@@ -35,8 +36,20 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (0., 0.).to_loc() }),
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         location: (0., 0.).to_loc(),
@@ -44,8 +57,12 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    capacity: None,
+                    skills: None,
+                    limits: None,
+                    costs: None,
                 }],
-                capacity: vec![1],
+                capacity: vec![1.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -65,30 +82,30 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(1., 2.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(1)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(3., 5.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(2)
                             .build_single("reload", "reload"),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(7., 8.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(4)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(10., 10.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(6)
                             .build_arrival(),
                     ])
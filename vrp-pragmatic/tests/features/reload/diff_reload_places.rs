@@ -18,8 +18,20 @@ fn can_use_reloads_with_different_locations() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (32., 0.).to_loc() }),
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (32., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     breaks: None,
                     reloads: Some(vec![
                         VehicleReload {
@@ -36,8 +48,12 @@ fn can_use_reloads_with_different_locations() {
                         },
                     ]),
                     recharges: None,
+                    capacity: None,
+                    skills: None,
+                    limits: None,
+                    costs: None,
                 }],
-                capacity: vec![2],
+                capacity: vec![2.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
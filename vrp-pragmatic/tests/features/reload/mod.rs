@@ -5,4 +5,5 @@ mod multi_dim_reload;
 mod multi_job_reload;
 mod multi_vehicle_reload;
 mod picks_devs_reload;
+mod satellite_reload;
 mod shared_reload;
@@ -7,16 +7,28 @@ fn can_use_multi_dim_capacity() {
     let problem = Problem {
         plan: Plan {
             jobs: vec![
-                create_delivery_job_with_demand("job1", (1., 0.), vec![1, 1]),
-                create_delivery_job_with_demand("job2", (2., 0.), vec![1, 1]),
+                create_delivery_job_with_demand("job1", (1., 0.), vec![1., 1.]),
+                create_delivery_job_with_demand("job2", (2., 0.), vec![1., 1.]),
             ],
             ..create_empty_plan()
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (0., 0.).to_loc() }),
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     reloads: Some(vec![VehicleReload {
                         location: (0., 0.).to_loc(),
                         duration: 2.0,
@@ -24,7 +36,7 @@ fn can_use_multi_dim_capacity() {
                     }]),
                     ..create_default_vehicle_shift()
                 }],
-                capacity: vec![1, 1],
+                capacity: vec![1., 1.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -44,30 +56,30 @@ fn can_use_multi_dim_capacity() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![1, 1])
+                            .load(vec![1., 1.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(1., 2.)
-                            .load(vec![0, 0])
+                            .load(vec![0., 0.])
                             .distance(1)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(3., 5.)
-                            .load(vec![1, 1])
+                            .load(vec![1., 1.])
                             .distance(2)
                             .build_single("reload", "reload"),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(7., 8.)
-                            .load(vec![0, 0])
+                            .load(vec![0., 0.])
                             .distance(4)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(10., 10.)
-                            .load(vec![0, 0])
+                            .load(vec![0., 0.])
                             .distance(6)
                             .build_arrival(),
                     ])
@@ -83,8 +95,8 @@ fn can_use_multi_dim_capacity_with_different_values() {
     let problem = Problem {
         plan: Plan {
             jobs: vec![
-                create_delivery_job_with_demand("job1", (1., 0.), vec![1, 1]),
-                create_delivery_job_with_demand("job2", (2., 0.), vec![1, 1]),
+                create_delivery_job_with_demand("job1", (1., 0.), vec![1., 1.]),
+                create_delivery_job_with_demand("job2", (2., 0.), vec![1., 1.]),
             ],
             ..create_empty_plan()
         },
@@ -94,7 +106,7 @@ fn can_use_multi_dim_capacity_with_different_values() {
                     reloads: Some(vec![create_default_reload()]),
                     ..create_default_vehicle_shift()
                 }],
-                capacity: vec![2, 1],
+                capacity: vec![2., 1.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -13,8 +13,8 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 create_delivery_job("simple3", (7., 0.)),
                 create_multi_job(
                     "multi",
-                    vec![((2., 0.), 1., vec![1]), ((8., 0.), 1., vec![1])],
-                    vec![((9., 0.), 1., vec![2])],
+                    vec![((2., 0.), 1., vec![1.]), ((8., 0.), 1., vec![1.])],
+                    vec![((9., 0.), 1., vec![2.])],
                 ),
             ],
             ..create_empty_plan()
@@ -22,8 +22,20 @@ fn can_serve_multi_job_and_delivery_with_reload() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (10., 0.).to_loc() }),
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (10., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         location: (0., 0.).to_loc(),
@@ -31,8 +43,12 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    capacity: None,
+                    skills: None,
+                    limits: None,
+                    costs: None,
                 }],
-                capacity: vec![2],
+                capacity: vec![2.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -56,11 +72,11 @@ fn can_properly_handle_load_without_capacity_violation() {
             jobs: vec![
                 create_pickup_delivery_job_with_params(
                     "job1",
-                    vec![2],
+                    vec![2.],
                     ((52., 0.), 10., vec![]),
                     ((1., 0.), 12., vec![]),
                 ),
-                create_pickup_job_with_demand("job2", (67., 0.), vec![2]),
+                create_pickup_job_with_demand("job2", (67., 0.), vec![2.]),
             ],
             ..create_empty_plan()
         },
@@ -82,7 +98,7 @@ fn can_properly_handle_load_without_capacity_violation() {
                     ]),
                     ..create_default_vehicle_shift()
                 }],
-                capacity: vec![2],
+                capacity: vec![2.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
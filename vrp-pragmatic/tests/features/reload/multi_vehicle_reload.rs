@@ -13,8 +13,20 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
             vehicles: vec![VehicleType {
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (0., 0.).to_loc() }),
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     reloads: Some(vec![VehicleReload {
                         location: (0., 0.).to_loc(),
                         duration: 2.0,
@@ -22,7 +34,7 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                     }]),
                     ..create_default_vehicle_shift()
                 }],
-                capacity: vec![1],
+                capacity: vec![1.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -42,30 +54,30 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(1., 2.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(1)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(3., 5.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(2)
                             .build_single("reload", "reload"),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(7., 8.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(4)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(10., 10.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(6)
                             .build_arrival(),
                     ])
@@ -18,8 +18,20 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (6., 0.).to_loc() }),
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(100.),
+                        location: (6., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         location: (3., 0.).to_loc(),
@@ -27,8 +39,12 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    capacity: None,
+                    skills: None,
+                    limits: None,
+                    costs: None,
                 }],
-                capacity: vec![1],
+                capacity: vec![1.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -43,41 +59,41 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
         solution.tours,
         vec![TourBuilder::default()
             .stops(vec![
-                StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+                StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1.]).build_departure(),
                 StopBuilder::default()
                     .coordinate((1., 0.))
                     .schedule_stamp(1., 2.)
-                    .load(vec![0])
+                    .load(vec![0.])
                     .distance(1)
                     .build_single("d1", "delivery"),
                 StopBuilder::default()
                     .coordinate((2., 0.))
                     .schedule_stamp(3., 4.)
-                    .load(vec![1])
+                    .load(vec![1.])
                     .distance(2)
                     .build_single("p1", "pickup"),
                 StopBuilder::default()
                     .coordinate((3., 0.))
                     .schedule_stamp(5., 7.)
-                    .load(vec![1])
+                    .load(vec![1.])
                     .distance(3)
                     .build_single("reload", "reload"),
                 StopBuilder::default()
                     .coordinate((4., 0.))
                     .schedule_stamp(8., 9.)
-                    .load(vec![0])
+                    .load(vec![0.])
                     .distance(4)
                     .build_single("d2", "delivery"),
                 StopBuilder::default()
                     .coordinate((5., 0.))
                     .schedule_stamp(10., 11.)
-                    .load(vec![1])
+                    .load(vec![1.])
                     .distance(5)
                     .build_single("p2", "pickup"),
                 StopBuilder::default()
                     .coordinate((6., 0.))
                     .schedule_stamp(12., 12.)
-                    .load(vec![0])
+                    .load(vec![0.])
                     .distance(6)
                     .build_arrival(),
             ])
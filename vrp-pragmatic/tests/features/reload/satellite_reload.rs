@@ -0,0 +1,113 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+const RESOURCE_CODE: &str = "RELOAD_RESOURCE_CONSTRAINT";
+
+fn create_two_echelon_problem(cargo: f64, bike_capacity: f64, bike_reloads: usize) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_skills("job1", (11., 0.), all_of_skills(vec!["bike".to_string()])),
+                create_delivery_job_with_skills("job2", (12., 0.), all_of_skills(vec!["bike".to_string()])),
+                create_delivery_job_with_skills("job3", (13., 0.), all_of_skills(vec!["bike".to_string()])),
+                Job {
+                    skills: Some(all_of_skills(vec!["truck".to_string()])),
+                    ..create_delivery_job_with_demand("cargo", (10., 0.), vec![cargo])
+                },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                VehicleType {
+                    skills: Some(vec![VehicleSkill::Plain("truck".to_string())]),
+                    ..create_vehicle_with_capacity("truck", vec![3.])
+                },
+                VehicleType {
+                    shifts: vec![VehicleShift {
+                        reloads: Some(
+                            (0..bike_reloads)
+                                .map(|_| VehicleReload {
+                                    location: (10., 0.).to_loc(),
+                                    resource_id: Some("satellite".to_string()),
+                                    ..create_default_reload()
+                                })
+                                .collect(),
+                        ),
+                        ..create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))
+                    }],
+                    skills: Some(vec![VehicleSkill::Plain("bike".to_string())]),
+                    ..create_vehicle_with_capacity("bike", vec![bike_capacity])
+                },
+            ],
+            resources: Some(vec![VehicleResource::Satellite {
+                id: "satellite".to_string(),
+                job_id: "cargo".to_string(),
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_departure_time(solution: &Solution, vehicle_id: &str) -> Option<f64> {
+    solution
+        .tours
+        .iter()
+        .find(|tour| tour.vehicle_id == vehicle_id)
+        .and_then(|tour| tour.stops.first())
+        .map(|stop| parse_time(&stop.schedule().departure))
+}
+
+fn get_cargo_departure_time(solution: &Solution) -> Option<f64> {
+    solution.tours.iter().flat_map(|tour| tour.stops.iter()).find_map(|stop| {
+        stop.activities()
+            .iter()
+            .any(|activity| activity.job_id == "cargo")
+            .then(|| parse_time(&stop.schedule().departure))
+    })
+}
+
+#[test]
+fn can_start_second_level_vehicle_after_cargo_is_delivered() {
+    let problem = create_two_echelon_problem(3., 3., 0);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let cargo_departure = get_cargo_departure_time(&solution).expect("cargo should be delivered");
+    let bike_departure = get_departure_time(&solution, "bike_1").expect("bike should be used");
+    assert!(bike_departure >= cargo_departure);
+}
+
+#[test]
+fn can_limit_second_level_deliveries_by_satellite_cargo() {
+    let problem = create_two_echelon_problem(2., 3., 0);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.expect("one job should be unassigned");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].reasons.first().map(|reason| reason.code.as_str()), Some(RESOURCE_CODE));
+}
+
+#[test]
+fn can_use_satellite_reloads_for_multiple_trips() {
+    let problem = create_two_echelon_problem(3., 1., 2);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let bike_tour = solution.tours.iter().find(|tour| tour.vehicle_id == "bike_1").expect("bike should be used");
+    assert_eq!(
+        bike_tour.stops.iter().flat_map(|stop| stop.activities()).filter(|a| a.activity_type == "reload").count(),
+        2
+    );
+    let cargo_departure = get_cargo_departure_time(&solution).expect("cargo should be delivered");
+    assert!(get_departure_time(&solution, "bike_1").unwrap() >= cargo_departure);
+}
@@ -55,12 +55,12 @@ fn can_consume_limited_resource_with_single_vehicle_impl(
                     ),
                     ..create_default_vehicle_shift()
                 }],
-                capacity: vec![vehicle_capacity],
+                capacity: vec![vehicle_capacity as f64],
                 ..create_default_vehicle_type()
             }],
             resources: Some(vec![VehicleResource::Reload {
                 id: "resource_1".to_string(),
-                capacity: vec![resource_capacity],
+                capacity: vec![resource_capacity as f64],
             }]),
             ..create_default_fleet()
         },
@@ -115,14 +115,17 @@ fn can_consume_limited_resource_with_two_vehicles_impl(
                         }]),
                         ..create_default_vehicle_shift()
                     }],
-                    capacity: vec![capacity],
+                    capacity: vec![capacity as f64],
                     ..create_default_vehicle_type()
                 })
                 .collect(),
             resources: Some(
                 resources
                     .into_iter()
-                    .map(|(id, capacity)| VehicleResource::Reload { id: id.to_string(), capacity: vec![capacity] })
+                    .map(|(id, capacity)| VehicleResource::Reload {
+                        id: id.to_string(),
+                        capacity: vec![capacity as f64],
+                    })
                     .collect(),
             ),
             ..create_default_fleet()
@@ -19,7 +19,7 @@ fn can_wait_for_job_start() {
                     type_id: "vehicle_with_skill".to_string(),
                     vehicle_ids: vec!["vehicle_with_skill_1".to_string()],
                     shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
-                    skills: Some(vec!["unique_skill".to_string()]),
+                    skills: Some(vec![VehicleSkill::Plain("unique_skill".to_string())]),
                     ..create_default_vehicle_type()
                 },
             ],
@@ -42,18 +42,18 @@ fn can_wait_for_job_start() {
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(9., 10.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(9)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(19., 19.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(18)
                             .build_arrival(),
                     ])
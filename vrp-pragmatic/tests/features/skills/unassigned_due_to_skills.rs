@@ -29,7 +29,8 @@ fn can_have_unassigned_due_to_missing_vehicle_skill() {
                     code: "SKILL_CONSTRAINT".to_string(),
                     description: "cannot serve required skill".to_string(),
                     details: None
-                }]
+                }],
+                remaining: None,
             }]))
             .build()
     );
@@ -30,42 +30,42 @@ fn can_use_multiple_times() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![5])
+                            .load(vec![5.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(10., 10.)
-                            .load(vec![4])
+                            .load(vec![4.])
                             .distance(10)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((20., 0.))
                             .schedule_stamp(20., 20.)
-                            .load(vec![3])
+                            .load(vec![3.])
                             .distance(20)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((40., 0.))
                             .schedule_stamp(40., 40.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .distance(40)
                             .build_single("job4", "delivery"),
                         StopBuilder::default()
                             .coordinate((50., 0.))
                             .schedule_stamp(50., 50.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(50)
                             .build_single("job5", "delivery"),
                         StopBuilder::default()
                             .coordinate((30., 0.))
                             .schedule_stamp(70., 100.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(70)
                             .build_single_time("job3", "delivery", (100., 100.)),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(130., 130.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(100)
                             .build_arrival(),
                     ])
@@ -28,24 +28,24 @@ fn can_wait_for_job_start() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(1., 1.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(1)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((2., 0.))
                             .schedule_stamp(2., 10.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(2)
                             .build_single_time("job2", "delivery", (10., 10.)),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(12., 12.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(4)
                             .build_arrival(),
                     ])
@@ -79,18 +79,18 @@ fn can_skip_initial_waiting() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 9.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(10., 20.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(1)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(21., 21.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(2)
                             .build_arrival(),
                     ])
@@ -115,6 +115,8 @@ fn can_consider_latest_departure_time() {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
                         latest: Some("1970-01-01T00:00:05Z".to_string()),
                         location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+                        timezone: None,
+                        alternatives: None,
                     },
                     ..create_default_vehicle_shift()
                 }],
@@ -137,18 +139,18 @@ fn can_consider_latest_departure_time() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 5.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((1., 0.))
                             .schedule_stamp(6., 20.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(1)
                             .build_single_time("job1", "delivery", (10., 20.)),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(21., 21.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(2)
                             .build_arrival(),
                     ])
@@ -31,36 +31,36 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![4])
+                            .load(vec![4.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(10., 10.)
-                            .load(vec![3])
+                            .load(vec![3.])
                             .distance(10)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((20., 0.))
                             .schedule_stamp(20., 20.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .distance(20)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((30., 0.))
                             .schedule_stamp(30., 30.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(30)
                             .build_single("job3", "delivery"),
                         StopBuilder::default()
                             .coordinate((40., 0.))
                             .schedule_stamp(40., 40.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(40)
                             .build_single("job4", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(80., 80.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(80)
                             .build_arrival(),
                     ])
@@ -73,7 +73,8 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
                     code: "TIME_WINDOW_CONSTRAINT".to_string(),
                     description: "cannot be visited within time window".to_string(),
                     details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
-                }]
+                }],
+                remaining: None,
             }]))
             .build()
     );
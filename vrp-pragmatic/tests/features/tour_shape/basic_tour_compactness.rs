@@ -32,7 +32,7 @@ fn can_compact_tour() {
             vehicles: vec![VehicleType {
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 shifts: vec![create_default_open_vehicle_shift()],
-                ..create_vehicle_with_capacity("my_vehicle", vec![6])
+                ..create_vehicle_with_capacity("my_vehicle", vec![6.])
             }],
             ..create_default_fleet()
         },
@@ -0,0 +1,110 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+use crate::parse_time;
+
+fn create_transfer_point(id: &str, location: (f64, f64), times: Option<Vec<(i32, i32)>>) -> TransferPoint {
+    TransferPoint {
+        id: id.to_string(),
+        location: location.to_loc(),
+        duration: 1.,
+        times: times.map(|times| {
+            times.into_iter().map(|(start, end)| vec![format_time(start as f64), format_time(end as f64)]).collect()
+        }),
+        timezone: None,
+    }
+}
+
+fn create_vehicle_with_distance_limit(id: &str, location: (f64, f64), max_distance: f64) -> VehicleType {
+    VehicleType {
+        shifts: vec![create_default_vehicle_shift_with_locations(location, location)],
+        limits: Some(VehicleLimits {
+            max_distance: Some(max_distance),
+            max_duration: None,
+            tour_size: None,
+            max_leg_distance: None,
+            max_leg_duration: None,
+        }),
+        ..create_default_vehicle(id)
+    }
+}
+
+fn create_transfer_problem(max_distance: f64, hub_times: Option<Vec<(i32, i32)>>) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                transfers: Some(vec!["hub".to_string()]),
+                ..create_pickup_delivery_job("job1", (2., 0.), (18., 0.))
+            }],
+            transfers: Some(vec![create_transfer_point("hub", (10., 0.), hub_times)]),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_distance_limit("west", (0., 0.), max_distance),
+                create_vehicle_with_distance_limit("east", (20., 0.), max_distance),
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+/// Returns vehicle id, job id and departure time of handover activities.
+fn get_handovers(solution: &Solution) -> Vec<(String, String, f64)> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter().map(move |stop| (tour, stop)))
+        .flat_map(|(tour, stop)| stop.activities().iter().map(move |activity| (tour, stop, activity)))
+        .filter(|(_, _, activity)| activity.activity_type == "handover")
+        .map(|(tour, stop, activity)| {
+            let departure = activity.time.as_ref().map_or(&stop.schedule().departure, |time| &time.end);
+            (tour.vehicle_id.clone(), activity.job_id.clone(), parse_time(departure))
+        })
+        .collect()
+}
+
+#[test]
+fn can_transfer_job_between_vehicles_at_transfer_point() {
+    let problem = create_transfer_problem(22., None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let handovers = get_handovers(&solution);
+    let drop = handovers.iter().find(|(vehicle_id, ..)| vehicle_id == "west_1").expect("no handover in west tour");
+    let pick = handovers.iter().find(|(vehicle_id, ..)| vehicle_id == "east_1").expect("no handover in east tour");
+    assert_eq!(drop.1, "job1");
+    assert_eq!(pick.1, "job1");
+    // NOTE handover duration is 1
+    assert!(pick.2 >= drop.2 + 1.);
+}
+
+#[test]
+fn can_serve_job_directly_when_transfer_is_not_needed() {
+    let problem = create_transfer_problem(100., None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert!(get_handovers(&solution).is_empty());
+}
+
+#[test]
+fn can_unassign_job_when_transfer_point_cannot_be_reached_in_time() {
+    let problem = create_transfer_problem(22., Some(vec![(0, 5)]));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.expect("job should be unassigned");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job1");
+    assert!(solution.tours.is_empty());
+}
@@ -1,2 +1,3 @@
 mod multi_reasons;
+mod partial_service;
 mod single_reason;
@@ -7,8 +7,8 @@ fn can_have_multiple_unassigned_reasons() {
     let problem = Problem {
         plan: Plan {
             jobs: vec![
-                create_delivery_job_with_demand("job1", (1., 0.), vec![9]),
-                create_delivery_job_with_demand("job2", (1., 0.), vec![9]),
+                create_delivery_job_with_demand("job1", (1., 0.), vec![9.]),
+                create_delivery_job_with_demand("job2", (1., 0.), vec![9.]),
                 create_delivery_job_with_skills("job3", (1., 0.), all_of_skills(vec!["unique_skill".to_string()])),
             ],
             ..create_empty_plan()
@@ -36,7 +36,8 @@ fn can_have_multiple_unassigned_reasons() {
                     UnassignedJobDetail { vehicle_id: "vehicle1_1".to_string(), shift_index: 0 },
                     UnassignedJobDetail { vehicle_id: "vehicle2_1".to_string(), shift_index: 0 }
                 ])
-            }]
+            }],
+            remaining: None,
         }])
     );
 }
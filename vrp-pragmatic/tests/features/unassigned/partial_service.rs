@@ -0,0 +1,118 @@
+use crate::format::problem::*;
+use crate::format::solution::UnassignedJobRemaining;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_serve_divisible_job_partially, (divisible, expected), {
+    can_serve_divisible_job_partially_impl(divisible, expected);
+}}
+
+can_serve_divisible_job_partially! {
+    case01_not_divisible: (None, None),
+    case02_divisible: (Some(JobDivisible { parts: 4 }), Some(("PARTIAL_SERVICE", 3))),
+}
+
+fn can_serve_divisible_job_partially_impl(divisible: Option<JobDivisible>, expected: Option<(&str, usize)>) {
+    let service = JobTask {
+        demand: None,
+        places: vec![JobPlace { duration: 8., ..create_job_place((1., 0.), None) }],
+        order: None,
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { services: Some(vec![service]), divisible, ..create_job("job1") }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(5.),
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.iter().flatten().collect::<Vec<_>>();
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job1");
+    if let Some((code, remaining)) = expected {
+        assert_eq!(solution.tours.len(), 1);
+        assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec!["job1"], vec!["arrival"]]);
+        assert_eq!(unassigned[0].reasons[0].code, code);
+        assert_eq!(
+            unassigned[0].remaining,
+            Some(UnassignedJobRemaining { parts: remaining, demand: vec![], duration: 2. * remaining as f64 })
+        );
+    } else {
+        assert!(solution.tours.is_empty());
+        assert_eq!(unassigned[0].remaining, None);
+    }
+}
+
+parameterized_test! {can_divide_demand_of_divisible_job, (capacity, expected_remaining), {
+    can_divide_demand_of_divisible_job_impl(capacity, expected_remaining);
+}}
+
+can_divide_demand_of_divisible_job! {
+    case01_half_fits: (4., 2),
+    case02_one_part_fits: (3., 3),
+    case03_all_fits: (8., 0),
+}
+
+fn can_divide_demand_of_divisible_job_impl(capacity: f64, expected_remaining: usize) {
+    let delivery = JobTask {
+        demand: Some(vec![8.]),
+        places: vec![JobPlace { duration: 4., ..create_job_place((1., 0.), None) }],
+        order: None,
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![delivery]),
+                divisible: Some(JobDivisible { parts: 4 }),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![capacity])],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    let load = solution.tours[0].stops.first().map(|stop| stop.load().clone()).unwrap();
+    assert_eq!(load, vec![8. - 2. * expected_remaining as f64]);
+    let unassigned = solution.unassigned.iter().flatten().collect::<Vec<_>>();
+    if expected_remaining > 0 {
+        assert_eq!(unassigned.len(), 1);
+        assert_eq!(unassigned[0].reasons[0].code, "PARTIAL_SERVICE");
+        assert_eq!(
+            unassigned[0].remaining,
+            Some(UnassignedJobRemaining {
+                parts: expected_remaining,
+                demand: vec![2. * expected_remaining as f64],
+                duration: expected_remaining as f64,
+            })
+        );
+    } else {
+        assert!(unassigned.is_empty());
+    }
+}
@@ -29,7 +29,8 @@ fn can_have_empty_detail_in_empty_solution() {
                     code: "SKILL_CONSTRAINT".to_string(),
                     description: "cannot serve required skill".to_string(),
                     details: None
-                }]
+                }],
+                remaining: None,
             }]))
             .build()
     );
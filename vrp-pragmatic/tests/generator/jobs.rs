@@ -50,7 +50,7 @@ prop_compose! {
     pub fn pickup_delivery_job_prototype(
         pickup_place: impl Strategy<Value = JobPlace>,
         delivery_place: impl Strategy<Value = JobPlace>,
-        demand_proto: impl Strategy<Value = Option<Vec<i32>>>,
+        demand_proto: impl Strategy<Value = Option<Vec<f64>>>,
         order_proto: impl Strategy<Value = Option<i32>>,
         skills_proto: impl Strategy<Value = Option<JobSkills>>,
         value_proto: impl Strategy<Value = Option<f64>>,
@@ -90,7 +90,13 @@ prop_compose! {
             skills,
             value,
             group,
-            compatibility
+            compatibility,
+            conflicts: None,
+            affinity: None,
+            transfers: None,
+            truck_only: None,
+            value_decay: None,
+            divisible: None,
         }
     }
 }
@@ -137,6 +143,12 @@ prop_compose! {
             value,
             group,
             compatibility,
+            conflicts: None,
+            affinity: None,
+            transfers: None,
+            truck_only: None,
+            value_decay: None,
+            divisible: None,
         }
     }
 }
@@ -144,7 +156,7 @@ prop_compose! {
 prop_compose! {
     pub fn job_task_prototype(
         places: impl Strategy<Value = JobPlace>,
-        demand_proto: impl Strategy<Value = Option<Vec<i32>>>,
+        demand_proto: impl Strategy<Value = Option<Vec<f64>>>,
         order_proto: impl Strategy<Value = Option<i32>>,
     )
     (
@@ -169,14 +181,14 @@ prop_compose! {
      times in time_windows,
      tag in tags
     ) -> JobPlace {
-      JobPlace { times, location, duration, tag }
+      JobPlace { times, location, duration, tag, timezone: None }
     }
 }
 
 prop_compose! {
     /// Generates one dimensional demand in range.
-    pub fn generate_simple_demand(range: Range<i32>)(demand in range) -> Option<Vec<i32>> {
-        Some(vec![demand])
+    pub fn generate_simple_demand(range: Range<i32>)(demand in range) -> Option<Vec<f64>> {
+        Some(vec![demand as f64])
     }
 }
 
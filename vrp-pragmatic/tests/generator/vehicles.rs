@@ -8,9 +8,9 @@ prop_compose! {
     pub fn generate_vehicle(
         amount_proto: Range<usize>,
         profile_proto: impl Strategy<Value = VehicleProfile>,
-        capacity_proto: impl Strategy<Value = Vec<i32>>,
+        capacity_proto: impl Strategy<Value = Vec<f64>>,
         costs_proto: impl Strategy<Value = VehicleCosts>,
-        skills_proto: impl Strategy<Value = Option<Vec<String>>>,
+        skills_proto: impl Strategy<Value = Option<Vec<VehicleSkill>>>,
         limits_proto: impl Strategy<Value = Option<VehicleLimits>>,
         shifts_proto: impl Strategy<Value = Vec<VehicleShift>>,
     )
@@ -33,6 +33,8 @@ prop_compose! {
             capacity,
             skills,
             limits,
+            trailer: None,
+            backhaul: None,
         }
     }
 }
@@ -77,7 +79,7 @@ prop_compose! {
           times,
           location,
           duration,
-          tag,
+          tag, timezone: None,
         }
     }
 }
@@ -109,6 +111,10 @@ prop_compose! {
           breaks,
           reloads,
           recharges,
+          capacity: None,
+          skills: None,
+          limits: None,
+          costs: None,
         }
     }
 }
@@ -163,7 +169,7 @@ prop_compose! {
 
 prop_compose! {
     /// Generates no vehicle skills.
-    pub fn generate_no_vehicle_skills()(_ in ".*") -> Option<Vec<String>> {
+    pub fn generate_no_vehicle_skills()(_ in ".*") -> Option<Vec<VehicleSkill>> {
         None
     }
 }
@@ -176,7 +182,7 @@ prop_compose! {
 
 prop_compose! {
     /// Generates one dimensional capacity in range.
-    pub fn generate_simple_capacity(range: Range<i32>)(capacity in range) -> Vec<i32> {
-        vec![capacity]
+    pub fn generate_simple_capacity(range: Range<i32>)(capacity in range) -> Vec<f64> {
+        vec![capacity as f64]
     }
 }
@@ -8,11 +8,11 @@ use vrp_core::models::problem::{TransportCost, TravelTime};
 use vrp_core::models::solution::Route;
 
 pub fn create_job_place(location: (f64, f64), tag: Option<String>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1., tag }
+    JobPlace { times: None, location: location.to_loc(), duration: 1., tag, timezone: None }
 }
 
 pub fn create_task(location: (f64, f64), tag: Option<String>) -> JobTask {
-    JobTask { places: vec![create_job_place(location, tag)], demand: Some(vec![1]), order: None }
+    JobTask { places: vec![create_job_place(location, tag)], demand: Some(vec![1.]), order: None }
 }
 
 pub fn create_job(id: &str) -> Job {
@@ -26,6 +26,12 @@ pub fn create_job(id: &str) -> Job {
         value: None,
         group: None,
         compatibility: None,
+        conflicts: None,
+        affinity: None,
+        transfers: None,
+        truck_only: None,
+        value_decay: None,
+        divisible: None,
     }
 }
 
@@ -37,7 +43,7 @@ pub fn create_delivery_job_with_order(id: &str, location: (f64, f64), order: i32
     Job {
         deliveries: Some(vec![JobTask {
             places: vec![create_job_place(location, None)],
-            demand: Some(vec![1]),
+            demand: Some(vec![1.]),
             order: Some(order),
         }]),
         ..create_job(id)
@@ -48,7 +54,7 @@ pub fn create_delivery_job_with_group(id: &str, location: (f64, f64), group: &st
     Job {
         deliveries: Some(vec![JobTask {
             places: vec![create_job_place(location, None)],
-            demand: Some(vec![1]),
+            demand: Some(vec![1.]),
             order: None,
         }]),
         group: Some(group.to_string()),
@@ -60,7 +66,7 @@ pub fn create_delivery_job_with_compatibility(id: &str, location: (f64, f64), co
     Job {
         deliveries: Some(vec![JobTask {
             places: vec![create_job_place(location, None)],
-            demand: Some(vec![1]),
+            demand: Some(vec![1.]),
             order: None,
         }]),
         compatibility: Some(compatibility.to_string()),
@@ -72,7 +78,7 @@ pub fn create_delivery_job_with_skills(id: &str, location: (f64, f64), skills: J
     Job { skills: Some(skills), ..create_delivery_job(id, location) }
 }
 
-pub fn create_delivery_job_with_demand(id: &str, location: (f64, f64), demand: Vec<i32>) -> Job {
+pub fn create_delivery_job_with_demand(id: &str, location: (f64, f64), demand: Vec<f64>) -> Job {
     Job { deliveries: Some(vec![JobTask { demand: Some(demand), ..create_task(location, None) }]), ..create_job(id) }
 }
 
@@ -80,7 +86,7 @@ pub fn create_delivery_job_with_duration(id: &str, location: (f64, f64), duratio
     Job {
         deliveries: Some(vec![JobTask {
             places: vec![JobPlace { duration, ..create_job_place(location, None) }],
-            demand: Some(vec![1]),
+            demand: Some(vec![1.]),
             order: None,
         }]),
         ..create_job(id)
@@ -91,7 +97,7 @@ pub fn create_delivery_job_with_times(id: &str, location: (f64, f64), times: Vec
     Job {
         deliveries: Some(vec![JobTask {
             places: vec![JobPlace { duration, times: convert_times(&times), ..create_job_place(location, None) }],
-            demand: Some(vec![1]),
+            demand: Some(vec![1.]),
             order: None,
         }]),
         ..create_job(id)
@@ -106,7 +112,7 @@ pub fn create_pickup_job(id: &str, location: (f64, f64)) -> Job {
     Job { pickups: Some(vec![create_task(location, None)]), ..create_job(id) }
 }
 
-pub fn create_pickup_job_with_demand(id: &str, location: (f64, f64), demand: Vec<i32>) -> Job {
+pub fn create_pickup_job_with_demand(id: &str, location: (f64, f64), demand: Vec<f64>) -> Job {
     Job { pickups: Some(vec![JobTask { demand: Some(demand), ..create_task(location, None) }]), ..create_job(id) }
 }
 
@@ -128,7 +134,7 @@ pub fn create_pickup_delivery_job(id: &str, pickup_location: (f64, f64), deliver
 
 pub fn create_pickup_delivery_job_with_params(
     id: &str,
-    demand: Vec<i32>,
+    demand: Vec<f64>,
     pickup: ((f64, f64), f64, Vec<(i32, i32)>),
     delivery: ((f64, f64), f64, Vec<(i32, i32)>),
 ) -> Job {
@@ -159,8 +165,14 @@ pub fn create_pickup_delivery_job_with_params(
 pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: Location::Reference { index }, duration: 1., tag: None }],
-            demand: Some(vec![1]),
+            places: vec![JobPlace {
+                times: None,
+                location: Location::Reference { index },
+                duration: 1.,
+                tag: None,
+                timezone: None,
+            }],
+            demand: Some(vec![1.]),
             order: None,
         }]),
         ..create_job(id)
@@ -169,10 +181,10 @@ pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
 
 pub fn create_multi_job(
     id: &str,
-    pickups: Vec<((f64, f64), f64, Vec<i32>)>,
-    deliveries: Vec<((f64, f64), f64, Vec<i32>)>,
+    pickups: Vec<((f64, f64), f64, Vec<f64>)>,
+    deliveries: Vec<((f64, f64), f64, Vec<f64>)>,
 ) -> Job {
-    let create_tasks = |tasks: Vec<((f64, f64), f64, Vec<i32>)>, prefix: &str| {
+    let create_tasks = |tasks: Vec<((f64, f64), f64, Vec<f64>)>, prefix: &str| {
         let tasks = tasks
             .into_iter()
             .enumerate()
@@ -206,21 +218,47 @@ pub fn create_default_vehicle_shift() -> VehicleShift {
 
 pub fn create_default_open_vehicle_shift() -> VehicleShift {
     VehicleShift {
-        start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+        start: ShiftStart {
+            earliest: format_time(0.),
+            latest: None,
+            location: (0., 0.).to_loc(),
+            timezone: None,
+            alternatives: None,
+        },
         end: None,
         breaks: None,
         reloads: None,
         recharges: None,
+        capacity: None,
+        skills: None,
+        limits: None,
+        costs: None,
     }
 }
 
 pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64, f64)) -> VehicleShift {
     VehicleShift {
-        start: ShiftStart { earliest: format_time(0.), latest: None, location: (start.0, start.1).to_loc() },
-        end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (end.0, end.1).to_loc() }),
+        start: ShiftStart {
+            earliest: format_time(0.),
+            latest: None,
+            location: (start.0, start.1).to_loc(),
+            timezone: None,
+            alternatives: None,
+        },
+        end: Some(ShiftEnd {
+            earliest: None,
+            latest: format_time(1000.),
+            location: (end.0, end.1).to_loc(),
+            timezone: None,
+            alternatives: None,
+        }),
         breaks: None,
         reloads: None,
         recharges: None,
+        capacity: None,
+        skills: None,
+        limits: None,
+        costs: None,
     }
 }
 
@@ -241,10 +279,10 @@ pub fn create_default_vehicle_type() -> VehicleType {
 }
 
 pub fn create_default_vehicle(id: &str) -> VehicleType {
-    create_vehicle_with_capacity(id, vec![10])
+    create_vehicle_with_capacity(id, vec![10.])
 }
 
-pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType {
+pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<f64>) -> VehicleType {
     VehicleType {
         type_id: id.to_string(),
         vehicle_ids: vec![format!("{id}_1")],
@@ -254,6 +292,8 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         capacity,
         skills: None,
         limits: None,
+        trailer: None,
+        backhaul: None,
     }
 }
 
@@ -262,7 +302,14 @@ pub fn create_default_fleet() -> Fleet {
 }
 
 pub fn create_default_matrix_profiles() -> Vec<MatrixProfile> {
-    vec![MatrixProfile { name: "car".to_string(), speed: None }]
+    vec![MatrixProfile {
+        name: "car".to_string(),
+        speed: None,
+        speed_bands: None,
+        overlay: None,
+        distance_model: None,
+        travel_time_uncertainty: None,
+    }]
 }
 
 pub fn create_min_jobs_cost_objective() -> Option<Vec<Objective>> {
@@ -270,7 +317,7 @@ pub fn create_min_jobs_cost_objective() -> Option<Vec<Objective>> {
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, clustering: None, transfers: None }
 }
 
 pub fn create_empty_problem() -> Problem {
@@ -278,6 +325,8 @@ pub fn create_empty_problem() -> Problem {
         plan: create_empty_plan(),
         fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None },
         objectives: None,
+        timezone: None,
+        matrix_storage: None,
     }
 }
 
@@ -318,7 +367,7 @@ pub fn to_strings(data: Vec<&str>) -> Vec<String> {
 }
 
 pub fn all_of_skills(skills: Vec<String>) -> JobSkills {
-    JobSkills { all_of: Some(skills), one_of: None, none_of: None }
+    JobSkills { all_of: Some(skills), one_of: None, none_of: None, min_levels: None }
 }
 
 fn convert_times(times: &[(i32, i32)]) -> Option<Vec<Vec<String>>> {
@@ -62,7 +62,7 @@ impl StopBuilder {
         self
     }
 
-    pub fn load(mut self, load: Vec<i32>) -> Self {
+    pub fn load(mut self, load: Vec<f64>) -> Self {
         *self.stop.load_mut() = load;
 
         self
@@ -22,8 +22,9 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T13:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                timezone: None,
                             }],
-                            demand: Some(vec![1]),
+                            demand: Some(vec![1.]),
                             order: None,
                         }]),
                         ..create_job("job1")
@@ -38,8 +39,9 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T11:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                timezone: None,
                             }],
-                            demand: Some(vec![1]),
+                            demand: Some(vec![1.]),
                             order: None,
                         }]),
                         ..create_job("job2")
@@ -54,8 +56,9 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                timezone: None,
                             }],
-                            demand: Some(vec![1]),
+                            demand: Some(vec![1.]),
                             order: None,
                         }]),
                         ..create_job("job3")
@@ -70,8 +73,9 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T16:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                timezone: None,
                             }],
-                            demand: Some(vec![2]),
+                            demand: Some(vec![2.]),
                             order: None,
                         }]),
                         ..create_job("job4")
@@ -86,8 +90,9 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                timezone: None,
                             }],
-                            demand: Some(vec![3]),
+                            demand: Some(vec![3.]),
                             order: None,
                         }]),
                         ..create_job("job5")
@@ -102,8 +107,9 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T18:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                timezone: None,
                             }],
-                            demand: Some(vec![1]),
+                            demand: Some(vec![1.]),
                             order: None,
                         }]),
                         ..create_job("job6")
@@ -122,11 +128,15 @@ fn can_handle_properly_invalid_break_removal() {
                             earliest: "2020-07-04T09:00:00Z".to_string(),
                             latest: None,
                             location: Location::Coordinate { lat: 52.44105158292253, lng: 13.424429791168873 },
+                            timezone: None,
+                            alternatives: None,
                         },
                         end: Some(ShiftEnd {
                             earliest: None,
                             latest: "2020-07-04T18:00:00Z".to_string(),
                             location: Location::Coordinate { lat: 52.44105158292253, lng: 13.424429791168873 },
+                            timezone: None,
+                            alternatives: None,
                         }),
                         breaks: Some(vec![VehicleBreak::Optional {
                             time: VehicleOptionalBreakTime::TimeWindow(vec![
@@ -138,10 +148,16 @@ fn can_handle_properly_invalid_break_removal() {
                         }]),
                         reloads: None,
                         recharges: None,
+                        capacity: None,
+                        skills: None,
+                        limits: None,
+                        costs: None,
                     }],
-                    capacity: vec![5],
+                    capacity: vec![5.],
                     skills: None,
                     limits: None,
+                    trailer: None,
+                    backhaul: None,
                 }],
                 ..create_default_fleet()
             },
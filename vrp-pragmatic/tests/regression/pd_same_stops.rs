@@ -13,8 +13,9 @@ fn can_handle_two_pd_jobs_with_same_locations_and_unusual_routing() {
             duration: 0.,
             times: None,
             tag: Some(tag.to_string()),
+            timezone: None,
         }],
-        demand: Some(vec![1]),
+        demand: Some(vec![1.]),
         order: None,
     };
 
@@ -42,11 +43,15 @@ fn can_handle_two_pd_jobs_with_same_locations_and_unusual_routing() {
                         earliest: format_time(0.),
                         latest: None,
                         location: Location::Reference { index: 2 },
+                        timezone: None,
+                        alternatives: None,
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: format_time(7200.),
                         location: Location::Reference { index: 2 },
+                        timezone: None,
+                        alternatives: None,
                     }),
                     ..create_default_vehicle_shift()
                 }],
@@ -131,8 +131,9 @@ fn check_jobs_impl(
                     duration: 0.0,
                     times: None,
                     tag: Some(format!("{tgt}{idx}")),
+                    timezone: None,
                 }],
-                demand: if tgt != "service" { Some(vec![1]) } else { None },
+                demand: if tgt != "service" { Some(vec![1.]) } else { None },
                 order: None,
             })
             .collect()
@@ -169,7 +170,10 @@ fn check_jobs_impl(
             })
             .collect(),
         unassigned: Some(
-            unassigned.into_iter().map(|job| UnassignedJob { job_id: job.to_string(), reasons: vec![] }).collect(),
+            unassigned
+                .into_iter()
+                .map(|job| UnassignedJob { job_id: job.to_string(), reasons: vec![], remaining: None })
+                .collect(),
         ),
         ..SolutionBuilder::default().build()
     };
@@ -194,17 +198,17 @@ fn can_detect_time_window_violation() {
         .tour(
             TourBuilder::default()
                 .stops(vec![
-                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(2., 2.).load(vec![1]).build_departure(),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(2., 2.).load(vec![1.]).build_departure(),
                     StopBuilder::default()
                         .coordinate((1., 0.))
                         .schedule_stamp(3., 4.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(1)
                         .build_single("job1", "delivery"),
                     StopBuilder::default()
                         .coordinate((0., 0.))
                         .schedule_stamp(5., 5.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(2)
                         .build_arrival(),
                 ])
@@ -234,17 +238,17 @@ fn can_detect_job_duration_violation() {
         .tour(
             TourBuilder::default()
                 .stops(vec![
-                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(2., 2.).load(vec![1]).build_departure(),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(2., 2.).load(vec![1.]).build_departure(),
                     StopBuilder::default()
                         .coordinate((1., 0.))
                         .schedule_stamp(5., 7.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(1)
                         .build_single("job1", "delivery"),
                     StopBuilder::default()
                         .coordinate((0., 0.))
                         .schedule_stamp(8., 8.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(2)
                         .build_arrival(),
                 ])
@@ -284,17 +288,17 @@ fn can_detect_group_violations() {
         TourBuilder::default()
             .vehicle_id(vehicle_id)
             .stops(vec![
-                StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+                StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1.]).build_departure(),
                 StopBuilder::default()
                     .coordinate((1., 0.))
                     .schedule_stamp(1., 2.)
-                    .load(vec![0])
+                    .load(vec![0.])
                     .distance(1)
                     .build_single(job_id, "delivery"),
                 StopBuilder::default()
                     .coordinate((0., 0.))
                     .schedule_stamp(3., 3.)
-                    .load(vec![0])
+                    .load(vec![0.])
                     .distance(2)
                     .build_arrival(),
             ])
@@ -309,3 +313,298 @@ fn can_detect_group_violations() {
 
     assert_eq!(result, Err("job groups are not respected: 'group1'".into()));
 }
+
+parameterized_test! {can_check_shift_locations, (start, end, expected), {
+    can_check_shift_locations_impl(start, end, expected);
+}}
+
+can_check_shift_locations! {
+    case01_shift_locations: ((0., 0.), (0., 0.), Ok(())),
+    case02_alternative_start: ((2., 0.), (0., 0.), Ok(())),
+    case03_alternative_end: ((0., 0.), (3., 0.), Ok(())),
+    case04_unknown_start: ((3., 0.), (0., 0.), Err(())),
+    case05_unknown_end: ((0., 0.), (2., 0.), Err(())),
+}
+
+fn can_check_shift_locations_impl(start: (f64, f64), end: (f64, f64), expected: Result<(), ()>) {
+    let create_alternative =
+        |location: (f64, f64)| ShiftLocation { location: location.to_loc(), cost: None, tag: None };
+    let shift = create_default_vehicle_shift();
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { alternatives: Some(vec![create_alternative((2., 0.))]), ..shift.start },
+                    end: shift
+                        .end
+                        .map(|end| ShiftEnd { alternatives: Some(vec![create_alternative((3., 0.))]), ..end }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .vehicle_id("my_vehicle_1")
+                .stops(vec![
+                    StopBuilder::default().coordinate(start).schedule_stamp(0., 0.).load(vec![1.]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![0.])
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default().coordinate(end).schedule_stamp(3., 3.).load(vec![0.]).build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_shift_locations(&ctx).map_err(|_| ());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_detect_conflict_violations() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { conflicts: Some(vec!["job2".to_string()]), ..create_delivery_job("job1", (1., 0.)) },
+                create_delivery_job("job2", (2., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { vehicle_ids: vec!["v1".to_string()], ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .vehicle_id("v1")
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2.]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![1.])
+                        .distance(1)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((2., 0.))
+                        .schedule_stamp(3., 4.)
+                        .load(vec![0.])
+                        .distance(2)
+                        .build_single("job2", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(6., 6.)
+                        .load(vec![0.])
+                        .distance(4)
+                        .build_arrival(),
+                ])
+                .statistic(StatisticBuilder::default().driving(4).serving(2).build())
+                .build(),
+        )
+        .build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_conflicts(&ctx);
+
+    assert_eq!(result, Err("job conflicts are not respected: 'job1'".into()));
+}
+
+parameterized_test! {can_check_trailers, (has_trailer, demand, activities, expected), {
+    can_check_trailers_impl(has_trailer, demand, activities, expected);
+}}
+
+can_check_trailers! {
+    case01_truck_only_detached: (true, 1., vec![("decouple", 1.), ("job1", 2.), ("recouple", 1.)], Ok(())),
+    case02_truck_only_attached: (true, 1., vec![("job1", 2.)], Err("truck only job 'job1' is served with trailer".into())),
+    case03_not_recoupled: (true, 1., vec![("decouple", 1.), ("job1", 2.)], Err("trailer is not recoupled in tour 'v1'".into())),
+    case04_nested_decouple: (
+        true, 1., vec![("decouple", 1.), ("decouple", 1.), ("job1", 2.), ("recouple", 1.)],
+        Err("nested trailer decouple in tour 'v1'".into())
+    ),
+    case05_truck_only_without_trailer: (false, 1., vec![("job1", 2.)], Ok(())),
+    case06_truck_capacity_exceeded: (
+        true, 11., vec![("decouple", 1.), ("job1", 2.), ("recouple", 1.)],
+        Err("truck capacity is exceeded while trailer is decoupled in tour 'v1'".into())
+    ),
+}
+
+fn can_check_trailers_impl(has_trailer: bool, demand: f64, activities: Vec<(&str, f64)>, expected: GenericResult<()>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                truck_only: Some(true),
+                ..create_delivery_job_with_demand("job1", (2., 0.), vec![demand])
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string()],
+                trailer: has_trailer.then(|| VehicleTrailer {
+                    capacity: vec![1.],
+                    parkings: vec![VehicleTrailerParking {
+                        location: (1., 0.).to_loc(),
+                        duration: 0.,
+                        times: None,
+                        tag: None,
+                        timezone: None,
+                    }],
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let stops = std::iter::once(
+        StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![demand]).build_departure(),
+    )
+    .chain(activities.into_iter().map(|(id, location)| {
+        let activity_type = if id == "job1" { "delivery" } else { id };
+        StopBuilder::default()
+            .coordinate((location, 0.))
+            .schedule_stamp(location, location)
+            .load(vec![0.])
+            .build_single(id, activity_type)
+    }))
+    .collect();
+    let solution =
+        SolutionBuilder::default().tour(TourBuilder::default().vehicle_id("v1").stops(stops).build()).build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_trailers(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_partially_served_jobs, (divisible, is_assigned, expected), {
+    can_check_partially_served_jobs_impl(divisible, is_assigned, expected);
+}}
+
+can_check_partially_served_jobs! {
+    case_01_divisible_assigned: (Some(JobDivisible { parts: 2 }), true, Ok(())),
+    case_02_not_divisible: (None, true, Err("job is not divisible, but served partially: 'job1'".into())),
+    case_03_divisible_not_assigned: (
+        Some(JobDivisible { parts: 2 }), false, Err("partially served job is not assigned: 'job1'".into())
+    ),
+}
+
+fn can_check_partially_served_jobs_impl(
+    divisible: Option<JobDivisible>,
+    is_assigned: bool,
+    expected: Result<(), GenericError>,
+) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { divisible, ..create_service_job("job1", (0., 0.)) }], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let stops = if is_assigned {
+        vec![StopBuilder::default().coordinate((0., 0.)).build_single("job1", "service")]
+    } else {
+        vec![]
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops,
+            statistic: Statistic::default(),
+        }],
+        unassigned: Some(vec![UnassignedJob {
+            job_id: "job1".to_string(),
+            reasons: vec![],
+            remaining: Some(UnassignedJobRemaining { parts: 1, demand: vec![], duration: 1. }),
+        }]),
+        ..SolutionBuilder::default().build()
+    };
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_jobs_presence(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_skill_levels, (vehicle_skills, expected), {
+    can_check_skill_levels_impl(vehicle_skills, expected);
+}}
+
+can_check_skill_levels! {
+    case01_same_level: (Some(vec![("welding", Some(2))]), Ok(())),
+    case02_higher_level: (Some(vec![("welding", Some(3))]), Ok(())),
+    case03_plain_skill: (Some(vec![("welding", None)]), Err("job 'job1' requires higher skill levels than vehicle 'v1' has".into())),
+    case04_lower_level: (Some(vec![("welding", Some(1))]), Err("job 'job1' requires higher skill levels than vehicle 'v1' has".into())),
+    case05_no_skills: (None, Err("job 'job1' requires higher skill levels than vehicle 'v1' has".into())),
+}
+
+fn can_check_skill_levels_impl(vehicle_skills: Option<Vec<(&str, Option<u32>)>>, expected: GenericResult<()>) {
+    let skills = JobSkills {
+        all_of: None,
+        one_of: None,
+        none_of: None,
+        min_levels: Some(vec![SkillLevel { name: "welding".to_string(), level: 2 }]),
+    };
+    let vehicle_skills = vehicle_skills.map(|skills| {
+        skills
+            .into_iter()
+            .map(|(name, level)| match level {
+                Some(level) => VehicleSkill::Leveled(SkillLevel { name: name.to_string(), level }),
+                None => VehicleSkill::Plain(name.to_string()),
+            })
+            .collect()
+    });
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { skills: Some(skills), ..create_delivery_job("job1", (1., 0.)) }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string()],
+                skills: vehicle_skills,
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .vehicle_id("v1")
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1.]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![0.])
+                        .distance(1)
+                        .build_single("job1", "delivery"),
+                ])
+                .build(),
+        )
+        .build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_skill_levels(&ctx);
+
+    assert_eq!(result, expected);
+}
@@ -77,8 +77,20 @@ fn can_check_breaks_impl(
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: break_times,
                         places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
@@ -86,8 +98,12 @@ fn can_check_breaks_impl(
                     }]),
                     reloads: None,
                     recharges: None,
+                    capacity: None,
+                    skills: None,
+                    limits: None,
+                    costs: None,
                 }],
-                capacity: vec![5],
+                capacity: vec![5.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -118,24 +134,24 @@ fn can_check_breaks_impl(
         .tour(
             TourBuilder::default()
                 .stops(vec![
-                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2.]).build_departure(),
                     StopBuilder::default()
                         .coordinate((1., 0.))
                         .schedule_stamp(1., 2.)
-                        .load(vec![1])
+                        .load(vec![1.])
                         .distance(1)
                         .build_single("job1", "delivery"),
                     StopBuilder::default()
                         .coordinate((2., 0.))
                         .schedule_stamp(3., 6.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(2)
                         .activities(activities)
                         .build(),
                     StopBuilder::default()
                         .coordinate((0., 0.))
                         .schedule_stamp(8., 8.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(4)
                         .build_arrival(),
                 ])
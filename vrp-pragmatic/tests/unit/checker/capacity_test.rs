@@ -8,20 +8,20 @@ parameterized_test! {can_check_load, (stop_loads, expected_result), {
 }}
 
 can_check_load! {
-    case00: ( vec![1, 1, 3, 1, 2, 1, 0], Ok(())),
+    case00: ( vec![1., 1., 3., 1., 2., 1., 0.], Ok(())),
 
-    case01: ( vec![1, 2, 3, 1, 2, 1, 0], Err(vec!["load mismatch at stop 1 in tour 'my_vehicle_1'".into()])),
-    case02: ( vec![1, 1, 2, 1, 2, 1, 0], Err(vec!["load mismatch at stops 2, 3 in tour 'my_vehicle_1'".into()])),
-    case03: ( vec![1, 1, 3, 2, 2, 1, 0], Err(vec!["load mismatch at stop 3 in tour 'my_vehicle_1'".into()])),
-    case04: ( vec![1, 1, 3, 1, 1, 1, 0], Err(vec!["load mismatch at stop 4 in tour 'my_vehicle_1'".into()])),
-    case05: ( vec![1, 1, 3, 1, 2, 2, 0], Err(vec!["load mismatch at stop 5 in tour 'my_vehicle_1'".into()])),
+    case01: ( vec![1., 2., 3., 1., 2., 1., 0.], Err(vec!["load mismatch at stop 1 in tour 'my_vehicle_1'".into()])),
+    case02: ( vec![1., 1., 2., 1., 2., 1., 0.], Err(vec!["load mismatch at stops 2, 3 in tour 'my_vehicle_1'".into()])),
+    case03: ( vec![1., 1., 3., 2., 2., 1., 0.], Err(vec!["load mismatch at stop 3 in tour 'my_vehicle_1'".into()])),
+    case04: ( vec![1., 1., 3., 1., 1., 1., 0.], Err(vec!["load mismatch at stop 4 in tour 'my_vehicle_1'".into()])),
+    case05: ( vec![1., 1., 3., 1., 2., 2., 0.], Err(vec!["load mismatch at stop 5 in tour 'my_vehicle_1'".into()])),
 
-    case06_1: ( vec![10, 1, 3, 1, 2, 1, 0], Err(vec!["load exceeds capacity in tour 'my_vehicle_1'".into()])),
-    case06_2: ( vec![1, 1, 30, 1, 2, 1, 0], Err(vec!["load exceeds capacity in tour 'my_vehicle_1'".into()])),
-    case06_3: ( vec![1, 1, 3, 1, 20, 1, 0], Err(vec!["load exceeds capacity in tour 'my_vehicle_1'".into()])),
+    case06_1: ( vec![10., 1., 3., 1., 2., 1., 0.], Err(vec!["load exceeds capacity in tour 'my_vehicle_1'".into()])),
+    case06_2: ( vec![1., 1., 30., 1., 2., 1., 0.], Err(vec!["load exceeds capacity in tour 'my_vehicle_1'".into()])),
+    case06_3: ( vec![1., 1., 3., 1., 20., 1., 0.], Err(vec!["load exceeds capacity in tour 'my_vehicle_1'".into()])),
 }
 
-fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<GenericError>>) {
+fn can_check_load_impl(stop_loads: Vec<f64>, expected_result: Result<(), Vec<GenericError>>) {
     let problem = Problem {
         plan: Plan {
             jobs: vec![
@@ -36,8 +36,20 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Gen
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(1000.),
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     breaks: None,
                     reloads: Some(vec![VehicleReload {
                         location: (0., 0.).to_loc(),
@@ -45,8 +57,12 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Gen
                         ..create_default_reload()
                     }]),
                     recharges: None,
+                    capacity: None,
+                    skills: None,
+                    limits: None,
+                    costs: None,
                 }],
-                capacity: vec![5],
+                capacity: vec![5.],
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -123,7 +139,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), Vec<Gen
 fn can_check_load_when_departure_has_other_activity() {
     let problem = Problem {
         plan: Plan { jobs: vec![create_pickup_delivery_job("job1", (0., 0.), (1., 0.))], ..create_empty_plan() },
-        fleet: Fleet { vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])], ..create_default_fleet() },
+        fleet: Fleet { vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2.])], ..create_default_fleet() },
         ..create_empty_problem()
     };
     let solution = SolutionBuilder::default()
@@ -133,7 +149,7 @@ fn can_check_load_when_departure_has_other_activity() {
                     StopBuilder::default()
                         .coordinate((1., 0.))
                         .schedule_stamp(0., 1.)
-                        .load(vec![1])
+                        .load(vec![1.])
                         .distance(0)
                         .activity(ActivityBuilder::default().job_id("departure").activity_type("departure").build())
                         .activity(ActivityBuilder::pickup().job_id("job1").tag("p1").build())
@@ -141,13 +157,13 @@ fn can_check_load_when_departure_has_other_activity() {
                     StopBuilder::default()
                         .coordinate((1., 0.))
                         .schedule_stamp(2., 3.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(1)
                         .build_single_tag("job1", "delivery", "d1"),
                     StopBuilder::default()
                         .coordinate((0., 0.))
                         .schedule_stamp(4., 4.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(2)
                         .build_arrival(),
                 ])
@@ -183,9 +199,9 @@ fn can_check_resource_consumption() {
                     }]),
                     ..create_default_open_vehicle_shift()
                 }],
-                ..create_vehicle_with_capacity("my_vehicle", vec![2])
+                ..create_vehicle_with_capacity("my_vehicle", vec![2.])
             }],
-            resources: Some(vec![VehicleResource::Reload { id: "resource_1".to_string(), capacity: vec![1] }]),
+            resources: Some(vec![VehicleResource::Reload { id: "resource_1".to_string(), capacity: vec![1.] }]),
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -194,29 +210,29 @@ fn can_check_resource_consumption() {
         .tour(
             TourBuilder::default()
                 .stops(vec![
-                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1.]).build_departure(),
                     StopBuilder::default()
                         .coordinate((1., 0.))
                         .schedule_stamp(1., 2.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(1)
                         .build_single("job1", "delivery"),
                     StopBuilder::default()
                         .coordinate((4., 0.))
                         .schedule_stamp(5., 7.)
-                        .load(vec![2])
+                        .load(vec![2.])
                         .distance(4)
                         .build_single("reload", "reload"),
                     StopBuilder::default()
                         .coordinate((3., 0.))
                         .schedule_stamp(8., 9.)
-                        .load(vec![1])
+                        .load(vec![1.])
                         .distance(5)
                         .build_single("job3", "delivery"),
                     StopBuilder::default()
                         .coordinate((2., 0.))
                         .schedule_stamp(10., 11.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(6)
                         .build_single("job2", "delivery"),
                 ])
@@ -228,9 +244,63 @@ fn can_check_resource_consumption() {
 
     let result = check_resource_consumption(&ctx);
 
-    assert_eq!(
-        result,
-        Err("consumed more resource 'resource_1' than available: [2, 0, 0, 0, 0, 0, 0, 0] vs [1, 0, 0, 0, 0, 0, 0, 0]"
-            .into())
-    );
+    assert_eq!(result, Err("consumed more resource 'resource_1' than available: [2.0] vs [1.0]".into()));
+}
+
+parameterized_test! {can_check_backhauls, (backhaul, expected_result), {
+    can_check_backhauls_impl(backhaul, expected_result);
+}}
+
+can_check_backhauls! {
+    case01_strict: (Some(VehicleBackhaul::Strict), Err(vec![
+        "backhaul policy violation: delivery of job 'job2' is served after pickup, vehicle id 'my_vehicle_1', shift index: 0".into()
+    ])),
+    case02_mixed: (Some(VehicleBackhaul::Mixed { penalty: 10. }), Ok(())),
+    case03_none: (None, Ok(())),
+}
+
+fn can_check_backhauls_impl(backhaul: Option<VehicleBackhaul>, expected_result: Result<(), Vec<GenericError>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_pickup_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                capacity: vec![2.],
+                backhaul,
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1.]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., 2.)
+                        .load(vec![2.])
+                        .distance(1)
+                        .build_single("job1", "pickup"),
+                    StopBuilder::default()
+                        .coordinate((2., 0.))
+                        .schedule_stamp(3., 4.)
+                        .load(vec![1.])
+                        .distance(2)
+                        .build_single("job2", "delivery"),
+                ])
+                .statistic(StatisticBuilder::default().driving(2).serving(2).build())
+                .build(),
+        )
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_vehicle_load(&ctx);
+
+    assert_eq!(result, expected_result);
 }
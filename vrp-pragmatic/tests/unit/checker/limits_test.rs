@@ -59,7 +59,13 @@ pub fn can_check_shift_and_distance_limit_impl(
     actual: i64,
     expected: Result<(), GenericError>,
 ) {
-    let problem = create_test_problem(Some(VehicleLimits { max_distance, max_duration, tour_size: None }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance,
+        max_duration,
+        tour_size: None,
+        max_leg_distance: None,
+        max_leg_duration: None,
+    }));
     let solution =
         create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() }, vec![]);
     let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
@@ -71,34 +77,39 @@ pub fn can_check_shift_and_distance_limit_impl(
 
 #[test]
 pub fn can_check_tour_size_limit() {
-    let problem =
-        create_test_problem(Some(VehicleLimits { max_distance: None, max_duration: None, tour_size: Some(2) }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        max_duration: None,
+        tour_size: Some(2),
+        max_leg_distance: None,
+        max_leg_duration: None,
+    }));
     let solution = create_test_solution(
         Statistic::default(),
         vec![
-            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![3]).build_departure(),
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![3.]).build_departure(),
             StopBuilder::default()
                 .coordinate((1., 0.))
                 .schedule_stamp(1., 1.)
-                .load(vec![2])
+                .load(vec![2.])
                 .distance(1)
                 .build_single("job1", "delivery"),
             StopBuilder::default()
                 .coordinate((2., 0.))
                 .schedule_stamp(2., 2.)
-                .load(vec![1])
+                .load(vec![1.])
                 .distance(2)
                 .build_single("job2", "delivery"),
             StopBuilder::default()
                 .coordinate((3., 0.))
                 .schedule_stamp(3., 3.)
-                .load(vec![0])
+                .load(vec![0.])
                 .distance(3)
                 .build_single("job3", "delivery"),
             StopBuilder::default()
                 .coordinate((0., 0.))
                 .schedule_stamp(6., 6.)
-                .load(vec![0])
+                .load(vec![0.])
                 .distance(6)
                 .build_arrival(),
         ],
@@ -124,8 +135,20 @@ fn can_check_shift_time() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
-                    end: Some(ShiftEnd { earliest: None, latest: format_time(5.), location: (0., 0.).to_loc() }),
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: format_time(5.),
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    }),
                     ..create_default_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
@@ -139,17 +162,17 @@ fn can_check_shift_time() {
         .tour(
             TourBuilder::default()
                 .stops(vec![
-                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(2., 2.).load(vec![1]).build_departure(),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(2., 2.).load(vec![1.]).build_departure(),
                     StopBuilder::default()
                         .coordinate((1., 0.))
                         .schedule_stamp(5., 6.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(1)
                         .build_single("job1", "delivery"),
                     StopBuilder::default()
                         .coordinate((0., 0.))
                         .schedule_stamp(7., 7.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(2)
                         .build_arrival(),
                 ])
@@ -175,7 +198,13 @@ fn can_check_recharge_distance() {
         fleet: Fleet {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
-                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: None,
+                        location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
+                    },
                     end: None,
                     recharges: Some(VehicleRecharges {
                         max_distance: 8.,
@@ -184,6 +213,7 @@ fn can_check_recharge_distance() {
                             duration: 0.,
                             times: None,
                             tag: None,
+                            timezone: None,
                         }],
                     }),
                     ..create_default_vehicle_shift()
@@ -199,17 +229,17 @@ fn can_check_recharge_distance() {
         .tour(
             TourBuilder::default()
                 .stops(vec![
-                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2.]).build_departure(),
                     StopBuilder::default()
                         .coordinate((1., 0.))
                         .schedule_stamp(1., 2.)
-                        .load(vec![1])
+                        .load(vec![1.])
                         .distance(1)
                         .build_single("job1", "delivery"),
                     StopBuilder::default()
                         .coordinate((10., 0.))
                         .schedule_stamp(11., 12.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(10)
                         .build_single("job2", "delivery"),
                 ])
@@ -228,3 +258,60 @@ fn can_check_recharge_distance() {
             .into())
     );
 }
+
+parameterized_test! {can_check_leg_limits, (max_leg_distance, max_leg_duration, has_transit, expected), {
+    can_check_leg_limits_impl(max_leg_distance, max_leg_duration, has_transit, expected);
+}}
+
+can_check_leg_limits! {
+    case_01: (Some(3.), None, false, Ok(())),
+    case_02: (Some(2.), None, false, Err("max leg distance limit violation, expected: not more than 2, got: 3".to_string())),
+    case_03: (None, Some(4.), false, Ok(())),
+    case_04: (None, Some(3.), false, Err("max leg duration limit violation, expected: not more than 3, got: 4".to_string())),
+    case_05: (Some(3.), None, true, Ok(())),
+    case_06: (None, Some(4.), true, Ok(())),
+    case_07: (None, Some(3.), true, Err("max leg duration limit violation, expected: not more than 3, got: 4".to_string())),
+}
+
+fn can_check_leg_limits_impl(
+    max_leg_distance: Option<f64>,
+    max_leg_duration: Option<f64>,
+    has_transit: bool,
+    expected: Result<(), String>,
+) {
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        max_duration: None,
+        tour_size: None,
+        max_leg_distance,
+        max_leg_duration,
+    }));
+    let mut stops = vec![
+        StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2.]).build_departure(),
+        StopBuilder::default()
+            .coordinate((1., 0.))
+            .schedule_stamp(1., 2.)
+            .load(vec![1.])
+            .distance(1)
+            .build_single("job1", "delivery"),
+        StopBuilder::default()
+            .coordinate((4., 0.))
+            .schedule_stamp(6., 7.)
+            .load(vec![0.])
+            .distance(4)
+            .build_single("job2", "delivery"),
+    ];
+    if has_transit {
+        stops
+            .insert(2, StopBuilder::new_transit().schedule_stamp(3., 4.).load(vec![1.]).build_single("break", "break"));
+    }
+    let solution = create_test_solution(Statistic::default(), stops);
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_leg_limits(&ctx);
+
+    assert_eq!(
+        result,
+        expected.map_err(|prefix| format!("{prefix}, vehicle id 'some_real_vehicle', shift index: 0").into())
+    );
+}
@@ -23,23 +23,23 @@ fn create_test_solution(statistic: Statistic, stop_data: &[(f64, i64); 3]) -> So
         .tour(
             TourBuilder::default()
                 .stops(vec![
-                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2.]).build_departure(),
                     StopBuilder::default()
                         .coordinate((1., 0.))
                         .schedule_stamp(first.0, 2.)
-                        .load(vec![1])
+                        .load(vec![1.])
                         .distance(first.1)
                         .build_single("job1", "delivery"),
                     StopBuilder::default()
                         .coordinate((2., 0.))
                         .schedule_stamp(second.0, 4.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(second.1)
                         .build_single("job2", "delivery"),
                     StopBuilder::default()
                         .coordinate((0., 0.))
                         .schedule_stamp(third.0, 6.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(third.1)
                         .build_arrival(),
                 ])
@@ -0,0 +1,208 @@
+use super::*;
+use crate::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem};
+use crate::helpers::{SIMPLE_MATRIX, SIMPLE_PROBLEM};
+use std::io::BufReader;
+
+fn create_matrix(error_codes: Option<Vec<i64>>) -> Matrix {
+    Matrix {
+        profile: Some("car".to_string()),
+        timestamp: Some("2019-07-04T10:00:00Z".to_string()),
+        travel_times: vec![0, 10, 20, 0],
+        distances: vec![0, 100, -200, 0],
+        error_codes,
+    }
+}
+
+fn to_bytes(matrix: &Matrix, is_compressed: bool) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    write_binary_matrix(matrix, is_compressed, &mut bytes).expect("cannot write matrix");
+
+    bytes
+}
+
+parameterized_test! {can_write_and_read_binary_matrix, (is_compressed, error_codes), {
+    can_write_and_read_binary_matrix_impl(is_compressed, error_codes);
+}}
+
+can_write_and_read_binary_matrix! {
+    case01_raw: (false, None),
+    case02_compressed: (true, None),
+    case03_raw_error_codes: (false, Some(vec![0, 0, 1, 0])),
+    case04_compressed_error_codes: (true, Some(vec![0, 1, 0, 0])),
+}
+
+fn can_write_and_read_binary_matrix_impl(is_compressed: bool, error_codes: Option<Vec<i64>>) {
+    let original = create_matrix(error_codes);
+    let bytes = to_bytes(&original, is_compressed);
+
+    let matrix = read_binary_matrix(bytes.as_slice()).expect("cannot read matrix");
+
+    assert_eq!(matrix.profile, original.profile);
+    assert_eq!(matrix.timestamp, original.timestamp);
+    assert_eq!(matrix.travel_times, original.travel_times);
+    assert_eq!(matrix.distances, original.distances);
+    assert_eq!(matrix.error_codes, original.error_codes);
+}
+
+#[test]
+fn can_read_binary_matrix_without_profile_and_timestamp() {
+    let original = Matrix { profile: None, timestamp: None, ..create_matrix(None) };
+
+    let matrix = read_binary_matrix(to_bytes(&original, false).as_slice()).expect("cannot read matrix");
+
+    assert!(matrix.profile.is_none());
+    assert!(matrix.timestamp.is_none());
+    assert_eq!(matrix.travel_times, original.travel_times);
+}
+
+#[test]
+fn can_detect_binary_matrix_by_magic_bytes() {
+    let bytes = to_bytes(&create_matrix(None), true);
+
+    assert!(is_binary_matrix(&mut BufReader::new(bytes.as_slice())).unwrap());
+    assert!(!is_binary_matrix(&mut BufReader::new(SIMPLE_MATRIX.as_bytes())).unwrap());
+
+    let matrix = deserialize_matrix(BufReader::new(bytes.as_slice())).expect("cannot deserialize binary matrix");
+    assert_eq!(matrix.distances, vec![0, 100, -200, 0]);
+    let matrix = deserialize_matrix(BufReader::new(SIMPLE_MATRIX.as_bytes())).expect("cannot deserialize json matrix");
+    assert!(!matrix.travel_times.is_empty());
+}
+
+#[test]
+fn can_detect_compressed_binary_matrix() {
+    let is_compressed = |bytes: &[u8]| is_compressed_binary_matrix(&mut BufReader::new(bytes)).unwrap();
+
+    assert!(is_compressed(to_bytes(&create_matrix(None), true).as_slice()));
+    assert!(!is_compressed(to_bytes(&create_matrix(None), false).as_slice()));
+    assert!(!is_compressed(SIMPLE_MATRIX.as_bytes()));
+}
+
+#[test]
+fn can_read_problem_with_binary_matrix() {
+    let matrix = deserialize_matrix(BufReader::new(SIMPLE_MATRIX.as_bytes())).unwrap();
+    let bytes = to_bytes(&matrix, true);
+
+    let problem = (BufReader::new(SIMPLE_PROBLEM.as_bytes()), vec![BufReader::new(bytes.as_slice())]).read_pragmatic();
+
+    assert!(problem.is_ok());
+    assert!(deserialize_problem(BufReader::new(SIMPLE_PROBLEM.as_bytes())).is_ok());
+}
+
+#[test]
+fn can_detect_invalid_binary_matrix() {
+    let bytes = to_bytes(&create_matrix(None), false);
+
+    let mut unsupported = bytes.clone();
+    unsupported[4] = 2;
+    assert_eq!(
+        read_binary_matrix(unsupported.as_slice()).err().map(|err| err.to_string()).unwrap(),
+        "unsupported version: 2"
+    );
+
+    let mut unsupported = bytes.clone();
+    unsupported[5] = 0b1000;
+    assert_eq!(
+        read_binary_matrix(unsupported.as_slice()).err().map(|err| err.to_string()).unwrap(),
+        "unsupported flags: 0b00001000"
+    );
+
+    assert!(read_binary_matrix(&bytes[..bytes.len() - 1]).is_err());
+
+    let err = deserialize_matrix(BufReader::new(&bytes[..bytes.len() - 1])).expect_err("no error");
+    assert!(err.to_string().contains("check input binary matrix"));
+}
+
+parameterized_test! {can_detect_invalid_matrix_on_write, (matrix, expected), {
+    let mut bytes = Vec::new();
+
+    let result = write_binary_matrix(&matrix, false, &mut bytes).err().map(|err| err.to_string());
+
+    assert_eq!(result, Some(expected.to_string()));
+}}
+
+can_detect_invalid_matrix_on_write! {
+    case01_not_square: (Matrix { travel_times: vec![0, 1, 2], distances: vec![0, 1, 2], ..create_matrix(None) },
+                        "matrix is not square or has arrays of different length"),
+    case02_different_length: (Matrix { distances: vec![0], ..create_matrix(None) },
+                              "matrix is not square or has arrays of different length"),
+}
+
+parameterized_test! {can_use_wide_values_only_when_needed, (is_compressed, travel_times, expected_wide), {
+    can_use_wide_values_only_when_needed_impl(is_compressed, travel_times, expected_wide);
+}}
+
+can_use_wide_values_only_when_needed! {
+    case01_narrow: (false, vec![0, i32::MAX as i64, i32::MIN as i64, 0], false),
+    case02_wide_max: (false, vec![0, i64::MAX, 20, 0], true),
+    case03_wide_min: (false, vec![0, 10, i32::MIN as i64 - 1, 0], true),
+    case04_compressed_wide: (true, vec![0, 10, i64::MAX, 0], true),
+}
+
+fn can_use_wide_values_only_when_needed_impl(is_compressed: bool, travel_times: Vec<i64>, expected_wide: bool) {
+    let original = Matrix { travel_times, ..create_matrix(Some(vec![0, 0, 1, 0])) };
+    let narrow_size = to_bytes(&create_matrix(Some(vec![0, 0, 1, 0])), is_compressed).len();
+
+    let bytes = to_bytes(&original, is_compressed);
+    let matrix = read_binary_matrix(bytes.as_slice()).expect("cannot read matrix");
+
+    assert_eq!(bytes[5] & 0b100 != 0, expected_wide);
+    if !is_compressed {
+        assert_eq!(bytes.len(), if expected_wide { narrow_size + 3 * 4 * 4 } else { narrow_size });
+    }
+    assert_eq!(matrix.travel_times, original.travel_times);
+    assert_eq!(matrix.distances, original.distances);
+    assert_eq!(matrix.error_codes, original.error_codes);
+}
+
+fn write_matrix_file(name: &str, matrix: &Matrix, is_compressed: bool) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("vrp_pragmatic_{name}_{}.bin", std::process::id()));
+    std::fs::write(&path, to_bytes(matrix, is_compressed)).expect("cannot write matrix file");
+
+    path
+}
+
+parameterized_test! {can_open_binary_matrix_values, (name, travel_times, error_codes, expected_durations), {
+    can_open_binary_matrix_values_impl(name, travel_times, error_codes, expected_durations);
+}}
+
+can_open_binary_matrix_values! {
+    case01_narrow: ("narrow", vec![0, 10, 20, 0], None, vec![0., 10., 20., 0.]),
+    case02_wide: ("wide", vec![0, 10, i32::MAX as i64 + 1, 0], None, vec![0., 10., i32::MAX as f64 + 1., 0.]),
+    case03_error_codes: ("error_codes", vec![0, 10, 20, 0], Some(vec![0, 0, 1, 0]), vec![0., 10., -1., 0.]),
+}
+
+fn can_open_binary_matrix_values_impl(
+    name: &str,
+    travel_times: Vec<i64>,
+    error_codes: Option<Vec<i64>>,
+    expected_durations: Vec<f64>,
+) {
+    let path = write_matrix_file(name, &Matrix { travel_times, timestamp: None, ..create_matrix(error_codes) }, false);
+
+    let values = open_binary_matrix_values(path.as_path()).expect("cannot open matrix values");
+
+    let get_values =
+        |values: &dyn MatrixValues| (0..values.size()).filter_map(|idx| values.get(idx)).collect::<Vec<_>>();
+    assert_eq!(values.profile, Some("car".to_string()));
+    assert_eq!(get_values(values.durations.as_ref()), expected_durations);
+    assert_eq!(get_values(values.distances.as_ref()), vec![0., 100., -1., 0.]);
+    std::fs::remove_file(path).unwrap();
+}
+
+parameterized_test! {can_detect_unsupported_binary_matrix_values, (name, matrix, is_compressed, expected), {
+    can_detect_unsupported_binary_matrix_values_impl(name, matrix, is_compressed, expected);
+}}
+
+can_detect_unsupported_binary_matrix_values! {
+    case01_compressed: ("compressed", create_matrix(None), true, "compressed binary matrix cannot be read on demand"),
+    case02_time_aware: ("time_aware", create_matrix(None), false, "time aware binary matrix cannot be read on demand"),
+}
+
+fn can_detect_unsupported_binary_matrix_values_impl(name: &str, matrix: Matrix, is_compressed: bool, expected: &str) {
+    let path = write_matrix_file(name, &matrix, is_compressed);
+
+    let result = open_binary_matrix_values(path.as_path());
+
+    assert_eq!(result.err().map(|err| err.to_string()), Some(expected.to_string()));
+    std::fs::remove_file(path).unwrap();
+}
@@ -1,5 +1,6 @@
-use super::create_transport_costs;
+use super::{create_approx_matrices, create_transport_costs};
 use crate::format::problem::*;
+use crate::format::{CoordIndex, Location};
 use crate::format_time;
 use crate::helpers::*;
 use std::sync::Arc;
@@ -31,7 +32,17 @@ fn wrong_matrix(profile: Option<&str>, timestamp: Option<String>) -> Matrix {
 fn create_problem(profiles: &[&str]) -> Problem {
     Problem {
         fleet: Fleet {
-            profiles: profiles.iter().map(|p| MatrixProfile { name: p.to_string(), speed: None }).collect(),
+            profiles: profiles
+                .iter()
+                .map(|p| MatrixProfile {
+                    name: p.to_string(),
+                    speed: None,
+                    speed_bands: None,
+                    overlay: None,
+                    distance_model: None,
+                    travel_time_uncertainty: None,
+                })
+                .collect(),
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -173,3 +184,144 @@ fn can_create_transport_costs_positive_cases_impl(
         assert_eq!(result, distance);
     });
 }
+
+fn create_problem_with_speed_bands(bands: Vec<(f64, f64)>) -> Problem {
+    let mut problem = create_problem(&["car"]);
+    problem.fleet.profiles[0].speed_bands =
+        Some(bands.into_iter().map(|(start, factor)| SpeedBand { start: format_time(start), factor }).collect());
+
+    problem
+}
+
+#[test]
+fn can_create_transport_costs_with_speed_bands() {
+    let problem = create_problem_with_speed_bands(vec![(100., 0.5)]);
+    let coord_index = Arc::new(CoordIndex::new(&problem));
+    let route = Route {
+        actor: Arc::new(Actor {
+            vehicle: Arc::new(Vehicle { profile: CoreProfile::new(0, None), ..test_vehicle("v1") }),
+            driver: Arc::new(test_driver()),
+            detail: ActorDetail { start: None, end: None, time: TimeWindow::new(0., 1.) },
+        }),
+        tour: Default::default(),
+    };
+
+    let transport = create_transport_costs(&problem, &[matrix(Some("car"), None, 100, 4)], coord_index).unwrap();
+
+    assert_eq!(transport.duration(&route, 0, 1, TravelTime::Departure(0.)), 100.);
+    assert_eq!(transport.duration(&route, 0, 1, TravelTime::Departure(50.)), 150.);
+    assert_eq!(transport.duration(&route, 0, 1, TravelTime::Departure(100.)), 200.);
+    assert_eq!(transport.distance(&route, 0, 1, TravelTime::Departure(100.)), 100.);
+}
+
+#[test]
+fn can_detect_speed_bands_with_time_aware_matrices() {
+    let problem = create_problem_with_speed_bands(vec![(100., 0.5)]);
+    let coord_index = Arc::new(CoordIndex::new(&problem));
+    let matrices = [matrix(Some("car"), Some(0.), 1, 4), matrix(Some("car"), Some(10.), 2, 4)];
+
+    let result = create_transport_costs(&problem, &matrices, coord_index).err();
+
+    assert_eq!(result, Some("speed bands cannot be used with time dependent routing matrices".into()));
+}
+
+fn create_problem_with_overlay() -> Problem {
+    let mut problem = create_problem(&["car", "truck"]);
+    problem.plan.jobs = (0..3).map(|index| create_delivery_job_with_index(&format!("job{index}"), index)).collect();
+    problem.fleet.profiles[1].overlay = Some(MatrixOverlay {
+        base: "car".to_string(),
+        unreachable: Some(vec![Location::new_reference(2)]),
+        edges: Some(vec![MatrixOverlayEdge {
+            from: Location::new_reference(0),
+            to: Location::new_reference(1),
+            duration: Some(30.),
+            distance: None,
+        }]),
+    });
+
+    problem
+}
+
+#[test]
+fn can_create_transport_costs_with_overlay() {
+    let problem = create_problem_with_overlay();
+    let coord_index = Arc::new(CoordIndex::new(&problem));
+    let (car, truck) = (CoreProfile::new(0, None), CoreProfile::new(1, None));
+
+    let transport = create_transport_costs(&problem, &[matrix(Some("car"), None, 100, 9)], coord_index).unwrap();
+
+    assert_eq!(transport.duration_approx(&car, 0, 1), 100.);
+    assert_eq!(transport.duration_approx(&car, 0, 2), 100.);
+    assert_eq!(transport.duration_approx(&truck, 0, 1), 30.);
+    assert_eq!(transport.distance_approx(&truck, 0, 1), 100.);
+    assert_eq!(transport.duration_approx(&truck, 1, 0), 100.);
+    assert_eq!(transport.duration_approx(&truck, 0, 2), -1.);
+    assert_eq!(transport.distance_approx(&truck, 2, 1), -1.);
+}
+
+#[test]
+fn can_detect_matrix_for_profile_with_overlay() {
+    let problem = create_problem_with_overlay();
+    let coord_index = Arc::new(CoordIndex::new(&problem));
+    let matrices = [matrix(Some("car"), None, 1, 9), matrix(Some("truck"), None, 1, 9)];
+
+    let result = create_transport_costs(&problem, &matrices, coord_index).err();
+
+    assert_eq!(result, Some("routing matrix should not be specified for profile with overlay: 'truck'".into()));
+}
+
+parameterized_test! {can_create_transport_costs_with_matrix_storage, (storage, value, expected), {
+    can_create_transport_costs_with_matrix_storage_impl(storage, value, expected);
+}}
+
+can_create_transport_costs_with_matrix_storage! {
+    case01_default: (None, 100, Ok(100.)),
+    case02_full_big_value: (Some(MatrixStorageType::Full), u32::MAX as i64 + 1, Ok(u32::MAX as f64 + 1.)),
+    case03_compact: (Some(MatrixStorageType::Compact), 100, Ok(100.)),
+    case04_compact_big_value: (
+        Some(MatrixStorageType::Compact),
+        u32::MAX as i64 + 1,
+        Err("matrix value is too big for integer storage: 4294967296")
+    ),
+}
+
+fn can_create_transport_costs_with_matrix_storage_impl(
+    storage: Option<MatrixStorageType>,
+    value: i64,
+    expected: Result<f64, &str>,
+) {
+    let problem = Problem { matrix_storage: storage, ..create_problem(&["car"]) };
+    let coord_index = Arc::new(CoordIndex::new(&problem));
+
+    let result = create_transport_costs(&problem, &[matrix(Some("car"), None, value, 4)], coord_index)
+        .map(|transport| transport.distance_approx(&CoreProfile::new(0, None), 0, 1));
+
+    assert_eq!(result, expected.map_err(|err| err.into()));
+}
+
+#[test]
+fn can_create_approx_matrices_with_distance_models() {
+    let mut problem = create_problem(&["car", "truck", "bike"]);
+    problem.plan.jobs = vec![create_delivery_job("job1", (400., 300.))];
+    let distance_models = [(DistanceType::Euclidean, None, None), (DistanceType::Manhattan, Some(2.), Some(5.))];
+    problem.fleet.profiles.iter_mut().zip(distance_models.iter().cycle()).for_each(
+        |(profile, &(distance_type, detour_factor, speed))| {
+            profile.distance_model = Some(DistanceModel { distance_type, detour_factor });
+            profile.speed = speed;
+        },
+    );
+
+    let matrices = create_approx_matrices(&problem);
+
+    assert_eq!(
+        matrices
+            .iter()
+            .map(|matrix| (matrix.profile.clone().unwrap(), matrix.travel_times.clone(), matrix.distances.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("car".to_string(), vec![0, 50, 50, 0], vec![0, 500, 500, 0]),
+            ("truck".to_string(), vec![0, 280, 280, 0], vec![0, 1400, 1400, 0]),
+            ("bike".to_string(), vec![0, 50, 50, 0], vec![0, 500, 500, 0]),
+        ]
+    );
+}
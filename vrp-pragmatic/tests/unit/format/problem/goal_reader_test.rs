@@ -0,0 +1,99 @@
+use super::*;
+use crate::helpers::*;
+use std::cmp::Ordering;
+use vrp_core::construction::heuristics::{InsertionContext, MoveContext};
+use vrp_core::utils::Environment;
+
+struct SolutionSizeObjective {
+    size_fn: fn(&InsertionContext) -> usize,
+}
+
+impl FeatureObjective for SolutionSizeObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        (self.size_fn)(solution) as Cost
+    }
+
+    fn estimate(&self, _: &MoveContext<'_>) -> Cost {
+        Cost::default()
+    }
+}
+
+fn create_objectives() -> Vec<Arc<dyn FeatureObjective>> {
+    vec![
+        Arc::new(SolutionSizeObjective { size_fn: |solution| solution.solution.required.len() }),
+        Arc::new(SolutionSizeObjective { size_fn: |solution| solution.solution.ignored.len() }),
+    ]
+}
+
+/// Creates two solutions: the first one has two required jobs, the second one has one required and one ignored job.
+fn create_solutions() -> (InsertionContext, InsertionContext) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let problem = Arc::new((problem, vec![matrix]).read_pragmatic().expect("cannot read problem"));
+
+    let jobs = problem.jobs.all().collect::<Vec<_>>();
+
+    let mut a = InsertionContext::new(problem.clone(), Arc::new(Environment::default()));
+    a.solution.required = jobs.clone();
+    let mut b = InsertionContext::new(problem, Arc::new(Environment::default()));
+    b.solution.required = jobs[..1].to_vec();
+    b.solution.ignored = jobs[1..].to_vec();
+
+    (a, b)
+}
+
+parameterized_test! {can_check_weights, (weights, expected), {
+    can_check_weights_impl(weights, expected);
+}}
+
+can_check_weights! {
+    case01_same_size: (vec![1., 2.], true),
+    case02_less_weights: (vec![1.], false),
+    case03_more_weights: (vec![1., 2., 3.], false),
+}
+
+fn can_check_weights_impl(weights: Vec<f64>, expected: bool) {
+    let objectives = create_objectives();
+
+    let result = check_weights(&objectives, &weights);
+
+    assert_eq!(result.is_ok(), expected);
+}
+
+parameterized_test! {can_compare_solutions_with_multi_strategy, (strategy, expected), {
+    can_compare_solutions_with_multi_strategy_impl(strategy, expected);
+}}
+
+can_compare_solutions_with_multi_strategy! {
+    case01_weighted_sum_uses_dominance: (MultiStrategy::WeightedSum { weights: vec![1., 3.] }, Ordering::Equal),
+    case02_weighted_fitness_high_weight: (MultiStrategy::WeightedFitness { weights: vec![1., 3.] }, Ordering::Less),
+    case03_weighted_fitness_low_weight: (MultiStrategy::WeightedFitness { weights: vec![1., 0.5] }, Ordering::Greater),
+}
+
+fn can_compare_solutions_with_multi_strategy_impl(strategy: MultiStrategy, expected: Ordering) {
+    let objectives = create_objectives();
+    let (a, b) = create_solutions();
+
+    let goal = eval_multi_objective_strategy(&objectives, &strategy, GoalBuilder::default())
+        .and_then(|builder| builder.build())
+        .expect("cannot build goal");
+
+    assert_eq!(goal.total_order(&a, &b), expected);
+}
+
+#[test]
+fn can_reject_weighted_fitness_with_wrong_weights() {
+    let objectives = create_objectives();
+    let strategy = MultiStrategy::WeightedFitness { weights: vec![1.] };
+
+    let result = eval_multi_objective_strategy(&objectives, &strategy, GoalBuilder::default());
+
+    assert!(result.is_err());
+}
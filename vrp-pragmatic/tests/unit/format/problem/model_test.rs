@@ -17,7 +17,7 @@ fn assert_location(actual: &Location, expected: (f64, f64)) {
     assert_eq!(lng, expected.1);
 }
 
-fn assert_demand(actual: &Option<Vec<i32>>, expected: i32) {
+fn assert_demand(actual: &Option<Vec<f64>>, expected: f64) {
     let actual = actual.as_ref().expect("Empty demand!");
     assert_eq!(actual.len(), 1);
     assert_eq!(*actual.first().unwrap(), expected);
@@ -41,7 +41,7 @@ fn can_deserialize_problem() {
     let deliveries = job.deliveries.as_ref().unwrap();
     assert_eq!(deliveries.len(), 1);
     let delivery = deliveries.first().unwrap();
-    assert_demand(&delivery.demand, 1);
+    assert_demand(&delivery.demand, 1.);
     assert!(delivery.places.first().unwrap().tag.is_none());
 
     assert_eq!(delivery.places.len(), 1);
@@ -77,8 +77,9 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 100.0,
                             tag: Some("my_delivery".to_string()),
+                            timezone: None,
                         }],
-                        demand: Some(vec![0, 1]),
+                        demand: Some(vec![0., 1.]),
                         order: None,
                     }]),
                     skills: Some(all_of_skills(vec!["unique".to_string()])),
@@ -94,8 +95,9 @@ fn can_read_complex_problem() {
                             location: (52.48300, 13.4420).to_loc(),
                             duration: 110.0,
                             tag: None,
+                            timezone: None,
                         }],
-                        demand: Some(vec![2]),
+                        demand: Some(vec![2.]),
                         order: None,
                     }]),
                     deliveries: Some(vec![JobTask {
@@ -107,8 +109,9 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 120.0,
                             tag: None,
+                            timezone: None,
                         }],
-                        demand: Some(vec![2]),
+                        demand: Some(vec![2.]),
                         order: None,
                     }]),
                     ..create_job("pickup_delivery_job")
@@ -123,8 +126,9 @@ fn can_read_complex_problem() {
                             location: (52.48321, 13.4438).to_loc(),
                             duration: 90.0,
                             tag: None,
+                            timezone: None,
                         }],
-                        demand: Some(vec![3]),
+                        demand: Some(vec![3.]),
                         order: None,
                     }]),
                     skills: Some(all_of_skills(vec!["unique2".to_string()])),
@@ -144,11 +148,15 @@ fn can_read_complex_problem() {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
                         latest: None,
                         location: (52.4862, 13.45148).to_loc(),
+                        timezone: None,
+                        alternatives: None,
                     },
                     end: Some(ShiftEnd {
                         earliest: None,
                         latest: "1970-01-01T00:01:40Z".to_string(),
                         location: (52.4862, 13.45148).to_loc(),
+                        timezone: None,
+                        alternatives: None,
                     }),
                     breaks: Some(vec![VehicleBreak::Optional {
                         time: VehicleOptionalBreakTime::TimeWindow(vec![
@@ -164,14 +172,31 @@ fn can_read_complex_problem() {
                     }]),
                     reloads: None,
                     recharges: None,
+                    capacity: None,
+                    skills: None,
+                    limits: None,
+                    costs: None,
                 }],
-                capacity: vec![10, 1],
-                skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
-                limits: Some(VehicleLimits { max_distance: Some(123.1), max_duration: Some(100.), tour_size: Some(3) }),
+                capacity: vec![10., 1.],
+                skills: Some(vec![
+                    VehicleSkill::Plain("unique1".to_string()),
+                    VehicleSkill::Plain("unique2".to_string()),
+                ]),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(123.1),
+                    max_duration: Some(100.),
+                    tour_size: Some(3),
+                    max_leg_distance: None,
+                    max_leg_duration: None,
+                }),
+                trailer: None,
+                backhaul: None,
             }],
             ..create_default_fleet()
         },
         objectives: None,
+        timezone: None,
+        matrix_storage: None,
     };
     let matrix = Matrix {
         profile: Some("car".to_owned()),
@@ -195,7 +220,7 @@ fn can_read_complex_problem() {
         job.dimens.get_job_demand().expect("cannot get demand"),
         &Demand {
             pickup: (MultiDimLoad::default(), MultiDimLoad::default()),
-            delivery: (MultiDimLoad::new(vec![0, 1]), MultiDimLoad::default()),
+            delivery: (MultiDimLoad::new(vec![0., 1.]), MultiDimLoad::default()),
         },
     );
     assert_time_spans(&place.times, vec![(0., 100.), (110., 120.)]);
@@ -292,10 +317,38 @@ fn can_create_approximation_matrices() {
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                MatrixProfile { name: "car1".to_string(), speed: Some(8.) },
-                MatrixProfile { name: "car2".to_string(), speed: Some(10.) },
-                MatrixProfile { name: "car3".to_string(), speed: Some(5.) },
-                MatrixProfile { name: "car4".to_string(), speed: None },
+                MatrixProfile {
+                    name: "car1".to_string(),
+                    speed: Some(8.),
+                    speed_bands: None,
+                    overlay: None,
+                    distance_model: None,
+                    travel_time_uncertainty: None,
+                },
+                MatrixProfile {
+                    name: "car2".to_string(),
+                    speed: Some(10.),
+                    speed_bands: None,
+                    overlay: None,
+                    distance_model: None,
+                    travel_time_uncertainty: None,
+                },
+                MatrixProfile {
+                    name: "car3".to_string(),
+                    speed: Some(5.),
+                    speed_bands: None,
+                    overlay: None,
+                    distance_model: None,
+                    travel_time_uncertainty: None,
+                },
+                MatrixProfile {
+                    name: "car4".to_string(),
+                    speed: None,
+                    speed_bands: None,
+                    overlay: None,
+                    distance_model: None,
+                    travel_time_uncertainty: None,
+                },
             ],
             ..create_default_fleet()
         },
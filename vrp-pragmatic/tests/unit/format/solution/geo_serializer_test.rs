@@ -11,7 +11,7 @@ fn can_create_geo_json_from_solution() {
         plan: Plan {
             jobs: vec![
                 create_delivery_job("job1", (1., 0.)),
-                create_delivery_job_with_demand("job2", (2., 0.), vec![10]),
+                create_delivery_job_with_demand("job2", (2., 0.), vec![10.]),
                 create_delivery_job("job3", (3., 0.)),
             ],
             ..create_empty_plan()
@@ -14,8 +14,8 @@ fn create_basic_problem(breaks: Option<Vec<VehicleBreak>>) -> Problem {
                 create_delivery_job("job1", (1., 0.)),
                 create_multi_job(
                     "job2",
-                    vec![((2., 0.), 1., vec![1]), ((3., 0.), 1., vec![1])],
-                    vec![((4., 0.), 1., vec![2])],
+                    vec![((2., 0.), 1., vec![1.]), ((3., 0.), 1., vec![1.])],
+                    vec![((4., 0.), 1., vec![2.])],
                 ),
                 create_pickup_job("job3", (5., 0.)),
             ],
@@ -52,6 +52,7 @@ fn create_unassigned_jobs(job_ids: &[&str]) -> Option<Vec<UnassignedJob>> {
                     description: "unknown".to_string(),
                     details: None,
                 }],
+                remaining: None,
             })
             .collect(),
     )
@@ -87,23 +88,23 @@ fn can_read_basic_init_solution() {
         .tour(
             TourBuilder::default()
                 .stops(vec![
-                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1.]).build_departure(),
                     StopBuilder::default()
                         .coordinate((1., 0.))
                         .schedule_stamp(1., 2.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(1)
                         .build_single("job1", "delivery"),
                     StopBuilder::default()
                         .coordinate((2., 0.))
                         .schedule_stamp(3., 4.)
-                        .load(vec![1])
+                        .load(vec![1.])
                         .distance(2)
                         .build_single_tag("job2", "pickup", "p1"),
                     StopBuilder::default()
                         .coordinate((3., 0.))
                         .schedule_stamp(5., 8.)
-                        .load(vec![2])
+                        .load(vec![2.])
                         .distance(3)
                         .activity(
                             ActivityBuilder::pickup()
@@ -118,13 +119,13 @@ fn can_read_basic_init_solution() {
                     StopBuilder::default()
                         .coordinate((4., 0.))
                         .schedule_stamp(9., 10.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(4)
                         .build_single_tag("job2", "delivery", "d1"),
                     StopBuilder::default()
                         .coordinate((0., 0.))
                         .schedule_stamp(14., 14.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(8)
                         .build_arrival(),
                 ])
@@ -165,11 +166,11 @@ fn can_handle_commute_error_in_init_solution() {
         .tour(
             TourBuilder::default()
                 .stops(vec![
-                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1.]).build_departure(),
                     StopBuilder::default()
                         .coordinate((1., 0.))
                         .schedule_stamp(1., 2.)
-                        .load(vec![0])
+                        .load(vec![0.])
                         .distance(1)
                         .activity(
                             ActivityBuilder::delivery()
@@ -189,3 +190,38 @@ fn can_handle_commute_error_in_init_solution() {
 
     assert_eq!(result_solution, Err("commute property in initial solution is not supported".into()));
 }
+
+#[test]
+fn can_handle_unknown_shift_location_in_init_solution() {
+    let problem = create_basic_problem(None);
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((5., 0.)).schedule_stamp(0., 0.).load(vec![1.]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(5., 6.)
+                        .load(vec![0.])
+                        .distance(4)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(7., 7.)
+                        .load(vec![0.])
+                        .distance(5)
+                        .build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+
+    let result_solution = get_init_solution(problem, &solution);
+
+    assert_eq!(
+        result_solution,
+        Err("tour of vehicle 'my_vehicle_1' with shift index 0 starts or ends at location which is not its shift \
+             location or one of its alternatives"
+            .into())
+    );
+}
@@ -50,24 +50,24 @@ fn can_create_solution() {
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(0., 0.)
-                            .load(vec![2])
+                            .load(vec![2.])
                             .build_departure(),
                         StopBuilder::default()
                             .coordinate((10., 0.))
                             .schedule_stamp(10., 11.)
-                            .load(vec![1])
+                            .load(vec![1.])
                             .distance(10)
                             .build_single("job2", "delivery"),
                         StopBuilder::default()
                             .coordinate((5., 0.))
                             .schedule_stamp(16., 17.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(15)
                             .build_single("job1", "delivery"),
                         StopBuilder::default()
                             .coordinate((0., 0.))
                             .schedule_stamp(22., 22.)
-                            .load(vec![0])
+                            .load(vec![0.])
                             .distance(20)
                             .build_arrival(),
                     ])
@@ -210,3 +210,17 @@ fn can_merge_required_break_on_stop_arrival_time_properly() {
     assert_eq!(tour.stops.len(), 3);
     assert_eq!(get_ids_from_tour(&tour).into_iter().flatten().filter(|id| id == "break").count(), 1);
 }
+
+#[test]
+fn can_serialize_integral_load_as_integers() {
+    let stop = TransitStop {
+        time: Schedule { arrival: format_time(0.), departure: format_time(10.) },
+        load: vec![10., 0., 2.5],
+        activities: vec![],
+    };
+
+    let json = serde_json::to_string(&stop).unwrap();
+
+    assert!(json.contains(r#""load":[10,0,2.5]"#), "{json}");
+    assert_eq!(serde_json::from_str::<TransitStop>(json.as_str()).unwrap(), stop);
+}
@@ -0,0 +1,157 @@
+use super::*;
+use crate::format::Location;
+use crate::helpers::*;
+
+parameterized_test! {can_localize_time, (time, timezone, expected), {
+    can_localize_time_impl(time, timezone, expected);
+}}
+
+can_localize_time! {
+    case01_winter_time: ("2024-01-15T09:00:00", Some("Europe/Berlin"), "2024-01-15T09:00:00+01:00"),
+    case02_summer_time: ("2024-07-15T09:00:00", Some("Europe/Berlin"), "2024-07-15T09:00:00+02:00"),
+    case03_other_zone: ("2024-07-15T09:00:00", Some("America/New_York"), "2024-07-15T09:00:00-04:00"),
+    case04_dst_gap: ("2024-03-31T02:30:00", Some("Europe/Berlin"), "2024-03-31T02:30:00+01:00"),
+    case05_dst_ambiguous: ("2024-10-27T02:30:00", Some("Europe/Berlin"), "2024-10-27T02:30:00+02:00"),
+    case06_offset_is_kept: ("2024-07-15T09:00:00Z", Some("Europe/Berlin"), "2024-07-15T09:00:00Z"),
+    case07_no_timezone: ("2024-07-15T09:00:00", None, "2024-07-15T09:00:00"),
+    case08_unknown_timezone: ("2024-07-15T09:00:00", Some("Mars/Olympus"), "2024-07-15T09:00:00"),
+}
+
+fn can_localize_time_impl(time: &str, timezone: Option<&str>, expected: &str) {
+    let timezone = get_timezone(&timezone.map(|name| name.to_string()), None);
+    let mut time = time.to_string();
+
+    localize_time(&mut time, timezone);
+
+    assert_eq!(time, expected);
+}
+
+#[test]
+fn can_resolve_dst_gap_as_absolute_time() {
+    let mut time = "2024-03-31T02:30:00".to_string();
+
+    localize_time(&mut time, timezones::get_by_name("Europe/Berlin"));
+
+    // NOTE 02:30 does not exist, so it is shifted forward to 03:30 local summer time
+    assert_eq!(parse_time(&time), parse_time("2024-03-31T03:30:00+02:00"));
+}
+
+#[test]
+fn can_format_time_with_timezone() {
+    let timezone = timezones::get_by_name("Asia/Kolkata").unwrap();
+    let time = parse_time("2024-07-15T09:00:00Z");
+
+    assert_eq!(format_time_with_timezone(time, timezone), "2024-07-15T14:30:00+05:30");
+}
+
+#[test]
+fn can_fallback_to_utc_when_formatting_time_out_of_timezone_range() {
+    let timezone = timezones::get_by_name("Asia/Kolkata").unwrap();
+    let time = parse_time("9999-12-31T23:00:00Z");
+
+    assert_eq!(format_time_with_timezone(time, timezone), "9999-12-31T23:00:00Z");
+}
+
+#[test]
+fn can_localize_problem_using_place_and_shift_timezones() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace {
+                        times: Some(vec![vec!["2024-07-15T09:00:00".to_string(), "2024-07-15T10:00:00".to_string()]]),
+                        timezone: Some("America/New_York".to_string()),
+                        ..create_job_place((1., 0.), None)
+                    }],
+                    demand: Some(vec![1.]),
+                    order: None,
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: "2024-07-15T08:00:00".to_string(),
+                        latest: None,
+                        location: Location::new_coordinate(0., 0.),
+                        timezone: None,
+                        alternatives: None,
+                    },
+                    end: Some(ShiftEnd {
+                        earliest: None,
+                        latest: "2024-07-15T18:00:00".to_string(),
+                        location: Location::new_coordinate(0., 0.),
+                        timezone: Some("Europe/London".to_string()),
+                        alternatives: None,
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        timezone: Some("Europe/Berlin".to_string()),
+        matrix_storage: None,
+        ..create_empty_problem()
+    };
+
+    let problem = localize_problem(problem);
+
+    let place = &problem.plan.jobs[0].deliveries.as_ref().unwrap()[0].places[0];
+    assert_eq!(
+        place.times,
+        Some(vec![vec!["2024-07-15T09:00:00-04:00".to_string(), "2024-07-15T10:00:00-04:00".to_string()]])
+    );
+    let shift = &problem.fleet.vehicles[0].shifts[0];
+    assert_eq!(shift.start.earliest, "2024-07-15T08:00:00+02:00");
+    assert_eq!(shift.end.as_ref().unwrap().latest, "2024-07-15T18:00:00+01:00");
+}
+
+#[test]
+fn can_skip_timezone_index_without_timezones() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+
+    assert!(TimezoneIndex::new(&problem, &coord_index).is_none());
+}
+
+#[test]
+fn can_get_location_timezone_with_fallback_to_default() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                Job {
+                    deliveries: Some(vec![JobTask {
+                        places: vec![JobPlace {
+                            timezone: Some("Asia/Tokyo".to_string()),
+                            ..create_job_place((2., 0.), None)
+                        }],
+                        demand: Some(vec![1.]),
+                        order: None,
+                    }]),
+                    ..create_job("job2")
+                },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        timezone: Some("Europe/Paris".to_string()),
+        matrix_storage: None,
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let get_location = |location: (f64, f64)| coord_index.get_by_loc(&location.to_loc()).unwrap();
+
+    let index = TimezoneIndex::new(&problem, &coord_index).expect("should have timezone index");
+
+    assert_eq!(index.get(get_location((1., 0.))).map(|tz| tz.name()), Some("Europe/Paris"));
+    assert_eq!(index.get(get_location((2., 0.))).map(|tz| tz.name()), Some("Asia/Tokyo"));
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::format::problem::DistanceType;
 use crate::format::Location;
 use vrp_core::models::common::Profile;
 use vrp_core::models::problem::{create_matrix_transport_cost, MatrixData};
@@ -21,12 +22,60 @@ fn can_calculate_distance_between_two_locations() {
     assert_eq!(distance.round(), 5078.);
 }
 
+parameterized_test! {can_calculate_distance_using_distance_type, (distance_type, l1, l2, expected), {
+    can_calculate_distance_using_distance_type_impl(distance_type, l1, l2, expected);
+}}
+
+can_calculate_distance_using_distance_type! {
+    case01_geodesic: (DistanceType::Geodesic, (-37.951_033_416, 144.424_867_889), (-37.652_821_139, 143.926_495_528), 54972.271),
+    case02_geodesic_same: (DistanceType::Geodesic, (52.52599, 13.45413), (52.52599, 13.45413), 0.),
+    case03_geodesic_equator: (DistanceType::Geodesic, (0., 0.), (0., 1.), 111319.491),
+    case04_euclidean: (DistanceType::Euclidean, (0., 0.), (300., 400.), 500.),
+    case05_manhattan: (DistanceType::Manhattan, (0., 0.), (300., 400.), 700.),
+    case06_manhattan_negative: (DistanceType::Manhattan, (100., 0.), (-200., -400.), 700.),
+}
+
+fn can_calculate_distance_using_distance_type_impl(
+    distance_type: DistanceType,
+    l1: (f64, f64),
+    l2: (f64, f64),
+    expected: f64,
+) {
+    let l1 = Location::Coordinate { lat: l1.0, lng: l1.1 };
+    let l2 = Location::Coordinate { lat: l2.0, lng: l2.1 };
+
+    let distance = get_distance(distance_type, &l1, &l2);
+
+    assert!((distance - expected).abs() < 1E-3, "distance: {distance}, expected: {expected}");
+}
+
+#[test]
+fn can_use_different_profiles() {
+    let locations = vec![Location::Coordinate { lat: 0., lng: 0. }, Location::Coordinate { lat: 400., lng: 300. }];
+    let profiles = [
+        ApproxProfile::new(DistanceType::Euclidean, 1., 10.),
+        ApproxProfile::new(DistanceType::Euclidean, 1.5, 5.),
+        ApproxProfile::new(DistanceType::Manhattan, 1., 10.),
+    ];
+
+    let approx_data = get_approx_transportation(&locations, &profiles);
+
+    assert_eq!(
+        approx_data,
+        vec![
+            (vec![0, 50, 50, 0], vec![0, 500, 500, 0]),
+            (vec![0, 150, 150, 0], vec![0, 750, 750, 0]),
+            (vec![0, 70, 70, 0], vec![0, 700, 700, 0]),
+        ]
+    );
+}
+
 #[test]
 fn can_use_approximated_with_matrix_costs() {
     let profile = Profile::default();
     let locations = get_test_locations();
     let speed = 10.;
-    let approx_data = get_approx_transportation(&locations, &[speed]);
+    let approx_data = get_approx_transportation(&locations, &[ApproxProfile::new(DistanceType::Haversine, 1., speed)]);
     assert_eq!(approx_data.len(), 1);
 
     let (durations, distances) = approx_data.first().unwrap();
@@ -1,4 +1,5 @@
 use super::*;
+use crate::format_time;
 use crate::helpers::*;
 
 fn assert_result(code: &str, action: &str, result: Option<FormatError>) {
@@ -63,7 +64,7 @@ fn can_detect_negative_duration() {
 fn can_detect_negative_demand() {
     let problem = Problem {
         plan: Plan {
-            jobs: vec![create_delivery_job_with_demand("job1", (1., 0.), vec![0, -1])],
+            jobs: vec![create_delivery_job_with_demand("job1", (1., 0.), vec![0., -1.])],
             ..create_empty_plan()
         },
         ..create_empty_problem()
@@ -73,3 +74,258 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+fn create_transfer_point(id: &str, duration: f64) -> TransferPoint {
+    TransferPoint { id: id.to_string(), location: (10., 0.).to_loc(), duration, times: None, timezone: None }
+}
+
+parameterized_test! {can_detect_invalid_transfer_points, (transfers, expected), {
+    can_detect_invalid_transfer_points_impl(transfers, expected);
+}}
+
+can_detect_invalid_transfer_points! {
+    case01_valid: (vec![("hub1", 1.), ("hub2", 1.)], None),
+    case02_duplicate_ids: (vec![("hub1", 1.), ("hub1", 1.)], Some("hub1")),
+    case03_negative_duration: (vec![("hub1", 1.), ("hub2", -1.)], Some("hub2")),
+}
+
+fn can_detect_invalid_transfer_points_impl(transfers: Vec<(&str, f64)>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            transfers: Some(transfers.into_iter().map(|(id, duration)| create_transfer_point(id, duration)).collect()),
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1108_correct_transfer_points(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(action) = expected {
+        assert_result("E1108", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_job_transfers, (job, has_relation, expected), {
+    can_detect_invalid_job_transfers_impl(job, has_relation, expected);
+}}
+
+can_detect_invalid_job_transfers! {
+    case01_valid: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), false, None),
+    case02_unknown_transfer: (Job {
+        transfers: Some(vec!["unknown".to_string()]),
+        ..create_pickup_delivery_job("job1", (1., 0.), (2., 0.))
+    }, false, Some("job1")),
+    case03_delivery_job: (create_delivery_job("job1", (1., 0.)), false, Some("job1")),
+    case04_group: (Job {
+        group: Some("group".to_string()),
+        ..create_pickup_delivery_job("job1", (1., 0.), (2., 0.))
+    }, false, Some("job1")),
+    case05_relation: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), true, Some("job1")),
+}
+
+fn can_detect_invalid_job_transfers_impl(job: Job, has_relation: bool, expected: Option<&str>) {
+    let job = Job { transfers: job.transfers.or_else(|| Some(vec!["hub".to_string()])), ..job };
+    let problem = Problem {
+        plan: Plan {
+            relations: if has_relation {
+                Some(vec![Relation {
+                    type_field: RelationType::Any,
+                    jobs: vec![job.id.clone()],
+                    vehicle_id: "my_vehicle_1".to_string(),
+                    shift_index: None,
+                }])
+            } else {
+                None
+            },
+            jobs: vec![job],
+            transfers: Some(vec![create_transfer_point("hub", 1.)]),
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1109_correct_job_transfers(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(action) = expected {
+        assert_result("E1109", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_job_affinity, (group, weight, expected), {
+    can_detect_invalid_job_affinity_impl(group, weight, expected);
+}}
+
+can_detect_invalid_job_affinity! {
+    case01_valid: ("group", Some(2.), None),
+    case02_default_weight: ("group", None, None),
+    case03_empty_group: ("", Some(1.), Some("job1")),
+    case04_zero_weight: ("group", Some(0.), Some("job1")),
+    case05_negative_weight: ("group", Some(-1.), Some("job1")),
+}
+
+fn can_detect_invalid_job_affinity_impl(group: &str, weight: Option<f64>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                affinity: Some(JobAffinity { group: group.to_string(), weight }),
+                ..create_delivery_job("job1", (1., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1110_correct_job_affinity(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(action) = expected {
+        assert_result("E1110", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_job_conflicts, (conflicts, group, expected), {
+    can_detect_invalid_job_conflicts_impl(conflicts, group, expected);
+}}
+
+can_detect_invalid_job_conflicts! {
+    case01_valid: (vec!["job2"], None, None),
+    case02_unknown_job: (vec!["job3"], None, Some("job1")),
+    case03_self_conflict: (vec!["job1"], None, Some("job1")),
+    case04_same_group: (vec!["job2"], Some("group"), Some("job1")),
+}
+
+fn can_detect_invalid_job_conflicts_impl(conflicts: Vec<&str>, group: Option<&str>, expected: Option<&str>) {
+    let group = group.map(|group| group.to_string());
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    conflicts: Some(conflicts.into_iter().map(|id| id.to_string()).collect()),
+                    group: group.clone(),
+                    ..create_delivery_job("job1", (1., 0.))
+                },
+                Job { group, ..create_delivery_job("job2", (2., 0.)) },
+            ],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1111_correct_job_conflicts(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(action) = expected {
+        assert_result("E1111", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_job_skill_levels, (levels, expected), {
+    can_detect_invalid_job_skill_levels_impl(levels, expected);
+}}
+
+can_detect_invalid_job_skill_levels! {
+    case01_valid: (vec![("a", 1), ("b", 2)], None),
+    case02_zero_level: (vec![("a", 0)], Some("job1")),
+    case03_duplicated_skill: (vec![("a", 1), ("a", 2)], Some("job1")),
+}
+
+fn can_detect_invalid_job_skill_levels_impl(levels: Vec<(&str, u32)>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                skills: Some(JobSkills {
+                    min_levels: Some(
+                        levels.into_iter().map(|(name, level)| SkillLevel { name: name.to_string(), level }).collect(),
+                    ),
+                    ..all_of_skills(vec![])
+                }),
+                ..create_delivery_job("job1", (1., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1112_correct_job_skill_levels(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(action) = expected {
+        assert_result("E1112", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_job_value_decay, (value, points, expected), {
+    can_detect_invalid_job_value_decay_impl(value, points, expected);
+}}
+
+can_detect_invalid_job_value_decay! {
+    case01_valid: (Some(10.), vec![(0., 1.), (10., 0.)], None),
+    case02_no_value: (None, vec![(0., 1.), (10., 0.)], Some("job1")),
+    case03_empty: (Some(10.), vec![], Some("job1")),
+    case04_unsorted: (Some(10.), vec![(10., 1.), (0., 0.)], Some("job1")),
+    case05_negative_factor: (Some(10.), vec![(0., -1.)], Some("job1")),
+}
+
+fn can_detect_invalid_job_value_decay_impl(value: Option<f64>, points: Vec<(f64, f64)>, expected: Option<&str>) {
+    let value_decay = points
+        .into_iter()
+        .map(|(time, factor)| JobValueDecayPoint { time: format_time(time), factor })
+        .collect::<Vec<_>>();
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { value, value_decay: Some(value_decay), ..create_delivery_job("job1", (1., 0.)) }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1113_correct_job_value_decay(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(action) = expected {
+        assert_result("E1113", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_invalid_job_divisible, (job, parts, expected), {
+    can_detect_invalid_job_divisible_impl(job, parts, expected);
+}}
+
+can_detect_invalid_job_divisible! {
+    case01_valid: (create_service_job("job1", (1., 0.)), 2, None),
+    case02_single_part: (create_service_job("job1", (1., 0.)), 1, None),
+    case03_zero_parts: (create_service_job("job1", (1., 0.)), 0, Some("job1")),
+    case04_delivery: (create_delivery_job("job1", (1., 0.)), 2, None),
+    case05_pickup_delivery: (create_pickup_delivery_job("job1", (1., 0.), (2., 0.)), 2, Some("job1")),
+    case06_too_many_parts: (create_delivery_job("job1", (1., 0.)), 33, Some("job1")),
+}
+
+fn can_detect_invalid_job_divisible_impl(job: Job, parts: usize, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { divisible: Some(JobDivisible { parts }), ..job }], ..create_empty_plan() },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1114_correct_job_divisible(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    if let Some(action) = expected {
+        assert_result("E1114", action, result);
+    } else {
+        assert!(result.is_none());
+    }
+}
@@ -206,3 +206,77 @@ fn can_detect_missing_value_objective_impl(objectives: Option<Vec<Objective>>, e
 
     assert_eq!(result.err().map(|e| e.code), expected);
 }
+
+parameterized_test! {can_detect_affinity_objective_mismatch, (has_affinity_job, objectives, expected), {
+    can_detect_affinity_objective_mismatch_impl(has_affinity_job, objectives, expected);
+}}
+
+can_detect_affinity_objective_mismatch! {
+    case01_redundant: (false, vec![MinimizeUnassigned { breaks: None }, JobAffinity, MinimizeCost], Some("E1608")),
+    case02_missing: (true, vec![MinimizeUnassigned { breaks: None }, MinimizeCost], Some("E1609")),
+    case03_valid: (true, vec![MinimizeUnassigned { breaks: None }, JobAffinity, MinimizeCost], None),
+    case04_valid_multi: (true, vec![
+                MinimizeUnassigned { breaks: None },
+                MultiObjective { strategy: MultiStrategy::Sum, objectives: vec![MinimizeCost, JobAffinity] },
+            ], None),
+}
+
+fn can_detect_affinity_objective_mismatch_impl(
+    has_affinity_job: bool,
+    objectives: Vec<Objective>,
+    expected: Option<&str>,
+) {
+    let affinity = if has_affinity_job {
+        Some(crate::format::problem::JobAffinity { group: "group".to_string(), weight: None })
+    } else {
+        None
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { affinity, ..create_delivery_job("job1", (1., 0.)) }], ..create_empty_plan() },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1608_no_jobs_with_affinity_objective(&ctx, &objectives)
+        .and_then(|_| check_e1609_jobs_with_affinity_but_no_objective(&ctx, &objectives));
+
+    assert_eq!(result.err().map(|e| e.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_backhaul_objective_mismatch, (backhaul, objectives, expected), {
+    can_detect_backhaul_objective_mismatch_impl(backhaul, objectives, expected);
+}}
+
+can_detect_backhaul_objective_mismatch! {
+    case01_redundant: (None, vec![MinimizeUnassigned { breaks: None }, Backhaul, MinimizeCost], Some("E1610")),
+    case02_redundant_strict: (Some(VehicleBackhaul::Strict), vec![MinimizeUnassigned { breaks: None }, Backhaul, MinimizeCost], Some("E1610")),
+    case03_missing: (Some(VehicleBackhaul::Mixed { penalty: 10. }), vec![MinimizeUnassigned { breaks: None }, MinimizeCost], Some("E1611")),
+    case04_valid: (Some(VehicleBackhaul::Mixed { penalty: 10. }), vec![MinimizeUnassigned { breaks: None }, Backhaul, MinimizeCost], None),
+    case05_valid_strict: (Some(VehicleBackhaul::Strict), vec![MinimizeUnassigned { breaks: None }, MinimizeCost], None),
+}
+
+fn can_detect_backhaul_objective_mismatch_impl(
+    backhaul: Option<VehicleBackhaul>,
+    objectives: Vec<Objective>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { backhaul, ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap();
+
+    let result = check_e1610_no_vehicles_with_backhaul_objective(&ctx, &objectives)
+        .and_then(|_| check_e1611_vehicles_with_backhaul_but_no_objective(&ctx, &objectives));
+
+    assert_eq!(result.err().map(|e| e.code), expected.map(|code| code.to_string()));
+}
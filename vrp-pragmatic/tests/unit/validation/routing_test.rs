@@ -1,4 +1,5 @@
 use super::*;
+use crate::format::Location;
 use crate::helpers::*;
 
 #[test]
@@ -6,8 +7,22 @@ fn can_detect_duplicates() {
     let problem = Problem {
         fleet: Fleet {
             profiles: vec![
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
-                MatrixProfile { name: "my_vehicle".to_string(), speed: None },
+                MatrixProfile {
+                    name: "my_vehicle".to_string(),
+                    speed: None,
+                    speed_bands: None,
+                    overlay: None,
+                    distance_model: None,
+                    travel_time_uncertainty: None,
+                },
+                MatrixProfile {
+                    name: "my_vehicle".to_string(),
+                    speed: None,
+                    speed_bands: None,
+                    overlay: None,
+                    distance_model: None,
+                    travel_time_uncertainty: None,
+                },
             ],
             ..create_default_fleet()
         },
@@ -102,7 +117,14 @@ fn can_detect_missing_profile() {
                 VehicleType { profile: create_vehicle_profile_with_name("car"), ..create_default_vehicle_type() },
                 VehicleType { profile: create_vehicle_profile_with_name("truck"), ..create_default_vehicle_type() },
             ],
-            profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                speed_bands: None,
+                overlay: None,
+                distance_model: None,
+                travel_time_uncertainty: None,
+            }],
             ..create_default_fleet()
         },
         ..create_empty_problem()
@@ -114,3 +136,195 @@ fn can_detect_missing_profile() {
 
     assert_eq!(result.err().map(|err| err.code), Some("E1505".to_string()));
 }
+
+parameterized_test! {can_detect_invalid_speed_bands, (bands, timestamp, expected), {
+    can_detect_invalid_speed_bands_impl(bands, timestamp, expected);
+}}
+
+can_detect_invalid_speed_bands! {
+    case01_valid: (vec![("2020-07-04T09:00:00Z", 0.5), ("2020-07-04T10:00:00Z", 1.5)], None, None),
+    case02_not_sorted: (vec![("2020-07-04T10:00:00Z", 0.5), ("2020-07-04T09:00:00Z", 1.5)], None, Some("E1506")),
+    case03_bad_time: (vec![("2020-07-04", 0.5)], None, Some("E1506")),
+    case04_zero_factor: (vec![("2020-07-04T09:00:00Z", 0.)], None, Some("E1506")),
+    case05_timestamp: (vec![("2020-07-04T09:00:00Z", 0.5)], Some("2020-07-04T09:00:00Z"), Some("E1506")),
+}
+
+fn can_detect_invalid_speed_bands_impl(bands: Vec<(&str, f64)>, timestamp: Option<&str>, expected: Option<&str>) {
+    let speed_bands = bands.into_iter().map(|(start, factor)| SpeedBand { start: start.to_string(), factor }).collect();
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                speed_bands: Some(speed_bands),
+                overlay: None,
+                distance_model: None,
+                travel_time_uncertainty: None,
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrices = vec![Matrix {
+        profile: Some("car".to_string()),
+        timestamp: timestamp.map(|timestamp| timestamp.to_string()),
+        travel_times: vec![1; 4],
+        distances: vec![1; 4],
+        error_codes: None,
+    }];
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1506_speed_bands(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_matrix_overlays, (base, matrix_profiles, duration, expected), {
+    can_detect_invalid_matrix_overlays_impl(base, matrix_profiles, duration, expected);
+}}
+
+can_detect_invalid_matrix_overlays! {
+    case01_valid: ("car", vec!["car"], Some(10.), None),
+    case02_unknown_base: ("bike", vec!["car"], None, Some("E1507")),
+    case03_base_with_overlay: ("truck", vec!["car"], None, Some("E1507")),
+    case04_own_matrix: ("car", vec!["car", "truck"], None, Some("E1507")),
+    case05_negative_value: ("car", vec!["car"], Some(-1.), Some("E1507")),
+}
+
+fn can_detect_invalid_matrix_overlays_impl(
+    base: &str,
+    matrix_profiles: Vec<&str>,
+    duration: Option<f64>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![
+                MatrixProfile {
+                    name: "car".to_string(),
+                    speed: None,
+                    speed_bands: None,
+                    overlay: None,
+                    distance_model: None,
+                    travel_time_uncertainty: None,
+                },
+                MatrixProfile {
+                    name: "truck".to_string(),
+                    speed: None,
+                    speed_bands: None,
+                    overlay: Some(MatrixOverlay {
+                        base: base.to_string(),
+                        unreachable: Some(vec![Location::new_reference(1)]),
+                        edges: Some(vec![MatrixOverlayEdge {
+                            from: Location::new_reference(0),
+                            to: Location::new_reference(1),
+                            duration,
+                            distance: None,
+                        }]),
+                    }),
+                    distance_model: None,
+                    travel_time_uncertainty: None,
+                },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrices = matrix_profiles
+        .into_iter()
+        .map(|profile| Matrix {
+            profile: Some(profile.to_string()),
+            timestamp: None,
+            travel_times: vec![1; 4],
+            distances: vec![1; 4],
+            error_codes: None,
+        })
+        .collect::<Vec<_>>();
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1507_matrix_overlays(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_distance_models, (detour_factor, speed, has_overlay, expected), {
+    can_detect_invalid_distance_models_impl(detour_factor, speed, has_overlay, expected);
+}}
+
+can_detect_invalid_distance_models! {
+    case01_valid: (Some(1.3), Some(10.), false, None),
+    case02_default_values: (None, None, false, None),
+    case03_zero_detour: (Some(0.), None, false, Some("E1508")),
+    case04_negative_speed: (None, Some(-1.), false, Some("E1508")),
+    case05_infinite_detour: (Some(f64::INFINITY), None, false, Some("E1508")),
+    case06_overlay: (None, None, true, Some("E1508")),
+}
+
+fn can_detect_invalid_distance_models_impl(
+    detour_factor: Option<f64>,
+    speed: Option<f64>,
+    has_overlay: bool,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed,
+                speed_bands: None,
+                overlay: if has_overlay {
+                    Some(MatrixOverlay { base: "truck".to_string(), unreachable: None, edges: None })
+                } else {
+                    None
+                },
+                distance_model: Some(DistanceModel { distance_type: DistanceType::Euclidean, detour_factor }),
+                travel_time_uncertainty: None,
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1508_distance_models(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_travel_time_uncertainty, (deviation, probability, expected), {
+    can_detect_invalid_travel_time_uncertainty_impl(deviation, probability, expected);
+}}
+
+can_detect_invalid_travel_time_uncertainty! {
+    case01_valid: (0.2, 0.95, None),
+    case02_zero_deviation: (0., 0.5, None),
+    case03_negative_deviation: (-0.2, 0.95, Some("E1509")),
+    case04_low_probability: (0.2, 0.3, Some("E1509")),
+    case05_certain_probability: (0.2, 1., Some("E1509")),
+}
+
+fn can_detect_invalid_travel_time_uncertainty_impl(deviation: f64, probability: f64, expected: Option<&str>) {
+    let problem = Problem {
+        fleet: Fleet {
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                speed_bands: None,
+                overlay: None,
+                distance_model: None,
+                travel_time_uncertainty: Some(TravelTimeUncertainty { deviation, probability }),
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1509_travel_time_uncertainty(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
@@ -0,0 +1,42 @@
+use super::*;
+use crate::helpers::*;
+
+parameterized_test! {can_detect_unknown_timezones, (problem_tz, place_tz, expected), {
+    can_detect_unknown_timezones_impl(problem_tz, place_tz, expected);
+}}
+
+can_detect_unknown_timezones! {
+    case01_no_timezones: (None, None, None),
+    case02_known_timezones: (Some("Europe/Berlin"), Some("Asia/Tokyo"), None),
+    case03_unknown_problem_timezone: (Some("Berlin"), None, Some("E1400")),
+    case04_unknown_place_timezone: (Some("Europe/Berlin"), Some("GMT+25"), Some("E1400")),
+}
+
+fn can_detect_unknown_timezones_impl(problem_tz: Option<&str>, place_tz: Option<&str>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace {
+                        timezone: place_tz.map(|tz| tz.to_string()),
+                        ..create_job_place((1., 0.), None)
+                    }],
+                    demand: Some(vec![1.]),
+                    order: None,
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        timezone: problem_tz.map(|tz| tz.to_string()),
+        matrix_storage: None,
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+
+    let result = check_e1400_unknown_timezones(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
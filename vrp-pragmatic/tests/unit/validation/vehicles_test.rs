@@ -77,6 +77,8 @@ fn can_handle_rescheduling_with_required_break_impl(latest: Option<f64>, expecte
                         earliest: format_time(0.),
                         latest: latest.map(format_time),
                         location: (0., 0.).to_loc(),
+                        timezone: None,
+                        alternatives: None,
                     },
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 10., latest: 10. },
@@ -124,7 +126,7 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
                 ..create_default_vehicle_type()
             }],
             resources: resources.map(|ids| {
-                ids.iter().map(|id| VehicleResource::Reload { id: id.to_string(), capacity: vec![2] }).collect()
+                ids.iter().map(|id| VehicleResource::Reload { id: id.to_string(), capacity: vec![2.] }).collect()
             }),
             ..create_default_fleet()
         },
@@ -136,3 +138,193 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_handle_shift_alternatives, (start_costs, end_costs, expected), {
+    can_handle_shift_alternatives_impl(start_costs, end_costs, expected);
+}}
+
+can_handle_shift_alternatives! {
+    case01_no_alternatives: (None, None, None),
+    case02_valid_alternatives: (Some(vec![Some(1.), None]), Some(vec![Some(0.)]), None),
+    case03_empty_start: (Some(vec![]), None, Some("E1309".to_string())),
+    case04_empty_end: (None, Some(vec![]), Some("E1309".to_string())),
+    case05_negative_cost: (Some(vec![Some(-1.)]), None, Some("E1309".to_string())),
+    case06_max_combinations: (Some(vec![None; 3]), Some(vec![None; 3]), None),
+    case07_too_many_combinations: (Some(vec![None; 4]), Some(vec![None; 3]), Some("E1309".to_string())),
+}
+
+fn can_handle_shift_alternatives_impl(
+    start_costs: Option<Vec<Option<f64>>>,
+    end_costs: Option<Vec<Option<f64>>>,
+    expected: Option<String>,
+) {
+    let create_alternatives = |costs: Option<Vec<Option<f64>>>| {
+        costs.map(|costs| {
+            costs
+                .into_iter()
+                .enumerate()
+                .map(|(idx, cost)| ShiftLocation { location: (idx as f64 + 1., 0.).to_loc(), cost, tag: None })
+                .collect()
+        })
+    };
+    let shift = create_default_vehicle_shift();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { alternatives: create_alternatives(start_costs), ..shift.start.clone() },
+                    end: shift.end.clone().map(|end| ShiftEnd { alternatives: create_alternatives(end_costs), ..end }),
+                    ..shift
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1309_vehicle_shift_alternatives(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_satellite_resources, (job_ids, expected), {
+    can_detect_invalid_satellite_resources_impl(job_ids, expected);
+}}
+
+can_detect_invalid_satellite_resources! {
+    case01_valid: (vec!["cargo"], None),
+    case02_unknown_job: (vec!["unknown"], Some("E1310".to_string())),
+    case03_pickup_job: (vec!["pickup"], Some("E1310".to_string())),
+    case04_duplicated_job: (vec!["cargo", "cargo"], Some("E1310".to_string())),
+}
+
+fn can_detect_invalid_satellite_resources_impl(job_ids: Vec<&str>, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("cargo", (1., 0.)), create_pickup_job("pickup", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            resources: Some(
+                job_ids
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, job_id)| VehicleResource::Satellite {
+                        id: format!("satellite{idx}"),
+                        job_id: job_id.to_string(),
+                    })
+                    .collect(),
+            ),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1310_vehicle_satellite_resources(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_vehicle_skill_levels, (skills, expected), {
+    can_detect_invalid_vehicle_skill_levels_impl(skills, expected);
+}}
+
+can_detect_invalid_vehicle_skill_levels! {
+    case01_valid: (vec![("a", None), ("b", Some(2))], None),
+    case02_zero_level: (vec![("a", Some(0))], Some("E1311".to_string())),
+    case03_duplicated_leveled: (vec![("a", Some(1)), ("a", Some(2))], Some("E1311".to_string())),
+    case04_duplicated_plain: (vec![("a", None), ("a", Some(2))], Some("E1311".to_string())),
+}
+
+fn can_detect_invalid_vehicle_skill_levels_impl(skills: Vec<(&str, Option<u32>)>, expected: Option<String>) {
+    let skills = skills
+        .into_iter()
+        .map(|(name, level)| match level {
+            Some(level) => VehicleSkill::Leveled(SkillLevel { name: name.to_string(), level }),
+            None => VehicleSkill::Plain(name.to_string()),
+        })
+        .collect();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { skills: Some(skills), ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1311_vehicle_skill_levels(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_vehicle_trailer, (capacity, parkings, expected), {
+    can_detect_invalid_vehicle_trailer_impl(capacity, parkings, expected);
+}}
+
+can_detect_invalid_vehicle_trailer! {
+    case01_valid: (vec![2.], vec![(10., None)], None),
+    case02_no_parkings: (vec![2.], vec![], Some("E1312".to_string())),
+    case03_negative_capacity: (vec![-1.], vec![(10., None)], Some("E1312".to_string())),
+    case04_negative_duration: (vec![2.], vec![(-1., None)], Some("E1312".to_string())),
+    case05_invalid_times: (vec![2.], vec![(10., Some(vec![vec![format_time(10.), format_time(5.)]]))], Some("E1312".to_string())),
+}
+
+fn can_detect_invalid_vehicle_trailer_impl(
+    capacity: Vec<f64>,
+    parkings: Vec<(f64, Option<Vec<Vec<String>>>)>,
+    expected: Option<String>,
+) {
+    let parkings = parkings
+        .into_iter()
+        .map(|(duration, times)| VehicleTrailerParking {
+            location: (1., 0.).to_loc(),
+            duration,
+            times,
+            tag: None,
+            timezone: None,
+        })
+        .collect();
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                trailer: Some(VehicleTrailer { capacity, parkings }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1312_vehicle_trailer(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_vehicle_backhaul, (backhaul, expected), {
+    can_detect_invalid_vehicle_backhaul_impl(backhaul, expected);
+}}
+
+can_detect_invalid_vehicle_backhaul! {
+    case01_strict: (VehicleBackhaul::Strict, None),
+    case02_mixed: (VehicleBackhaul::Mixed { penalty: 10. }, None),
+    case03_mixed_zero: (VehicleBackhaul::Mixed { penalty: 0. }, None),
+    case04_mixed_negative: (VehicleBackhaul::Mixed { penalty: -1. }, Some("E1313".to_string())),
+    case05_mixed_infinite: (VehicleBackhaul::Mixed { penalty: f64::INFINITY }, Some("E1313".to_string())),
+}
+
+fn can_detect_invalid_vehicle_backhaul_impl(backhaul: VehicleBackhaul, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType { backhaul: Some(backhaul), ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1313_vehicle_backhaul(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}